@@ -34,9 +34,15 @@ export function build_tx_vacuum_multi_order(
   circuit: ApiPromise,
   batchOrders: Order[],
   speedMode: number,
+  auctionMode: 'Open' | 'SealedBid' = 'Open',
 ) {
-  // @ts-ignore - augmeneted type from @polkadot/types
-  return circuit.tx.vacuum.order(
+  const order =
+    auctionMode === 'SealedBid'
+      ? // @ts-ignore - augmeneted type from @polkadot/types
+        circuit.tx.vacuum.sealedBidOrder
+      : // @ts-ignore - augmeneted type from @polkadot/types
+        circuit.tx.vacuum.order
+  return order(
     batchOrders.map((order: Order) => {
       return {
         sfx_action: {
@@ -85,6 +85,7 @@ export class CircuitRelayer extends EventEmitter {
         encoded_payload: sfx.inclusionProof.encoded_payload,
         payload_proof: sfx.inclusionProof.payload_proof,
         block_hash: sfx.inclusionProof.block_hash,
+        event_pointer: sfx.inclusionProof.event_pointer,
       });
     } else {
       inclusionData = this.api.createType("ParachainInclusionProof", {
@@ -92,6 +93,7 @@ export class CircuitRelayer extends EventEmitter {
         payload_proof: sfx.inclusionProof.payload_proof,
         header_proof: sfx.inclusionProof.header_proof,
         relay_block_hash: sfx.inclusionProof.block_hash,
+        event_pointer: sfx.inclusionProof.event_pointer,
       });
     }
     logger.debug(
@@ -4,7 +4,12 @@ import { EventMapper, SideEffect } from "../../executionManager/sideEffect";
 import { getBalanceWithDecimals, getEventProofs } from "../../utils";
 import { SubmittableExtrinsic } from "@polkadot/api/promise/types";
 import { SfxType } from "@t3rn/sdk/side-effects/types";
-import { InclusionProof, RelayerEventData, RelayerEvents } from "../types";
+import {
+  EventRecordPointer,
+  InclusionProof,
+  RelayerEventData,
+  RelayerEvents,
+} from "../types";
 import Estimator from "./estimator";
 import { CostEstimator, Estimate } from "./estimator/cost";
 import { Sdk, Utils } from "@t3rn/sdk";
@@ -240,14 +245,16 @@ export class SubstrateRelayer extends EventEmitter {
   ): Promise<number> {
     const blockNumber = await this.getBlockNumber(blockHash);
     const event = this.getEvent(sfx.action, events as never);
+    const encodedEvent = (event as unknown as { toHex: () => string }).toHex();
     const inclusionProof = await getEventProofs(this.client, blockHash);
     const inclusionData: InclusionProof = {
-      encoded_payload: (event as unknown as { toHex: () => string }).toHex(),
+      encoded_payload: encodedEvent,
       payload_proof: {
         // @ts-ignore - property does not exist on type
         trieNodes: inclusionProof.toJSON().proof as string,
       },
       block_hash: blockHash,
+      event_pointer: await this.getEventRecordPointer(blockHash, encodedEvent),
     };
 
     if (sfx.target !== "roco") {
@@ -289,6 +296,40 @@ export class SubstrateRelayer extends EventEmitter {
     return blockNumber.toNumber();
   }
 
+  /**
+   * Locates an event within the System.Events of a block, so the circuit can verify that single record
+   *
+   * @param blockHash Hash of the block in which the event was emitted
+   * @param encodedEvent The SCALE encoded event
+   * @returns Pointer to the event record
+   */
+  async getEventRecordPointer(
+    blockHash: string,
+    encodedEvent: string,
+  ): Promise<EventRecordPointer> {
+    const apiAt = await this.client.at(blockHash);
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    const records = (await apiAt.query.system.events()) as any;
+    const eventIndex = records.findIndex(
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      (record: any) => record.event.toHex() === encodedEvent,
+    );
+
+    if (eventIndex === -1) {
+      throw new Error("Event not found in System.Events of the block");
+    }
+
+    const record = records[eventIndex];
+    return {
+      event_index: eventIndex,
+      extrinsic_index: record.phase.asApplyExtrinsic.toNumber(),
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      topics: record.topics.map((topic: any) => topic.toHex()),
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      event_lengths: records.map((item: any) => item.event.toU8a().length),
+    };
+  }
+
   /**
    * Fetches the block number of the relaychain block, containing the parachain block
    *
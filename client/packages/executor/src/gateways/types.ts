@@ -27,4 +27,18 @@ export type InclusionProof = {
     trieNodes: string;
   };
   block_hash: string;
+  event_pointer: EventRecordPointer;
+};
+
+/**
+ * Locates the proven event within the System.Events of the including block
+ *
+ * @category Substrate
+ * @group Gateways
+ */
+export type EventRecordPointer = {
+  event_index: number;
+  extrinsic_index: number;
+  topics: string[];
+  event_lengths: number[];
 };
@@ -59,6 +59,17 @@ impl AuthoritySet {
     }
 }
 
+/// A GRANDPA authority set change signalled by a bridged header, waiting to be enacted.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+pub struct PendingAuthorityChange<N> {
+    /// Authorities of the next set.
+    pub next_authorities: AuthorityList,
+    /// Height of the bridged chain at which the change takes effect (signal height + delay).
+    pub effective_at: N,
+    /// Forced changes don't wait for the current set to finalize the enactment height.
+    pub forced: bool,
+}
+
 /// Data required for initializing the bridge pallet.
 ///
 /// The bridge needs to know where to start its sync from, and this provides that initial context.
@@ -49,12 +49,12 @@ use bridges::{
 };
 use finality_grandpa::voter_set::VoterSet;
 use frame_support::{ensure, pallet_prelude::*, transactional, StorageHasher};
-use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor, RawOrigin};
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor, Phase, RawOrigin};
 
-use sp_consensus_grandpa::{ConsensusLog, GRANDPA_ENGINE_ID};
+use sp_consensus_grandpa::{ConsensusLog, SetId, GRANDPA_ENGINE_ID};
 use sp_core::crypto::ByteArray;
 use sp_runtime::traits::{BadOrigin, Header as HeaderT, Zero};
-use t3rn_primitives::light_client::LightClientAsyncAPI;
+use t3rn_primitives::light_client::{LightClientAsyncAPI, ScaleLayout};
 
 use sp_std::{convert::TryInto, vec, vec::Vec};
 use sp_trie::{read_trie_value, LayoutV1, StorageProof};
@@ -99,8 +99,9 @@ pub fn to_local_block_number<T: Config<I>, I: 'static>(
 }
 
 use crate::types::{
-    GrandpaHeaderData, ParachainInclusionProof, ParachainRegistrationData,
-    RelaychainInclusionProof, RelaychainRegistrationData,
+    EventRecordPointer, GrandpaHeaderData, OpaqueEventRecord, ParachainInclusionProof,
+    ParachainRegistrationData, ParachainTrieInclusionProof, RelaychainInclusionProof,
+    RelaychainRegistrationData, RelaychainTrieInclusionProof,
 };
 use frame_system::pallet_prelude::*;
 
@@ -108,6 +109,9 @@ use t3rn_primitives::ExecutionSource;
 
 use t3rn_primitives::light_client::InclusionReceipt;
 
+/// Number of the most recent authority sets kept in `AuthoritySetHistory`.
+pub const AUTHORITY_SET_HISTORY_DEPTH: SetId = 16;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -185,6 +189,16 @@ pub mod pallet {
         InvalidStorageProof,
         /// The event was not found in the specified block
         EventNotIncluded,
+        /// The proven events couldn't be split into event records with the given event pointer
+        InvalidEventRecords,
+        /// The selected event was emitted by a different pallet
+        UnexpectedEventPallet,
+        /// The selected event is a different variant of the pallet's events
+        UnexpectedEventVariant,
+        /// The selected event wasn't emitted by the given extrinsic
+        UnexpectedEventPhase,
+        /// The topics of the selected event don't match
+        UnexpectedEventTopics,
         /// The given bytes couldn't be decoded as a header
         HeaderDecodingError,
         /// The given bytes couldn't be decoded as header data
@@ -201,7 +215,7 @@ pub mod pallet {
         EventDecodingFailed,
         /// The side effect is not known for this vendor
         UnkownSideEffect,
-        /// A forced change was detected, which is not supported
+        /// An authority set change was signalled while another one is still pending
         UnsupportedScheduledChange,
         /// The pallet is currently halted
         Halted,
@@ -258,6 +272,20 @@ pub mod pallet {
     pub(super) type CurrentAuthoritySet<T: Config<I>, I: 'static = ()> =
         StorageValue<_, bp_header_chain::AuthoritySet, OptionQuery>;
 
+    /// Authority sets the bridged chain went through, by their set_id.
+    /// Only the last AUTHORITY_SET_HISTORY_DEPTH sets are kept.
+    #[pallet::storage]
+    pub(super) type AuthoritySetHistory<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, SetId, bp_header_chain::AuthoritySet>;
+
+    /// Authority set change signalled by an imported header, which is yet to be enacted.
+    #[pallet::storage]
+    pub(super) type PendingAuthoritySetChange<T: Config<I>, I: 'static = ()> = StorageValue<
+        _,
+        bp_header_chain::PendingAuthorityChange<BridgedBlockNumber<T, I>>,
+        OptionQuery,
+    >;
+
     /// Maps a parachain chain_id to the corresponding chain ID.
     #[pallet::storage]
     pub(super) type ParachainIdMap<T: Config<I>, I: 'static = ()> =
@@ -350,56 +378,106 @@ pub mod pallet {
             <ImportedHashesPointer<T, I>>::kill(); // one ahead of first value
             <RelayChainId<T, I>>::kill();
             <CurrentAuthoritySet<T, I>>::kill();
+            for _ in <AuthoritySetHistory<T, I>>::drain() {}
+            <PendingAuthoritySetChange<T, I>>::kill();
             <IsHalted<T, I>>::kill();
             <PalletOwner<T, I>>::kill();
             Ok(().into())
         }
     }
 
-    /// Check the given header for a GRANDPA scheduled authority set change. If a change
-    /// is found it will be enacted immediately.
+    /// Check the given header for a GRANDPA forced authority set change signal.
     ///
-    /// This function does not support forced changes, or scheduled changes with delays
-    /// since these types of changes are indicitive of abnormal behaviour from GRANDPA.
-    pub(crate) fn try_enact_authority_change_single<T: Config<I>, I: 'static>(
+    /// Forced changes are signalled when the current set has stalled, so they replace whatever
+    /// change is pending, and are noted ahead of verification as the stalled set can't finalize
+    /// the range signalling them.
+    pub(crate) fn note_forced_change_single<T: Config<I>, I: 'static>(
         header: &BridgedHeader<T, I>,
-        current_set_id: sp_consensus_grandpa::SetId,
-    ) -> Result<bool, sp_runtime::DispatchError> {
-        let mut change_enacted = false;
-
-        // We don't support forced changes - at that point governance intervention is required.
-        ensure!(
-            super::find_forced_change(header).is_none(),
-            <Error<T, I>>::UnsupportedScheduledChange
-        );
+        pending_change: &mut Option<
+            bp_header_chain::PendingAuthorityChange<BridgedBlockNumber<T, I>>,
+        >,
+    ) {
+        if let Some((_median, change)) = super::find_forced_change(header) {
+            *pending_change = Some(bp_header_chain::PendingAuthorityChange {
+                next_authorities: change.next_authorities,
+                effective_at: header.number().saturating_add(change.delay),
+                forced: true,
+            });
+        }
+    }
 
+    /// Check the given, already finalized header for a GRANDPA scheduled authority set change
+    /// signal. A found change is kept pending until the bridged chain reaches its enactment height.
+    ///
+    /// Only a single change may be pending at a time.
+    pub(crate) fn note_scheduled_change_single<T: Config<I>, I: 'static>(
+        header: &BridgedHeader<T, I>,
+        pending_change: &mut Option<
+            bp_header_chain::PendingAuthorityChange<BridgedBlockNumber<T, I>>,
+        >,
+    ) -> Result<(), sp_runtime::DispatchError> {
         if let Some(change) = find_scheduled_change(header) {
-            // GRANDPA only includes a `delay` for forced changes, so this isn't valid.
             ensure!(
-                change.delay == Zero::zero(),
+                pending_change.is_none(),
                 <Error<T, I>>::UnsupportedScheduledChange
             );
+            *pending_change = Some(bp_header_chain::PendingAuthorityChange {
+                next_authorities: change.next_authorities,
+                effective_at: header.number().saturating_add(change.delay),
+                forced: false,
+            });
+        }
 
-            // TODO [#788]: Stop manually increasing the `set_id` here.
-            let next_authorities = bp_header_chain::AuthoritySet {
-                authorities: change.next_authorities,
-                set_id: current_set_id + 1,
-            };
+        Ok(())
+    }
 
-            // Since our header schedules a change and we know the delay is 0, it must also enact
-            // the change.
-            <CurrentAuthoritySet<T, I>>::put(&next_authorities);
-            change_enacted = true;
+    /// Enact the pending authority set change, if it's due at the given height.
+    ///
+    /// Scheduled changes are due once the header at the enactment height is finalized, which the
+    /// outgoing set does itself, so headers above it belong to the next set. Forced changes are due
+    /// as soon as the enactment height is reached. Returns the enacted change, if any.
+    pub(crate) fn enact_due_authority_change_single<T: Config<I>, I: 'static>(
+        authority_set: &mut bp_header_chain::AuthoritySet,
+        pending_change: &mut Option<
+            bp_header_chain::PendingAuthorityChange<BridgedBlockNumber<T, I>>,
+        >,
+        number: BridgedBlockNumber<T, I>,
+        is_finalized: bool,
+    ) -> Option<bp_header_chain::PendingAuthorityChange<BridgedBlockNumber<T, I>>> {
+        let is_due = match pending_change {
+            Some(change) if change.forced || is_finalized => change.effective_at <= number,
+            Some(change) => change.effective_at < number,
+            None => false,
+        };
+        if !is_due {
+            return None
+        }
+        let change = pending_change.take()?;
 
-            log::info!(
-                "Transitioned from authority set {} to {}! New authorities are: {:?}",
-                current_set_id,
-                current_set_id + 1,
-                next_authorities,
-            );
+        // TODO [#788]: Stop manually increasing the `set_id` here.
+        let next_authorities = bp_header_chain::AuthoritySet {
+            authorities: change.next_authorities.clone(),
+            set_id: authority_set.set_id + 1,
         };
+        <AuthoritySetHistory<T, I>>::insert(next_authorities.set_id, &next_authorities);
+        if let Some(expired_set_id) = next_authorities
+            .set_id
+            .checked_sub(AUTHORITY_SET_HISTORY_DEPTH)
+        {
+            <AuthoritySetHistory<T, I>>::remove(expired_set_id);
+        }
+
+        log::info!(
+            "Transitioned from authority set {} to {} at {:?} (forced: {})! New authorities are: {:?}",
+            authority_set.set_id,
+            next_authorities.set_id,
+            change.effective_at,
+            change.forced,
+            next_authorities,
+        );
 
-        Ok(change_enacted)
+        *authority_set = next_authorities;
+        Some(change)
     }
 
     /// Verify a GRANDPA justification (finality proof) for a given header.
@@ -456,6 +534,7 @@ pub mod pallet {
         <ImportedHashesPointer<T, I>>::put(0); // one ahead of first value
         <RelayChainId<T, I>>::put(gateway_id);
         let authority_set = bp_header_chain::AuthoritySet::new(authority_list, set_id);
+        <AuthoritySetHistory<T, I>>::insert(set_id, &authority_set);
         <CurrentAuthoritySet<T, I>>::put(authority_set);
 
         // Other configs
@@ -528,33 +607,43 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // 3. The justification verifies a header that follows the linkage rule of the range
 
         // For efficiency we check the the justification first. If it's invalid, we can skip the rest
-        let (signed_hash, signed_number) = (signed_header.hash(), signed_header.number());
-        let authority_set =
+        let (signed_hash, signed_number) = (signed_header.hash(), *signed_header.number());
+        let mut authority_set =
             <CurrentAuthoritySet<T, I>>::get().ok_or(Error::<T, I>::InvalidAuthoritySet)?;
+        // Scheduled changes pending have been noted from already verified headers only. Forced
+        // changes are picked from the submitted range as well, since the current set has stalled
+        // and can't finalize it. Either is enacted ahead of verification if the signed header is
+        // past its enactment height, so the justification has to come from the next set.
+        let mut pending_change = <PendingAuthoritySetChange<T, I>>::get();
+        for header in range.iter().chain(sp_std::iter::once(&signed_header)) {
+            note_forced_change_single::<T, I>(header, &mut pending_change);
+        }
+        let _enacted = enact_due_authority_change_single::<T, I>(
+            &mut authority_set,
+            &mut pending_change,
+            signed_number,
+            false,
+        );
 
-        let set_id = authority_set.set_id;
         // °°°°° Begin Check: #2 °°°°°
         verify_justification_single::<T, I>(
             &justification,
             signed_hash,
-            *signed_number,
-            authority_set,
+            signed_number,
+            authority_set.clone(),
         )?;
         // °°°°° Checked: #2 °°°°°°
 
-        // check for authority set update and enact if available.
-        let _enacted = try_enact_authority_change_single::<T, I>(&signed_header, set_id)?;
-
         // We get the latest buffer_index, which maps to the next header we can overwrite, and the index where we insert the verified header
         let mut buffer_index = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
 
         // °°°°° Begin Check: #1 °°°°°
-        for header in range {
+        for header in range.iter() {
             if best_finalized_hash == *header.parent_hash() {
                 // write header to storage if correct
                 write_and_clean_header_data::<T, I>(
                     &mut buffer_index,
-                    &header,
+                    header,
                     header.hash(),
                     false,
                 )?;
@@ -582,10 +671,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // °°°°° Check Success: #3 °°°°°
         // Proof success! Submitted header range valid
 
+        // Only now the whole range is known to be finalized by the verified justification, so the
+        // scheduled changes it signals can be noted. Changes due at the signed header are
+        // enacted right away, as the set which verified the justification finalized their height.
+        for header in range.iter().chain(sp_std::iter::once(&signed_header)) {
+            note_scheduled_change_single::<T, I>(header, &mut pending_change)?;
+        }
+        let _enacted = enact_due_authority_change_single::<T, I>(
+            &mut authority_set,
+            &mut pending_change,
+            signed_number,
+            true,
+        );
+        <CurrentAuthoritySet<T, I>>::put(authority_set);
+        <PendingAuthoritySetChange<T, I>>::set(pending_change);
+
         // Update pointer
         <ImportedHashesPointer<T, I>>::set(Some(buffer_index));
 
-        Self::deposit_event(Event::HeadersAdded(*signed_number));
+        Self::deposit_event(Event::HeadersAdded(signed_number));
         Ok(())
     }
 
@@ -780,7 +884,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let is_relaychain = Some(gateway_id) == <RelayChainId<T, I>>::get();
 
-        let (payload_proof, encoded_payload, header, header_hash) = if is_relaychain {
+        let (payload_proof, encoded_payload, event_pointer, header, header_hash) = if is_relaychain
+        {
             let proof: RelaychainInclusionProof<BridgedHeader<T, I>> =
                 Decode::decode(&mut &*encoded_inclusion_proof)
                     .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
@@ -791,6 +896,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             (
                 proof.payload_proof,
                 proof.encoded_payload,
+                proof.event_pointer,
                 header,
                 proof.block_hash,
             )
@@ -807,13 +913,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             (
                 proof.payload_proof,
                 proof.encoded_payload,
+                proof.event_pointer,
                 header,
                 proof.relay_block_hash,
             )
         };
 
-        let message =
-            verify_event_storage_proof::<T, I>(payload_proof, header.clone(), encoded_payload)?;
+        let event_index = event_pointer.event_index;
+        let message = verify_event_storage_proof::<T, I>(
+            gateway_id,
+            payload_proof,
+            header.clone(),
+            encoded_payload,
+            event_pointer,
+        )?;
 
         if let Some(source) = maybe_source {
             Self::check_vm_source(source, message.clone())?;
@@ -823,6 +936,74 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             height: to_local_block_number::<T, I>(*header.number())?,
             including_header: header_hash.encode(),
             message,
+            event_index: Some(event_index),
+        })
+    }
+
+    /// Verifies that a storage entry is included in the state trie of a finalized header.
+    /// Returns the proven storage value as the receipt's message.
+    pub fn confirm_state_inclusion(
+        gateway_id: ChainId,
+        encoded_inclusion_proof: Vec<u8>,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::confirm_trie_inclusion(gateway_id, encoded_inclusion_proof, ProofTriePointer::State)
+    }
+
+    /// Verifies that an extrinsic is included in the transaction trie of a finalized header.
+    /// Returns the proven encoded extrinsic as the receipt's message.
+    pub fn confirm_tx_inclusion(
+        gateway_id: ChainId,
+        encoded_inclusion_proof: Vec<u8>,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::confirm_trie_inclusion(
+            gateway_id,
+            encoded_inclusion_proof,
+            ProofTriePointer::Transaction,
+        )
+    }
+
+    fn confirm_trie_inclusion(
+        gateway_id: ChainId,
+        encoded_inclusion_proof: Vec<u8>,
+        trie_type: ProofTriePointer,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let is_relaychain = Some(gateway_id) == <RelayChainId<T, I>>::get();
+
+        let (key, payload_proof, header, header_hash) = if is_relaychain {
+            let proof: RelaychainTrieInclusionProof<BridgedHeader<T, I>> =
+                Decode::decode(&mut &*encoded_inclusion_proof)
+                    .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
+
+            let header = <ImportedHeaders<T, I>>::get(proof.block_hash)
+                .ok_or(Error::<T, I>::UnknownHeader)?;
+
+            (proof.key, proof.payload_proof, header, proof.block_hash)
+        } else {
+            let proof: ParachainTrieInclusionProof<BridgedHeader<T, I>> =
+                Decode::decode(&mut &*encoded_inclusion_proof)
+                    .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
+            let header = verify_header_storage_proof::<T, I>(
+                proof.relay_block_hash,
+                proof.header_proof,
+                <ParachainIdMap<T, I>>::get(gateway_id)
+                    .ok_or(Error::<T, I>::ParachainEntryNotFound)?,
+            )?;
+            (
+                proof.key,
+                proof.payload_proof,
+                header,
+                proof.relay_block_hash,
+            )
+        };
+
+        let message = verify_storage_proof::<T, I>(header.clone(), key, payload_proof, trie_type)
+            .map_err(|_| Error::<T, I>::InvalidStorageProof)?;
+
+        Ok(InclusionReceipt::<BlockNumberFor<T>> {
+            height: to_local_block_number::<T, I>(*header.number())?,
+            including_header: header_hash.encode(),
+            message,
+            event_index: None,
         })
     }
 
@@ -948,9 +1129,11 @@ pub(crate) fn find_forced_change<H: HeaderT>(
 }
 
 pub(crate) fn verify_event_storage_proof<T: Config<I>, I: 'static>(
+    gateway_id: ChainId,
     storage_proof: StorageProof,
     header: BridgedHeader<T, I>,
     encoded_payload: Vec<u8>,
+    event_pointer: EventRecordPointer<BridgedBlockHash<T, I>>,
 ) -> Result<Vec<u8>, DispatchError> {
     // storage key for System_Events
     let key: Vec<u8> = [
@@ -961,16 +1144,93 @@ pub(crate) fn verify_event_storage_proof<T: Config<I>, I: 'static>(
     let verified_block_events =
         verify_storage_proof::<T, I>(header, key, storage_proof, ProofTriePointer::Receipts)?;
 
-    // the problem here is that in substrates current design its not possible to prove the inclusion of a single event, only all events of a block
-    // https://github.com/paritytech/substrate/issues/11216
+    let record = select_event_record::<BridgedBlockHash<T, I>>(
+        verified_block_events.as_slice(),
+        event_pointer.event_lengths.as_slice(),
+        event_pointer.event_index,
+    )
+    .ok_or(Error::<T, I>::InvalidEventRecords)?;
+
+    // The first two bytes of an encoded runtime event are the pallet and the event variant indices
     ensure!(
-        is_sub(verified_block_events.as_slice(), encoded_payload.as_slice()),
+        encoded_payload.len() >= 2 && record.event.len() >= 2,
+        Error::<T, I>::UnexpectedEventLength
+    );
+    // Selected events of layouts registered for the bridged chain must also decode exactly by their fields
+    if let Some(layout) =
+        T::LightClientAsyncAPI::get_event_layout(gateway_id, [record.event[0], record.event[1]])
+    {
+        let mut fields = &record.event[2..];
+        ensure!(
+            ScaleLayout::skip_all(&layout, &mut fields).is_some() && fields.is_empty(),
+            Error::<T, I>::UnexpectedEventLength
+        );
+    }
+    ensure!(
+        record.event[0] == encoded_payload[0],
+        Error::<T, I>::UnexpectedEventPallet
+    );
+    ensure!(
+        record.event[1] == encoded_payload[1],
+        Error::<T, I>::UnexpectedEventVariant
+    );
+    ensure!(
+        record.phase == Phase::ApplyExtrinsic(event_pointer.extrinsic_index),
+        Error::<T, I>::UnexpectedEventPhase
+    );
+    ensure!(
+        record.topics == event_pointer.topics,
+        Error::<T, I>::UnexpectedEventTopics
+    );
+    ensure!(
+        record.event == encoded_payload,
         Error::<T, I>::EventNotIncluded
     );
 
     Ok(encoded_payload)
 }
 
+/// Splits the encoded `Vec<EventRecord>` of a bridged chain into records and returns the one at
+/// `event_index`. Runtime events can't be decoded without the bridged runtime's metadata, so the
+/// records are delimited with the supplied event lengths, which have to cover the whole input.
+pub(crate) fn select_event_record<Hash: Decode>(
+    mut encoded_events: &[u8],
+    event_lengths: &[u32],
+    event_index: u32,
+) -> Option<OpaqueEventRecord<Hash>> {
+    let input = &mut encoded_events;
+    let records_count = <codec::Compact<u32>>::decode(input).ok()?.0;
+    if records_count as usize != event_lengths.len() || event_index >= records_count {
+        return None
+    }
+
+    let mut selected = None;
+    for (index, length) in event_lengths.iter().enumerate() {
+        let phase = Phase::decode(input).ok()?;
+        let length = *length as usize;
+        if input.len() < length {
+            return None
+        }
+        let (event, remainder) = (*input).split_at(length);
+        *input = remainder;
+        let topics = <Vec<Hash>>::decode(input).ok()?;
+
+        if index == event_index as usize {
+            selected = Some(OpaqueEventRecord {
+                phase,
+                event: event.to_vec(),
+                topics,
+            });
+        }
+    }
+
+    if !input.is_empty() {
+        return None
+    }
+
+    selected
+}
+
 pub(crate) fn verify_header_storage_proof<T: Config<I>, I: 'static>(
     relay_block_hash: BridgedBlockHash<T, I>,
     proof: StorageProof,
@@ -1000,16 +1260,6 @@ pub(crate) fn verify_header_storage_proof<T: Config<I>, I: 'static>(
     Ok(header)
 }
 
-pub(crate) fn is_sub<T: PartialEq>(mut haystack: &[T], needle: &[T]) -> bool {
-    while !haystack.is_empty() {
-        if haystack.starts_with(needle) {
-            return true
-        }
-        haystack = &haystack[1..];
-    }
-    false
-}
-
 /// (Re)initialize bridge with given header for using it in `pallet-bridge-messages` benchmarks.
 // #[cfg(feature = "runtime-benchmarks")]
 // pub fn initialize_for_benchmarks<T: Config<I>, I: 'static>(header: BridgedHeader<T, I>) {
@@ -1327,6 +1577,8 @@ pub mod tests {
             assert_eq!(ImportedHashesPointer::<TestRuntime>::get(), None);
             assert_eq!(RelayChainId::<TestRuntime>::get(), None);
             assert_eq!(CurrentAuthoritySet::<TestRuntime>::get(), None);
+            assert_eq!(AuthoritySetHistory::<TestRuntime>::iter().count(), 0);
+            assert_eq!(PendingAuthoritySetChange::<TestRuntime>::get(), None);
             assert_eq!(IsHalted::<TestRuntime>::get(), false);
             assert_eq!(PalletOwner::<TestRuntime>::get(), None);
             //can re-register
@@ -1686,58 +1938,336 @@ pub mod tests {
         })
     }
 
+    fn test_header_range_with_digest(to: u32, at: u32, digest: Digest) -> Vec<TestHeader> {
+        let mut headers: Vec<TestHeader> = vec![];
+        for number in 0..=to {
+            let mut header =
+                test_header_with_correct_parent(number, headers.last().map(|h| h.hash()));
+            if number == at {
+                header.digest = digest.clone();
+            }
+            headers.push(header);
+        }
+        headers
+    }
+
+    fn next_set_justification(
+        header: &TestHeader,
+        set_id: sp_consensus_grandpa::SetId,
+    ) -> GrandpaJustification<TestHeader> {
+        make_justification_for_header(JustificationGeneratorParams::<TestHeader> {
+            header: header.clone(),
+            set_id,
+            authorities: vec![(ALICE, 1), (BOB, 1)],
+            ..Default::default()
+        })
+    }
+
     #[test]
-    fn importing_header_rejects_header_with_scheduled_change_delay() {
+    fn importing_header_with_scheduled_change_delay_enacts_it_once_finalized() {
         run_test(|| {
             let _ = initialize_relaychain(Origin::root());
 
-            // Need to update the header digest to indicate that our header signals an authority set
-            // change. However, the change doesn't happen until the next block.
-            let headers: Vec<TestHeader> = test_header_range(2);
-            let mut signed_header = headers[2].clone();
-            let mut range: Vec<TestHeader> = headers[1..2].to_vec();
-            range.reverse();
+            // Header #2 schedules a change taking effect at #4
+            let headers = test_header_range_with_digest(5, 2, change_log(2));
 
-            signed_header.digest = change_log(1);
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[3].clone(),
+                range: headers[1..3].to_vec(),
+                justification: make_default_justification(&headers[3]),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
 
-            let justification = make_default_justification(&signed_header);
+            // #3 is below the enactment height, so the current set stays in force
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get().map(|set| set.set_id),
+                Some(1)
+            );
+            assert_eq!(
+                <PendingAuthoritySetChange<TestRuntime>>::get(),
+                Some(bp_header_chain::PendingAuthorityChange {
+                    next_authorities: vec![(ALICE.into(), 1), (BOB.into(), 1)],
+                    effective_at: 4,
+                    forced: false,
+                })
+            );
+
+            // #4 is finalized by the outgoing set, which enacts the change
             let data = GrandpaHeaderData::<TestHeader> {
-                signed_header,
-                range,
-                justification,
+                signed_header: headers[4].clone(),
+                range: vec![],
+                justification: make_default_justification(&headers[4]),
             };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
 
-            // Should not be allowed to import this header
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get(),
+                Some(bp_header_chain::AuthoritySet::new(
+                    vec![(ALICE.into(), 1), (BOB.into(), 1)],
+                    2
+                )),
+            );
+            assert_eq!(<PendingAuthoritySetChange<TestRuntime>>::get(), None);
+            assert_eq!(
+                <AuthoritySetHistory<TestRuntime>>::get(1).map(|set| set.authorities),
+                Some(authority_list())
+            );
+            assert!(<AuthoritySetHistory<TestRuntime>>::contains_key(2));
+
+            // #5 has to be finalized by the next set
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[5].clone(),
+                range: vec![],
+                justification: make_default_justification(&headers[5]),
+            };
             assert_noop!(
                 Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
-                <Error<TestRuntime>>::UnsupportedScheduledChange
+                <Error<TestRuntime>>::InvalidGrandpaJustification
             );
+
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[5].clone(),
+                range: vec![],
+                justification: next_set_justification(&headers[5], 2),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
         })
     }
 
     #[test]
-    fn importing_header_rejects_header_with_forced_changes() {
+    fn importing_range_crossing_enactment_of_change_signalled_within_it_is_rejected() {
         run_test(|| {
             let _ = initialize_relaychain(Origin::root());
 
-            // Need to update the header digest to indicate that it signals a forced authority set
-            // change.
-            let headers: Vec<TestHeader> = test_header_range(2);
-            let mut signed_header = headers[2].clone();
-            let mut range: Vec<TestHeader> = headers[1..2].to_vec();
-            range.reverse();
+            // Header #2 schedules a change taking effect at #3, so #4 is finalized by the next set
+            let headers = test_header_range_with_digest(4, 2, change_log(1));
+
+            // Changes signalled within the range aren't trusted before the range is verified
+            for justification in [
+                make_default_justification(&headers[4]),
+                next_set_justification(&headers[4], 2),
+            ] {
+                let data = GrandpaHeaderData::<TestHeader> {
+                    signed_header: headers[4].clone(),
+                    range: headers[1..4].to_vec(),
+                    justification,
+                };
+                assert_noop!(
+                    Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
+                    <Error<TestRuntime>>::InvalidGrandpaJustification
+                );
+            }
 
-            signed_header.digest = change_log(1);
+            // Range up to the enactment height is finalized by the current set
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[3].clone(),
+                range: headers[1..3].to_vec(),
+                justification: make_default_justification(&headers[3]),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get().map(|set| set.set_id),
+                Some(2)
+            );
 
-            let justification = make_default_justification(&signed_header);
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[4].clone(),
+                range: vec![],
+                justification: next_set_justification(&headers[4], 2),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
+        })
+    }
+
+    #[test]
+    fn forged_header_scheduling_change_cannot_authorize_its_own_justification() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            // Forged #1 schedules the attacker's authorities with no delay
+            let headers = test_header_range_with_digest(2, 1, change_log(0));
 
             let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[2].clone(),
+                range: headers[1..2].to_vec(),
+                justification: next_set_justification(&headers[2], 2),
+            };
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
+                <Error<TestRuntime>>::InvalidGrandpaJustification
+            );
+
+            // Justification by the attacker's authorities under the current set id fails as well
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[2].clone(),
+                range: headers[1..2].to_vec(),
+                justification: next_set_justification(&headers[2], 1),
+            };
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
+                <Error<TestRuntime>>::InvalidGrandpaJustification
+            );
+
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get().map(|set| set.authorities),
+                Some(authority_list())
+            );
+            assert_eq!(<PendingAuthoritySetChange<TestRuntime>>::get(), None);
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+        })
+    }
+
+    #[test]
+    fn importing_header_with_forced_change_enacts_it_once_reached() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            // Header #2 forces a change taking effect at #3
+            let headers = test_header_range_with_digest(4, 2, forced_change_log(1));
+
+            // The current set has stalled and can't finalize any header of the range
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[4].clone(),
+                range: headers[1..4].to_vec(),
+                justification: make_default_justification(&headers[4]),
+            };
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
+                <Error<TestRuntime>>::InvalidGrandpaJustification
+            );
+
+            // The forced set finalizes #4 right away, without the signalling header being finalized first
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[4].clone(),
+                range: headers[1..4].to_vec(),
+                justification: next_set_justification(&headers[4], 2),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
+
+            assert_eq!(
+                <BestFinalizedHash<TestRuntime>>::get(),
+                Some(headers[4].hash())
+            );
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get(),
+                Some(bp_header_chain::AuthoritySet::new(
+                    vec![(ALICE.into(), 1), (BOB.into(), 1)],
+                    2
+                )),
+            );
+            assert_eq!(<PendingAuthoritySetChange<TestRuntime>>::get(), None);
+        })
+    }
+
+    #[test]
+    fn importing_header_at_forced_change_height_requires_forced_set_justification() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            let headers = test_header_range_with_digest(3, 2, forced_change_log(1));
+
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[3].clone(),
+                range: headers[1..3].to_vec(),
+                justification: make_default_justification(&headers[3]),
+            };
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
+                <Error<TestRuntime>>::InvalidGrandpaJustification
+            );
+
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[3].clone(),
+                range: headers[1..3].to_vec(),
+                justification: next_set_justification(&headers[3], 2),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
+
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get().map(|set| set.set_id),
+                Some(2)
+            );
+        })
+    }
+
+    #[test]
+    fn importing_header_below_forced_change_height_keeps_it_pending() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            // Header #2 forces a change taking effect at #4
+            let headers = test_header_range_with_digest(2, 2, forced_change_log(2));
+
+            let data = GrandpaHeaderData::<TestHeader> {
+                signed_header: headers[2].clone(),
+                range: headers[1..2].to_vec(),
+                justification: make_default_justification(&headers[2]),
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_encoded_headers(data.encode()));
+
+            assert_eq!(
+                <CurrentAuthoritySet<TestRuntime>>::get().map(|set| set.set_id),
+                Some(1)
+            );
+            assert_eq!(
+                <PendingAuthoritySetChange<TestRuntime>>::get(),
+                Some(bp_header_chain::PendingAuthorityChange {
+                    next_authorities: vec![(ALICE.into(), 1), (BOB.into(), 1)],
+                    effective_at: 4,
+                    forced: true,
+                })
+            );
+        })
+    }
+
+    #[test]
+    fn enacting_authority_set_changes_prunes_history_beyond_its_depth() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            let mut authority_set = <CurrentAuthoritySet<TestRuntime>>::get().unwrap();
+            for number in 1..=AUTHORITY_SET_HISTORY_DEPTH as u32 + 1 {
+                let mut pending_change = Some(bp_header_chain::PendingAuthorityChange {
+                    next_authorities: authority_list(),
+                    effective_at: number,
+                    forced: true,
+                });
+                assert!(enact_due_authority_change_single::<TestRuntime, ()>(
+                    &mut authority_set,
+                    &mut pending_change,
+                    number,
+                    false,
+                )
+                .is_some());
+            }
+
+            assert_eq!(authority_set.set_id, AUTHORITY_SET_HISTORY_DEPTH + 2);
+            assert_eq!(
+                <AuthoritySetHistory<TestRuntime>>::iter().count() as SetId,
+                AUTHORITY_SET_HISTORY_DEPTH
+            );
+            assert!(!<AuthoritySetHistory<TestRuntime>>::contains_key(2));
+            assert!(<AuthoritySetHistory<TestRuntime>>::contains_key(3));
+        })
+    }
+
+    #[test]
+    fn importing_header_rejects_scheduled_change_while_another_is_pending() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            let headers = test_header_range_with_digest(2, 1, change_log(5));
+            let mut signed_header = headers[2].clone();
+            signed_header.digest = change_log(1);
+
+            let data = GrandpaHeaderData::<TestHeader> {
+                justification: make_default_justification(&signed_header),
                 signed_header,
-                range,
-                justification,
+                range: headers[1..2].to_vec(),
             };
 
-            // Should not be allowed to import this header
             assert_noop!(
                 Pallet::<TestRuntime>::submit_encoded_headers(data.encode()),
                 <Error<TestRuntime>>::UnsupportedScheduledChange
@@ -1854,6 +2384,293 @@ pub mod tests {
         });
     }
 
+    fn encode_event_records(records: Vec<(Phase, Vec<u8>, Vec<H256>)>) -> (Vec<u8>, Vec<u32>) {
+        let mut encoded_events = codec::Compact(records.len() as u32).encode();
+        let mut event_lengths = vec![];
+        for (phase, event, topics) in records {
+            phase.encode_to(&mut encoded_events);
+            encoded_events.extend_from_slice(&event);
+            topics.encode_to(&mut encoded_events);
+            event_lengths.push(event.len() as u32);
+        }
+        (encoded_events, event_lengths)
+    }
+
+    fn import_header_with_events(encoded_events: Vec<u8>) -> (H256, StorageProof) {
+        use sp_state_machine::{backend::Backend, prove_read, InMemoryBackend};
+
+        let key = frame_support::storage::storage_prefix(b"System", b"Events").to_vec();
+        let state_version = sp_runtime::StateVersion::default();
+        let backend = <InMemoryBackend<sp_core::Blake2Hasher>>::from((
+            vec![(None, vec![(key.clone(), Some(encoded_events))])],
+            state_version,
+        ));
+        let state_root = backend.storage_root(sp_std::iter::empty(), state_version).0;
+        let proof = StorageProof::new(prove_read(backend, &[key]).unwrap().into_iter_nodes());
+
+        let mut header = test_header(2);
+        header.set_state_root(state_root);
+        let hash = header.hash();
+        <ImportedHeaders<TestRuntime>>::insert(hash, header);
+
+        (hash, proof)
+    }
+
+    // Events of layouts known to MockEventLayouts: (2, 2) carrying u32 and (4, 0) carrying Vec<u8>
+    const U32_EVENT: [u8; 6] = [2, 2, 1, 2, 3, 4];
+    const BYTES_EVENT: [u8; 6] = [4, 0, 12, 9, 9, 9];
+    // Event of a pallet without a registered layout
+    const OPAQUE_EVENT: [u8; 4] = [7, 1, 5, 5];
+
+    fn two_event_records() -> (Vec<u8>, Vec<u32>) {
+        encode_event_records(vec![
+            (Phase::ApplyExtrinsic(0), U32_EVENT.to_vec(), vec![]),
+            (
+                Phase::ApplyExtrinsic(1),
+                BYTES_EVENT.to_vec(),
+                vec![H256::repeat_byte(7)],
+            ),
+        ])
+    }
+
+    fn event_inclusion_proof(
+        encoded_payload: Vec<u8>,
+        payload_proof: StorageProof,
+        block_hash: H256,
+        event_pointer: EventRecordPointer<H256>,
+    ) -> Vec<u8> {
+        RelaychainInclusionProof::<TestHeader> {
+            encoded_payload,
+            payload_proof,
+            block_hash,
+            event_pointer,
+        }
+        .encode()
+    }
+
+    #[test]
+    fn select_event_record_splits_records_by_event_lengths() {
+        let (encoded_events, event_lengths) = two_event_records();
+
+        assert_eq!(
+            select_event_record::<H256>(&encoded_events, &event_lengths, 1),
+            Some(OpaqueEventRecord {
+                phase: Phase::ApplyExtrinsic(1),
+                event: BYTES_EVENT.to_vec(),
+                topics: vec![H256::repeat_byte(7)],
+            })
+        );
+        // index out of bounds
+        assert_eq!(
+            select_event_record::<H256>(&encoded_events, &event_lengths, 2),
+            None
+        );
+        // lengths that don't cover all of the records
+        assert_eq!(select_event_record::<H256>(&encoded_events, &[6], 0), None);
+        // lengths that don't cover the whole input
+        assert_eq!(
+            select_event_record::<H256>(&encoded_events, &[6, 5], 0),
+            None
+        );
+    }
+
+    #[test]
+    fn confirm_event_inclusion_returns_selected_event_with_its_index() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let (encoded_events, event_lengths) = two_event_records();
+            let (block_hash, proof) = import_header_with_events(encoded_events);
+
+            let receipt = Pallet::<TestRuntime>::confirm_event_inclusion(
+                *b"pdot",
+                event_inclusion_proof(
+                    BYTES_EVENT.to_vec(),
+                    proof,
+                    block_hash,
+                    EventRecordPointer {
+                        event_index: 1,
+                        extrinsic_index: 1,
+                        topics: vec![H256::repeat_byte(7)],
+                        event_lengths,
+                    },
+                ),
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(
+                receipt,
+                InclusionReceipt {
+                    height: 2,
+                    including_header: block_hash.encode(),
+                    message: BYTES_EVENT.to_vec(),
+                    event_index: Some(1),
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn confirm_event_inclusion_rejects_payload_spanning_part_of_another_event() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let (encoded_events, event_lengths) = two_event_records();
+            let (block_hash, proof) = import_header_with_events(encoded_events);
+
+            // [1, 2, 3] is a byte run within the first event, but not an event itself
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_event_inclusion(
+                    *b"pdot",
+                    event_inclusion_proof(
+                        vec![1, 2, 3],
+                        proof,
+                        block_hash,
+                        EventRecordPointer {
+                            event_index: 0,
+                            extrinsic_index: 0,
+                            topics: vec![],
+                            event_lengths,
+                        },
+                    ),
+                    None,
+                ),
+                Error::<TestRuntime>::UnexpectedEventPallet
+            );
+        });
+    }
+
+    #[test]
+    fn confirm_event_inclusion_rejects_event_from_other_extrinsic_or_with_other_topics() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let (encoded_events, event_lengths) = two_event_records();
+            let (block_hash, proof) = import_header_with_events(encoded_events);
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_event_inclusion(
+                    *b"pdot",
+                    event_inclusion_proof(
+                        BYTES_EVENT.to_vec(),
+                        proof.clone(),
+                        block_hash,
+                        EventRecordPointer {
+                            event_index: 1,
+                            extrinsic_index: 0,
+                            topics: vec![H256::repeat_byte(7)],
+                            event_lengths: event_lengths.clone(),
+                        },
+                    ),
+                    None,
+                ),
+                Error::<TestRuntime>::UnexpectedEventPhase
+            );
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_event_inclusion(
+                    *b"pdot",
+                    event_inclusion_proof(
+                        BYTES_EVENT.to_vec(),
+                        proof.clone(),
+                        block_hash,
+                        EventRecordPointer {
+                            event_index: 1,
+                            extrinsic_index: 1,
+                            topics: vec![],
+                            event_lengths: event_lengths.clone(),
+                        },
+                    ),
+                    None,
+                ),
+                Error::<TestRuntime>::UnexpectedEventTopics
+            );
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_event_inclusion(
+                    *b"pdot",
+                    event_inclusion_proof(
+                        vec![4, 0, 12, 9, 9, 8],
+                        proof,
+                        block_hash,
+                        EventRecordPointer {
+                            event_index: 1,
+                            extrinsic_index: 1,
+                            topics: vec![H256::repeat_byte(7)],
+                            event_lengths,
+                        },
+                    ),
+                    None,
+                ),
+                Error::<TestRuntime>::EventNotIncluded
+            );
+        });
+    }
+
+    #[test]
+    fn confirm_event_inclusion_verifies_events_of_pallets_without_registered_layout() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let (encoded_events, event_lengths) = encode_event_records(vec![
+                (Phase::ApplyExtrinsic(0), OPAQUE_EVENT.to_vec(), vec![]),
+                (Phase::ApplyExtrinsic(1), OPAQUE_EVENT.to_vec(), vec![]),
+            ]);
+            let (block_hash, proof) = import_header_with_events(encoded_events);
+
+            let receipt = Pallet::<TestRuntime>::confirm_event_inclusion(
+                *b"pdot",
+                event_inclusion_proof(
+                    OPAQUE_EVENT.to_vec(),
+                    proof,
+                    block_hash,
+                    EventRecordPointer {
+                        event_index: 1,
+                        extrinsic_index: 1,
+                        topics: vec![],
+                        event_lengths,
+                    },
+                ),
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(receipt.message, OPAQUE_EVENT.to_vec());
+            assert_eq!(receipt.event_index, Some(1));
+        });
+    }
+
+    #[test]
+    fn confirm_event_inclusion_rejects_selected_event_not_decoding_by_its_registered_layout() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            // (4, 0) carries Vec<u8>, which here claims 3 bytes but is followed by 4
+            let malformed_event = vec![4, 0, 12, 9, 9, 9, 9];
+            let (encoded_events, event_lengths) = encode_event_records(vec![(
+                Phase::ApplyExtrinsic(0),
+                malformed_event.clone(),
+                vec![],
+            )]);
+            let (block_hash, proof) = import_header_with_events(encoded_events);
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_event_inclusion(
+                    *b"pdot",
+                    event_inclusion_proof(
+                        malformed_event,
+                        proof,
+                        block_hash,
+                        EventRecordPointer {
+                            event_index: 0,
+                            extrinsic_index: 0,
+                            topics: vec![],
+                            event_lengths,
+                        },
+                    ),
+                    None,
+                ),
+                Error::<TestRuntime>::UnexpectedEventLength
+            );
+        });
+    }
+
     #[test]
     fn should_prune_headers_over_headers_to_keep_parameter() {
         run_test(|| {
@@ -394,20 +394,22 @@ impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
 
     fn verify_state_inclusion(
         &self,
-        _gateway_id: [u8; 4],
+        gateway_id: [u8; 4],
+        // GrandpaFV does not support speed mode - it is always set to Finalized since it's fast
         _speed_mode: SpeedMode,
-        _message: Bytes,
+        message: Bytes,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
-        Err("GrandpaFV::verify_state_inclusion not implemented yet".into())
+        Pallet::<T, I>::confirm_state_inclusion(gateway_id, message)
     }
 
     fn verify_tx_inclusion(
         &self,
-        _gateway_id: [u8; 4],
+        gateway_id: [u8; 4],
+        // GrandpaFV does not support speed mode - it is always set to Finalized since it's fast
         _speed_mode: SpeedMode,
-        _message: Bytes,
+        message: Bytes,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
-        Err("GrandpaFV::verify_tx_inclusion not implemented yet".into())
+        Pallet::<T, I>::confirm_tx_inclusion(gateway_id, message)
     }
 
     fn verify_event_inclusion_precompile(
@@ -429,20 +431,26 @@ impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
 
     fn verify_state_inclusion_precompile(
         &self,
-        _gateway_id: [u8; 4],
+        gateway_id: [u8; 4],
         _speed_mode: SpeedMode,
-        _message: Bytes,
+        message: Bytes,
     ) -> Result<Bytes, DispatchError> {
-        Err("GrandpaFV::verify_state_inclusion not implemented yet".into())
+        match Pallet::<T, I>::confirm_state_inclusion(gateway_id, message) {
+            Ok(receipt) => Ok(receipt.message.encode()),
+            Err(err) => Err(err),
+        }
     }
 
     fn verify_tx_inclusion_precompile(
         &self,
-        _gateway_id: [u8; 4],
+        gateway_id: [u8; 4],
         _speed_mode: SpeedMode,
-        _message: Bytes,
+        message: Bytes,
     ) -> Result<Bytes, DispatchError> {
-        Err("GrandpaFV::verify_tx_inclusion not implemented yet".into())
+        match Pallet::<T, I>::confirm_tx_inclusion(gateway_id, message) {
+            Ok(receipt) => Ok(receipt.message.encode()),
+            Err(err) => Err(err),
+        }
     }
 }
 
@@ -757,7 +765,7 @@ pub mod grandpa_light_clients_test {
     }
 
     #[test]
-    fn verify_tx_and_state_inclusion_for_kusama_are_not_active_yet() {
+    fn verify_tx_and_state_inclusion_for_kusama_reject_undecodable_proofs() {
         stage_test_and_init_instance::<TestRuntime, KusamaInstance>(
             || {
                 let light_client =
@@ -772,7 +780,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, KusamaInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -781,7 +789,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, KusamaInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -790,7 +798,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, KusamaInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -799,7 +807,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, KusamaInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -828,7 +836,7 @@ pub mod grandpa_light_clients_test {
     }
 
     #[test]
-    fn verify_tx_and_state_inclusion_for_rococo_are_not_active_yet() {
+    fn verify_tx_and_state_inclusion_for_rococo_reject_undecodable_proofs() {
         stage_test_and_init_instance::<TestRuntime, RococoInstance>(
             || {
                 let light_client =
@@ -843,7 +851,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, RococoInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -852,7 +860,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, RococoInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -861,7 +869,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, RococoInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -870,7 +878,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, RococoInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -899,7 +907,7 @@ pub mod grandpa_light_clients_test {
     }
 
     #[test]
-    fn verify_tx_and_state_inclusion_for_polkadot_are_not_active_yet() {
+    fn verify_tx_and_state_inclusion_for_polkadot_reject_undecodable_proofs() {
         stage_test_and_init_instance::<TestRuntime, PolkadotInstance>(
             || {
                 let light_client = select_grandpa_light_client_instance::<TestRuntime, ()>(
@@ -913,7 +921,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, PolkadotInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -922,7 +930,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, PolkadotInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -931,7 +939,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_tx_inclusion not implemented yet"
+                    Error::<TestRuntime, PolkadotInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -940,7 +948,7 @@ pub mod grandpa_light_clients_test {
                         SpeedMode::Finalized,
                         b"any message".to_vec()
                     ),
-                    "GrandpaFV::verify_state_inclusion not implemented yet"
+                    Error::<TestRuntime, PolkadotInstance>::HeaderDataDecodingError
                 );
 
                 assert_err!(
@@ -1034,6 +1042,230 @@ pub mod grandpa_light_clients_test {
         );
     }
 
+    fn insert_header_with_roots_to_roco(
+        state_root: sp_core::H256,
+        extrinsics_root: sp_core::H256,
+    ) -> sp_core::H256 {
+        use sp_runtime::traits::Header as HeaderT;
+
+        let mut header = test_header(2);
+        header.set_state_root(state_root);
+        header.set_extrinsics_root(extrinsics_root);
+        let hash = header.hash();
+        crate::ImportedHeaders::<TestRuntime, RococoInstance>::insert(hash, header);
+        hash
+    }
+
+    // Extrinsics root is an ordered trie keyed by compact-encoded extrinsic indices
+    fn craft_valid_tx_proof(
+        extrinsics: Vec<Vec<u8>>,
+        proven_index: u32,
+    ) -> (sp_core::H256, sp_trie::StorageProof) {
+        use sp_state_machine::{backend::Backend, prove_read, InMemoryBackend};
+
+        let state_version = sp_runtime::StateVersion::V0;
+        let entries = extrinsics
+            .into_iter()
+            .enumerate()
+            .map(|(index, extrinsic)| (codec::Compact(index as u32).encode(), Some(extrinsic)))
+            .collect::<Vec<_>>();
+        let backend =
+            <InMemoryBackend<sp_core::Blake2Hasher>>::from((vec![(None, entries)], state_version));
+        let root = backend.storage_root(sp_std::iter::empty(), state_version).0;
+        let proof = sp_trie::StorageProof::new(
+            prove_read(backend, &[codec::Compact(proven_index).encode()])
+                .unwrap()
+                .into_iter_nodes(),
+        );
+
+        (root, proof)
+    }
+
+    #[test]
+    fn verify_state_inclusion_for_rococo_returns_proven_storage_value() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                let (state_root, storage_proof) =
+                    crate::bridges::runtime::craft_valid_storage_proof();
+                let block_hash = insert_header_with_roots_to_roco(state_root, Default::default());
+
+                let proof = crate::types::RelaychainTrieInclusionProof::<TestHeader> {
+                    key: b"key1".to_vec(),
+                    payload_proof: storage_proof,
+                    block_hash,
+                };
+
+                assert_eq!(
+                    light_client.verify_state_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Ok(InclusionReceipt {
+                        height: 2,
+                        including_header: block_hash.encode(),
+                        message: b"value1".to_vec(),
+                        event_index: None,
+                    })
+                );
+
+                assert_eq!(
+                    light_client.verify_state_inclusion_precompile(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Ok(b"value1".to_vec().encode())
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn verify_state_inclusion_for_rococo_rejects_key_outside_of_proof() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                let (state_root, storage_proof) =
+                    crate::bridges::runtime::craft_valid_storage_proof();
+                let block_hash = insert_header_with_roots_to_roco(state_root, Default::default());
+
+                let proof = crate::types::RelaychainTrieInclusionProof::<TestHeader> {
+                    key: b"key3".to_vec(),
+                    payload_proof: storage_proof,
+                    block_hash,
+                };
+
+                assert_err!(
+                    light_client.verify_state_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Error::<TestRuntime, RococoInstance>::InvalidStorageProof
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn verify_state_inclusion_for_rococo_rejects_unknown_header() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                let (_state_root, storage_proof) =
+                    crate::bridges::runtime::craft_valid_storage_proof();
+
+                let proof = crate::types::RelaychainTrieInclusionProof::<TestHeader> {
+                    key: b"key1".to_vec(),
+                    payload_proof: storage_proof,
+                    block_hash: Default::default(),
+                };
+
+                assert_err!(
+                    light_client.verify_state_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Error::<TestRuntime, RococoInstance>::UnknownHeader
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn verify_tx_inclusion_for_rococo_returns_proven_extrinsic() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                let extrinsics = vec![
+                    b"timestamp::set".to_vec(),
+                    b"balances::transfer".to_vec(),
+                    b"system::remark".to_vec(),
+                ];
+                let (extrinsics_root, tx_proof) = craft_valid_tx_proof(extrinsics, 1);
+                let block_hash =
+                    insert_header_with_roots_to_roco(Default::default(), extrinsics_root);
+
+                let proof = crate::types::RelaychainTrieInclusionProof::<TestHeader> {
+                    key: codec::Compact(1u32).encode(),
+                    payload_proof: tx_proof,
+                    block_hash,
+                };
+
+                assert_eq!(
+                    light_client.verify_tx_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Ok(InclusionReceipt {
+                        height: 2,
+                        including_header: block_hash.encode(),
+                        message: b"balances::transfer".to_vec(),
+                        event_index: None,
+                    })
+                );
+
+                // The same proof checked against the state root must fail
+                assert_err!(
+                    light_client.verify_state_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Error::<TestRuntime, RococoInstance>::InvalidStorageProof
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn verify_tx_inclusion_for_unregistered_parachain_fails() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                let proof = crate::types::ParachainTrieInclusionProof::<TestHeader> {
+                    key: codec::Compact(0u32).encode(),
+                    header_proof: sp_trie::StorageProof::new(vec![]),
+                    payload_proof: sp_trie::StorageProof::new(vec![]),
+                    relay_block_hash: Default::default(),
+                };
+
+                assert_err!(
+                    light_client.verify_tx_inclusion(
+                        *b"moon",
+                        SpeedMode::Finalized,
+                        proof.encode()
+                    ),
+                    Error::<TestRuntime, RococoInstance>::ParachainEntryNotFound
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
     // returns the last header in encoded form
     pub fn insert_headers_range_to_roco(headers_range: GrandpaHeaderData<TestHeader>) -> Bytes {
         let roco_light_client =
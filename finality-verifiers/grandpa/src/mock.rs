@@ -28,7 +28,12 @@ use sp_runtime::{
 
 type Header = generic::Header<u32, BlakeTwo256>;
 use sp_std::convert::{TryFrom, TryInto};
-use t3rn_primitives::{light_client::LightClientAsyncAPIEmptyMock, GatewayVendor};
+use t3rn_primitives::{
+    light_client::{
+        LightClientAsyncAPI, LightClientAsyncAPIEmptyMock, LightClientHeartbeat, ScaleLayout,
+    },
+    ChainId, GatewayVendor,
+};
 
 use crate::bridges::runtime::Chain;
 pub type AccountId = u64;
@@ -154,7 +159,7 @@ impl Config<RococoInstance> for TestRuntime {
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
     type HeadersToStore = HeadersToStore;
-    type LightClientAsyncAPI = LightClientAsyncAPIEmptyMock<TestRuntime>;
+    type LightClientAsyncAPI = MockEventLayouts;
     type MyVendor = RococoVendor;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
@@ -187,6 +192,26 @@ impl Config<PolkadotInstance> for TestRuntime {
     type WeightInfo = ();
 }
 
+/// Knows the layouts of the events crafted by tests: (2, 2) carrying u32 and (4, 0) carrying Vec<u8>
+pub struct MockEventLayouts;
+
+impl LightClientAsyncAPI<TestRuntime> for MockEventLayouts {
+    fn on_new_epoch(
+        _verifier: GatewayVendor,
+        _new_epoch: frame_system::pallet_prelude::BlockNumberFor<TestRuntime>,
+        _current_hearbeat: LightClientHeartbeat<TestRuntime>,
+    ) {
+    }
+
+    fn get_event_layout(_gateway_id: ChainId, event_index: [u8; 2]) -> Option<Vec<ScaleLayout>> {
+        match event_index {
+            [2, 2] => Some(vec![ScaleLayout::Fixed(4)]),
+            [4, 0] => Some(vec![ScaleLayout::Vec(Box::new(ScaleLayout::Fixed(1)))]),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TestCircuitLikeChain;
 
@@ -1,5 +1,6 @@
 use crate::{bridges::header_chain::justification::GrandpaJustification, TypeInfo};
 use codec::{Decode, Encode};
+use frame_system::Phase;
 use sp_consensus_grandpa::{AuthorityId, SetId};
 use sp_std::vec::Vec;
 use sp_trie::StorageProof;
@@ -37,6 +38,7 @@ pub struct RelaychainInclusionProof<Header: sp_runtime::traits::Header> {
     pub encoded_payload: Vec<u8>,
     pub payload_proof: StorageProof,
     pub block_hash: Header::Hash,
+    pub event_pointer: EventRecordPointer<Header::Hash>,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
@@ -46,4 +48,43 @@ pub struct ParachainInclusionProof<Header: sp_runtime::traits::Header> {
     pub header_proof: StorageProof,
     pub payload_proof: StorageProof,
     pub relay_block_hash: Header::Hash,
+    pub event_pointer: EventRecordPointer<Header::Hash>,
+}
+
+/// Locates a single event record within the proven `System::Events` of a block
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct EventRecordPointer<Hash> {
+    /// index of the event record within `System::Events`
+    pub event_index: u32,
+    /// index of the extrinsic that emitted the event
+    pub extrinsic_index: u32,
+    pub topics: Vec<Hash>,
+    /// encoded length of each runtime event in `System::Events`. The events are opaque to us,
+    /// so these are required to split the proven value into records
+    pub event_lengths: Vec<u32>,
+}
+
+/// `frame_system::EventRecord` with the runtime event of the bridged chain kept opaque
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OpaqueEventRecord<Hash> {
+    pub phase: Phase,
+    pub event: Vec<u8>,
+    pub topics: Vec<Hash>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct RelaychainTrieInclusionProof<Header: sp_runtime::traits::Header> {
+    /// key of the proven trie entry - the storage key for state proofs, or the compact-encoded extrinsic index for transaction proofs
+    pub key: Vec<u8>,
+    pub payload_proof: StorageProof,
+    pub block_hash: Header::Hash,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct ParachainTrieInclusionProof<Header: sp_runtime::traits::Header> {
+    /// key of the proven trie entry - the storage key for state proofs, or the compact-encoded extrinsic index for transaction proofs
+    pub key: Vec<u8>,
+    pub header_proof: StorageProof,
+    pub payload_proof: StorageProof,
+    pub relay_block_hash: Header::Hash,
 }
@@ -7,13 +7,16 @@
 
 use std::sync::Arc;
 
-use circuit_standalone_runtime::{opaque::Block, AccountId, Balance, Hash, Nonce};
+use circuit_standalone_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Nonce};
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+use pallet_attesters_rpc::{Attesters, AttestersApiServer};
+use pallet_circuit_rpc::{Circuit, CircuitApiServer};
+use pallet_evm_rpc::{Eth, EthApiServer};
 use pallet_portal_rpc::{Portal, PortalApiServer};
 use pallet_xdns_rpc::{Xdns, XdnsApiServer};
 
@@ -38,10 +41,13 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId>,
+    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId, Balance>,
     C::Api: pallet_portal_rpc::PortalRuntimeApi<Block, AccountId, Balance, Hash>,
+    C::Api: pallet_circuit_rpc::CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
+    C::Api: pallet_attesters_rpc::AttestersRuntimeApi<Block, Balance>,
+    C::Api: pallet_evm_rpc::EvmRuntimeApi<Block>,
     C::Api: BlockBuilder<Block>,
-    P: TransactionPool + 'static,
+    P: TransactionPool<Block = Block> + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -53,10 +59,13 @@ where
         deny_unsafe,
     } = deps;
 
-    module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+    module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     module.merge(Xdns::new(client.clone()).into_rpc())?;
-    module.merge(Portal::new(client).into_rpc())?;
+    module.merge(Portal::new(client.clone()).into_rpc())?;
+    module.merge(Circuit::new(client.clone()).into_rpc())?;
+    module.merge(Attesters::new(client.clone()).into_rpc())?;
+    module.merge(Eth::new(client, pool).into_rpc())?;
 
     Ok(module)
 }
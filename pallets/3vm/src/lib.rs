@@ -5,7 +5,11 @@
 pub use pallet::*;
 
 use frame_support::{
-    dispatch::DispatchResult, ensure, sp_runtime::DispatchError, traits::Currency,
+    dispatch::DispatchResult,
+    ensure,
+    sp_runtime::DispatchError,
+    traits::{Currency, Get},
+    weights::{Weight, WeightToFee},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::traits::StaticLookup;
@@ -16,8 +20,9 @@ use t3rn_primitives::{
     contract_metadata::ContractType,
     contracts_registry::{AuthorInfo, ContractsRegistry, KindValidator, RegistryContract},
     threevm::{
-        LocalStateAccess, ModuleOperations, Precompile, PrecompileArgs, PrecompileInvocation,
-        Remunerated, Remuneration, SignalOpcode, ThreeVm,
+        ContractVm, DispatchXtxCallback, InvokeXtxCallback, LocalStateAccess, ModuleOperations,
+        Precompile, PrecompileArgs, PrecompileInvocation, Remunerated, Remuneration, SignalOpcode,
+        ThreeVm, XtxCallback, XtxCallbackFailure, XtxResolution,
     },
 };
 use t3rn_sdk_primitives::signal::{ExecutionSignal, Signaller};
@@ -45,12 +50,12 @@ pub type BalanceOf<T> =
 pub mod pallet {
 
     use crate::BalanceOf;
-    use frame_support::{pallet_prelude::*, traits::Currency};
+    use frame_support::{pallet_prelude::*, traits::Currency, weights::WeightToFee};
     use frame_system::pallet_prelude::BlockNumberFor;
     use sp_std::vec::Vec;
     use t3rn_primitives::{
         account_manager::AccountManager, circuit::OnLocalTrigger, contract_metadata::ContractType,
-        contracts_registry::ContractsRegistry, portal::Portal, ChainId,
+        contracts_registry::ContractsRegistry, portal::Portal, threevm::InvokeXtxCallback, ChainId,
     };
 
     use t3rn_sdk_primitives::signal::SignalKind;
@@ -92,6 +97,15 @@ pub mod pallet {
 
         /// Inject access to portal so contracts can use light clients
         type Portal: Portal<Self>;
+
+        /// Calls back WASM contracts once the xtx they submitted resolves
+        type WasmCallbacks: InvokeXtxCallback<Self::AccountId>;
+
+        /// Calls back EVM contracts once the xtx they submitted resolves
+        type EvmCallbacks: InvokeXtxCallback<Self::AccountId>;
+
+        /// Converts the weight consumed by an xtx callback into the fee charged to the contract
+        type CallbackWeightToFee: WeightToFee<Balance = BalanceOf<Self>>;
     }
 
     #[pallet::pallet]
@@ -183,6 +197,17 @@ impl<T: Config> Pallet<T> {
         log::debug!(target: LOG_TARGET, "Reading author {:?}", contract);
         Self::author_of(contract)
     }
+
+    /// Charges the contract for the weight its xtx callback consumed
+    fn charge_xtx_callback(contract: &T::AccountId, consumed: Weight) -> DispatchResult {
+        let fee = T::CallbackWeightToFee::weight_to_fee(&consumed);
+        if let Some(charge_id) =
+            remuneration::try_charge_callback::<T>(contract, fee)?.remuneration_id
+        {
+            remuneration::try_finalize::<T>(charge_id, Outcome::Commit)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: Config> Precompile<T, BalanceOf<T>> for Pallet<T> {
@@ -200,6 +225,10 @@ impl<T: Config> Precompile<T, BalanceOf<T>> for Pallet<T> {
         precompile::invoke_raw::<T>(precompile, &mut &args.to_vec()[..], output)
     }
 
+    fn invoke_raw_weight(precompile: &u8) -> Weight {
+        precompile::invoke_raw_weight::<T>(precompile)
+    }
+
     fn invoke(
         args: PrecompileArgs<T, BalanceOf<T>>,
     ) -> Result<PrecompileInvocation<T, BalanceOf<T>>, DispatchError> {
@@ -248,6 +277,62 @@ impl<T: Config> Remuneration<T, BalanceOf<T>> for Pallet<T> {
     }
 }
 
+impl<T: Config> DispatchXtxCallback<T::AccountId, T::Hash> for Pallet<T> {
+    fn dispatch_xtx_callback(
+        callback: &XtxCallback<T::AccountId>,
+        resolution: &XtxResolution<T::Hash>,
+        gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure> {
+        log::debug!(
+            target: LOG_TARGET,
+            "Calling back {:?} contract {:?} on xtx {:?} resolved as {:?}",
+            callback.vm,
+            callback.contract,
+            resolution.xtx_id,
+            resolution.status
+        );
+        let caller = T::EscrowAccount::get();
+        let input = resolution.to_callback_input(callback.vm);
+
+        // Effects of the callback are kept only if it succeeds and its fee is charged
+        let outcome = frame_support::storage::with_storage_layer(|| {
+            let consumed = match callback.vm {
+                ContractVm::Wasm => T::WasmCallbacks::invoke_xtx_callback(
+                    &caller,
+                    &callback.contract,
+                    input,
+                    gas_limit,
+                ),
+                ContractVm::Evm => T::EvmCallbacks::invoke_xtx_callback(
+                    &caller,
+                    &callback.contract,
+                    input,
+                    gas_limit,
+                ),
+            }?;
+            Self::charge_xtx_callback(&callback.contract, consumed)
+                .map_err(|error| XtxCallbackFailure { consumed, error })?;
+            Ok(consumed)
+        });
+
+        // Failed callback still pays for the weight it consumed
+        if let Err(failure) = &outcome {
+            if let Err(err) = frame_support::storage::with_storage_layer(|| {
+                Self::charge_xtx_callback(&callback.contract, failure.consumed)
+            }) {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Failed to charge contract {:?} for its failed callback: {:?}",
+                    callback.contract,
+                    err
+                );
+            }
+        }
+
+        outcome
+    }
+}
+
 impl<T: Config> ThreeVm<T, BalanceOf<T>> for Pallet<T> {
     fn peek_registry(
         id: &T::Hash,
@@ -182,14 +182,17 @@ impl pallet_assets::Config for Test {
 impl pallet_3vm::Config for Test {
     type AccountManager = AccountManager;
     type AssetId = u32;
+    type CallbackWeightToFee = frame_support::weights::IdentityFee<Balance>;
     type CircuitTargetId = CircuitTargetId;
     type ContractsRegistry = ContractsRegistry;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
+    type EvmCallbacks = ();
     type OnLocalTrigger = Circuit;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SignalBounceThreshold = ConstU32<2>;
+    type WasmCallbacks = ();
 }
 
 impl pallet_contracts_registry::Config for Test {
@@ -214,6 +217,10 @@ impl pallet_account_manager::Config for Test {
 
 parameter_types! {
     pub const CircuitAccountId: AccountId = 33;
+    pub MaxXtxCallbackWeight: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(2_000_000_000, 64 * 1024);
+    pub MaxXtxCallbacksWeightPerBlock: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(10_500_000_000, 336 * 1024);
 }
 
 impl pallet_circuit::Config for Test {
@@ -221,9 +228,12 @@ impl pallet_circuit::Config for Test {
     type Attesters =
         t3rn_primitives::attesters::AttestersReadApiEmptyMock<AccountId, Balance, DispatchError>;
     type Balances = Balances;
+    type Clock = t3rn_primitives::clock::ClockMock<Self>;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<1024>;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type MaxXtxCallbackWeight = MaxXtxCallbackWeight;
+    type MaxXtxCallbacksWeightPerBlock = MaxXtxCallbacksWeightPerBlock;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3>;
@@ -233,7 +243,12 @@ impl pallet_circuit::Config for Test {
     type SignalQueueDepth = ConstU32<4>;
     type TreasuryAccounts = Test;
     type WeightInfo = ();
+    // Not connected to sibling parachains over XCM
+    type XcmResponseOrigin =
+        frame_support::traits::NeverEnsureOrigin<pallet_circuit::xcm_route::MultiLocation>;
+    type XcmRouter = ();
     type Xdns = Xdns;
+    type XtxCallbackDispatcher = ThreeVm;
     type XtxTimeoutCheckInterval = ConstU32<1024>;
     type XtxTimeoutDefault = ConstU32<1024>;
 }
@@ -1,6 +1,6 @@
 use crate::{BalanceOf, Config, Error, Pallet, PrecompileIndex};
 use codec::{Decode, Encode};
-use frame_support::{dispatch::RawOrigin, sp_runtime::DispatchError};
+use frame_support::{dispatch::RawOrigin, sp_runtime::DispatchError, traits::Get, weights::Weight};
 use frame_system::ensure_signed;
 use sp_std::prelude::*;
 use t3rn_primitives::{
@@ -8,8 +8,8 @@ use t3rn_primitives::{
     execution_source_to_option,
     portal::{Portal, PrecompileArgs as PortalPrecompileArgs},
     threevm::{
-        GetState, LocalStateAccess, PrecompileArgs, PrecompileInvocation, GET_STATE, PORTAL,
-        POST_SIGNAL, SUBMIT,
+        ContractVm, GetState, LocalStateAccess, PrecompileArgs, PrecompileInvocation, XtxCallback,
+        GET_STATE, PORTAL, POST_SIGNAL, SUBMIT,
     },
     SpeedMode, T3rnCodec,
 };
@@ -26,6 +26,17 @@ pub(crate) fn lookup<T: Config>(dest: &T::Hash) -> Option<u8> {
     PrecompileIndex::<T>::get(dest)
 }
 
+pub(crate) fn invoke_raw_weight<T: Config>(precompile: &u8) -> Weight {
+    match *precompile {
+        GET_STATE => T::OnLocalTrigger::load_local_state_weight(),
+        SUBMIT => T::OnLocalTrigger::on_local_trigger_weight(),
+        POST_SIGNAL => T::OnLocalTrigger::on_signal_weight(),
+        // Verifying through the Portal reads the target's light client twice
+        PORTAL => T::DbWeight::get().reads(2),
+        _ => Weight::zero(),
+    }
+}
+
 // FIXME: figure out charging, costing
 pub(crate) fn invoke_raw<T: Config>(precompile: &u8, args: &mut &[u8], output: &mut Vec<u8>) {
     if args.len() < 2 {
@@ -43,8 +54,7 @@ pub(crate) fn invoke_raw<T: Config>(precompile: &u8, args: &mut &[u8], output: &
             GET_STATE => {
                 let args: CodecResult<GetState<T>> = match codec {
                     T3rnCodec::Scale => Decode::decode(args),
-                    T3rnCodec::Rlp =>
-                        Err(codec::Error::from("Cannot decode GetState with RLP yet")),
+                    T3rnCodec::Rlp => decode_solidity_get_state::<T>(*args),
                 };
 
                 if let Ok(args) = args {
@@ -67,15 +77,26 @@ pub(crate) fn invoke_raw<T: Config>(precompile: &u8, args: &mut &[u8], output: &
                     SpeedMode,
                 )> = match codec {
                     T3rnCodec::Scale => Decode::decode(args),
-                    T3rnCodec::Rlp =>
-                        Err(codec::Error::from("Cannot decode SideEffects with RLP yet")),
+                    T3rnCodec::Rlp => decode_solidity_submit::<T>(*args),
                 };
 
                 if let Ok((sfx_arg, speed_mode_arg)) = args {
+                    // The contract calling the precompile is called back once its xtx resolves
+                    let maybe_callback =
+                        ensure_signed(origin.clone())
+                            .ok()
+                            .map(|contract| XtxCallback {
+                                contract,
+                                vm: match codec {
+                                    T3rnCodec::Scale => ContractVm::Wasm,
+                                    T3rnCodec::Rlp => ContractVm::Evm,
+                                },
+                            });
                     match invoke::<T>(PrecompileArgs::SubmitSideEffects(
                         origin,
                         sfx_arg,
                         speed_mode_arg,
+                        maybe_callback,
                     )) {
                         Ok(precompile_invocation) => {
                             let out_execution_state_view = precompile_invocation.get_submit();
@@ -97,7 +118,7 @@ pub(crate) fn invoke_raw<T: Config>(precompile: &u8, args: &mut &[u8], output: &
             POST_SIGNAL => {
                 let args: CodecResult<ExecutionSignal<T::Hash>> = match codec {
                     T3rnCodec::Scale => Decode::decode(args),
-                    T3rnCodec::Rlp => Err(codec::Error::from("Cannot decode Signals with RLP yet")),
+                    T3rnCodec::Rlp => decode_solidity_signal::<T>(*args),
                 };
 
                 if let Ok(args) = args {
@@ -160,7 +181,7 @@ fn extract_origin<T: Config>(codec: &T3rnCodec, args: &mut &[u8]) -> Option<T::R
         },
         T3rnCodec::Rlp => {
             // TODO: inject addressmapping here, dont always assume padded 12
-            let address_bytes = [args.take(..=20)?, &[0_u8; 12][..]].concat();
+            let address_bytes = [args.take(..20)?, &[0_u8; 12][..]].concat();
 
             match <T::AccountId as Decode>::decode(&mut &address_bytes[..]) {
                 Ok(account) => Some(T::RuntimeOrigin::from(RawOrigin::Signed(account))),
@@ -173,6 +194,74 @@ fn extract_origin<T: Config>(codec: &T3rnCodec, args: &mut &[u8]) -> Option<T::R
     }
 }
 
+// EVM callers pass the arguments encoded with Solidity ABI, as declared by the
+// interface of `pallet-evm-precompile-3vm-dispatch`. Side effects are carried as
+// `bytes` holding the SCALE encoded SDK `SideEffects`, same as for ink! callers.
+const SOLIDITY_WORD: usize = 32;
+
+fn solidity_word(args: &[u8], index: usize) -> CodecResult<&[u8]> {
+    args.get(index * SOLIDITY_WORD..(index + 1) * SOLIDITY_WORD)
+        .ok_or_else(|| codec::Error::from("Solidity argument out of bounds"))
+}
+
+fn solidity_uint(args: &[u8], index: usize, size: usize) -> CodecResult<&[u8]> {
+    let word = solidity_word(args, index)?;
+    let (padding, value) = word.split_at(SOLIDITY_WORD - size);
+    if padding.iter().any(|byte| *byte != 0) {
+        return Err(codec::Error::from("Solidity uint overflows its type"))
+    }
+    Ok(value)
+}
+
+fn solidity_usize(args: &[u8], index: usize) -> CodecResult<usize> {
+    let value = solidity_uint(args, index, 4)?;
+    Ok(u32::from_be_bytes([value[0], value[1], value[2], value[3]]) as usize)
+}
+
+fn solidity_bytes(args: &[u8], index: usize) -> CodecResult<&[u8]> {
+    let offset = solidity_usize(args, index)?;
+    let tail = args
+        .get(offset..)
+        .ok_or_else(|| codec::Error::from("Solidity bytes offset out of bounds"))?;
+    let len = solidity_usize(tail, 0)?;
+    tail.get(SOLIDITY_WORD..SOLIDITY_WORD.saturating_add(len))
+        .ok_or_else(|| codec::Error::from("Solidity bytes length out of bounds"))
+}
+
+/// getState(bytes32 xtxId), where zeroed xtxId reads the state of a fresh Xtx
+fn decode_solidity_get_state<T: Config>(args: &[u8]) -> CodecResult<GetState<T>> {
+    let word = solidity_word(args, 0)?;
+    let xtx_id = if word == [0u8; SOLIDITY_WORD] {
+        None
+    } else {
+        Some(T::Hash::decode(&mut &word[..])?)
+    };
+    Ok(GetState { xtx_id })
+}
+
+/// submit(bytes sideEffects, uint8 speedMode)
+fn decode_solidity_submit<T: Config>(
+    args: &[u8],
+) -> CodecResult<(SideEffects<T::AccountId, BalanceOf<T>, T::Hash>, SpeedMode)> {
+    let side_effects = Decode::decode(&mut solidity_bytes(args, 0)?)?;
+    let speed_mode = SpeedMode::decode(&mut solidity_uint(args, 1, 1)?)?;
+    Ok((side_effects, speed_mode))
+}
+
+/// signal(bytes32 xtxId, uint32 step, uint8 kind, uint8 killReason)
+fn decode_solidity_signal<T: Config>(args: &[u8]) -> CodecResult<ExecutionSignal<T::Hash>> {
+    let execution_id = T::Hash::decode(&mut solidity_word(args, 0)?)?;
+    let step = solidity_usize(args, 1)? as u32;
+    // Kill reason is only read for SignalKind::Kill
+    let kind =
+        Decode::decode(&mut &[solidity_uint(args, 2, 1)?[0], solidity_uint(args, 3, 1)?[0]][..])?;
+    Ok(ExecutionSignal {
+        step,
+        kind,
+        execution_id,
+    })
+}
+
 pub(crate) fn invoke<T: Config>(
     precompile: PrecompileArgs<T, BalanceOf<T>>,
 ) -> Result<PrecompileInvocation<T, BalanceOf<T>>, DispatchError> {
@@ -185,7 +274,7 @@ pub(crate) fn invoke<T: Config>(
             )?;
             Ok(PrecompileInvocation::GetState(state))
         },
-        PrecompileArgs::SubmitSideEffects(origin, side_effects, speed_mode) => {
+        PrecompileArgs::SubmitSideEffects(origin, side_effects, speed_mode, maybe_callback) => {
             let account = ensure_signed(origin.clone()).map_err(|_e| Error::<T>::InvalidOrigin)?;
 
             // todo: change parameter of t3rn_sdk::state::SideEffects to have optional execution_id
@@ -195,7 +284,7 @@ pub(crate) fn invoke<T: Config>(
                 Some(side_effects.execution_id)
             };
             if !side_effects.side_effects.is_empty() {
-                let trigger = LocalTrigger::<T>::new(
+                let mut trigger = LocalTrigger::<T>::new(
                     account, // FIXME: this is not right, investigate the contract address param
                     side_effects
                         .side_effects
@@ -205,6 +294,9 @@ pub(crate) fn invoke<T: Config>(
                     speed_mode,
                     maybe_xtx_id,
                 );
+                if let Some(callback) = maybe_callback {
+                    trigger = trigger.with_callback(callback);
+                }
 
                 T::OnLocalTrigger::on_local_trigger(&origin, trigger).map(
                     |local_state_execution_view| {
@@ -289,7 +381,7 @@ mod tests {
     fn test_extract_origin_consumes_buffer_rlp() {
         new_test_ext().execute_with(|| {
             let account = H160::from_low_u64_be(4);
-            let buffer = &mut &rlp::encode(&account)[..];
+            let buffer = &mut &account.as_bytes()[..];
             let result = extract_origin::<Test>(&T3rnCodec::Rlp, buffer).unwrap();
             println!("{result:?}");
 
@@ -302,7 +394,7 @@ mod tests {
         new_test_ext().execute_with(|| {
             let account = H160::from_low_u64_be(4);
             let args =
-                &mut &[vec![T3rnCodec::Rlp.into()], rlp::encode(&account).to_vec()].concat()[..];
+                &mut &[vec![T3rnCodec::Rlp.into()], account.as_bytes().to_vec()].concat()[..];
             let mut out = Vec::<u8>::new();
 
             invoke_raw::<Test>(&244_u8, args, &mut out);
@@ -404,166 +496,247 @@ mod tests {
         });
     }
 
+    fn transfer_side_effects() -> SideEffects<AccountId, u128, H256> {
+        let mut side_effects_bounded_vec: BoundedVec<Chain<AccountId, u128, H256>, 16> =
+            BoundedVec::default();
+
+        side_effects_bounded_vec
+            .try_push(Chain::Kusama(Operation::Transfer {
+                caller: 5_u64,
+                to: 6_u64,
+                amount: 1_u128,
+                insurance: None,
+            }))
+            .unwrap();
+
+        SideEffects::<AccountId, u128, H256> {
+            execution_id: H256::zero(),
+            side_effects: side_effects_bounded_vec,
+        }
+    }
+
+    fn solidity_uint_word(value: u32) -> Vec<u8> {
+        [vec![0u8; 28], value.to_be_bytes().to_vec()].concat()
+    }
+
+    fn solidity_submit_args(side_effects: &[u8], speed_mode: u8) -> Vec<u8> {
+        let mut padded_side_effects = side_effects.to_vec();
+        padded_side_effects.resize((side_effects.len() + 31) / 32 * 32, 0);
+        [
+            solidity_uint_word(64),
+            solidity_uint_word(speed_mode as u32),
+            solidity_uint_word(side_effects.len() as u32),
+            padded_side_effects,
+        ]
+        .concat()
+    }
+
     #[test]
     fn invoke_submit_sfx_with_speed_mode() {
         new_test_ext().execute_with(|| {
-            let account = 4_u64;
-            let caller = 5_u64;
-            let dest = 6_u64;
-            let mut side_effects_bounded_vec: BoundedVec<Chain<AccountId, u128, H256>, 16> =
-                BoundedVec::default();
-
-            side_effects_bounded_vec
-                .try_push(Chain::Kusama(Operation::Transfer {
-                    caller,
-                    to: dest,
-                    amount: 1_u128,
-                    insurance: None,
-                }))
-                .unwrap();
-
-            let speed_mode = SpeedMode::Finalized;
-            let submit_sfx_args = SideEffects::<AccountId, u128, H256> {
-                execution_id: H256::zero(),
-                side_effects: side_effects_bounded_vec,
-            };
+            let args = &mut &[
+                vec![T3rnCodec::Scale.into()],
+                4_u64.encode(),
+                transfer_side_effects().encode(),
+                SpeedMode::Finalized.encode(),
+            ]
+            .concat()[..];
+            let mut out = Vec::<u8>::new();
+
+            invoke_raw::<Test>(&SUBMIT, args, &mut out);
 
+            // Arguments are decoded and handed over to circuit
+            assert!(!out.is_empty());
+            assert_ne!(
+                out,
+                Err::<(), _>(Error::<Test>::InvalidPrecompileArgs).encode()
+            );
+            assert_ne!(out, Err::<(), _>(Error::<Test>::InvalidOrigin).encode());
+        });
+    }
+
+    #[test]
+    fn invoke_submit_sfx_with_solidity_args() {
+        new_test_ext().execute_with(|| {
+            let account = H160::from_low_u64_be(4);
             let args = &mut &[
-                account.encode(),
-                submit_sfx_args.encode(),
-                speed_mode.encode(),
+                vec![T3rnCodec::Rlp.into()],
+                account.as_bytes().to_vec(),
+                solidity_submit_args(&transfer_side_effects().encode(), 2),
             ]
             .concat()[..];
             let mut out = Vec::<u8>::new();
 
-            invoke_raw::<Test>(&GET_STATE, args, &mut out);
-            let res = <core::result::Result<
-                LocalStateExecutionView<Test, BalanceOf<Test>>,
-                crate::Error<Test>,
-            > as Decode>::decode(&mut &out[..])
-            .unwrap();
+            invoke_raw::<Test>(&SUBMIT, args, &mut out);
 
-            assert_eq!(
-                res,
-                Ok(LocalStateExecutionView::<Test, BalanceOf<Test>> {
-                    local_state: Default::default(),
-                    hardened_side_effects: vec![vec![]],
-                    steps_cnt: (0, 1),
-                    xtx_id: <Test as frame_system::Config>::Hash::decode(
-                        &mut &hex::decode(
-                            "daa77426c30c02a43d9fba4e841a6556c524d47030762eb14dc4af897e605d9b"
-                        )
-                        .unwrap()[..]
-                    )
-                    .unwrap()
-                })
+            assert!(!out.is_empty());
+            assert_ne!(
+                out,
+                Err::<(), _>(Error::<Test>::InvalidPrecompileArgs).encode()
             );
+            assert_ne!(out, Err::<(), _>(Error::<Test>::InvalidOrigin).encode());
         });
     }
 
-    // #[test]
-    // fn test_get_latest_finalized_header_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = GetLatestFinalizedHeader(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, GetLatestFinalizedHeader(chain_id));
-    // }
-
-    // #[test]
-    // fn test_get_latest_finalized_height_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = GetLatestFinalizedHeight(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, GetLatestFinalizedHeight(chain_id));
-    // }
-
-    // #[test]
-    // fn test_get_latest_updated_height_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = GetLatestUpdatedHeight(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, GetLatestUpdatedHeight(chain_id));
-    // }
-
-    // #[test]
-    // fn test_get_current_epoch_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = GetCurrentEpoch(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, GetCurrentEpoch(chain_id));
-    // }
-
-    // #[test]
-    // fn test_read_epoch_offset_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = ReadEpochOffset(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, ReadEpochOffset(chain_id));
-    // }
-
-    // #[test]
-    // fn test_read_fast_confirmation_offset_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = ReadFastConfirmationOffset(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, ReadFastConfirmationOffset(chain_id));
-    // }
-
-    // #[test]
-    // fn test_read_rational_confirmation_offset_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let portal_call = ReadRationalConfirmationOffset(chain_id);
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(
-    //         recoded_portal_call,
-    //         ReadRationalConfirmationOffset(chain_id)
-    //     );
-    // }
-
-    // #[test]
-    // fn test_verify_event_inclusion_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let event = vec![1, 2, 3, 4];
-    //     let portal_call = VerifyEventInclusion(chain_id, event.clone());
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, VerifyEventInclusion(chain_id, event));
-    // }
-
-    // #[test]
-    // fn test_verify_state_inclusion_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let event = vec![1, 2, 3, 4];
-    //     let portal_call = VerifyStateInclusion(chain_id, event.clone());
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, VerifyStateInclusion(chain_id, event));
-    // }
-
-    // #[test]
-    // fn test_verify_tx_inclusion_recodes_correctly_to_scale() {
-    //     let chain_id: [u8; 4] = [9, 9, 9, 9];
-    //     let event = vec![1, 2, 3, 4];
-    //     let portal_call = VerifyTxInclusion(chain_id, event.clone());
-    //     let encoded_portal_call = portal_call.encode();
-    //     let recoded_portal_call = recode_input_as_portal_api_enum(&encoded_portal_call).unwrap();
-
-    //     assert_eq!(recoded_portal_call, VerifyTxInclusion(chain_id, event));
-    // }
+    #[test]
+    fn solidity_submit_args_decode_to_sdk_side_effects() {
+        let side_effects = transfer_side_effects();
+        let args = solidity_submit_args(&side_effects.encode(), 3);
+
+        let (decoded_side_effects, speed_mode) = decode_solidity_submit::<Test>(&args).unwrap();
+
+        assert_eq!(decoded_side_effects.encode(), side_effects.encode());
+        assert_eq!(speed_mode, SpeedMode::Instant);
+    }
+
+    #[test]
+    fn solidity_submit_args_with_overflowing_speed_mode_fail_to_decode() {
+        let mut args = solidity_submit_args(&transfer_side_effects().encode(), 3);
+        args[32] = 1;
+
+        assert!(decode_solidity_submit::<Test>(&args).is_err());
+    }
+
+    #[test]
+    fn solidity_submit_args_with_truncated_bytes_fail_to_decode() {
+        let side_effects = transfer_side_effects().encode();
+        let args = solidity_submit_args(&side_effects, 2);
+
+        assert!(decode_solidity_submit::<Test>(&args[..64 + side_effects.len()]).is_err());
+    }
+
+    #[test]
+    fn solidity_get_state_args_decode_zeroed_xtx_id_as_fresh() {
+        assert_eq!(
+            decode_solidity_get_state::<Test>(&[0u8; 32])
+                .unwrap()
+                .xtx_id,
+            None
+        );
+        assert_eq!(
+            decode_solidity_get_state::<Test>(&[1u8; 32])
+                .unwrap()
+                .xtx_id,
+            Some(H256::repeat_byte(1))
+        );
+    }
+
+    #[test]
+    fn solidity_signal_args_decode_to_execution_signal() {
+        use t3rn_sdk_primitives::signal::{KillReason, SignalKind};
+
+        let args = [
+            [7u8; 32].to_vec(),
+            solidity_uint_word(3),
+            solidity_uint_word(1),
+            solidity_uint_word(2),
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_solidity_signal::<Test>(&args).unwrap(),
+            ExecutionSignal {
+                step: 3,
+                kind: SignalKind::Kill(KillReason::Timeout),
+                execution_id: H256::repeat_byte(7),
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_latest_finalized_header_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::GetLatestFinalizedHeader([9, 9, 9, 9]);
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_get_finalized_height_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::GetFinalizedHeight([9, 9, 9, 9]);
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_get_rational_height_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::GetRationalHeight([9, 9, 9, 9]);
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_get_fast_height_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::GetFastHeight([9, 9, 9, 9]);
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_verify_event_inclusion_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::VerifyEventInclusion(
+            [9, 9, 9, 9],
+            SpeedMode::Finalized,
+            [0u8; 32],
+            vec![1, 2, 3, 4],
+        );
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_verify_state_inclusion_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::VerifyStateInclusion(
+            [9, 9, 9, 9],
+            SpeedMode::Fast,
+            vec![1, 2, 3, 4],
+        );
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
+
+    #[test]
+    fn test_verify_tx_inclusion_recodes_correctly_to_scale() {
+        let portal_call = PortalPrecompileArgs::VerifyTxInclusion(
+            [9, 9, 9, 9],
+            SpeedMode::Rational,
+            vec![1, 2, 3, 4],
+        );
+        let recoded_portal_call = PortalPrecompileArgs::recode_to_scale_and_decode(
+            &T3rnCodec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(recoded_portal_call, portal_call);
+    }
 }
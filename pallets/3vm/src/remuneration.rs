@@ -33,6 +33,32 @@ pub(crate) fn try_remunerate_exact<T: Config, Module: ModuleOperations<T, Balanc
     }
 }
 
+/// Charges the contract for the weight its xtx callback consumed, settled to the escrow
+pub(crate) fn try_charge_callback<T: Config>(
+    payee: &T::AccountId,
+    amount: BalanceOf<T>,
+) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
+    if amount > BalanceOf::<T>::zero() {
+        let next_charge_id = T::AccountManager::bump_contracts_registry_nonce()?;
+        T::AccountManager::deposit(
+            next_charge_id,
+            RequestCharge {
+                payee: payee.clone(),
+                offered_reward: amount,
+                charge_fee: Zero::zero(),
+                source: BenefitSource::TrafficRewards,
+                role: CircuitRole::ContractAuthor,
+                recipient: None,
+                maybe_asset_id: None,
+            },
+        )?;
+
+        Ok(Remunerated::<T::Hash>::new(Some(next_charge_id)))
+    } else {
+        Ok(Remunerated::<T::Hash>::default())
+    }
+}
+
 pub(crate) fn try_finalize<T: Config>(ledger_id: T::Hash, outcome: Outcome) -> DispatchResult {
     T::AccountManager::finalize(ledger_id, outcome, None, Option::<BalanceOf<T>>::None)
 }
@@ -0,0 +1,29 @@
+//! Runtime API definition required by Attesters RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding read access to attestation batches and finality fees.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_core::H256;
+
+use sp_std::prelude::*;
+
+pub use t3rn_primitives::attesters::BatchingFactor;
+pub use t3rn_types::sfx::TargetId;
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Attesters
+    pub trait AttestersRuntimeApi<Balance> where
+        Balance: Codec,
+    {
+        /// Returns (index, message hash) of batches pending attestation for each active target
+        fn fetch_pending_batches() -> Vec<(TargetId, Vec<(u32, H256)>)>;
+        /// Returns the latest batching factor for each active target
+        fn fetch_latest_batching_factor_overview() -> Vec<(TargetId, Option<BatchingFactor>)>;
+        /// Returns the finality fee users currently pay for the target, or None if the target isn't active
+        fn estimate_user_finality_fee(target: TargetId) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,100 @@
+//! RPC interface for the Attesters pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+};
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+pub use pallet_attesters_rpc_runtime_api::AttestersRuntimeApi;
+use pallet_attesters_rpc_runtime_api::{BatchingFactor, TargetId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+#[rpc(client, server)]
+pub trait AttestersApi<Balance> {
+    /// Returns (index, message hash) of batches pending attestation for each active target
+    #[method(name = "attesters_fetchPendingBatches")]
+    fn fetch_pending_batches(&self) -> RpcResult<Vec<(TargetId, Vec<(u32, H256)>)>>;
+
+    /// Returns the latest batching factor for each active target
+    #[method(name = "attesters_fetchLatestBatchingFactorOverview")]
+    fn fetch_latest_batching_factor_overview(
+        &self,
+    ) -> RpcResult<Vec<(TargetId, Option<BatchingFactor>)>>;
+
+    /// Returns the finality fee users currently pay for the target
+    #[method(name = "attesters_estimateUserFinalityFee")]
+    fn estimate_user_finality_fee(&self, target: TargetId) -> RpcResult<Balance>;
+}
+
+/// A struct that implements the [`AttestersApi`].
+pub struct Attesters<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Attesters<C, P> {
+    /// Create new `Attesters` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, Balance> AttestersApiServer<Balance> for Attesters<C, Block>
+where
+    Balance: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AttestersRuntimeApi<Block, Balance>,
+{
+    fn fetch_pending_batches(&self) -> RpcResult<Vec<(TargetId, Vec<(u32, H256)>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Vec<(TargetId, Vec<(u32, H256)>)> = api
+            .fetch_pending_batches(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_latest_batching_factor_overview(
+        &self,
+    ) -> RpcResult<Vec<(TargetId, Option<BatchingFactor>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Vec<(TargetId, Option<BatchingFactor>)> = api
+            .fetch_latest_batching_factor_overview(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn estimate_user_finality_fee(&self, target: TargetId) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<Balance> = api
+            .estimate_user_finality_fee(at, target)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(finality_fee) => Ok(finality_fee),
+            None => Err(runtime_error_into_rpc_err("Target isn't active")),
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Custom(format!("{err:?}"))
+}
@@ -1,40 +1,76 @@
-// #![cfg(feature = "runtime-benchmarks")]
-//
-// use super::*;
-// use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-// use frame_system::RawOrigin;
-// // Import zero sp
-//
-// benchmarks! {
-//     submit_attestation {
-//         let s in 0 .. 4096;
-//         let signature_len = 65;
-//
-//         let caller: T::AccountId = whitelisted_caller();
-//         let ecdsa_key = ecdsa::Public::default();
-//         let ed25519_key = ed25519::Public::default();
-//         let sr25519_key = sr25519::Public::default();
-//         let target_as_self: [u8; 4] = [3u8; 4];
-//
-//         // Register the caller as an attester
-//         Attesters::<T>::insert(&caller, AttesterInfo {
-//             key_ed: ed25519_key,
-//             key_sr: sr25519_key,
-//             key_ec: ecdsa_key,
-//             commission: sp_runtime::traits::Zero::zero(),
-//             index: 0,
-//         });
-//
-//         let message = vec![1; s as usize];
-//         let signature = vec![0; signature_len as usize];
-//
-//     }: _(RawOrigin::Signed(caller.clone()), message, signature.into(), target_as_self)
-//     verify {
-//         // Ensure the event was emitted
-//         // System::assert_last_event::<T>(Event::AttestationSubmitted(caller).into());
-//
-//         Ok(())
-//     }
-// }
-//
-// impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use sp_std::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::Percent;
+
+const BENCHMARK_TARGET: TargetId = [3u8; 4];
+
+fn register_attester_with_bls_key<T: Config>(attester: &T::AccountId, secret_key: [u8; 32]) {
+    Attesters::<T>::insert(
+        attester,
+        AttesterInfo {
+            key_ed: [0u8; 32],
+            key_ec: [0u8; 33],
+            key_sr: [0u8; 32],
+            commission: Percent::from_percent(10),
+            index: 0,
+        },
+    );
+    AttestersBlsKeys::<T>::insert(
+        attester,
+        bls::public_key_from_secret(&secret_key).expect("Valid BLS secret key"),
+    );
+}
+
+benchmarks! {
+
+    submit_bls_attestation {
+        let caller: T::AccountId = whitelisted_caller();
+        let co_signer: T::AccountId = account("attester", 1, 0);
+        let caller_secret_key = [1u8; 32];
+        let co_signer_secret_key = [2u8; 32];
+
+        register_attester_with_bls_key::<T>(&caller, caller_secret_key);
+        register_attester_with_bls_key::<T>(&co_signer, co_signer_secret_key);
+        AttestationTargets::<T>::put(vec![BENCHMARK_TARGET]);
+        CurrentCommittee::<T>::put(vec![co_signer.clone(), caller.clone()]);
+
+        let mut batch = BatchMessage::<BlockNumberFor<T>>::default();
+        batch.status = BatchStatus::PendingAttestation;
+        let message_hash = batch.message_hash();
+
+        // Worst case aggregates into the existing aggregate of another committee member
+        let co_signer_signature = bls::sign(&co_signer_secret_key, bls::BLS_ATTESTATION_DST, message_hash.as_bytes())
+            .expect("Valid BLS signature");
+        let mut aggregated = AggregatedBlsSignature::default();
+        aggregated
+            .aggregate(0, &co_signer_signature, &AttestersBlsKeys::<T>::get(&co_signer).expect("Registered BLS key"))
+            .expect("Valid aggregation");
+        batch.aggregated_bls_signature = Some(aggregated);
+        Batches::<T>::insert(BENCHMARK_TARGET, vec![batch]);
+
+        let signature = bls::sign(&caller_secret_key, bls::BLS_ATTESTATION_DST, message_hash.as_bytes())
+            .expect("Valid BLS signature");
+    }: _(RawOrigin::Signed(caller), message_hash, signature, BENCHMARK_TARGET)
+    verify {
+        let batch = Pallet::<T>::get_batch_by_message_hash(BENCHMARK_TARGET, message_hash).expect("Batch exists");
+        assert_eq!(batch.aggregated_bls_signature.expect("Aggregated signature").signers_count(), 2);
+    }
+
+    register_bls_key {
+        let caller: T::AccountId = whitelisted_caller();
+        let secret_key = [1u8; 32];
+
+        register_attester_with_bls_key::<T>(&caller, [9u8; 32]);
+        let public_key = bls::public_key_from_secret(&secret_key).expect("Valid BLS secret key");
+        let proof_of_possession = bls::sign(&secret_key, bls::BLS_PROOF_OF_POSSESSION_DST, public_key.as_slice())
+            .expect("Valid BLS signature");
+    }: _(RawOrigin::Signed(caller.clone()), public_key, proof_of_possession)
+    verify {
+        assert_eq!(AttestersBlsKeys::<T>::get(&caller), Some(public_key));
+    }
+}
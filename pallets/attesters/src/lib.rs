@@ -2,6 +2,8 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod light_client;
+pub mod weights;
 
 pub use crate::pallet::*;
 
@@ -17,12 +19,24 @@ pub mod pallet {
     const TWO_EPOCHS_IN_LOCAL_BLOCKS_U8: u8 = 2 * 32;
     const ONE_EPOCHS_IN_LOCAL_BLOCKS_U8: u8 = 32;
     pub const REWARD_ADJUSTMENT: Percent = Percent::from_percent(25);
+    // Slash of the equivocating attester's self-nomination and the grace slash of its nominators.
+    pub const EQUIVOCATION_SLASH: Percent = Percent::from_percent(50);
+    pub const EQUIVOCATION_NOMINATOR_GRACE: Percent = Percent::from_percent(10);
+    // Share of the equivocation slash paid out to the reporter, the rest goes to the slash treasury.
+    pub const EQUIVOCATION_REPORTER_REWARD: Percent = Percent::from_percent(10);
+    // Reference time of recovering the ECDSA signer of the attested message.
+    const SIGNATURE_RECOVERY_WEIGHT: u64 = 50_000_000;
+    // Version of the storage layout, migrated in on_runtime_upgrade.
+    pub const CURRENT_STORAGE_VERSION: u32 = 1;
+    // Ethereum headers hold at least 15 fields, with the block number at index 8.
+    const ETH_HEADER_MIN_FIELDS: usize = 15;
+    const ETH_HEADER_NUMBER_FIELD: usize = 8;
 
     use super::*;
     t3rn_primitives::reexport_currency_types!();
     use tiny_keccak::{Hasher, Keccak};
 
-    use codec::{Decode, Encode};
+    use codec::{Decode, DecodeAll, Encode};
     use frame_support::{
         dispatch::DispatchResult,
         pallet_prelude::*,
@@ -37,17 +51,20 @@ pub mod pallet {
     };
 
     use sp_runtime::{
-        traits::{CheckedAdd, CheckedDiv, CheckedMul, Saturating, Zero},
+        traits::{CheckedAdd, CheckedDiv, CheckedMul, SaturatedConversion, Saturating, Zero},
         Percent,
     };
     use sp_std::{convert::TryInto, prelude::*};
 
     use t3rn_abi::{Codec, FilledAbi};
 
+    pub use crate::weights::WeightInfo;
+
     pub use t3rn_primitives::attesters::{
-        AttesterInfo, AttestersChange, AttestersReadApi, AttestersWriteApi,
-        BatchConfirmedSfxWithGMPPayload, BatchRevertedSfxId, BatchingFactor,
-        CommitteeTransitionIndices, LatencyStatus, PublicKeyEcdsa33b, Signature65b, COMMITTEE_SIZE,
+        bls, AggregatedBlsSignature, AttesterInfo, AttestersChange, AttestersReadApi,
+        AttestersWriteApi, BatchConfirmedSfxWithGMPPayload, BatchRevertedSfxId, BatchingFactor,
+        CommitteeTransitionIndices, LatencyStatus, PublicKeyBls96b, PublicKeyEcdsa33b,
+        Signature65b, SignatureBls48b, BLS_ATTESTER_KEY_TYPE_ID, COMMITTEE_SIZE,
         ECDSA_ATTESTER_KEY_TYPE_ID, ED25519_ATTESTER_KEY_TYPE_ID, SR25519_ATTESTER_KEY_TYPE_ID,
     };
     use t3rn_primitives::{
@@ -56,7 +73,7 @@ pub mod pallet {
         portal::Portal,
         rewards::RewardsWriteApi,
         xdns::Xdns,
-        ExecutionVendor, GatewayVendor, SpeedMode, TreasuryAccountProvider,
+        ExecutionSource, ExecutionVendor, GatewayVendor, SpeedMode, TreasuryAccountProvider,
     };
 
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, PartialOrd)]
@@ -76,16 +93,55 @@ pub mod pallet {
         pub committed_sfx: Option<BatchConfirmedSfxWithGMPPayload>,
         pub reverted_sfx: Option<BatchRevertedSfxId>,
         pub next_committee: Option<CommitteeRecoverable>,
+        // Aggregated BLS public key of the next committee, set only if all of its members registered their keys
+        pub next_committee_bls_public_key: Option<PublicKeyBls96b>,
         pub banned_committee: Option<CommitteeRecoverable>,
         pub index: u32,
         // Below fields are not part of the message, but are used to track the state of the message
         pub signatures: Vec<(u32, Signature65b)>,
+        pub aggregated_bls_signature: Option<AggregatedBlsSignature>,
+        pub created: BlockNumber,
+        pub status: BatchStatus,
+        pub latency: LatencyStatus,
+        pub halt: bool,
+    }
+
+    /// Layout of BatchMessage before the BLS attestations, translated in on_runtime_upgrade.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    pub struct BatchMessageV0<BlockNumber> {
+        pub available_to_commit_at: BlockNumber,
+        pub committed_sfx: Option<BatchConfirmedSfxWithGMPPayload>,
+        pub reverted_sfx: Option<BatchRevertedSfxId>,
+        pub next_committee: Option<CommitteeRecoverable>,
+        pub banned_committee: Option<CommitteeRecoverable>,
+        pub index: u32,
+        pub signatures: Vec<(u32, Signature65b)>,
         pub created: BlockNumber,
         pub status: BatchStatus,
         pub latency: LatencyStatus,
         pub halt: bool,
     }
 
+    impl<BlockNumber> From<BatchMessageV0<BlockNumber>> for BatchMessage<BlockNumber> {
+        fn from(batch: BatchMessageV0<BlockNumber>) -> Self {
+            BatchMessage {
+                available_to_commit_at: batch.available_to_commit_at,
+                committed_sfx: batch.committed_sfx,
+                reverted_sfx: batch.reverted_sfx,
+                next_committee: batch.next_committee,
+                next_committee_bls_public_key: None,
+                banned_committee: batch.banned_committee,
+                index: batch.index,
+                signatures: batch.signatures,
+                aggregated_bls_signature: None,
+                created: batch.created,
+                status: batch.status,
+                latency: batch.latency,
+                halt: batch.halt,
+            }
+        }
+    }
+
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
     pub struct InfluxMessage<BlockNumber> {
         pub message_hash: H256,
@@ -97,6 +153,16 @@ pub mod pallet {
         pub status: BatchStatus,
     }
 
+    /// Influx message which reached the committee's quorum, verified as a remote event, state or header.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    pub struct AttestedInflux<BlockNumber> {
+        pub gateway: TargetId,
+        pub message: H256,
+        pub message_hash: H256,
+        pub height_there: BlockNumber,
+        pub attested_at: BlockNumber,
+    }
+
     impl<BlockNumber: Zero> Default for BatchMessage<BlockNumber> {
         fn default() -> Self {
             BatchMessage {
@@ -104,8 +170,10 @@ pub mod pallet {
                 committed_sfx: None,
                 reverted_sfx: None,
                 next_committee: None,
+                next_committee_bls_public_key: None,
                 banned_committee: None,
                 signatures: Vec::new(),
+                aggregated_bls_signature: None,
                 status: BatchStatus::PendingMessage,
                 created: Zero::zero(),
                 latency: LatencyStatus::OnTime,
@@ -145,6 +213,14 @@ pub mod pallet {
             if let Some(ref committee) = self.next_committee {
                 encode_eth_committee_addresses_into_message(committee);
             }
+            // Targets verifying the aggregated BLS signature learn the next committee's key along with its addresses
+            if let Some(public_key) = self
+                .next_committee_bls_public_key
+                .as_ref()
+                .and_then(bls::public_key_for_pairing_precompile)
+            {
+                encoded_message.extend_from_slice(public_key.as_slice());
+            }
             if let Some(ref committee) = self.banned_committee {
                 encode_eth_committee_addresses_into_message(committee);
             }
@@ -162,6 +238,18 @@ pub mod pallet {
             encoded_message
         }
 
+        /// Message followed by the signers bitmap and the committee's aggregated BLS signature encoded as G1 point
+        ///     for EIP-2539 pairing precompiles - for targets verifying a single pairing instead of ecrecover per signer.
+        pub fn message_with_aggregated_bls_signature(&self) -> Option<Vec<u8>> {
+            let aggregated = self.aggregated_bls_signature.as_ref()?;
+            let mut encoded_message = self.message();
+            encoded_message.extend_from_slice(aggregated.signers_bitmap.to_be_bytes().as_slice());
+            encoded_message.extend_from_slice(
+                bls::signature_for_pairing_precompile(&aggregated.signature)?.as_slice(),
+            );
+            Some(encoded_message)
+        }
+
         pub fn message_hash(&self) -> H256 {
             let mut keccak = Keccak::v256();
             keccak.update(&self.message());
@@ -202,6 +290,27 @@ pub mod pallet {
         Permanent,
     }
 
+    /// Two signatures of the same attester over conflicting messages at the same position on target -
+    ///     either batch messages with the same index or influx messages claiming different headers at the same height there.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    pub enum EquivocationProof<BlockNumber> {
+        ConflictingBatches {
+            // Signed messages are recalculated with BatchMessage::message()
+            first_batch: BatchMessage<BlockNumber>,
+            first_signature: Vec<u8>,
+            second_batch: BatchMessage<BlockNumber>,
+            second_signature: Vec<u8>,
+        },
+        ConflictingInflux {
+            height_there: BlockNumber,
+            // Encoded headers of target, with influx messages being their hashes
+            first_header: Vec<u8>,
+            first_signature: Vec<u8>,
+            second_header: Vec<u8>,
+            second_signature: Vec<u8>,
+        },
+    }
+
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
     pub struct TargetBatchInclusionProof {
         // The batch message that was included in the block
@@ -224,6 +333,8 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type ActiveSetSize: Get<u32>;
+        /// Blocks an attested influx message stays verifiable by the attesters light client before it's pruned.
+        type AttestedInfluxRetention: Get<BlockNumberFor<Self>>;
         type CommitteeSize: Get<u32>;
         type BatchingWindow: Get<BlockNumberFor<Self>>;
         type RepatriationPeriod: Get<BlockNumberFor<Self>>;
@@ -241,12 +352,13 @@ pub mod pallet {
         type ReadSFX: ReadSFX<Self::Hash, Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
         type Xdns: Xdns<Self, BalanceOf<Self>>;
         type LightClientAsyncAPI: LightClientAsyncAPI<Self>;
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::without_storage_info]
-    pub struct Pallet<T>(_);
+    pub struct Pallet<T>(pub PhantomData<T>);
 
     #[pallet::storage]
     #[pallet::getter(fn attesters)]
@@ -269,6 +381,23 @@ pub mod pallet {
     pub type AttestationsInflux<T: Config> =
         StorageDoubleMap<_, Identity, TargetId, Identity, H256, InfluxMessage<BlockNumberFor<T>>>;
 
+    /// Influx messages attested by the committee's quorum, indexed by the attested message.
+    #[pallet::storage]
+    #[pallet::getter(fn attested_influx)]
+    pub type AttestedInfluxMessages<T: Config> =
+        StorageDoubleMap<_, Identity, TargetId, Identity, H256, AttestedInflux<BlockNumberFor<T>>>;
+
+    /// Attested influx messages to prune at the block their retention window ends.
+    #[pallet::storage]
+    pub type AttestedInfluxExpiries<T: Config> =
+        StorageMap<_, Identity, BlockNumberFor<T>, Vec<(TargetId, H256)>, ValueQuery>;
+
+    /// Highest influx message attested by the committee's quorum per target - the head of the target's attesters light client.
+    #[pallet::storage]
+    #[pallet::getter(fn latest_attested_influx)]
+    pub type LatestAttestedInflux<T: Config> =
+        StorageMap<_, Identity, TargetId, AttestedInflux<BlockNumberFor<T>>, OptionQuery>;
+
     #[pallet::storage]
     pub type CurrentRetributionPerSFXPercentage<T: Config> = StorageValue<_, Percent, ValueQuery>;
 
@@ -310,6 +439,14 @@ pub mod pallet {
         TargetId, // Target
         Vec<u8>,  // Recoverable pubkey/address from signature on target
     >;
+    #[pallet::storage]
+    pub type StorageMigrations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn attesters_bls_keys)]
+    pub type AttestersBlsKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PublicKeyBls96b>;
+
     #[pallet::storage]
     #[pallet::getter(fn next_batches)]
     pub type NextBatch<T: Config> =
@@ -378,7 +515,7 @@ pub mod pallet {
             Percent,      // auto regression param before
             Percent,      // auto regression param after
         ),
-        CollusionWithPermanentSlashDetected(TargetId, H256),
+        CollusionDetected(TargetId, H256),
         UserFinalityFeeEstimated(TargetId, BalanceOf<T>),
         NewAttestationBatch(TargetId, BatchMessage<BlockNumberFor<T>>),
         NewAttestationMessageHash(TargetId, H256, ExecutionVendor),
@@ -390,6 +527,12 @@ pub mod pallet {
         CurrentPendingAttestationBatches(TargetId, Vec<(u32, H256)>),
         AttestationsRemovedFromLateBatches(Vec<u32>),
         AttestationTargetRemoved(TargetId, Vec<TargetId>),
+        InfluxMessageAttested(TargetId, H256, BlockNumberFor<T>),
+        // AttesterEquivocationSlashed(offender, reporter, total slashed, reporter's reward)
+        AttesterEquivocationSlashed(T::AccountId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        BlsKeyRegistered(T::AccountId, PublicKeyBls96b),
+        // AggregatedBlsSignatureReady(target, message hash, aggregate) emitted on reaching quorum and full approval
+        AggregatedBlsSignatureReady(TargetId, H256, AggregatedBlsSignature),
         // ShufflingCompleted(current committee, previous committee, next committee)
         ShufflingCompleted(Vec<T::AccountId>, Vec<T::AccountId>, Vec<T::AccountId>),
     }
@@ -434,10 +577,21 @@ pub mod pallet {
         CommitteeSizeTooLarge,
         InfluxSignatureAlreadySubmitted,
         InfluxMessageHashIncorrect,
+        InfluxMessageNotAttested,
+        HeadersSubmittedOnlyViaInfluxAttestations,
+        AttestationTargetNotSpecified,
+        EquivocationProofInvalid,
+        EquivocationAlreadyReported,
+        BlsKeyNotRegistered,
+        BlsProofOfPossessionInvalid,
+        BlsKeyChangeWhileInCommittee,
+        EquivocationReportedByOffender,
+        EquivocationNominationsWitnessTooLow,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
         #[pallet::weight(60_000)]
         pub fn register_attester(
             origin: OriginFor<T>,
@@ -459,6 +613,7 @@ pub mod pallet {
             )
         }
 
+        #[pallet::call_index(1)]
         #[pallet::weight(60_000)]
         pub fn register_invulnerable_attester(
             origin: OriginFor<T>,
@@ -484,6 +639,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
         pub fn deregister_attester(origin: OriginFor<T>) -> DispatchResult {
             let attester = ensure_signed(origin)?;
@@ -522,6 +678,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(3)]
         #[pallet::weight(10_000)]
         pub fn remove_attestation_target(origin: OriginFor<T>, target: TargetId) -> DispatchResult {
             ensure_root(origin)?;
@@ -550,6 +707,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(4)]
         #[pallet::weight(10_000)]
         pub fn agree_to_new_attestation_target(
             origin: OriginFor<T>,
@@ -579,6 +737,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(5)]
         #[pallet::weight(10_000)]
         pub fn force_activate_target(origin: OriginFor<T>, target: TargetId) -> DispatchResult {
             ensure_root(origin)?;
@@ -606,6 +765,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(6)]
         #[pallet::weight(10_000)]
         pub fn add_attestation_target(origin: OriginFor<T>, target: TargetId) -> DispatchResult {
             ensure_root(origin)?;
@@ -632,6 +792,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(7)]
         #[pallet::weight(10_000)]
         pub fn submit_for_influx_attestation(
             // Must be signed by the attester in current Committee
@@ -658,6 +819,11 @@ pub mod pallet {
             // Lookup the attester in the storage
             let attester = Attesters::<T>::get(&account_id).ok_or(Error::<T>::NotRegistered)?;
 
+            ensure!(
+                !Self::is_permanently_slashed(&account_id),
+                Error::<T>::RejectingFromSlashedAttester
+            );
+
             // Check if Current Committee
             ensure!(
                 CurrentCommittee::<T>::get().contains(&account_id),
@@ -671,38 +837,9 @@ pub mod pallet {
             // Get the codec of target from XDNS
             let target_codec = <T as Config>::Xdns::get_target_codec(&target)?;
 
-            let mut message_bytes = message.as_bytes().to_vec();
-            // add target to the message (assumed encoded on 32 bytes)
-            message_bytes.extend_from_slice(&target.encode());
-            // add height_there to the message (assumed encoded on 4 bytes)
-
             // Check if message_hash is correct
-            let recalculate_message_hash: H256 = match target_codec {
-                Codec::Scale => {
-                    message_bytes.extend_from_slice(&height_there.encode());
-                    let substrate_message_hash =
-                        <sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash(
-                            &message_bytes.as_slice(),
-                        );
-                    H256::from(substrate_message_hash)
-                },
-                Codec::Rlp => {
-                    // reverse order for BigEndian encoding
-                    let height_there = height_there
-                        .encode()
-                        .iter()
-                        .rev()
-                        .cloned()
-                        .collect::<Vec<u8>>();
-                    message_bytes.extend_from_slice(&height_there);
-                    // calculate the hash of the message with tiny-keccak
-                    let mut keccak = Keccak::v256();
-                    keccak.update(&message_bytes);
-                    let mut evm_message_hash: [u8; 32] = [0; 32];
-                    keccak.finalize(&mut evm_message_hash);
-                    H256::from(evm_message_hash)
-                },
-            };
+            let recalculate_message_hash =
+                Self::influx_message_hash(&target_codec, &message, &target, &height_there);
 
             ensure!(
                 recalculate_message_hash == message_hash,
@@ -760,12 +897,21 @@ pub mod pallet {
                 BatchStatus::PendingAttestation
             };
             attestation_influx.status = status;
+
+            // Once the quorum is reached, the message counts as verified by the attesters light client
+            if attestation_influx.status != BatchStatus::PendingAttestation
+                && !AttestedInfluxMessages::<T>::contains_key(&target, &message)
+            {
+                Self::record_attested_influx(&attestation_influx);
+            }
+
             // Save Influx attestation into storage
             AttestationsInflux::<T>::insert(&target, &message_hash, attestation_influx);
 
             Ok(())
         }
 
+        #[pallet::call_index(8)]
         #[pallet::weight(10_000)]
         pub fn submit_attestation(
             // Must be signed by the attester in current Committee
@@ -788,6 +934,11 @@ pub mod pallet {
             // Lookup the attester in the storage
             let attester = Attesters::<T>::get(&account_id).ok_or(Error::<T>::NotRegistered)?;
 
+            ensure!(
+                !Self::is_permanently_slashed(&account_id),
+                Error::<T>::RejectingFromSlashedAttester
+            );
+
             // Check if active set
             ensure!(
                 ActiveSet::<T>::get().contains(&account_id),
@@ -888,6 +1039,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(9)]
         #[pallet::weight(10_000)]
         pub fn commit_batch(
             origin: OriginFor<T>,
@@ -920,6 +1072,7 @@ pub mod pallet {
                 height: Zero::zero(),
                 message: target_inclusion_proof_encoded,
                 including_header: [0u8; 32].encode(),
+                event_index: None,
             };
 
             #[cfg(not(feature = "test-skip-verification"))]
@@ -953,16 +1106,15 @@ pub mod pallet {
                     // At this point we know the valid message has been recorded on target Escrow Smart Contract
                     // If we can't find the corresponding batch by the message - we have a problem - attesters are colluding.
                     log::error!(
-                        "CollusionWithPermanentSlashDetected detected on target: {target:?} for message hash {recovered_enacted_batch_hash:?}"
+                        "CollusionDetected on target: {target:?} for message hash {recovered_enacted_batch_hash:?}"
                     );
-                    // fixme: must synchronize remote block height and local block height and fetch committee responsible for attesting false batch.
-                    Self::apply_permanent_attesters_slash(CurrentCommittee::<T>::get());
-
-                    Self::deposit_event(Event::CollusionWithPermanentSlashDetected(
+                    // Committee that signed the message isn't known from its hash alone. Individual signers
+                    // are slashed once their signatures of the colluded message are proven via report_equivocation.
+                    Self::deposit_event(Event::CollusionDetected(
                         target,
                         recovered_enacted_batch_hash,
                     ));
-                    // must return success here to apply permanent slash
+                    // must return success here to keep the event for reporters
                     return Ok(())
                 },
             };
@@ -1005,39 +1157,35 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Emits the pending attestation batches of all active targets as events.
+        #[pallet::call_index(10)]
         #[pallet::weight(10_000)]
         pub fn read_pending_batches(origin: OriginFor<T>) -> DispatchResult {
             ensure_signed(origin)?;
 
-            for target in AttestationTargets::<T>::get() {
-                let pending_attestation_batches = Self::get_all_batches_to_sign(target);
+            for (target, pending_attestation_batches) in Self::pending_batches_overview() {
                 Self::deposit_event(Event::CurrentPendingAttestationBatches(
                     target,
-                    pending_attestation_batches
-                        .iter()
-                        .map(|batch| (batch.index, batch.message_hash()))
-                        .collect::<Vec<(u32, H256)>>(),
+                    pending_attestation_batches,
                 ));
             }
 
             Ok(())
         }
 
+        /// Emits the latest batching factor of all active targets as an event.
+        #[pallet::call_index(11)]
         #[pallet::weight(10_000)]
         pub fn read_latest_batching_factor_overview(origin: OriginFor<T>) -> DispatchResult {
             ensure_signed(origin)?;
 
-            let mut batching_factor_overview = Vec::new();
-            for target in AttestationTargets::<T>::get() {
-                let batching_factor = Self::read_latest_batching_factor(&target);
-                batching_factor_overview.push((target, batching_factor));
-            }
-
-            Self::deposit_event(Event::BatchingFactorRead(batching_factor_overview));
+            Self::deposit_event(Event::BatchingFactorRead(Self::batching_factor_overview()));
 
             Ok(())
         }
 
+        /// Emits the finality fee estimate for the target as an event.
+        #[pallet::call_index(12)]
         #[pallet::weight(10_000)]
         pub fn estimate_user_finality_fee(
             origin: OriginFor<T>,
@@ -1045,23 +1193,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_signed(origin)?;
 
-            // Ensure target is active
-            ensure!(
-                AttestationTargets::<T>::get().contains(&target),
-                Error::<T>::TargetNotActive
-            );
-
-            let finality_fee = <Pallet<T> as AttestersReadApi<
-                T::AccountId,
-                BalanceOf<T>,
-                BlockNumberFor<T>,
-            >>::estimate_finality_fee(&target);
+            let finality_fee =
+                Self::user_finality_fee_estimate(&target).ok_or(Error::<T>::TargetNotActive)?;
 
             Self::deposit_event(Event::UserFinalityFeeEstimated(target, finality_fee));
 
             Ok(())
         }
 
+        #[pallet::call_index(13)]
         #[pallet::weight(10_000)]
         pub fn nominate(
             origin: OriginFor<T>,
@@ -1081,6 +1221,7 @@ pub mod pallet {
             Ok(())
         }
 
+        #[pallet::call_index(14)]
         #[pallet::weight(10_000)]
         pub fn unnominate(origin: OriginFor<T>, attester: T::AccountId) -> DispatchResult {
             let nominator = ensure_signed(origin)?;
@@ -1111,6 +1252,196 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Report the attester that signed two conflicting messages at the same position on target.
+        ///     Offender's self-nomination and its nominators are partially slashed, the offender is slashed permanently
+        ///     and the reporter receives a share of the slashed stake.
+        ///     Nominations witness is the upper bound of the offender's nominations count the weight is charged for.
+        #[pallet::call_index(15)]
+        #[pallet::weight(Pallet::<T>::report_equivocation_weight(*nominations_witness))]
+        pub fn report_equivocation(
+            origin: OriginFor<T>,
+            offender: T::AccountId,
+            target: TargetId,
+            proof: EquivocationProof<BlockNumberFor<T>>,
+            nominations_witness: u32,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            ensure!(
+                reporter != offender,
+                Error::<T>::EquivocationReportedByOffender
+            );
+
+            let attester = Attesters::<T>::get(&offender).ok_or(Error::<T>::NotRegistered)?;
+
+            ensure!(
+                !Self::is_permanently_slashed(&offender),
+                Error::<T>::EquivocationAlreadyReported
+            );
+
+            ensure!(
+                Nominations::<T>::iter_prefix(&offender)
+                    .take(nominations_witness.saturating_add(1) as usize)
+                    .count()
+                    <= nominations_witness as usize,
+                Error::<T>::EquivocationNominationsWitnessTooLow
+            );
+
+            let recoverable = AttestersAgreements::<T>::get(&offender, target)
+                .ok_or(Error::<T>::AttesterDidNotAgreeToNewTarget)?;
+
+            let vendor = <T as Config>::Xdns::get_verification_vendor(&target)
+                .map_err(|_| Error::<T>::XdnsTargetNotActive)?;
+
+            for (message_hash, signature) in Self::read_equivocated_messages(&target, proof)? {
+                let is_verified = attester
+                    .verify_attestation_signature(
+                        ECDSA_ATTESTER_KEY_TYPE_ID,
+                        &message_hash.encode(),
+                        &signature,
+                        recoverable.clone(),
+                        &vendor,
+                    )
+                    .map_err(|_| Error::<T>::InvalidSignature)?;
+                ensure!(is_verified, Error::<T>::InvalidSignature);
+            }
+
+            let (slashed, reporter_reward) = Self::apply_equivocation_slash(&offender, &reporter);
+
+            Self::deposit_event(Event::AttesterEquivocationSlashed(
+                offender,
+                reporter,
+                slashed,
+                reporter_reward,
+            ));
+
+            Ok(())
+        }
+
+        /// Attest to the batch message with the BLS key, aggregating the signature into the batch's committee signature.
+        ///     Aggregation runs alongside ECDSA attestations and doesn't affect the batch status.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::submit_bls_attestation())]
+        pub fn submit_bls_attestation(
+            origin: OriginFor<T>,
+            message: H256,
+            signature: SignatureBls48b,
+            target: TargetId,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(
+                AttestationTargets::<T>::get().contains(&target),
+                Error::<T>::TargetNotActive
+            );
+
+            ensure!(
+                Attesters::<T>::contains_key(&account_id),
+                Error::<T>::NotRegistered
+            );
+
+            ensure!(
+                !Self::is_permanently_slashed(&account_id),
+                Error::<T>::RejectingFromSlashedAttester
+            );
+
+            let committee_position = CurrentCommittee::<T>::get()
+                .iter()
+                .position(|member| member == &account_id)
+                .ok_or(Error::<T>::NotInCurrentCommittee)?;
+
+            let public_key =
+                AttestersBlsKeys::<T>::get(&account_id).ok_or(Error::<T>::BlsKeyNotRegistered)?;
+
+            ensure!(
+                bls::verify(
+                    &public_key,
+                    bls::BLS_ATTESTATION_DST,
+                    message.as_bytes(),
+                    &signature
+                ),
+                Error::<T>::InvalidSignature
+            );
+
+            Batches::<T>::try_mutate(target, |batches_option| {
+                let batches = batches_option.as_mut().ok_or(Error::<T>::BatchNotFound)?;
+
+                let batch = batches
+                    .iter_mut()
+                    .find(|batch| batch.message_hash() == message)
+                    .ok_or(Error::<T>::BatchNotFound)?;
+
+                ensure!(
+                    batch.status == BatchStatus::PendingAttestation
+                        || batch.status == BatchStatus::ReadyForSubmissionByMajority,
+                    Error::<T>::BatchFoundWithUnsignableStatus
+                );
+
+                let mut aggregated = batch.aggregated_bls_signature.clone().unwrap_or_default();
+                ensure!(
+                    !aggregated.has_signed(committee_position),
+                    Error::<T>::AttestationDoubleSignAttempt
+                );
+                aggregated
+                    .aggregate(committee_position, &signature, &public_key)
+                    .map_err(|_| Error::<T>::InvalidSignature)?;
+
+                let quorum = (T::CommitteeSize::get() * 2 / 3) as usize;
+                let full_approval = T::CommitteeSize::get() as usize;
+                if aggregated.signers_count() == quorum
+                    || aggregated.signers_count() == full_approval
+                {
+                    Self::deposit_event(Event::AggregatedBlsSignatureReady(
+                        target,
+                        message,
+                        aggregated.clone(),
+                    ));
+                }
+                batch.aggregated_bls_signature = Some(aggregated);
+
+                Self::deposit_event(Event::AttestationSubmitted(account_id));
+
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Ok(())
+        }
+
+        /// Register the BLS12-377 key of the attester, along with the proof of possession being the signature of the key itself.
+        ///     Registered key can't be changed while the attester is a member of the current or next committee.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::register_bls_key())]
+        pub fn register_bls_key(
+            origin: OriginFor<T>,
+            public_key: PublicKeyBls96b,
+            proof_of_possession: SignatureBls48b,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(
+                Attesters::<T>::contains_key(&account_id),
+                Error::<T>::NotRegistered
+            );
+
+            ensure!(
+                !AttestersBlsKeys::<T>::contains_key(&account_id)
+                    || !(CurrentCommittee::<T>::get().contains(&account_id)
+                        || NextCommittee::<T>::get().contains(&account_id)),
+                Error::<T>::BlsKeyChangeWhileInCommittee
+            );
+
+            ensure!(
+                bls::verify_proof_of_possession(&public_key, &proof_of_possession),
+                Error::<T>::BlsProofOfPossessionInvalid
+            );
+
+            AttestersBlsKeys::<T>::insert(&account_id, public_key);
+
+            Self::deposit_event(Event::BlsKeyRegistered(account_id, public_key));
+
+            Ok(())
+        }
     }
 
     impl<T: Config> AttestersWriteApi<T::AccountId, DispatchError> for Pallet<T> {
@@ -1214,15 +1545,20 @@ pub mod pallet {
                             .map(|(_index, recoverable)| recoverable)
                             .collect::<Vec<Vec<u8>>>();
 
-                        let next_committee = match committee_recoverable_on_target.len() {
-                            0 => None,
-                            _ => Some(committee_recoverable_on_target),
-                        };
+                        let (next_committee, next_committee_bls_public_key) =
+                            match committee_recoverable_on_target.len() {
+                                0 => (None, None),
+                                _ => (
+                                    Some(committee_recoverable_on_target),
+                                    Self::next_committee_bls_public_key(),
+                                ),
+                            };
                         // We only update the next_committee if it was None.
                         NextBatch::<T>::insert(
                             target,
                             BatchMessage {
                                 next_committee,
+                                next_committee_bls_public_key,
                                 ..next_batch
                             },
                         );
@@ -1507,6 +1843,100 @@ pub mod pallet {
             T::CommitteeSize::get() as usize
         }
 
+        /// Hashes the bytes the way the target chain does: Blake2 for SCALE targets and Keccak for RLP targets.
+        pub fn hash_by_target_codec(target_codec: &Codec, bytes: &[u8]) -> H256 {
+            match target_codec {
+                Codec::Scale => H256::from(
+                    <sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash(bytes),
+                ),
+                Codec::Rlp => {
+                    // calculate the hash of the message with tiny-keccak
+                    let mut keccak = Keccak::v256();
+                    keccak.update(bytes);
+                    let mut evm_message_hash: [u8; 32] = [0; 32];
+                    keccak.finalize(&mut evm_message_hash);
+                    H256::from(evm_message_hash)
+                },
+            }
+        }
+
+        /// Hash of the influx message signed by attesters - the message followed by the target and height there,
+        ///     with height encoded as big endian for RLP targets.
+        pub fn influx_message_hash(
+            target_codec: &Codec,
+            message: &H256,
+            target: &TargetId,
+            height_there: &BlockNumberFor<T>,
+        ) -> H256 {
+            let mut message_bytes = message.as_bytes().to_vec();
+            // add target to the message (assumed encoded on 32 bytes)
+            message_bytes.extend_from_slice(&target.encode());
+            // add height_there to the message (assumed encoded on 4 bytes)
+            match target_codec {
+                Codec::Scale => message_bytes.extend_from_slice(&height_there.encode()),
+                // reverse order for BigEndian encoding
+                Codec::Rlp => message_bytes.extend(height_there.encode().iter().rev()),
+            }
+            Self::hash_by_target_codec(target_codec, &message_bytes)
+        }
+
+        pub fn record_attested_influx(influx: &InfluxMessage<BlockNumberFor<T>>) {
+            let attested = AttestedInflux {
+                gateway: influx.gateway,
+                message: influx.message,
+                message_hash: influx.message_hash,
+                height_there: influx.height_there,
+                attested_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            AttestedInfluxMessages::<T>::insert(&influx.gateway, &influx.message, &attested);
+            AttestedInfluxExpiries::<T>::append(
+                attested.attested_at + T::AttestedInfluxRetention::get(),
+                (influx.gateway, influx.message),
+            );
+            // Messages may reach the quorum out of order - the head only moves forward
+            LatestAttestedInflux::<T>::mutate(&influx.gateway, |latest| {
+                if latest
+                    .as_ref()
+                    .map_or(true, |latest| attested.height_there > latest.height_there)
+                {
+                    *latest = Some(attested);
+                }
+            });
+
+            Self::deposit_event(Event::InfluxMessageAttested(
+                influx.gateway,
+                influx.message,
+                influx.height_there,
+            ));
+        }
+
+        /// Confirms the remote message was attested by the committee's quorum on the given target.
+        ///     Events of a known source are attested over the source followed by the event,
+        ///     so that the committee vouches for the emitter too.
+        pub fn confirm_attested_inclusion(
+            gateway_id: TargetId,
+            source: Option<ExecutionSource>,
+            message: Vec<u8>,
+        ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+            let target_codec = <T as Config>::Xdns::get_target_codec(&gateway_id)?;
+            let attested_message = match source {
+                Some(source) => Self::hash_by_target_codec(
+                    &target_codec,
+                    &[source.as_slice(), message.as_slice()].concat(),
+                ),
+                None => Self::hash_by_target_codec(&target_codec, &message),
+            };
+            let attested = AttestedInfluxMessages::<T>::get(&gateway_id, attested_message)
+                .ok_or(Error::<T>::InfluxMessageNotAttested)?;
+
+            Ok(InclusionReceipt {
+                height: attested.height_there,
+                including_header: attested.message_hash.encode(),
+                message,
+                event_index: None,
+            })
+        }
+
         /// This function applies a partial slash to the stakes of an attester and its nominators.
         /// It returns the self-nomination balance of the given attester and the updated nomination balances
         /// of the nominators with an applied grace percent.
@@ -1582,6 +2012,155 @@ pub mod pallet {
             }
         }
 
+        /// Checks both messages of the equivocation proof conflict at the same position,
+        ///     returning the hashes signed by the attester along with the signatures.
+        fn read_equivocated_messages(
+            target: &TargetId,
+            proof: EquivocationProof<BlockNumberFor<T>>,
+        ) -> Result<[(H256, Vec<u8>); 2], DispatchError> {
+            match proof {
+                EquivocationProof::ConflictingBatches {
+                    first_batch,
+                    first_signature,
+                    second_batch,
+                    second_signature,
+                } => {
+                    ensure!(
+                        first_batch.index == second_batch.index
+                            && first_batch.message() != second_batch.message(),
+                        Error::<T>::EquivocationProofInvalid
+                    );
+                    // Batch messages are signed as keccak hashes regardless of target's codec
+                    Ok([
+                        (first_batch.message_hash(), first_signature),
+                        (second_batch.message_hash(), second_signature),
+                    ])
+                },
+                EquivocationProof::ConflictingInflux {
+                    height_there,
+                    first_header,
+                    first_signature,
+                    second_header,
+                    second_signature,
+                } => {
+                    let target_codec = <T as Config>::Xdns::get_target_codec(target)?;
+                    // Only headers claim the slot at height there - events and state attested at the same height don't conflict
+                    let height_there_u64 = height_there.saturated_into::<u64>();
+                    ensure!(
+                        first_header != second_header
+                            && Self::read_header_number(&target_codec, &first_header)
+                                == Some(height_there_u64)
+                            && Self::read_header_number(&target_codec, &second_header)
+                                == Some(height_there_u64),
+                        Error::<T>::EquivocationProofInvalid
+                    );
+                    Ok([
+                        (
+                            Self::influx_message_hash(
+                                &target_codec,
+                                &Self::hash_by_target_codec(&target_codec, &first_header),
+                                target,
+                                &height_there,
+                            ),
+                            first_signature,
+                        ),
+                        (
+                            Self::influx_message_hash(
+                                &target_codec,
+                                &Self::hash_by_target_codec(&target_codec, &second_header),
+                                target,
+                                &height_there,
+                            ),
+                            second_signature,
+                        ),
+                    ])
+                },
+            }
+        }
+
+        /// Reads the number of the encoded header of target - Substrate header for SCALE targets
+        ///     and Ethereum header, with the number being the 9th field, for RLP targets.
+        fn read_header_number(target_codec: &Codec, header: &[u8]) -> Option<u64> {
+            match target_codec {
+                Codec::Scale =>
+                    sp_runtime::generic::Header::<u32, sp_runtime::traits::BlakeTwo256>::decode_all(
+                        &mut &header[..],
+                    )
+                    .ok()
+                    .map(|header| header.number as u64),
+                Codec::Rlp => {
+                    let header = rlp::Rlp::new(header);
+                    if !header.is_list() || header.item_count().ok()? < ETH_HEADER_MIN_FIELDS {
+                        return None
+                    }
+                    header.val_at::<u64>(ETH_HEADER_NUMBER_FIELD).ok()
+                },
+            }
+        }
+
+        /// Two signature recoveries and reads of the offender's records, along with the slash and update of each nomination.
+        pub fn report_equivocation_weight(nominations_witness: u32) -> Weight {
+            Weight::from_parts(2 * SIGNATURE_RECOVERY_WEIGHT, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(6, 4))
+                .saturating_add(
+                    T::DbWeight::get()
+                        .reads_writes(2, 2)
+                        .saturating_mul(nominations_witness as u64),
+                )
+        }
+
+        /// Slashes the equivocating attester permanently, along with the partial slash of its and its nominators' stake.
+        ///     Reporter receives EQUIVOCATION_REPORTER_REWARD of the slashed stake, the rest goes to the slash treasury.
+        ///     Returns the total slashed stake and the reporter's reward.
+        fn apply_equivocation_slash(
+            offender: &T::AccountId,
+            reporter: &T::AccountId,
+        ) -> (BalanceOf<T>, BalanceOf<T>) {
+            let nominations: Vec<(T::AccountId, BalanceOf<T>)> =
+                Nominations::<T>::iter_prefix(offender).collect();
+
+            let (_, nominations_after_slash) = Self::apply_partial_stake_slash(
+                offender.clone(),
+                nominations.clone(),
+                EQUIVOCATION_SLASH,
+                EQUIVOCATION_NOMINATOR_GRACE,
+            );
+
+            let mut slashed: BalanceOf<T> = Zero::zero();
+            for (nominator, balance_after_slash) in nominations_after_slash {
+                let balance_before_slash = nominations
+                    .iter()
+                    .find_map(|(account, balance)| (account == &nominator).then_some(*balance))
+                    .unwrap_or_else(Zero::zero);
+                let slash_amount = balance_before_slash.saturating_sub(balance_after_slash);
+                if slash_amount.is_zero() {
+                    continue
+                }
+                let (_burnt, not_slashed) = T::Currency::slash_reserved(&nominator, slash_amount);
+                slashed = slashed.saturating_add(slash_amount.saturating_sub(not_slashed));
+
+                if balance_after_slash.is_zero() {
+                    Nominations::<T>::remove(offender, &nominator);
+                } else {
+                    Nominations::<T>::insert(offender, &nominator, balance_after_slash);
+                }
+            }
+
+            if !slashed.is_zero() {
+                let _ = Self::update_sorted_nominated_attesters(offender, slashed);
+            }
+            Self::apply_permanent_attesters_slash(vec![offender.clone()]);
+
+            let reporter_reward = EQUIVOCATION_REPORTER_REWARD.mul_floor(slashed);
+            let _ = T::Currency::deposit_creating(reporter, reporter_reward);
+            let _ = T::Currency::deposit_creating(
+                &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Slash),
+                slashed.saturating_sub(reporter_reward),
+            );
+
+            (slashed, reporter_reward)
+        }
+
         pub fn find_and_set_batch_as_committed(
             target: TargetId,
             message_hash: &H256,
@@ -1704,6 +2283,26 @@ pub mod pallet {
             }
         }
 
+        /// Verifies the batch's aggregated BLS signature against the BLS keys of its signers snapshotted while signing.
+        pub fn verify_aggregated_batch_signature(target: TargetId, message_hash: H256) -> bool {
+            Self::get_batch_by_message_hash(target, message_hash)
+                .and_then(|batch| batch.aggregated_bls_signature)
+                .map(|aggregated| aggregated.verify(message_hash.as_bytes()))
+                .unwrap_or(false)
+        }
+
+        /// Aggregated BLS public key of the next committee, if all of its members registered their keys.
+        pub fn next_committee_bls_public_key() -> Option<PublicKeyBls96b> {
+            let public_keys = NextCommittee::<T>::get()
+                .iter()
+                .map(AttestersBlsKeys::<T>::get)
+                .collect::<Option<Vec<PublicKeyBls96b>>>()?;
+            if public_keys.is_empty() {
+                return None
+            }
+            bls::aggregate_public_keys(&public_keys)
+        }
+
         pub fn get_batches_to_commit(target: TargetId) -> Vec<BatchMessage<BlockNumberFor<T>>> {
             // Get the batches to sign
             match Batches::<T>::get(target) {
@@ -1728,6 +2327,39 @@ pub mod pallet {
             batches.first().cloned()
         }
 
+        pub fn pending_batches_overview() -> Vec<(TargetId, Vec<(u32, H256)>)> {
+            AttestationTargets::<T>::get()
+                .into_iter()
+                .map(|target| {
+                    let pending_attestation_batches = Self::get_all_batches_to_sign(target)
+                        .iter()
+                        .map(|batch| (batch.index, batch.message_hash()))
+                        .collect::<Vec<(u32, H256)>>();
+                    (target, pending_attestation_batches)
+                })
+                .collect()
+        }
+
+        pub fn batching_factor_overview() -> Vec<(TargetId, Option<BatchingFactor>)> {
+            AttestationTargets::<T>::get()
+                .into_iter()
+                .map(|target| (target, Self::read_latest_batching_factor(&target)))
+                .collect()
+        }
+
+        /// Returns None if the target isn't an active attestation target.
+        pub fn user_finality_fee_estimate(target: &TargetId) -> Option<BalanceOf<T>> {
+            if !AttestationTargets::<T>::get().contains(target) {
+                return None
+            }
+
+            Some(<Pallet<T> as AttestersReadApi<
+                T::AccountId,
+                BalanceOf<T>,
+                BlockNumberFor<T>,
+            >>::estimate_finality_fee(target))
+        }
+
         pub fn get_all_batches_to_sign(target: TargetId) -> Vec<BatchMessage<BlockNumberFor<T>>> {
             let mut batches = Self::get_batches(target, BatchStatus::PendingAttestation);
             batches.sort_by(|a, b| b.created.cmp(&a.created));
@@ -1925,6 +2557,25 @@ pub mod pallet {
             full_shuffle
         }
 
+        /// Prunes the attested influx messages whose retention window ends at this block.
+        ///     Messages attested again since are kept until their own window ends,
+        ///     and the target's head stays in LatestAttestedInflux until a higher message is attested.
+        pub fn prune_attested_influx(n: BlockNumberFor<T>, aggregated_weight: Weight) -> Weight {
+            let expired = AttestedInfluxExpiries::<T>::take(n);
+            let mut pruned: u64 = 0;
+            for (target, message) in expired.iter() {
+                let expired_at = AttestedInfluxMessages::<T>::get(target, message)
+                    .map(|attested| attested.attested_at + T::AttestedInfluxRetention::get());
+                if expired_at.map_or(false, |expired_at| expired_at <= n) {
+                    AttestedInfluxMessages::<T>::remove(target, message);
+                    pruned += 1;
+                }
+            }
+            aggregated_weight.saturating_add(
+                T::DbWeight::get().reads_writes(expired.len() as u64 + 1, pruned + 1),
+            )
+        }
+
         pub fn process_repatriations(n: BlockNumberFor<T>, aggregated_weight: Weight) -> Weight {
             for target in AttestationTargets::<T>::get() {
                 Batches::<T>::mutate(target, |batches| {
@@ -2195,8 +2846,48 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            StorageMigrations::<T>::mutate(|current_version| {
+                if *current_version == 0 {
+                    // Storage Migration: BatchMessage records the committee's aggregated BLS signature
+                    //     and the aggregated BLS public key of the next committee
+                    let mut migrated: u64 = 0;
+                    NextBatch::<T>::translate(
+                        |_target, batch: BatchMessageV0<BlockNumberFor<T>>| {
+                            migrated += 1;
+                            Some(batch.into())
+                        },
+                    );
+                    let translate_batches =
+                        |_target: TargetId, batches: Vec<BatchMessageV0<BlockNumberFor<T>>>| {
+                            Some(
+                                batches
+                                    .into_iter()
+                                    .map(BatchMessage::from)
+                                    .collect::<Vec<BatchMessage<BlockNumberFor<T>>>>(),
+                            )
+                        };
+                    Batches::<T>::translate(|target, batches| {
+                        migrated += 1;
+                        translate_batches(target, batches)
+                    });
+                    BatchesToSign::<T>::translate(|target, batches| {
+                        migrated += 1;
+                        translate_batches(target, batches)
+                    });
+                    weight = weight
+                        .saturating_add(T::DbWeight::get().reads_writes(migrated, migrated + 1));
+
+                    *current_version = CURRENT_STORAGE_VERSION;
+                }
+            });
+            weight
+        }
+
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let mut aggregated_weight: Weight = Zero::zero();
+            aggregated_weight = Self::prune_attested_influx(n, aggregated_weight);
             // Check if a shuffling round has passed
             if (n % T::ShufflingFrequency::get()).is_zero() && Self::is_last_transition_attested() {
                 // Process pending unnominations
@@ -2281,6 +2972,8 @@ pub mod pallet {
                 };
                 NextBatch::<T>::insert(target, new_next_batch.clone());
             }
+
+            StorageMigrations::<T>::put(CURRENT_STORAGE_VERSION);
         }
     }
 }
@@ -2293,11 +2986,15 @@ pub mod attesters_test {
     };
     use std::ops::Index;
 
-    use crate::{TargetBatchDispatchEvent, REWARD_ADJUSTMENT};
+    use crate::{
+        bls, AttestersBlsKeys, BatchMessageV0, Batches, EquivocationProof, StorageMigrations,
+        TargetBatchDispatchEvent, REWARD_ADJUSTMENT,
+    };
     use codec::Encode;
     use frame_support::{
         assert_err, assert_noop, assert_ok,
         traits::{Currency, Get, Hooks, Len},
+        weights::Weight,
         StorageValue,
     };
     use sp_application_crypto::{ecdsa, ed25519, sr25519, KeyTypeId, Pair, RuntimePublic};
@@ -2305,8 +3002,9 @@ pub mod attesters_test {
     use sp_runtime::traits::Keccak256;
     use sp_std::convert::TryInto;
     use t3rn_mini_mock_runtime::{
-        AccountId, ActiveSet, AttestationTargets, Attesters, AttestersAgreements, AttestersError,
-        AttestersEvent, AttestersStore, Balance, Balances, BatchMessage, BatchStatus, BlockNumber,
+        AccountId, ActiveSet, AttestationTargets, AttestedInflux, AttestedInfluxExpiries,
+        Attesters, AttestersAgreements, AttestersError, AttestersEvent, AttestersLightClient,
+        AttestersStore, Balance, Balances, BatchMessage, BatchStatus, BlockNumber,
         CommitteeTransitionOn, ConfigAttesters, ConfigRewards, CurrentCommittee,
         ExistentialDeposit, ExtBuilder, FullSideEffects, InfluxMessage, LatencyStatus, MiniRuntime,
         NextBatch, NextCommitteeOnTarget, Nominations, PaidFinalityFees, PendingUnnominations,
@@ -2323,7 +3021,8 @@ pub mod attesters_test {
             AdaptiveTimeout, CircuitStatus, FullSideEffect, SecurityLvl, SideEffect, XExecSignal,
         },
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
-        TreasuryAccount, TreasuryAccountProvider,
+        light_client::{HeightResult, LightClient},
+        ExecutionSource, SpeedMode, TreasuryAccount, TreasuryAccountProvider,
     };
     use tiny_keccak::{Hasher, Keccak};
 
@@ -2354,6 +3053,25 @@ pub mod attesters_test {
         });
     }
 
+    #[test]
+    fn user_finality_fee_estimate_is_only_available_for_active_targets() {
+        let mut ext = ExtBuilder::default().build();
+
+        ext.execute_with(|| {
+            let target_id = ETHEREUM_TARGET;
+            assert_eq!(Attesters::user_finality_fee_estimate(&target_id), None);
+
+            AttestationTargets::<MiniRuntime>::append(&target_id);
+            let base_user_fee_for_single_user: Balance = 10_000_000_000_000u128.try_into().unwrap();
+            let overcharge_32_percent_factor: Balance = 3_200_000_000_000u128.try_into().unwrap();
+
+            assert_eq!(
+                Attesters::user_finality_fee_estimate(&target_id),
+                Some(base_user_fee_for_single_user + overcharge_32_percent_factor)
+            );
+        });
+    }
+
     #[test]
     fn estimate_finality_fee_1_delay() {
         let mut ext = ExtBuilder::default()
@@ -2688,39 +3406,397 @@ pub mod attesters_test {
         (latest_batch_hash, signature)
     }
 
-    #[test]
-    fn submitting_attestation_reads_as_on_time_latency_status() {
-        let mut ext = ExtBuilder::default()
-            .with_standard_sfx_abi()
-            .with_eth_gateway_record()
-            .build();
+    #[test]
+    fn submitting_attestation_reads_as_on_time_latency_status() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            // Register an attester
+            let attester = AccountId::from([1; 32]);
+            let _attester_info = register_attester_with_single_private_key([1u8; 32]);
+            // Submit an attestation signed with the Ed25519 key
+            let sfx_id_to_sign_on: [u8; 32] = *b"message_that_needs_attestation32";
+            let (_hash, _signature) = sign_and_submit_sfx_to_latest_attestation(
+                attester,
+                vec![sfx_id_to_sign_on],
+                ECDSA_ATTESTER_KEY_TYPE_ID,
+                ETHEREUM_TARGET,
+                [1u8; 32],
+            );
+
+            let batch_latency = Attesters::read_attestation_latency(&ETHEREUM_TARGET);
+            assert!(batch_latency.is_some());
+            assert_eq!(batch_latency, Some(LatencyStatus::OnTime));
+        });
+    }
+
+    #[test]
+    fn submit_first_influx_attestation_rlp_encoded_with_correct_signature_sets_status_to_pending_attestation(
+    ) {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            // Register an attester
+            let attester = AccountId::from([1; 32]);
+            let _attester_info = register_attester_with_single_private_key([1u8; 32]);
+
+            add_target_and_transition_to_next_batch(ETHEREUM_TARGET, 0);
+
+            // Submit an attestation signed with the Ed25519 key
+            let influx_message_32b = *b"message_that_needs_attestation32";
+            let mut influx_message_to_sign_on: Vec<u8> = influx_message_32b.to_vec();
+            let target_there: [u8; 4] = ETHEREUM_TARGET;
+            let height_there: u32 = 8;
+            // Add target and height to the message
+            influx_message_to_sign_on.extend_from_slice(&target_there);
+            influx_message_to_sign_on.extend_from_slice(&height_there.to_be_bytes());
+
+            // Calculate the hash of the message
+            let mut hasher = Keccak::v256();
+            hasher.update(&influx_message_to_sign_on);
+            let mut hash = [0u8; 32];
+            hasher.finalize(&mut hash);
+            let sfx_id_to_sign_on = H256::from(hash);
+
+            // Sign the message
+            let signature = ecdsa::Pair::from_seed(&[1u8; 32])
+                .sign_prehashed(&sfx_id_to_sign_on.into())
+                .encode();
+
+            assert_ok!(Attesters::submit_for_influx_attestation(
+                RuntimeOrigin::signed(attester),
+                influx_message_32b.into(),
+                sfx_id_to_sign_on,
+                height_there,
+                ETHEREUM_TARGET,
+                signature.clone(),
+            ));
+
+            // current block
+            assert_eq!(
+                Attesters::attestations_influx(&ETHEREUM_TARGET, sfx_id_to_sign_on),
+                Some(InfluxMessage {
+                    message_hash: sfx_id_to_sign_on,
+                    message: influx_message_32b.into(),
+                    height_there,
+                    gateway: ETHEREUM_TARGET,
+                    signatures: vec![signature],
+                    created: System::block_number(),
+                    status: BatchStatus::PendingAttestation,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn report_equivocation_of_conflicting_batches_slashes_the_offending_attester() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let attester = AccountId::from([1; 32]);
+            let reporter = AccountId::from([4; 32]);
+            register_attester_with_single_private_key([1u8; 32]);
+            let _ = Balances::deposit_creating(&reporter, 100u128);
+
+            add_target_and_transition_to_next_batch(ETHEREUM_TARGET, 0);
+
+            let sign_batch = |batch: &BatchMessage<BlockNumber>| {
+                ecdsa::Pair::from_seed(&[1u8; 32])
+                    .sign_prehashed(&batch.message_hash().into())
+                    .encode()
+            };
+            let first_batch = BatchMessage {
+                committed_sfx: Some(vec![H512::repeat_byte(1)]),
+                index: 7,
+                ..Default::default()
+            };
+            let second_batch = BatchMessage {
+                reverted_sfx: Some(vec![H256::repeat_byte(1)]),
+                index: 7,
+                ..Default::default()
+            };
+
+            // Batches of different indices don't conflict
+            let next_batch = BatchMessage {
+                index: 8,
+                ..second_batch.clone()
+            };
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    EquivocationProof::ConflictingBatches {
+                        first_batch: first_batch.clone(),
+                        first_signature: sign_batch(&first_batch),
+                        second_batch: next_batch.clone(),
+                        second_signature: sign_batch(&next_batch),
+                    },
+                    1,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationProofInvalid
+            );
+
+            assert_ok!(Attesters::report_equivocation(
+                RuntimeOrigin::signed(reporter.clone()),
+                attester.clone(),
+                ETHEREUM_TARGET,
+                EquivocationProof::ConflictingBatches {
+                    first_batch: first_batch.clone(),
+                    first_signature: sign_batch(&first_batch),
+                    second_batch: second_batch.clone(),
+                    second_signature: sign_batch(&second_batch),
+                },
+                1,
+            ));
+
+            // 50% of 10 self-nomination slashed
+            assert_eq!(
+                Nominations::<MiniRuntime>::get(&attester, &attester),
+                Some(5)
+            );
+            assert_eq!(Balances::reserved_balance(&attester), 5);
+            assert_eq!(
+                PermanentSlashes::<MiniRuntime>::get(),
+                vec![attester.clone()]
+            );
+            assert!(System::events().iter().any(|record| record.event
+                == Event::Attesters(AttestersEvent::AttesterEquivocationSlashed(
+                    attester.clone(),
+                    reporter.clone(),
+                    5,
+                    0
+                ))));
+        });
+    }
+
+    #[test]
+    fn report_equivocation_of_conflicting_influx_slashes_only_the_offending_attester() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let attester = AccountId::from([1; 32]);
+            let honest_attester = AccountId::from([2; 32]);
+            let nominator = AccountId::from([3; 32]);
+            let reporter = AccountId::from([4; 32]);
+            register_attester_with_single_private_key([1u8; 32]);
+            register_attester_with_single_private_key([2u8; 32]);
+
+            let _ = Balances::deposit_creating(&nominator, 100u128 + ExistentialDeposit::get());
+            assert_ok!(Attesters::nominate(
+                RuntimeOrigin::signed(nominator.clone()),
+                attester.clone(),
+                100u128
+            ));
+            let _ = Balances::deposit_creating(&reporter, 100u128);
+
+            add_target_and_transition_to_next_batch(ETHEREUM_TARGET, 0);
+
+            // Sign the hashes of two different headers at the same height there
+            let height_there: u32 = 8;
+            let keccak = |bytes: &[u8]| {
+                let mut hasher = Keccak::v256();
+                hasher.update(bytes);
+                let mut hash = [0u8; 32];
+                hasher.finalize(&mut hash);
+                hash
+            };
+            let eth_header = |height: u32, fill: u8| {
+                let mut header = rlp::RlpStream::new_list(15);
+                for field in 0..15 {
+                    if field == 8 {
+                        header.append(&(height as u64));
+                    } else {
+                        header.append(&vec![fill; 32]);
+                    }
+                }
+                header.out().to_vec()
+            };
+            let sign_influx = |header: &Vec<u8>| {
+                let mut influx_message_to_sign_on: Vec<u8> = keccak(header).to_vec();
+                influx_message_to_sign_on.extend_from_slice(&ETHEREUM_TARGET);
+                influx_message_to_sign_on.extend_from_slice(&height_there.to_be_bytes());
+
+                ecdsa::Pair::from_seed(&[1u8; 32])
+                    .sign_prehashed(&keccak(&influx_message_to_sign_on))
+                    .encode()
+            };
+            let first_header = eth_header(height_there, 1);
+            let second_header = eth_header(height_there, 2);
+
+            let proof = EquivocationProof::ConflictingInflux {
+                height_there,
+                first_header: first_header.clone(),
+                first_signature: sign_influx(&first_header),
+                second_header: second_header.clone(),
+                second_signature: sign_influx(&second_header),
+            };
+
+            // The same header twice is no equivocation
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    EquivocationProof::ConflictingInflux {
+                        height_there,
+                        first_header: first_header.clone(),
+                        first_signature: sign_influx(&first_header),
+                        second_header: first_header.clone(),
+                        second_signature: sign_influx(&first_header),
+                    },
+                    2,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationProofInvalid
+            );
+
+            // Influx messages other than headers of the height there don't claim the same slot
+            let event_message = b"event_attested_at_the_same_height".to_vec();
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    EquivocationProof::ConflictingInflux {
+                        height_there,
+                        first_header: first_header.clone(),
+                        first_signature: sign_influx(&first_header),
+                        second_header: event_message.clone(),
+                        second_signature: sign_influx(&event_message),
+                    },
+                    2,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationProofInvalid
+            );
+            let header_at_other_height = eth_header(height_there + 1, 2);
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    EquivocationProof::ConflictingInflux {
+                        height_there,
+                        first_header: first_header.clone(),
+                        first_signature: sign_influx(&first_header),
+                        second_header: header_at_other_height.clone(),
+                        second_signature: sign_influx(&header_at_other_height),
+                    },
+                    2,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationProofInvalid
+            );
+
+            // Offender can't report itself to collect the reporter's reward
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(attester.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    proof.clone(),
+                    2,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationReportedByOffender
+            );
+
+            // Witness must cover the offender's nominations
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    ETHEREUM_TARGET,
+                    proof.clone(),
+                    1,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationNominationsWitnessTooLow
+            );
+
+            // Signatures of the offender don't prove the equivocation of another attester
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    honest_attester.clone(),
+                    ETHEREUM_TARGET,
+                    proof.clone(),
+                    1,
+                ),
+                AttestersError::<MiniRuntime>::InvalidSignature
+            );
 
-        ext.execute_with(|| {
-            // Register an attester
-            let attester = AccountId::from([1; 32]);
-            let _attester_info = register_attester_with_single_private_key([1u8; 32]);
-            // Submit an attestation signed with the Ed25519 key
-            let sfx_id_to_sign_on: [u8; 32] = *b"message_that_needs_attestation32";
-            let (_hash, _signature) = sign_and_submit_sfx_to_latest_attestation(
-                attester,
-                vec![sfx_id_to_sign_on],
-                ECDSA_ATTESTER_KEY_TYPE_ID,
+            assert_ok!(Attesters::report_equivocation(
+                RuntimeOrigin::signed(reporter.clone()),
+                attester.clone(),
                 ETHEREUM_TARGET,
-                [1u8; 32],
+                proof.clone(),
+                2,
+            ));
+
+            // 50% of 10 self-nomination and 10% of 100 nomination slashed
+            assert_eq!(
+                Nominations::<MiniRuntime>::get(&attester, &attester),
+                Some(5)
+            );
+            assert_eq!(
+                Nominations::<MiniRuntime>::get(&attester, &nominator),
+                Some(90)
+            );
+            assert_eq!(Balances::reserved_balance(&attester), 5);
+            assert_eq!(Balances::reserved_balance(&nominator), 90);
+            assert_eq!(Balances::free_balance(&reporter), 101);
+            assert_eq!(
+                Balances::free_balance(&MiniRuntime::get_treasury_account(TreasuryAccount::Slash)),
+                14
             );
 
-            let batch_latency = Attesters::read_attestation_latency(&ETHEREUM_TARGET);
-            assert!(batch_latency.is_some());
-            assert_eq!(batch_latency, Some(LatencyStatus::OnTime));
+            assert_eq!(
+                PermanentSlashes::<MiniRuntime>::get(),
+                vec![attester.clone()]
+            );
+            assert_eq!(
+                Nominations::<MiniRuntime>::get(&honest_attester, &honest_attester),
+                Some(10)
+            );
+
+            let events = System::events();
+            assert!(events.iter().any(|record| record.event
+                == Event::Attesters(AttestersEvent::AttesterEquivocationSlashed(
+                    attester.clone(),
+                    reporter.clone(),
+                    15,
+                    1
+                ))));
+
+            // Equivocation is slashed only once
+            assert_noop!(
+                Attesters::report_equivocation(
+                    RuntimeOrigin::signed(reporter),
+                    attester,
+                    ETHEREUM_TARGET,
+                    proof,
+                    2,
+                ),
+                AttestersError::<MiniRuntime>::EquivocationAlreadyReported
+            );
         });
     }
 
     #[test]
-    fn submit_first_influx_attestation_rlp_encoded_with_correct_signature_sets_status_to_pending_attestation(
+    fn submit_first_influx_attestation_scale_encoded_with_correct_signature_sets_status_to_pending_attestation(
     ) {
         let mut ext = ExtBuilder::default()
             .with_standard_sfx_abi()
-            .with_eth_gateway_record()
+            .with_polkadot_gateway_record()
             .build();
 
         ext.execute_with(|| {
@@ -2728,23 +3804,20 @@ pub mod attesters_test {
             let attester = AccountId::from([1; 32]);
             let _attester_info = register_attester_with_single_private_key([1u8; 32]);
 
-            add_target_and_transition_to_next_batch(ETHEREUM_TARGET, 0);
+            add_target_and_transition_to_next_batch(POLKADOT_TARGET, 0);
 
             // Submit an attestation signed with the Ed25519 key
             let influx_message_32b = *b"message_that_needs_attestation32";
             let mut influx_message_to_sign_on: Vec<u8> = influx_message_32b.to_vec();
-            let target_there: [u8; 4] = ETHEREUM_TARGET;
+            let target_there: [u8; 4] = POLKADOT_TARGET;
             let height_there: u32 = 8;
             // Add target and height to the message
             influx_message_to_sign_on.extend_from_slice(&target_there);
-            influx_message_to_sign_on.extend_from_slice(&height_there.to_be_bytes());
+            influx_message_to_sign_on.extend_from_slice(&height_there.to_le_bytes());
 
-            // Calculate the hash of the message
-            let mut hasher = Keccak::v256();
-            hasher.update(&influx_message_to_sign_on);
-            let mut hash = [0u8; 32];
-            hasher.finalize(&mut hash);
-            let sfx_id_to_sign_on = H256::from(hash);
+            // Calculate the Blake256 hash of the message
+            let sfx_id_to_sign_on =
+                H256::from(sp_core::hashing::blake2_256(&influx_message_to_sign_on));
 
             // Sign the message
             let signature = ecdsa::Pair::from_seed(&[1u8; 32])
@@ -2756,18 +3829,18 @@ pub mod attesters_test {
                 influx_message_32b.into(),
                 sfx_id_to_sign_on,
                 height_there,
-                ETHEREUM_TARGET,
+                POLKADOT_TARGET,
                 signature.clone(),
             ));
 
             // current block
             assert_eq!(
-                Attesters::attestations_influx(&ETHEREUM_TARGET, sfx_id_to_sign_on),
+                Attesters::attestations_influx(&POLKADOT_TARGET, sfx_id_to_sign_on),
                 Some(InfluxMessage {
                     message_hash: sfx_id_to_sign_on,
                     message: influx_message_32b.into(),
                     height_there,
-                    gateway: ETHEREUM_TARGET,
+                    gateway: POLKADOT_TARGET,
                     signatures: vec![signature],
                     created: System::block_number(),
                     status: BatchStatus::PendingAttestation,
@@ -2777,59 +3850,204 @@ pub mod attesters_test {
     }
 
     #[test]
-    fn submit_first_influx_attestation_scale_encoded_with_correct_signature_sets_status_to_pending_attestation(
-    ) {
+    fn influx_message_attested_by_quorum_is_verified_by_attesters_light_client() {
         let mut ext = ExtBuilder::default()
             .with_standard_sfx_abi()
             .with_polkadot_gateway_record()
             .build();
 
         ext.execute_with(|| {
-            // Register an attester
-            let attester = AccountId::from([1; 32]);
-            let _attester_info = register_attester_with_single_private_key([1u8; 32]);
-
+            for counter in 1..33u8 {
+                register_attester_with_single_private_key([counter; 32]);
+            }
+            select_new_committee();
             add_target_and_transition_to_next_batch(POLKADOT_TARGET, 0);
 
-            // Submit an attestation signed with the Ed25519 key
-            let influx_message_32b = *b"message_that_needs_attestation32";
-            let mut influx_message_to_sign_on: Vec<u8> = influx_message_32b.to_vec();
-            let target_there: [u8; 4] = POLKADOT_TARGET;
+            let influx_of = |remote_message: &[u8], height_there: u32| {
+                let influx_message = H256::from(sp_core::hashing::blake2_256(remote_message));
+                let mut influx_message_to_sign_on: Vec<u8> = influx_message.as_bytes().to_vec();
+                influx_message_to_sign_on.extend_from_slice(&POLKADOT_TARGET);
+                influx_message_to_sign_on.extend_from_slice(&height_there.to_le_bytes());
+                let message_hash =
+                    H256::from(sp_core::hashing::blake2_256(&influx_message_to_sign_on));
+                (influx_message, message_hash)
+            };
+            let submit_influx_signed_by =
+                |counter: u8, remote_message: &[u8], height_there: u32| {
+                    let (influx_message, message_hash) = influx_of(remote_message, height_there);
+                    let signature = ecdsa::Pair::from_seed(&[counter; 32])
+                        .sign_prehashed(&message_hash.into())
+                        .encode();
+                    assert_ok!(Attesters::submit_for_influx_attestation(
+                        RuntimeOrigin::signed(AccountId::from([counter; 32])),
+                        influx_message,
+                        message_hash,
+                        height_there,
+                        POLKADOT_TARGET,
+                        signature,
+                    ));
+                };
+
+            let light_client = AttestersLightClient::<MiniRuntime>::new(Some(POLKADOT_TARGET));
+            let remote_event = b"remote_event_attested_by_committee".to_vec();
             let height_there: u32 = 8;
-            // Add target and height to the message
-            influx_message_to_sign_on.extend_from_slice(&target_there);
-            influx_message_to_sign_on.extend_from_slice(&height_there.to_le_bytes());
+            let (influx_message, message_hash) = influx_of(&remote_event, height_there);
 
-            // Calculate the Blake256 hash of the message
-            let sfx_id_to_sign_on =
-                H256::from(sp_core::hashing::blake2_256(&influx_message_to_sign_on));
+            // 20 signatures are not enough to reach the quorum of 2/3 of the committee
+            for counter in 1..21u8 {
+                submit_influx_signed_by(counter, &remote_event, height_there);
+            }
+            assert_err!(
+                light_client.verify_event_inclusion(
+                    POLKADOT_TARGET,
+                    SpeedMode::Finalized,
+                    None,
+                    remote_event.clone()
+                ),
+                AttestersError::<MiniRuntime>::InfluxMessageNotAttested
+            );
+            assert_eq!(light_client.get_finalized_height(), HeightResult::NotActive);
 
-            // Sign the message
-            let signature = ecdsa::Pair::from_seed(&[1u8; 32])
-                .sign_prehashed(&sfx_id_to_sign_on.into())
-                .encode();
+            submit_influx_signed_by(21, &remote_event, height_there);
 
-            assert_ok!(Attesters::submit_for_influx_attestation(
-                RuntimeOrigin::signed(attester),
-                influx_message_32b.into(),
-                sfx_id_to_sign_on,
+            let expected_head = AttestedInflux {
+                gateway: POLKADOT_TARGET,
+                message: influx_message,
+                message_hash,
                 height_there,
-                POLKADOT_TARGET,
-                signature.clone(),
-            ));
+                attested_at: System::block_number(),
+            };
+            assert_eq!(
+                Attesters::latest_attested_influx(POLKADOT_TARGET),
+                Some(expected_head.clone())
+            );
+            assert_eq!(Attesters::latest_attested_influx(ETHEREUM_TARGET), None);
+            let receipt = light_client
+                .verify_event_inclusion(
+                    POLKADOT_TARGET,
+                    SpeedMode::Finalized,
+                    None,
+                    remote_event.clone(),
+                )
+                .unwrap();
+            assert_eq!(receipt.height, height_there);
+            assert_eq!(receipt.message, remote_event);
+            assert_eq!(
+                light_client.get_finalized_height(),
+                HeightResult::Height(height_there)
+            );
+            assert_eq!(
+                AttestersLightClient::<MiniRuntime>::new(Some(ETHEREUM_TARGET))
+                    .get_finalized_height(),
+                HeightResult::NotActive
+            );
+            assert_err!(
+                light_client.verify_event_inclusion(
+                    POLKADOT_TARGET,
+                    SpeedMode::Finalized,
+                    None,
+                    b"remote_event_never_attested".to_vec(),
+                ),
+                AttestersError::<MiniRuntime>::InfluxMessageNotAttested
+            );
 
-            // current block
+            // Events of a known source are verified only if attested over the source followed by the event
+            let source: ExecutionSource = [9u8; 32];
+            assert_err!(
+                light_client.verify_event_inclusion(
+                    POLKADOT_TARGET,
+                    SpeedMode::Finalized,
+                    Some(source),
+                    remote_event.clone(),
+                ),
+                AttestersError::<MiniRuntime>::InfluxMessageNotAttested
+            );
+            let sourced_remote_event = [source.as_slice(), remote_event.as_slice()].concat();
+            let lower_height_there: u32 = 5;
+            for counter in 1..22u8 {
+                submit_influx_signed_by(counter, &sourced_remote_event, lower_height_there);
+            }
+            let receipt = light_client
+                .verify_event_inclusion(
+                    POLKADOT_TARGET,
+                    SpeedMode::Finalized,
+                    Some(source),
+                    remote_event.clone(),
+                )
+                .unwrap();
+            assert_eq!(receipt.height, lower_height_there);
+            assert_eq!(receipt.message, remote_event);
+
+            // Influx attested at a lower height doesn't move the head back
             assert_eq!(
-                Attesters::attestations_influx(&POLKADOT_TARGET, sfx_id_to_sign_on),
-                Some(InfluxMessage {
-                    message_hash: sfx_id_to_sign_on,
-                    message: influx_message_32b.into(),
+                Attesters::latest_attested_influx(POLKADOT_TARGET),
+                Some(expected_head)
+            );
+            assert_eq!(
+                light_client.get_finalized_height(),
+                HeightResult::Height(height_there)
+            );
+
+            // Turning the light client off deactivates the attestation target
+            assert!(!light_client.get_latest_heartbeat().unwrap().is_halted);
+            assert_eq!(light_client.turn_off(RuntimeOrigin::root()), Ok(false));
+            assert!(!AttestationTargets::<MiniRuntime>::get().contains(&POLKADOT_TARGET));
+            assert!(light_client.get_latest_heartbeat().unwrap().is_halted);
+            assert_err!(
+                AttestersLightClient::<MiniRuntime>::new(None).turn_off(RuntimeOrigin::root()),
+                AttestersError::<MiniRuntime>::AttestationTargetNotSpecified
+            );
+        });
+    }
+
+    #[test]
+    fn attested_influx_is_pruned_once_retention_window_ends() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_polkadot_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let retention = <MiniRuntime as ConfigAttesters>::AttestedInfluxRetention::get();
+            let attest_at = |block: u32, message: H256, height_there: u32| {
+                System::set_block_number(block);
+                Attesters::record_attested_influx(&InfluxMessage {
+                    message_hash: H256::repeat_byte(height_there as u8),
+                    message,
                     height_there,
                     gateway: POLKADOT_TARGET,
-                    signatures: vec![signature],
-                    created: System::block_number(),
+                    signatures: vec![],
+                    created: block,
                     status: BatchStatus::PendingAttestation,
-                })
+                });
+            };
+            let early_message = H256::repeat_byte(1);
+            let reattested_message = H256::repeat_byte(2);
+            attest_at(1, early_message, 5);
+            attest_at(1, reattested_message, 6);
+            attest_at(2, reattested_message, 6);
+
+            Attesters::prune_attested_influx(retention, Weight::zero());
+            assert!(Attesters::attested_influx(POLKADOT_TARGET, early_message).is_some());
+
+            Attesters::prune_attested_influx(1 + retention, Weight::zero());
+            assert_eq!(
+                Attesters::attested_influx(POLKADOT_TARGET, early_message),
+                None
+            );
+            // Attested again since, so kept until its own window ends
+            assert!(Attesters::attested_influx(POLKADOT_TARGET, reattested_message).is_some());
+
+            Attesters::prune_attested_influx(2 + retention, Weight::zero());
+            assert_eq!(
+                Attesters::attested_influx(POLKADOT_TARGET, reattested_message),
+                None
+            );
+            assert_eq!(AttestedInfluxExpiries::<MiniRuntime>::iter().count(), 0);
+            // The head stays until a higher message is attested
+            assert_eq!(
+                Attesters::latest_attested_influx(POLKADOT_TARGET).map(|head| head.height_there),
+                Some(6)
             );
         });
     }
@@ -2908,6 +4126,192 @@ pub mod attesters_test {
         });
     }
 
+    fn register_bls_key_with_proof_of_possession(secret_key: [u8; 32]) -> [u8; 96] {
+        let public_key = bls::public_key_from_secret(&secret_key).unwrap();
+        let proof_of_possession = bls::sign(
+            &secret_key,
+            bls::BLS_PROOF_OF_POSSESSION_DST,
+            public_key.as_slice(),
+        )
+        .unwrap();
+        assert_ok!(Attesters::register_bls_key(
+            RuntimeOrigin::signed(AccountId::from(secret_key)),
+            public_key,
+            proof_of_possession,
+        ));
+        public_key
+    }
+
+    #[test]
+    fn register_and_submit_bls_attestations_aggregates_committee_signatures() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+        ext.execute_with(|| {
+            for counter in 1..4u8 {
+                register_attester_with_single_private_key([counter; 32]);
+            }
+            select_new_committee();
+
+            // Proof of possession of another key is rejected
+            let public_key = bls::public_key_from_secret(&[1u8; 32]).unwrap();
+            let foreign_proof_of_possession = bls::sign(
+                &[2u8; 32],
+                bls::BLS_PROOF_OF_POSSESSION_DST,
+                public_key.as_slice(),
+            )
+            .unwrap();
+            assert_noop!(
+                Attesters::register_bls_key(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    public_key,
+                    foreign_proof_of_possession,
+                ),
+                AttestersError::<MiniRuntime>::BlsProofOfPossessionInvalid
+            );
+
+            for counter in 1..4u8 {
+                register_bls_key_with_proof_of_possession([counter; 32]);
+            }
+
+            // Committee members can't swap the key they attest with
+            let another_public_key = bls::public_key_from_secret(&[9u8; 32]).unwrap();
+            let another_proof_of_possession = bls::sign(
+                &[9u8; 32],
+                bls::BLS_PROOF_OF_POSSESSION_DST,
+                another_public_key.as_slice(),
+            )
+            .unwrap();
+            assert_noop!(
+                Attesters::register_bls_key(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    another_public_key,
+                    another_proof_of_possession,
+                ),
+                AttestersError::<MiniRuntime>::BlsKeyChangeWhileInCommittee
+            );
+
+            // Sign the batch with ECDSA key to create the batch pending attestation
+            let (message_hash, _signature) = sign_and_submit_sfx_to_latest_attestation(
+                AccountId::from([1u8; 32]),
+                vec![*b"message_that_needs_attestation32"],
+                ECDSA_ATTESTER_KEY_TYPE_ID,
+                ETHEREUM_TARGET,
+                [1u8; 32],
+            );
+
+            for counter in 1..4u8 {
+                let signature = bls::sign(
+                    &[counter; 32],
+                    bls::BLS_ATTESTATION_DST,
+                    message_hash.as_bytes(),
+                )
+                .unwrap();
+                assert_ok!(Attesters::submit_bls_attestation(
+                    RuntimeOrigin::signed(AccountId::from([counter; 32])),
+                    message_hash,
+                    signature,
+                    ETHEREUM_TARGET,
+                ));
+            }
+
+            let signature = bls::sign(
+                &[1u8; 32],
+                bls::BLS_ATTESTATION_DST,
+                message_hash.as_bytes(),
+            )
+            .unwrap();
+            assert_noop!(
+                Attesters::submit_bls_attestation(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    message_hash,
+                    signature,
+                    ETHEREUM_TARGET,
+                ),
+                AttestersError::<MiniRuntime>::AttestationDoubleSignAttempt
+            );
+
+            let batch =
+                Attesters::get_batch_by_message_hash(ETHEREUM_TARGET, message_hash).unwrap();
+            let aggregated = batch.aggregated_bls_signature.clone().unwrap();
+            assert_eq!(aggregated.signers_count(), 3);
+            assert!(Attesters::verify_aggregated_batch_signature(
+                ETHEREUM_TARGET,
+                message_hash
+            ));
+
+            let committee_public_keys: Vec<[u8; 96]> = CurrentCommittee::<MiniRuntime>::get()
+                .iter()
+                .map(|member| AttestersBlsKeys::<MiniRuntime>::get(member).unwrap())
+                .collect();
+            assert!(bls::verify_aggregated(
+                &committee_public_keys,
+                bls::BLS_ATTESTATION_DST,
+                message_hash.as_bytes(),
+                &aggregated.signature,
+            ));
+            assert_eq!(
+                Some(aggregated.public_key),
+                bls::aggregate_public_keys(&committee_public_keys)
+            );
+
+            // Encoded for targets as message ++ signers bitmap ++ uncompressed G1 signature
+            let encoded_for_target = batch.message_with_aggregated_bls_signature().unwrap();
+            assert_eq!(encoded_for_target.len(), batch.message().len() + 4 + 128);
+            assert!(encoded_for_target.starts_with(&batch.message()));
+        });
+    }
+
+    #[test]
+    fn runtime_upgrade_migrates_batches_without_bls_fields() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+        ext.execute_with(|| {
+            let old_batch = BatchMessageV0::<BlockNumber> {
+                available_to_commit_at: 0,
+                committed_sfx: Some(vec![H512::repeat_byte(1)]),
+                reverted_sfx: None,
+                next_committee: None,
+                banned_committee: None,
+                index: 3,
+                signatures: vec![],
+                created: 1,
+                status: BatchStatus::PendingAttestation,
+                latency: LatencyStatus::OnTime,
+                halt: false,
+            };
+            frame_support::storage::unhashed::put(
+                &NextBatch::<MiniRuntime>::hashed_key_for(ETHEREUM_TARGET),
+                &old_batch,
+            );
+            frame_support::storage::unhashed::put(
+                &Batches::<MiniRuntime>::hashed_key_for(ETHEREUM_TARGET),
+                &vec![old_batch.clone()],
+            );
+            StorageMigrations::<MiniRuntime>::put(0);
+
+            <Attesters as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+            assert_eq!(
+                StorageMigrations::<MiniRuntime>::get(),
+                crate::CURRENT_STORAGE_VERSION
+            );
+            let migrated: BatchMessage<BlockNumber> = old_batch.into();
+            assert_eq!(migrated.aggregated_bls_signature, None);
+            assert_eq!(
+                NextBatch::<MiniRuntime>::get(ETHEREUM_TARGET),
+                Some(migrated.clone())
+            );
+            assert_eq!(
+                Batches::<MiniRuntime>::get(ETHEREUM_TARGET),
+                Some(vec![migrated])
+            );
+        });
+    }
+
     #[test]
     fn remove_and_add_back_attestation_targets_with_sudo_access() {
         let mut ext = ExtBuilder::default()
@@ -3001,6 +4405,8 @@ pub mod attesters_test {
                     next_committee: None,
                     banned_committee: None,
                     signatures: vec![],
+                    next_committee_bls_public_key: None,
+                    aggregated_bls_signature: None,
                     status: BatchStatus::PendingAttestation,
                     created: current_block_1,
                     latency: LatencyStatus::OnTime,
@@ -3330,6 +4736,9 @@ pub mod attesters_test {
             let (target, pending_batches) = expect_last_event_to_emit_pending_attestation_batches();
             assert_eq!(target, ETHEREUM_TARGET);
             assert_eq!(pending_batches.len(), 1);
+            assert!(
+                Attesters::pending_batches_overview().contains(&(ETHEREUM_TARGET, pending_batches))
+            );
 
             // Get latest batch
             let latest_batch =
@@ -3701,6 +5110,8 @@ pub mod attesters_test {
                         171, 176, 108, 112,
                     ]]),
                     signatures: vec![],
+                    next_committee_bls_public_key: None,
+                    aggregated_bls_signature: None,
                     status: BatchStatus::PendingAttestation,
                     created: current_block_1,
                     latency: LatencyStatus::OnTime,
@@ -3943,6 +5354,8 @@ pub mod attesters_test {
                 next_committee: None,
                 banned_committee: None,
                 signatures: vec![],
+                next_committee_bls_public_key: None,
+                aggregated_bls_signature: None,
                 status: BatchStatus::PendingMessage,
                 created: 0,
                 latency: LatencyStatus::OnTime,
@@ -3972,6 +5385,8 @@ pub mod attesters_test {
                     next_committee: None,
                     banned_committee: None,
                     signatures: vec![],
+                    next_committee_bls_public_key: None,
+                    aggregated_bls_signature: None,
                     status: BatchStatus::PendingAttestation,
                     created: 0,
                     latency: LatencyStatus::OnTime,
@@ -4338,7 +5753,7 @@ pub mod attesters_test {
     }
 
     #[test]
-    fn register_and_submit_32x_attestations_and_check_collusion_permanent_slash() {
+    fn submit_32x_colluding_attestations_leaves_slashing_to_equivocation_reports() {
         let target: TargetId = ETHEREUM_TARGET;
         let _mock_escrow_account: AccountId = AccountId::new([2u8; 32]);
 
@@ -4395,7 +5810,7 @@ pub mod attesters_test {
                     RuntimeOrigin::signed(AccountId::from([1; 32])),
                     target,
                     colluded_batch_confirmation.encode(),
-                ) // errors are emitted, but the call must be successfull to enact slash. AttestersError::<MiniRuntime>::CollusionWithPermanentSlashDetected // AttestersError::<MiniRuntime>::CollusionWithPermanentSlashDetected
+                ) // the call must be successfull to keep the CollusionDetected event
             );
 
             // Check if the batch status has not been updated to Committed
@@ -4404,14 +5819,14 @@ pub mod attesters_test {
 
             assert_eq!(batch.status, BatchStatus::ReadyForSubmissionFullyApproved);
 
-            let slashed_permanently = PermanentSlashes::<MiniRuntime>::get();
+            assert!(System::events().iter().any(|record| record.event
+                == Event::Attesters(AttestersEvent::CollusionDetected(
+                    target,
+                    colluded_message.into()
+                ))));
 
-            // Check if all of the attesters have been slashed
-            for counter in 1..33u8 {
-                let attester = AccountId::from([counter; 32]);
-                assert!(Attesters::is_permanently_slashed(&attester));
-                assert!(slashed_permanently.contains(&attester));
-            }
+            // Signers of the colluded message are only slashed once proven via report_equivocation
+            assert!(PermanentSlashes::<MiniRuntime>::get().is_empty());
         });
     }
 
@@ -4742,6 +6157,8 @@ pub mod attesters_test {
             ]),
             index: 1,
             signatures: vec![],
+            next_committee_bls_public_key: None,
+            aggregated_bls_signature: None,
             created: 0, //(),
             status: BatchStatus::PendingMessage,
             latency: Default::default(),
@@ -4769,6 +6186,8 @@ pub mod attesters_test {
             banned_committee: Some(vec![]),
             index: 1,
             signatures: vec![],
+            next_committee_bls_public_key: None,
+            aggregated_bls_signature: None,
             created: 0, //(),
             status: BatchStatus::PendingMessage,
             latency: Default::default(),
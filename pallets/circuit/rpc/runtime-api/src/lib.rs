@@ -0,0 +1,36 @@
+//! Runtime API definition required by Circuit RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding read access to Xtx and SFX status.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+use sp_std::prelude::*;
+
+pub use sp_core::H256;
+pub use t3rn_primitives::circuit::{DLQEntry, OrderStatusRead, ProcessedRemoteOrder};
+pub use t3rn_types::fsx::{FullSideEffect, TargetId};
+
+sp_api::decl_runtime_apis! {
+    /// The API to read Xtx and SFX status from pallet Circuit
+    pub trait CircuitRuntimeApi<AccountId, Balance, BlockNumber, Hash> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Returns the status of the order and each of its SFX
+        fn fetch_order_status(xtx_id: Hash) -> Option<OrderStatusRead<Hash, BlockNumber, AccountId>>;
+        /// Returns the status of all orders still pending in Circuit
+        fn fetch_all_pending_orders_status() -> Vec<OrderStatusRead<Hash, BlockNumber, AccountId>>;
+        /// Returns the FSX of the Xtx grouped by steps, including the best bids posted so far
+        fn fetch_full_side_effects(xtx_id: Hash) -> Option<Vec<Vec<FullSideEffect<AccountId, BlockNumber, Balance>>>>;
+        /// Returns the DLQ entries of all Xtx submitted by the requester
+        fn fetch_dlq_entries(requester: AccountId) -> Vec<(Hash, DLQEntry<BlockNumber, AccountId>)>;
+        /// Returns the ledger entry of the remote order if it has already been picked up, and by which Xtx
+        fn fetch_processed_remote_order(remote_target_id: TargetId, remote_sfx_id: H256, nonce: u32) -> Option<ProcessedRemoteOrder<Hash, BlockNumber, AccountId>>;
+    }
+}
@@ -0,0 +1,171 @@
+//! RPC interface for the Circuit pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+};
+use sp_std::vec::Vec;
+
+pub use pallet_circuit_rpc_runtime_api::CircuitRuntimeApi;
+use pallet_circuit_rpc_runtime_api::{
+    DLQEntry, FullSideEffect, OrderStatusRead, ProcessedRemoteOrder, TargetId, H256,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+#[rpc(client, server)]
+pub trait CircuitApi<AccountId, Balance, BlockNumber, Hash> {
+    /// Returns the status of the order and each of its SFX
+    #[method(name = "circuit_fetchOrderStatus")]
+    fn fetch_order_status(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<OrderStatusRead<Hash, BlockNumber, AccountId>>;
+
+    /// Returns the status of all orders still pending in Circuit
+    #[method(name = "circuit_fetchAllPendingOrdersStatus")]
+    fn fetch_all_pending_orders_status(
+        &self,
+    ) -> RpcResult<Vec<OrderStatusRead<Hash, BlockNumber, AccountId>>>;
+
+    /// Returns the FSX of the Xtx grouped by steps, including the best bids posted so far
+    #[method(name = "circuit_fetchFullSideEffects")]
+    fn fetch_full_side_effects(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<Vec<Vec<FullSideEffect<AccountId, BlockNumber, Balance>>>>;
+
+    /// Returns the DLQ entries of all Xtx submitted by the requester
+    #[method(name = "circuit_fetchDlqEntries")]
+    fn fetch_dlq_entries(
+        &self,
+        requester: AccountId,
+    ) -> RpcResult<Vec<(Hash, DLQEntry<BlockNumber, AccountId>)>>;
+
+    /// Returns the ledger entry of the remote order if it has already been picked up, and by which Xtx
+    #[method(name = "circuit_fetchProcessedRemoteOrder")]
+    fn fetch_processed_remote_order(
+        &self,
+        remote_target_id: TargetId,
+        remote_sfx_id: H256,
+        nonce: u32,
+    ) -> RpcResult<ProcessedRemoteOrder<Hash, BlockNumber, AccountId>>;
+}
+
+/// A struct that implements the [`CircuitApi`].
+pub struct Circuit<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Circuit<C, P> {
+    /// Create new `Circuit` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, Hash>
+    CircuitApiServer<AccountId, Balance, BlockNumber, Hash> for Circuit<C, Block>
+where
+    AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
+    BlockNumber: Codec + MaybeDisplay,
+    Hash: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
+{
+    fn fetch_order_status(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<OrderStatusRead<Hash, BlockNumber, AccountId>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<OrderStatusRead<Hash, BlockNumber, AccountId>> = api
+            .fetch_order_status(at, xtx_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(order_status) => Ok(order_status),
+            None => Err(runtime_error_into_rpc_err("Xtx doesn't exist")),
+        }
+    }
+
+    fn fetch_all_pending_orders_status(
+        &self,
+    ) -> RpcResult<Vec<OrderStatusRead<Hash, BlockNumber, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Vec<OrderStatusRead<Hash, BlockNumber, AccountId>> = api
+            .fetch_all_pending_orders_status(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_full_side_effects(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<Vec<Vec<FullSideEffect<AccountId, BlockNumber, Balance>>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<Vec<Vec<FullSideEffect<AccountId, BlockNumber, Balance>>>> = api
+            .fetch_full_side_effects(at, xtx_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(full_side_effects) => Ok(full_side_effects),
+            None => Err(runtime_error_into_rpc_err("Xtx doesn't exist")),
+        }
+    }
+
+    fn fetch_dlq_entries(
+        &self,
+        requester: AccountId,
+    ) -> RpcResult<Vec<(Hash, DLQEntry<BlockNumber, AccountId>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Vec<(Hash, DLQEntry<BlockNumber, AccountId>)> = api
+            .fetch_dlq_entries(at, requester)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_processed_remote_order(
+        &self,
+        remote_target_id: TargetId,
+        remote_sfx_id: H256,
+        nonce: u32,
+    ) -> RpcResult<ProcessedRemoteOrder<Hash, BlockNumber, AccountId>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<ProcessedRemoteOrder<Hash, BlockNumber, AccountId>> = api
+            .fetch_processed_remote_order(at, remote_target_id, remote_sfx_id, nonce)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(processed_order) => Ok(processed_order),
+            None => Err(runtime_error_into_rpc_err(
+                "Remote order hasn't been processed",
+            )),
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Custom(format!("{err:?}"))
+}
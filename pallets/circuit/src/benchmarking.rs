@@ -1 +1,158 @@
+#![cfg(feature = "runtime-benchmarks")]
+//! Benchmarks of the Xtx queues run by the clock jobs, over Xtx requested through Machine,
+//! and of settling instant confirmations.
+//!
+//! The pallet's tests run against circuit-mock-runtime, which implements the Config of its own instance of this crate,
+//! so `impl_benchmark_test_suite!` can't be instantiated here. Instead, tests.rs runs the setups below against the mock
+//! runtime with `benchmarked_xtx_queues_process_the_single_due_xtx`.
+use crate::Pallet as Circuit;
 
+use super::*;
+
+use crate::machine::{no_post_updates, Machine, PrecompileResult};
+use frame_benchmarking::{account, benchmarks};
+use sp_runtime::traits::Hash as HashT;
+
+/// Requests p empty Xtx through Machine, one per block from the current one onwards, so that their deadlines are spread over blocks.
+///     Returns the Xtx requested first, which is the only one due as its deadlines pass.
+pub fn request_pending_xtx<T: Config>(p: u32) -> XExecSignalId<T> {
+    let requested_at = frame_system::Pallet::<T>::block_number();
+    let mut first_xtx_id = None;
+    for i in 0..p {
+        frame_system::Pallet::<T>::set_block_number(requested_at.saturating_add(i.into()));
+        let requester: T::AccountId = account("requester", i, 0);
+        let mut local_ctx = Machine::<T>::setup(&[], &requester, None, &SecurityLvl::Optimistic)
+            .expect("Empty Xtx sets up");
+        Machine::<T>::compile(
+            &mut local_ctx,
+            |_, _, _, _, _| Ok(PrecompileResult::TryRequest),
+            no_post_updates::<T>,
+        )
+        .expect("Empty Xtx is requested");
+        first_xtx_id.get_or_insert(local_ctx.xtx_id);
+    }
+    frame_system::Pallet::<T>::set_block_number(requested_at);
+    first_xtx_id.expect("At least one Xtx is requested")
+}
+
+/// Moves the Xtx past bidding, so that its revert settles the executed steps through SquareUp.
+pub fn force_pending_execution<T: Config>(xtx_id: XExecSignalId<T>) {
+    <XExecSignals<T>>::mutate(xtx_id, |maybe_xtx| {
+        if let Some(xtx) = maybe_xtx {
+            xtx.status = CircuitStatus::PendingExecution;
+        }
+    });
+}
+
+/// Holds the executor's bid bond and the requester's charge of SFX provisionally confirmed under SpeedMode::Instant.
+///     Returns the SFX id along with its instant confirmation.
+pub fn schedule_instant_confirmation<T: Config>() -> (
+    SideEffectId<T>,
+    InstantConfirmation<T::AccountId, BlockNumberFor<T>, BalanceOf<T>, T::Hash>,
+) {
+    let executor: T::AccountId = account("executor", 0, 0);
+    let requester: T::AccountId = account("requester", 0, 0);
+    let amount = T::Currency::minimum_balance().saturating_mul(100u32.into());
+    T::Currency::make_free_balance_be(&executor, amount.saturating_mul(2u32.into()));
+    T::Currency::make_free_balance_be(&requester, amount.saturating_mul(2u32.into()));
+
+    let sfx_id = T::Hashing::hash(b"sfx");
+    let bid_id = T::Hashing::hash(b"bid");
+    T::AccountManager::deposit(
+        bid_id,
+        RequestCharge {
+            payee: executor.clone(),
+            offered_reward: amount,
+            maybe_asset_id: None,
+            charge_fee: Zero::zero(),
+            recipient: None,
+            source: BenefitSource::TrafficRewards,
+            role: CircuitRole::Executor,
+        },
+    )
+    .expect("Bid bond is held");
+    T::AccountManager::deposit(
+        sfx_id,
+        RequestCharge {
+            payee: requester,
+            offered_reward: amount,
+            maybe_asset_id: None,
+            charge_fee: Zero::zero(),
+            recipient: None,
+            source: BenefitSource::TrafficFees,
+            role: CircuitRole::Requester,
+        },
+    )
+    .expect("Requester's charge is held");
+
+    let instant = InstantConfirmation {
+        xtx_id: T::Hashing::hash(b"xtx"),
+        bid_id,
+        executor,
+        side_effect: SideEffect {
+            target: [0u8; 4],
+            max_reward: amount,
+            insurance: Zero::zero(),
+            action: *b"tran",
+            encoded_args: vec![],
+            signature: vec![],
+            enforce_executor: None,
+            reward_asset_id: None,
+        },
+        submission_target_height: Zero::zero(),
+        confirmed_at: Zero::zero(),
+        finality_timeout_at: One::one(),
+    };
+    Circuit::<T>::schedule_instant_confirmation(sfx_id, instant.clone());
+    (sfx_id, instant)
+}
+
+benchmarks! {
+
+    process_tick_one {
+        // The cost of the tick stays constant over the Xtx pending next to the due one
+        let p in 1 .. MAX_BENCHMARKED_PENDING_XTX;
+        let xtx_id = request_pending_xtx::<T>(p);
+        let n = Circuit::<T>::get_pending_xtx_bids_timeouts(xtx_id).expect("Bids timeout is scheduled");
+        frame_system::Pallet::<T>::set_block_number(n);
+    }: {
+        Circuit::<T>::process_xtx_tick_queue(n, One::one(), Weight::MAX);
+    }
+    verify {
+        assert_eq!(Circuit::<T>::get_x_exec_signals(xtx_id), None);
+        assert_eq!(
+            Circuit::<T>::get_xtx_timeouts_wheel_cursor(XtxTimeoutKind::Bids),
+            Some(n.saturating_add(One::one()))
+        );
+    }
+
+    process_revert_one {
+        // The cost of the tick stays constant over the Xtx pending next to the due one
+        let p in 1 .. MAX_BENCHMARKED_PENDING_XTX;
+        let xtx_id = request_pending_xtx::<T>(p);
+        force_pending_execution::<T>(xtx_id);
+        let n = Circuit::<T>::get_active_timing_links(xtx_id)
+            .expect("Xtx timeouts are scheduled")
+            .emergency_timeout_here;
+        frame_system::Pallet::<T>::set_block_number(n);
+    }: {
+        Circuit::<T>::process_emergency_revert_xtx_queue(n, One::one(), Weight::MAX);
+    }
+    verify {
+        assert_eq!(Circuit::<T>::get_x_exec_signals(xtx_id), None);
+        assert_eq!(
+            Circuit::<T>::get_xtx_timeouts_wheel_cursor(XtxTimeoutKind::Emergency),
+            Some(n.saturating_add(One::one()))
+        );
+    }
+
+    finalize_instant_confirmation {
+        // Worst case settles the bond and charge right away, as the Xtx has already squared up.
+        let (sfx_id, instant) = schedule_instant_confirmation::<T>();
+    }: {
+        Circuit::<T>::finalize_instant_confirmation(sfx_id, instant);
+    }
+    verify {
+        assert_eq!(Circuit::<T>::get_instant_confirmation(sfx_id), None);
+    }
+}
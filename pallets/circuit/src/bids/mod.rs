@@ -2,8 +2,9 @@ use crate::{pallet::Error, *};
 use frame_support::sp_runtime::traits::Zero;
 
 use crate::square_up::SquareUp;
+use sp_runtime::traits::Hash;
 use sp_std::marker::PhantomData;
-use t3rn_types::sfx::SFXBid;
+use t3rn_types::{bid::SealedSFXBid, sfx::SFXBid};
 
 pub struct Bids<T: Config> {
     _phantom: PhantomData<T>,
@@ -33,6 +34,163 @@ impl<T: Config> Bids<T> {
         >,
         Error<T>,
     > {
+        // Bids of sealed-bid auctions are only accepted as commitments.
+        if <pallet::Pallet<T> as Store>::SealedBidAuctions::contains_key(xtx_id) {
+            return Err(Error::<T>::BiddingRejectedSealedBidAuction)
+        }
+
+        let (bid, current_accepted_bid) =
+            Self::prepare_bid(step_fsx, bid_amount, bidder, requester, sfx_id, xtx_id)?;
+
+        SquareUp::<T>::try_bid(sfx_id, requester, bidder, &bid, current_accepted_bid).map_err(
+            |e| {
+                log::error!("Error while trying to SquareUp::try_bid: {:?}", e);
+                Error::<T>::BiddingRejectedFailedToDepositBidderBond
+            },
+        )?;
+
+        // Replace the best bid for the FSX
+        if let Some(fsx) = step_fsx
+            .iter_mut()
+            .find(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+        {
+            fsx.best_bid = Some(bid);
+        }
+
+        Ok(step_fsx.clone())
+    }
+
+    /// Commit to a sealed bid for SFX. Collateral is deposited as for a bid at SFX::max_reward,
+    ///     since the actual bid amount stays hidden until the reveal.
+    pub fn try_commit(
+        step_fsx: &[FullSideEffect<
+            T::AccountId,
+            frame_system::pallet_prelude::BlockNumberFor<T>,
+            BalanceOf<T>,
+        >],
+        bidder: &T::AccountId,
+        requester: &T::AccountId,
+        sfx_id: SideEffectId<T>,
+        xtx_id: XExecSignalId<T>,
+        commitment: T::Hash,
+    ) -> Result<SealedSFXBid<T::Hash, T::AccountId, BalanceOf<T>, u32>, Error<T>> {
+        let max_reward = step_fsx
+            .iter()
+            .find(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+            .ok_or(Error::<T>::FSXNotFoundById)?
+            .input
+            .max_reward;
+
+        let (bid, _) = Self::prepare_bid(step_fsx, max_reward, bidder, requester, sfx_id, xtx_id)?;
+
+        SquareUp::<T>::try_bid(sfx_id, requester, bidder, &bid, None).map_err(|e| {
+            log::error!("Error while trying to SquareUp::try_bid: {:?}", e);
+            Error::<T>::BiddingRejectedFailedToDepositBidderBond
+        })?;
+
+        Ok(SealedSFXBid {
+            commitment,
+            bid,
+            revealed: false,
+        })
+    }
+
+    /// Open the sealed bid if the revealed amount and salt match the commitment.
+    pub fn try_reveal(
+        sealed_bid: &mut SealedSFXBid<T::Hash, T::AccountId, BalanceOf<T>, u32>,
+        sfx_id: SideEffectId<T>,
+        bid_amount: BalanceOf<T>,
+        salt: T::Hash,
+    ) -> Result<(), Error<T>> {
+        if sealed_bid.revealed {
+            return Err(Error::<T>::SealedBidAlreadyRevealed)
+        }
+        if Self::seal(sfx_id, &sealed_bid.bid.executor, bid_amount, salt) != sealed_bid.commitment {
+            return Err(Error::<T>::SealedBidCommitmentMismatch)
+        }
+        // Check if bid doesn't go below dust limit.
+        if bid_amount < T::Currency::minimum_balance() {
+            return Err(Error::<T>::BiddingRejectedBidBelowDust)
+        }
+        // Sealed bid was committed with SFX::max_reward as its amount.
+        if bid_amount > sealed_bid.bid.amount {
+            return Err(Error::<T>::BiddingRejectedBidTooHigh)
+        }
+
+        sealed_bid.bid.amount = bid_amount;
+        sealed_bid.revealed = true;
+
+        Ok(())
+    }
+
+    /// Commitment to a sealed bid - binds the bid amount and salt to the SFX and bidder,
+    ///     so that commitments can't be replayed by other executors.
+    pub fn seal(
+        sfx_id: SideEffectId<T>,
+        bidder: &T::AccountId,
+        bid_amount: BalanceOf<T>,
+        salt: T::Hash,
+    ) -> T::Hash {
+        SystemHashing::<T>::hash_of(&(sfx_id, bidder, bid_amount, salt))
+    }
+
+    /// Resolve sealed-bid auctions of the step - the lowest valid reveal becomes FSX::best_bid,
+    ///     where ties go to the earliest commitment. All sealed bids are cleaned out.
+    pub fn award_sealed_bids(
+        step_fsx: &mut Vec<
+            FullSideEffect<
+                T::AccountId,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                BalanceOf<T>,
+            >,
+        >,
+        xtx_id: XExecSignalId<T>,
+    ) -> Vec<
+        FullSideEffect<T::AccountId, frame_system::pallet_prelude::BlockNumberFor<T>, BalanceOf<T>>,
+    > {
+        for fsx in step_fsx.iter_mut() {
+            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id);
+            let sealed_bids = <pallet::Pallet<T> as Store>::SealedBids::take(sfx_id);
+
+            let winning_bid = sealed_bids
+                .iter()
+                .filter(|sealed_bid| sealed_bid.revealed)
+                .min_by_key(|sealed_bid| sealed_bid.bid.amount)
+                .map(|sealed_bid| sealed_bid.bid.clone());
+
+            SquareUp::<T>::settle_sealed_bids(sfx_id, &sealed_bids, winning_bid.as_ref());
+
+            if winning_bid.is_some() {
+                fsx.best_bid = winning_bid;
+            }
+        }
+
+        step_fsx.clone()
+    }
+
+    fn prepare_bid(
+        step_fsx: &[FullSideEffect<
+            T::AccountId,
+            frame_system::pallet_prelude::BlockNumberFor<T>,
+            BalanceOf<T>,
+        >],
+        bid_amount: BalanceOf<T>,
+        bidder: &T::AccountId,
+        requester: &T::AccountId,
+        sfx_id: SideEffectId<T>,
+        xtx_id: XExecSignalId<T>,
+    ) -> Result<
+        (
+            SFXBid<T::AccountId, BalanceOf<T>, u32>,
+            Option<SFXBid<T::AccountId, BalanceOf<T>, u32>>,
+        ),
+        Error<T>,
+    > {
+        // Once the executors pallet selected an active set, only its members may bid.
+        let active_set = T::Executors::active_set();
+        if !active_set.is_empty() && !active_set.contains(bidder) {
+            return Err(Error::<T>::BiddingRejectedExecutorNotActive)
+        }
         // Check for the previous bids for SFX.
         let fsx = step_fsx
             .iter()
@@ -89,6 +247,8 @@ impl<T: Config> Bids<T> {
                 .filter(|&fsx| fsx.security_lvl == SecurityLvl::Optimistic)
                 // All FSX but the current one
                 .filter(|&fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) != sfx_id)
+                // Bond is reserved in the asset of the bid - only rewards in the same asset add up
+                .filter(|&other_fsx| other_fsx.input.reward_asset_id == bid.reward_asset_id)
                 // Since we don't know the final bid amounts, sum up the max reward for each SFX
                 .map(|fsx| fsx.input.max_reward)
                 .reduce(|total_reserved, next_amount| {
@@ -108,21 +268,6 @@ impl<T: Config> Bids<T> {
             };
         }
 
-        SquareUp::<T>::try_bid(sfx_id, requester, bidder, &bid, current_accepted_bid).map_err(
-            |e| {
-                log::error!("Error while trying to SquareUp::try_bid: {:?}", e);
-                Error::<T>::BiddingRejectedFailedToDepositBidderBond
-            },
-        )?;
-
-        // Replace the best bid for the FSX
-        if let Some(fsx) = step_fsx
-            .iter_mut()
-            .find(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
-        {
-            fsx.best_bid = Some(bid);
-        }
-
-        Ok(step_fsx.clone())
+        Ok((bid, current_accepted_bid))
     }
 }
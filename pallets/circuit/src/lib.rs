@@ -35,21 +35,23 @@ use frame_support::{
     RuntimeDebug,
 };
 use frame_system::{
-    ensure_signed,
+    ensure_root, ensure_signed,
     offchain::{SignedPayload, SigningTypes},
     pallet_prelude::{BlockNumberFor, OriginFor},
 };
 use sp_core::H256;
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
-    DispatchError, KeyTypeId,
+    traits::{CheckedAdd, One, Saturating, Zero},
+    DispatchError, KeyTypeId, ModuleError,
 };
 use sp_std::{convert::TryInto, vec, vec::Vec};
 
 pub use t3rn_types::{
-    bid::SFXBid,
+    bid::{SFXBid, SealedSFXBid},
     fsx::FullSideEffect,
-    sfx::{ConfirmedSideEffect, HardenedSideEffect, SecurityLvl, SideEffect, SideEffectId},
+    sfx::{
+        AuctionMode, ConfirmedSideEffect, HardenedSideEffect, SecurityLvl, SideEffect, SideEffectId,
+    },
 };
 
 pub use t3rn_primitives::{
@@ -69,15 +71,21 @@ pub use t3rn_primitives::{
 use crate::{
     machine::{Machine, *},
     square_up::SquareUp,
+    xcm_route::XcmRoute,
 };
 pub use state::XExecSignal;
 
-use t3rn_abi::{recode::Codec, sfx_abi::SFXAbi};
+use t3rn_abi::{
+    evm_ingress_logs::{get_instant_order_commit_abi_descriptor, RemoteEVMInstantOrderCommitLog},
+    recode::{recode_bytes_with_descriptor, Codec},
+    sfx_abi::SFXAbi,
+};
 
 pub use t3rn_primitives::light_client::InclusionReceipt;
 use t3rn_primitives::{
     attesters::AttestersWriteApi,
-    circuit::{CircuitSubmitAPI, ReadSFX},
+    circuit::{CircuitSubmitAPI, ReadSFX, XtxTimeoutKind},
+    threevm::{DispatchXtxCallback, XtxResolution},
 };
 pub use t3rn_sdk_primitives::signal::{ExecutionSignal, SignalKind};
 use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
@@ -86,13 +94,14 @@ use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
 pub mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
-mod benchmarking;
+pub mod benchmarking;
 
 pub mod bids;
 pub mod machine;
 pub mod square_up;
 pub mod state;
 pub mod weights;
+pub mod xcm_route;
 
 /// Defines application identifier for crypto keys of this module.
 /// Every module that deals with signatures needs to declare its unique identifier for
@@ -102,6 +111,10 @@ pub mod weights;
 /// The keys can be inserted manually via RPC (see `author_insertKey`).
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"circ");
 
+/// Xtx pending next to the due one that the Xtx queue ticks are benchmarked up to.
+/// Each tick loads the due Xtx only, so the queues are weighed at this upper bound.
+pub const MAX_BENCHMARKED_PENDING_XTX: u32 = 10_000;
+
 pub type SystemHashing<T> = <T as frame_system::Config>::Hashing;
 
 //
@@ -172,9 +185,11 @@ pub mod pallet {
         attesters::AttestersWriteApi,
         circuit::{
             CircuitDLQ, CircuitSubmitAPI, LocalStateExecutionView, LocalTrigger, OnLocalTrigger,
-            ReadSFX,
+            OrderStep, ReadSFX, StepOutputPlaceholder,
         },
+        clock::{Clock, StandardJobQueues, REVERT_XTX_QUEUE_JOB},
         portal::Portal,
+        threevm::{DispatchXtxCallback, XtxCallback},
         xdns::Xdns,
         SpeedMode,
     };
@@ -221,11 +236,79 @@ pub mod pallet {
     pub type PendingXtxBidsTimeoutsMap<T> =
         StorageMap<_, Identity, XExecSignalId<T>, BlockNumberFor<T>, OptionQuery>;
 
+    /// Bidding windows of Xtx requested with AuctionMode::SealedBid - blocks at which commitments and reveals close.
+    ///     Reveals open once commitments close and last for another Config::SFXBiddingPeriod.
+    #[pallet::storage]
+    #[pallet::getter(fn get_sealed_bid_auctions)]
+    pub type SealedBidAuctions<T> = StorageMap<
+        _,
+        Identity,
+        XExecSignalId<T>,
+        (BlockNumberFor<T>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Sealed bids committed for SFX in order of commitment. Cleaned out once the auction resolves,
+    ///     where the lowest valid reveal becomes FSX::best_bid and unrevealed commitments are slashed.
+    #[pallet::storage]
+    #[pallet::getter(fn get_sealed_bids)]
+    pub type SealedBids<T> = StorageMap<
+        _,
+        Identity,
+        SideEffectId<T>,
+        Vec<
+            SealedSFXBid<
+                <T as frame_system::Config>::Hash,
+                <T as frame_system::Config>::AccountId,
+                BalanceOf<T>,
+                u32,
+            >,
+        >,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn get_finalized_xtx)]
     pub type FinalizedXtx<T> =
         StorageMap<_, Identity, XExecSignalId<T>, BlockNumberFor<T>, OptionQuery>;
 
+    /// Deadlines of PendingXtxBidsTimeoutsMap, FinalizedXtx, PendingXtxTimeoutsMap and InstantConfirmations indexed by the block they're due at,
+    ///     so that each tick reads only the buckets due by now instead of iterating over all pending Xtx.
+    ///     Entries aren't removed when the Xtx moves on, therefore they're checked against the maps once due.
+    #[pallet::storage]
+    pub type XtxTimeoutsWheel<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (XtxTimeoutKind, BlockNumberFor<T>),
+        Identity,
+        XExecSignalId<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Next bucket of the XtxTimeoutsWheel to process for each kind of deadline.
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_timeouts_wheel_cursor)]
+    pub type XtxTimeoutsWheelCursor<T> =
+        StorageMap<_, Twox64Concat, XtxTimeoutKind, BlockNumberFor<T>, OptionQuery>;
+
+    /// SFX of Xtx with SpeedMode::Instant confirmed provisionally with the executor-signed OrderCommitted log,
+    ///     awaiting the finalized inclusion proof. Executor's bid bond and requester's charge are held until then.
+    #[pallet::storage]
+    #[pallet::getter(fn get_instant_confirmation)]
+    pub type InstantConfirmations<T> = StorageMap<
+        _,
+        Identity,
+        SideEffectId<T>,
+        InstantConfirmation<
+            <T as frame_system::Config>::AccountId,
+            BlockNumberFor<T>,
+            BalanceOf<T>,
+            <T as frame_system::Config>::Hash,
+        >,
+        OptionQuery,
+    >;
+
     /// Current Circuit's context of all accepted for execution cross-chain transactions.
     ///
     /// All Xtx that has been initially paid out by users will be left here.
@@ -278,13 +361,73 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Placeholders of multi-step Xtx arguments, resolved from outputs of the previous steps
+    ///     once the next step opens for bidding - one entry per step.
+    #[pallet::storage]
+    #[pallet::getter(fn get_step_output_placeholders)]
+    pub type StepOutputPlaceholders<T> =
+        StorageMap<_, Identity, XExecSignalId<T>, Vec<Vec<StepOutputPlaceholder>>, OptionQuery>;
+
+    /// Contracts to be called back once the Xtx they submitted through 3VM resolves
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_callback)]
+    pub type XtxCallbacks<T> = StorageMap<
+        _,
+        Identity,
+        XExecSignalId<T>,
+        XtxCallback<<T as frame_system::Config>::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Resolutions of Xtx whose contracts are yet to be called back, keyed by their position in the queue
+    #[pallet::storage]
+    pub type PendingXtxCallbacks<T> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        XtxResolution<<T as frame_system::Config>::Hash>,
+        OptionQuery,
+    >;
+
+    /// Head and tail of the PendingXtxCallbacks queue - position of the next resolution to call back and of the next one queued
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_xtx_callbacks_range)]
+    pub type PendingXtxCallbacksRange<T> = StorageValue<_, (u64, u64), ValueQuery>;
+
+    /// Pending XCM queries of SFX dispatched to sibling parachains, along with the sibling expected to respond
+    #[pallet::storage]
+    #[pallet::getter(fn get_xcm_queries)]
+    pub type XcmQueries<T> = StorageMap<
+        _,
+        Twox64Concat,
+        xcm::latest::QueryId,
+        (
+            XExecSignalId<T>,
+            SideEffectId<T>,
+            xcm::latest::MultiLocation,
+        ),
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn get_dlq)]
     pub type DLQ<T> = StorageMap<
         _,
         Identity,
         XExecSignalId<T>,
-        (BlockNumberFor<T>, Vec<TargetId>, SpeedMode),
+        DLQEntry<BlockNumberFor<T>, <T as frame_system::Config>::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Index of the DLQ entries by the requester of their Xtx
+    #[pallet::storage]
+    pub type DLQOfRequester<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Identity,
+        XExecSignalId<T>,
+        (),
         OptionQuery,
     >;
 
@@ -342,12 +485,16 @@ pub mod pallet {
 
         type Currency: Currency<Self::AccountId>;
 
+        /// A type that provides the weight limits of the clock jobs processing the Xtx queues
+        type Clock: Clock<Self>;
+
         /// A type that provides access to Xdns
         type Xdns: Xdns<Self, BalanceOf<Self>>;
 
         type Attesters: AttestersWriteApi<Self::AccountId, DispatchError>
             + AttestersReadApi<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
 
+        /// Executors staking - bids are accepted only from its active set, once it's selected.
         type Executors: Executors<Self, BalanceOf<Self>>;
 
         /// A type that provides access to AccountManager
@@ -380,6 +527,26 @@ pub mod pallet {
 
         // Needed in square_up mod
         type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+
+        /// A type that routes SFX::Escrow on sibling parachains over XCM
+        type XcmRouter: xcm_route::XcmSideEffectsRouter<Call<Self>, BlockNumberFor<Self>>;
+
+        /// Origin of XCM query responses, resolving to the location of the responder
+        type XcmResponseOrigin: EnsureOrigin<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Success = xcm::latest::MultiLocation,
+        >;
+
+        /// Calls back contracts once the Xtx they submitted through 3VM resolves
+        type XtxCallbackDispatcher: DispatchXtxCallback<Self::AccountId, Self::Hash>;
+
+        /// The maximum weight a single Xtx callback can consume
+        #[pallet::constant]
+        type MaxXtxCallbackWeight: Get<Weight>;
+
+        /// The weight budget of Xtx callbacks dispatched on initialize of each block
+        #[pallet::constant]
+        type MaxXtxCallbacksWeightPerBlock: Get<Weight>;
     }
 
     #[pallet::pallet]
@@ -390,7 +557,7 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            Weight::zero()
+            Self::process_xtx_callbacks(T::MaxXtxCallbacksWeightPerBlock::get())
         }
 
         fn on_finalize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {
@@ -400,10 +567,10 @@ pub mod pallet {
         fn offchain_worker(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {}
 
         fn on_runtime_upgrade() -> Weight {
-            // Define the maximum weight of this migration.
-            let max_weight = T::DbWeight::get().reads_writes(10, 10);
+            // Reading and bumping the storage migration version.
+            let version_weight = T::DbWeight::get().reads_writes(1, 1);
             // Define the current storage migration version.
-            const CURRENT_STORAGE_VERSION: u32 = 1;
+            const CURRENT_STORAGE_VERSION: u32 = 3;
             // Migrate the storage entries.
             StorageMigrations::<T>::try_mutate(|current_version| {
                 match *current_version {
@@ -411,6 +578,7 @@ pub mod pallet {
                         // Storage Migration: FSX::SFX updates field "encoded_action: Vec<u8>" to "action: Action: [u8; 4]"
                         // Storage Migration Details: 16-03-2023; v1.3.0-rc -> v1.4.0-rc
                         // Iterate through the old storage entries and migrate them.
+                        let mut translated_fsx: u64 = 0;
                         FullSideEffects::<T>::translate(
                             |_,
                              value: Vec<
@@ -422,6 +590,7 @@ pub mod pallet {
                                     >,
                                 >,
                             >| {
+                                translated_fsx += 1;
                                 Some(
                                     value
                                         .into_iter()
@@ -430,17 +599,47 @@ pub mod pallet {
                                 )
                             },
                         );
+                        let fsx_weight =
+                            T::DbWeight::get().reads_writes(translated_fsx, translated_fsx);
+                        let dlq_weight = Self::migrate_dlq_entries();
+                        let wheel_weight = Self::migrate_xtx_timeouts_into_wheel();
 
                         // Set migrations_done to true
                         *current_version = CURRENT_STORAGE_VERSION;
 
                         // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(max_weight)
+                        Ok::<Weight, DispatchError>(
+                            version_weight
+                                .saturating_add(fsx_weight)
+                                .saturating_add(dlq_weight)
+                                .saturating_add(wheel_weight),
+                        )
+                    },
+                    1 => {
+                        // Storage Migration: DLQ entries record the cause and the last status of Xtx
+                        let dlq_weight = Self::migrate_dlq_entries();
+                        let wheel_weight = Self::migrate_xtx_timeouts_into_wheel();
+
+                        *current_version = CURRENT_STORAGE_VERSION;
+
+                        Ok::<Weight, DispatchError>(
+                            version_weight
+                                .saturating_add(dlq_weight)
+                                .saturating_add(wheel_weight),
+                        )
+                    },
+                    2 => {
+                        // Storage Migration: deadlines of pending Xtx are indexed by block in XtxTimeoutsWheel
+                        let wheel_weight = Self::migrate_xtx_timeouts_into_wheel();
+
+                        *current_version = CURRENT_STORAGE_VERSION;
+
+                        Ok::<Weight, DispatchError>(version_weight.saturating_add(wheel_weight))
                     },
                     // Add more migration cases here, if needed in the future
                     _ => {
                         // No migration needed.
-                        Ok::<Weight, DispatchError>(Weight::zero())
+                        Ok::<Weight, DispatchError>(version_weight)
                     },
                 }
             })
@@ -457,7 +656,33 @@ pub mod pallet {
             n: frame_system::pallet_prelude::BlockNumberFor<T>,
             verifier: &GatewayVendor,
         ) -> Weight {
-            Self::process_adaptive_xtx_timeout_queue(n, verifier)
+            // Triggered by XDNS for each verifier's overview, within the share of the revert clock job.
+            let max_allowed_weight = T::Clock::job_weight_limit(REVERT_XTX_QUEUE_JOB);
+            Self::process_adaptive_xtx_timeout_queue(n, verifier, max_allowed_weight)
+        }
+    }
+
+    impl<T: Config> StandardJobQueues<T> for Pallet<T> {
+        fn process_signal_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+            let weight = Self::process_signal_queue(n, One::one(), job_weight_limit);
+            log::debug!("Circuit::process_signal_queue consumed: {:?}", weight);
+            weight
+        }
+
+        fn process_xtx_tick_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+            let weight = Self::process_xtx_tick_queue(n, One::one(), job_weight_limit);
+            log::debug!("Circuit::process_xtx_tick_queue consumed: {:?}", weight);
+            weight
+        }
+
+        fn process_revert_xtx_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+            let weight =
+                Self::process_emergency_revert_xtx_queue(n, 10u32.into(), job_weight_limit);
+            log::debug!(
+                "Circuit::process_emergency_revert_xtx_queue consumed: {:?}",
+                weight
+            );
+            weight
         }
     }
 
@@ -625,8 +850,43 @@ pub mod pallet {
             side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
             speed_mode: SpeedMode,
             preferred_security_level: SecurityLvl,
+            auction_mode: AuctionMode,
         ) -> DispatchResultWithPostInfo {
-            Self::on_extrinsic_trigger(origin, side_effects, speed_mode, preferred_security_level)
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            let _local_ctx = Self::do_on_extrinsic_trigger(
+                requester,
+                side_effects,
+                speed_mode,
+                &preferred_security_level,
+                auction_mode,
+                None,
+            )?;
+
+            Ok(().into())
+        }
+
+        fn on_multi_step_extrinsic_trigger(
+            origin: OriginFor<T>,
+            steps: Vec<OrderStep<SideEffect<T::AccountId, BalanceOf<T>>>>,
+            speed_mode: SpeedMode,
+            preferred_security_level: SecurityLvl,
+            auction_mode: AuctionMode,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            let _local_ctx = Self::do_on_multi_step_extrinsic_trigger(
+                requester,
+                steps,
+                speed_mode,
+                &preferred_security_level,
+                auction_mode,
+                None,
+            )?;
+
+            Ok(().into())
         }
 
         fn on_remote_origin_trigger(
@@ -772,6 +1032,11 @@ pub mod pallet {
             };
 
             let xtx_id = local_ctx.xtx_id;
+            if let Some(callback) = trigger.maybe_callback {
+                if !<XtxCallbacks<T>>::contains_key(xtx_id) {
+                    <XtxCallbacks<T>>::insert(xtx_id, callback);
+                }
+            }
             log::debug!(
                 target: "runtime::circuit",
                 "submit_side_effects xtx state with status: {:?}",
@@ -812,11 +1077,25 @@ pub mod pallet {
             })?;
             Ok(())
         }
+
+        fn on_local_trigger_weight() -> Weight {
+            <T as Config>::WeightInfo::on_local_trigger()
+        }
+
+        fn load_local_state_weight() -> Weight {
+            // Loading without an xtx id sets up a fresh xtx, as a trigger does
+            <T as Config>::WeightInfo::on_local_trigger()
+        }
+
+        fn on_signal_weight() -> Weight {
+            T::DbWeight::get().reads_writes(1, 1)
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Used by other pallets that want to create the exec order
+        #[pallet::call_index(0)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
         pub fn on_local_trigger(origin: OriginFor<T>, trigger: Vec<u8>) -> DispatchResult {
             let _execution_state_view =
@@ -828,27 +1107,113 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
-        pub fn on_xcm_trigger(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            // ToDo: Check TriggerAuthRights for local triggers
-            unimplemented!();
-        }
+        /// Notify call of the XCM queries reporting the outcome of SFX dispatched to sibling parachains.
+        ///     Confirms the SFX with Circuit as the executioner on success, or reverts the Xtx otherwise.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_side_effect())]
+        pub fn on_xcm_trigger(
+            origin: OriginFor<T>,
+            query_id: xcm::latest::QueryId,
+            response: xcm::latest::Response,
+        ) -> DispatchResultWithPostInfo {
+            let responder = T::XcmResponseOrigin::ensure_origin(origin)?;
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
-        pub fn on_remote_gateway_trigger(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            unimplemented!();
+            let (xtx_id, sfx_id, expected_responder) = <Self as Store>::XcmQueries::get(query_id)
+                .ok_or(Error::<T>::FailedToExitXBIPortal)?;
+            ensure!(
+                responder == expected_responder,
+                Error::<T>::XcmResponderMismatch
+            );
+            <Self as Store>::XcmQueries::remove(query_id);
+
+            match response {
+                xcm::latest::Response::ExecutionResult(None) => {
+                    Machine::<T>::compile(
+                        &mut Machine::<T>::load_xtx(xtx_id)?,
+                        |_current_fsx, _local_state, _steps_cnt, _status, _requester| {
+                            Ok(PrecompileResult::TryConfirm(
+                                sfx_id,
+                                ConfirmedSideEffect {
+                                    err: None,
+                                    output: None,
+                                    inclusion_data: query_id.encode(),
+                                    executioner: T::SelfAccountId::get(),
+                                    received_at: frame_system::Pallet::<T>::block_number(),
+                                    cost: None,
+                                },
+                            ))
+                        },
+                        |status_change, local_ctx| {
+                            Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                            // Next step of multi-step Xtx opened for bidding
+                            if status_change.1 == CircuitStatus::PendingBidding {
+                                Self::emit_step_fsx(local_ctx);
+                            }
+                            if status_change.1 == CircuitStatus::FinishedAllSteps
+                                || status_change.1 == CircuitStatus::Committed
+                            {
+                                Self::request_sfx_attestation(local_ctx);
+                            }
+                            Self::emit_status_update(
+                                local_ctx.xtx_id,
+                                Some(local_ctx.xtx.clone()),
+                                Some(local_ctx.full_side_effects.clone()),
+                            );
+                            Ok(())
+                        },
+                    )?;
+                },
+                xcm::latest::Response::ExecutionResult(Some((index, error))) => {
+                    log::error!(
+                        "on_xcm_trigger: SFX {:?} failed at instruction {:?} -- {:?}",
+                        sfx_id,
+                        index,
+                        error
+                    );
+                    Self::deposit_event(Event::SideEffectFailedOverXcm(xtx_id, sfx_id, query_id));
+                    // Failed transfers never left the sibling, return the escrowed funds to requester
+                    if let Some(fsx) = Machine::<T>::load_xtx(xtx_id)?
+                        .full_side_effects
+                        .iter()
+                        .flatten()
+                        .find(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+                    {
+                        XcmRoute::<T>::release_escrow(fsx, sfx_id);
+                    }
+                    let _success = Machine::<T>::revert(
+                        xtx_id,
+                        Cause::IntentionalKill,
+                        |_status_change, local_ctx| {
+                            Self::emit_status_update(
+                                local_ctx.xtx_id,
+                                Some(local_ctx.xtx.clone()),
+                                None,
+                            );
+                        },
+                    );
+                },
+                _ => return Err(Error::<T>::FailedToConvertXBIResult2SFXConfirmation.into()),
+            }
+
+            Ok(().into())
         }
 
+        // call_index(2) is retired: sibling parachains report SFX outcomes back via on_xcm_trigger
+        #[pallet::call_index(3)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_xtx())]
         pub fn cancel_xtx(origin: OriginFor<T>, xtx_id: T::Hash) -> DispatchResultWithPostInfo {
             let attempting_requester = Self::authorize(origin, CircuitRole::Requester)?;
 
             Machine::<T>::compile(
                 &mut Machine::<T>::load_xtx(xtx_id)?,
-                |current_fsx, _local_state, _steps_cnt, status, requester| {
+                |current_fsx, _local_state, steps_cnt, status, requester| {
                     if attempting_requester != requester || status > CircuitStatus::PendingBidding {
                         return Err(Error::<T>::UnauthorizedCancellation)
                     }
+                    // Drop cancellation once the first step of multi-step Xtx was executed
+                    if steps_cnt.0 > 0 {
+                        return Err(Error::<T>::UnauthorizedCancellation)
+                    }
                     // Drop cancellation in case some bids have already been posted
                     if current_fsx.iter().any(|fsx| fsx.best_bid.is_some()) {
                         return Err(Error::<T>::UnauthorizedCancellation)
@@ -861,6 +1226,7 @@ pub mod pallet {
             Ok(().into())
         }
 
+        #[pallet::call_index(4)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_xtx())]
         pub fn revert(origin: OriginFor<T>, xtx_id: T::Hash) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
@@ -869,6 +1235,7 @@ pub mod pallet {
             Ok(().into())
         }
 
+        #[pallet::call_index(5)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_xtx())]
         pub fn trigger_dlq(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
@@ -876,6 +1243,7 @@ pub mod pallet {
             Ok(().into())
         }
 
+        #[pallet::call_index(6)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger())]
         pub fn on_remote_origin_trigger(
             origin: OriginFor<T>,
@@ -897,12 +1265,14 @@ pub mod pallet {
                 side_effects,
                 speed_mode,
                 &SecurityLvl::Escrow,
+                AuctionMode::Open,
                 Some(call_origin),
             )?;
 
             Ok(().into())
         }
 
+        #[pallet::call_index(7)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger())]
         pub fn on_extrinsic_trigger(
             origin: OriginFor<T>,
@@ -918,12 +1288,14 @@ pub mod pallet {
                 side_effects,
                 speed_mode,
                 &preferred_security_level,
+                AuctionMode::Open,
                 None,
             )?;
 
             Ok(().into())
         }
 
+        #[pallet::call_index(8)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_side_effect())]
         pub fn escrow(origin: OriginFor<T>, sfx_id: SideEffectId<T>) -> DispatchResultWithPostInfo {
             // Authorize: Retrieve sender of the transaction.
@@ -997,6 +1369,10 @@ pub mod pallet {
                 },
                 |status_change, local_ctx| {
                     Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                    // Next step of multi-step Xtx opened for bidding
+                    if status_change.1 == CircuitStatus::PendingBidding {
+                        Self::emit_step_fsx(local_ctx);
+                    }
                     if status_change.1 == CircuitStatus::FinishedAllSteps
                         || status_change.1 == CircuitStatus::Committed
                     {
@@ -1033,6 +1409,7 @@ pub mod pallet {
             Ok(().into())
         }
 
+        #[pallet::call_index(9)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::bid_sfx())]
         pub fn bid_sfx(
             origin: OriginFor<T>,
@@ -1069,6 +1446,7 @@ pub mod pallet {
         }
 
         /// Blind version should only be used for testing - unsafe since skips inclusion proof check.
+        #[pallet::call_index(10)]
         #[pallet::weight(< T as Config >::WeightInfo::confirm_side_effect())]
         pub fn confirm_side_effect(
             origin: OriginFor<T>,
@@ -1095,12 +1473,18 @@ pub mod pallet {
                 },
                 |status_change, local_ctx| {
                     Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                    // Next step of multi-step Xtx opened for bidding
+                    if status_change.1 == CircuitStatus::PendingBidding {
+                        Self::emit_step_fsx(local_ctx);
+                    }
                     if status_change.1 == CircuitStatus::FinishedAllSteps
                         || status_change.1 == CircuitStatus::Committed
                     {
                         Self::request_sfx_attestation(local_ctx);
-                        // ToDo: uncomment when price + costs estimates are implemented
-                        // T::Xdns::estimate_costs(Machine::read_current_step_fsx(local_ctx));
+                        // Feed the winning bids into price history of requester-facing cost estimates
+                        for step_fsx in local_ctx.full_side_effects.iter() {
+                            T::Xdns::note_accepted_bids(step_fsx);
+                        }
                     }
                     // Emit: From Circuit events
                     Self::emit_status_update(
@@ -1114,95 +1498,356 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    use crate::machine::{no_mangle, Machine};
+        /// Retry the DLQ entry of the Xtx once all of its targets are active again:
+        ///     re-schedules the timeout of Xtx with inactive targets, or re-requests the attestations that failed.
+        ///     Available to the requester and root.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::retry_dlq())]
+        pub fn retry_dlq(origin: OriginFor<T>, xtx_id: T::Hash) -> DispatchResultWithPostInfo {
+            let entry = Self::authorize_dlq_action(origin, xtx_id)?;
+            ensure!(
+                Self::ensure_all_gateways_are_active(entry.targets.clone()),
+                Error::<T>::DLQTargetsStillInactive
+            );
+            let weight = Self::retry_dlq_entry(xtx_id, entry);
+            Self::deposit_event(Event::XtxDLQRetried(
+                xtx_id,
+                !<DLQ<T>>::contains_key(xtx_id),
+            ));
+            Ok(Some(weight.saturating_add(T::DbWeight::get().reads(2))).into())
+        }
 
-    /// Events for the pallet.
-    #[pallet::event]
-    #[pallet::generate_deposit(pub (super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        // XBI Exit events - consider moving to separate XBIPortalExit pallet.
-        Transfer(T::AccountId, AccountId32, AccountId32, Value),
-        TransferAssets(T::AccountId, AssetId, AccountId32, AccountId32, Value),
-        TransferORML(T::AccountId, AssetId, AccountId32, AccountId32, Value),
-        AddLiquidity(T::AccountId, AssetId, AssetId, Value, Value, Value),
-        Swap(T::AccountId, AssetId, AssetId, Value, Value, Value),
-        CallNative(T::AccountId, Data),
-        CallEvm(
-            T::AccountId,
-            AccountId20,
-            AccountId20,
-            ValueEvm,
-            Data,
-            Gas,
-            ValueEvm,
-            Option<ValueEvm>,
-            Option<ValueEvm>,
-            Vec<(AccountId20, Vec<sp_core::H256>)>,
-        ),
-        CallWasm(T::AccountId, AccountId32, Value, Gas, Option<Value>, Data),
-        CallCustom(
-            T::AccountId,
-            AccountId32,
-            AccountId32,
-            Value,
-            Data,
-            Gas,
-            Data,
-        ),
-        // Notification(T::AccountId, AccountId32, XBINotificationKind, Data, Data),
-        Result(T::AccountId, AccountId32, XbiResult, Data, Data),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and pending
-        XTransactionReceivedForExec(XExecSignalId<T>),
-        // New best bid for SFX has been accepted. Account here is an executor.
-        SFXNewBidReceived(
-            SideEffectId<T>,
-            <T as frame_system::Config>::AccountId,
-            BalanceOf<T>,
-        ),
-        // An executions SideEffect was confirmed.
-        SideEffectConfirmed(XExecSignalId<T>),
-        // An executions SideEffect was confirmed.
-        DynamicDestinationDealReplaced(XExecSignalId<T>, SideEffectId<T>, T::AccountId, Sfx4bId),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and ready
-        XTransactionReadyForExec(XExecSignalId<T>),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
-        XTransactionStepFinishedExec(XExecSignalId<T>),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
-        XTransactionXtxFinishedExecAllSteps(XExecSignalId<T>),
-        // Listeners - users + SDK + +executors + attesters to know FSX is resolved
-        XTransactionFSXCommitted(XExecSignalId<T>),
-        // Listeners - users + SDK + +executors + attesters to know Xtx is fully resolved
-        XTransactionXtxCommitted(XExecSignalId<T>),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
-        XTransactionXtxRevertedAfterTimeOut(XExecSignalId<T>),
-        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
-        XTransactionXtxDroppedAtBidding(XExecSignalId<T>),
-        // Listeners - executioners/relayers to know new challenges and perform offline risk/reward calc
-        //  of whether side effect is worth picking up
-        NewSideEffectsAvailable(
-            <T as frame_system::Config>::AccountId,
-            XExecSignalId<T>,
-            Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
-            Vec<SideEffectId<T>>,
-        ),
-        // Listeners - executioners/relayers to know that certain SideEffects are no longer valid
-        // ToDo: Implement Xtx timeout!
-        CancelledSideEffects(
-            <T as frame_system::Config>::AccountId,
-            XtxId<T>,
-            Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
-        ),
-        // Listeners - executioners/relayers to know whether they won the confirmation challenge
-        SideEffectsConfirmed(
-            XtxId<T>,
-            Vec<
-                Vec<
-                    FullSideEffect<
-                        <T as frame_system::Config>::AccountId,
-                        BlockNumberFor<T>,
-                        BalanceOf<T>,
+        /// Revert the Xtx stuck in DLQ regardless of its targets, refunding the requester and executors
+        ///     via AccountManager::finalize. Root only access.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::force_revert_dlq())]
+        pub fn force_revert_dlq(
+            origin: OriginFor<T>,
+            xtx_id: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::take_dlq_entry(xtx_id).ok_or(Error::<T>::DLQEntryNotFound)?;
+            let success = Machine::<T>::revert(
+                xtx_id,
+                Cause::IntentionalKill,
+                |_status_change, local_ctx| {
+                    Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx.clone()), None);
+                },
+            );
+            ensure!(success, Error::<T>::DLQForceRevertFailed);
+            Self::deposit_event(Event::XtxDLQForceReverted(xtx_id));
+            Ok(().into())
+        }
+
+        /// Take the Xtx with inactive targets out of DLQ, extending its timeout by the given number of blocks.
+        ///     Available to the requester and root.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::extend_dlq_timeout())]
+        pub fn extend_dlq_timeout(
+            origin: OriginFor<T>,
+            xtx_id: T::Hash,
+            extension: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let entry = Self::authorize_dlq_action(origin, xtx_id)?;
+            ensure!(
+                entry.cause == DLQCause::InactiveTargets,
+                Error::<T>::DLQTimeoutExtensionNotApplicable
+            );
+            Self::take_dlq_entry(xtx_id);
+
+            let mut adaptive_timeout = Self::get_adaptive_timeout(xtx_id, Some(entry.speed_mode));
+            adaptive_timeout.estimated_height_here = adaptive_timeout
+                .estimated_height_here
+                .saturating_add(extension);
+            adaptive_timeout.submit_by_height_here = adaptive_timeout
+                .submit_by_height_here
+                .saturating_add(extension);
+            adaptive_timeout.emergency_timeout_here = adaptive_timeout
+                .emergency_timeout_here
+                .saturating_add(extension);
+            Self::reschedule_xtx_timeout(xtx_id, adaptive_timeout.clone());
+
+            Self::deposit_event(Event::XtxDLQTimeoutExtended(
+                xtx_id,
+                adaptive_timeout.emergency_timeout_here,
+            ));
+            Ok(().into())
+        }
+
+        /// Commit to a bid for SFX of Xtx auctioned in AuctionMode::SealedBid.
+        ///     Commitment is expected to be Bids::seal of the SFX id, bidder, bid amount and salt.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bid_sfx())]
+        pub fn commit_sealed_bid(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let bidder = Self::authorize(origin, CircuitRole::Executor)?;
+            // retrieve xtx_id
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
+
+            let (commits_close_at, _reveals_close_at) =
+                <Self as Store>::SealedBidAuctions::get(xtx_id)
+                    .ok_or(Error::<T>::SealedBidAuctionNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < commits_close_at,
+                Error::<T>::SealedBidCommitPhaseClosed
+            );
+
+            let mut sealed_bids = <Self as Store>::SealedBids::get(sfx_id);
+            ensure!(
+                !sealed_bids
+                    .iter()
+                    .any(|sealed_bid| sealed_bid.bid.executor == bidder),
+                Error::<T>::SealedBidAlreadyCommitted
+            );
+
+            let local_ctx = Machine::<T>::load_xtx(xtx_id)?;
+            ensure!(
+                local_ctx.xtx.status == CircuitStatus::PendingBidding,
+                Error::<T>::BiddingInactive
+            );
+
+            sealed_bids.push(Bids::<T>::try_commit(
+                Machine::<T>::read_current_step_fsx(&local_ctx),
+                &bidder,
+                &local_ctx.xtx.requester,
+                sfx_id,
+                xtx_id,
+                commitment,
+            )?);
+            <Self as Store>::SealedBids::insert(sfx_id, sealed_bids);
+
+            Self::deposit_event(Event::SFXSealedBidCommitted(sfx_id, bidder));
+
+            Ok(().into())
+        }
+
+        /// Reveal the bid committed to with commit_sealed_bid once committing has closed.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bid_sfx())]
+        pub fn reveal_sealed_bid(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+            bid_amount: BalanceOf<T>,
+            salt: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let bidder = Self::authorize(origin, CircuitRole::Executor)?;
+            // retrieve xtx_id
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
+
+            let (commits_close_at, reveals_close_at) =
+                <Self as Store>::SealedBidAuctions::get(xtx_id)
+                    .ok_or(Error::<T>::SealedBidAuctionNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                now >= commits_close_at,
+                Error::<T>::SealedBidRevealPhaseNotOpen
+            );
+            ensure!(
+                now < reveals_close_at,
+                Error::<T>::SealedBidRevealPhaseClosed
+            );
+
+            <Self as Store>::SealedBids::try_mutate(sfx_id, |sealed_bids| {
+                let sealed_bid = sealed_bids
+                    .iter_mut()
+                    .find(|sealed_bid| sealed_bid.bid.executor == bidder)
+                    .ok_or(Error::<T>::SealedBidCommitmentNotFound)?;
+                Bids::<T>::try_reveal(sealed_bid, sfx_id, bid_amount, salt)
+            })?;
+
+            Self::deposit_event(Event::SFXSealedBidRevealed(sfx_id, bidder, bid_amount));
+
+            Ok(().into())
+        }
+
+        /// Submit the finalized inclusion proof of SFX provisionally confirmed under SpeedMode::Instant,
+        ///     releasing the executor's bid bond and committing the requester's charge held since the provisional confirmation.
+        ///     Only the executor who confirmed the SFX provisionally can finalize it.
+        #[pallet::call_index(16)]
+        #[pallet::weight(< T as Config >::WeightInfo::confirm_instant_finality())]
+        pub fn confirm_instant_finality(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+            confirmation: ConfirmedSideEffect<
+                <T as frame_system::Config>::AccountId,
+                BlockNumberFor<T>,
+                BalanceOf<T>,
+            >,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let executor = Self::authorize(origin, CircuitRole::Executor)?;
+            let instant = <Self as Store>::InstantConfirmations::get(sfx_id)
+                .ok_or(Error::<T>::InstantConfirmationNotFound)?;
+            ensure!(
+                executor == instant.executor,
+                Error::<T>::InstantConfirmationExecutorMismatch
+            );
+
+            Self::verify_sfx_inclusion(
+                &instant.side_effect,
+                instant.submission_target_height,
+                SpeedMode::Finalized,
+                &confirmation.inclusion_data,
+            )
+            .map_err(|e| {
+                log::error!("Self::verify_sfx_inclusion hit an error -- {:?}", e);
+                Error::<T>::ConfirmationFailed
+            })?;
+
+            Self::finalize_instant_confirmation(sfx_id, instant);
+
+            Ok(().into())
+        }
+
+        /// Submit SFX ordered in sequential steps as a single Xtx. Next step opens for bidding
+        ///     only after all SFX of the previous step are confirmed. Placeholders resolve
+        ///     the arguments of later steps from outputs of SFX confirmed at the previous steps.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_multi_step_extrinsic_trigger(
+            steps.len() as u32,
+            steps.iter().map(|step| step.side_effects.len() as u32).sum(),
+        ))]
+
+        pub fn on_multi_step_extrinsic_trigger(
+            origin: OriginFor<T>,
+            steps: Vec<OrderStep<SideEffect<T::AccountId, BalanceOf<T>>>>,
+            speed_mode: SpeedMode,
+            preferred_security_level: SecurityLvl,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            let _local_ctx = Self::do_on_multi_step_extrinsic_trigger(
+                requester,
+                steps,
+                speed_mode,
+                &preferred_security_level,
+                AuctionMode::Open,
+                None,
+            )?;
+
+            Ok(().into())
+        }
+    }
+
+    use crate::machine::{no_mangle, Machine};
+
+    /// Events for the pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub (super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        // XBI Exit events - consider moving to separate XBIPortalExit pallet.
+        Transfer(T::AccountId, AccountId32, AccountId32, Value),
+        TransferAssets(T::AccountId, AssetId, AccountId32, AccountId32, Value),
+        TransferORML(T::AccountId, AssetId, AccountId32, AccountId32, Value),
+        AddLiquidity(T::AccountId, AssetId, AssetId, Value, Value, Value),
+        Swap(T::AccountId, AssetId, AssetId, Value, Value, Value),
+        CallNative(T::AccountId, Data),
+        CallEvm(
+            T::AccountId,
+            AccountId20,
+            AccountId20,
+            ValueEvm,
+            Data,
+            Gas,
+            ValueEvm,
+            Option<ValueEvm>,
+            Option<ValueEvm>,
+            Vec<(AccountId20, Vec<sp_core::H256>)>,
+        ),
+        CallWasm(T::AccountId, AccountId32, Value, Gas, Option<Value>, Data),
+        CallCustom(
+            T::AccountId,
+            AccountId32,
+            AccountId32,
+            Value,
+            Data,
+            Gas,
+            Data,
+        ),
+        // Notification(T::AccountId, AccountId32, XBINotificationKind, Data, Data),
+        Result(T::AccountId, AccountId32, XbiResult, Data, Data),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and pending
+        XTransactionReceivedForExec(XExecSignalId<T>),
+        // New best bid for SFX has been accepted. Account here is an executor.
+        SFXNewBidReceived(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+            BalanceOf<T>,
+        ),
+        // Executor committed to a sealed bid for SFX. The bid amount stays hidden until revealed.
+        SFXSealedBidCommitted(SideEffectId<T>, <T as frame_system::Config>::AccountId),
+        // Executor revealed a sealed bid for SFX matching its commitment.
+        SFXSealedBidRevealed(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+            BalanceOf<T>,
+        ),
+        // An executions SideEffect was confirmed.
+        SideEffectConfirmed(XExecSignalId<T>),
+        // SFX of Xtx with SpeedMode::Instant was confirmed provisionally with the executor-signed commit log.
+        //  Finalized inclusion proof is due by the given block, otherwise the executor's bond is slashed.
+        SideEffectConfirmedInstantly(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+            BlockNumberFor<T>,
+        ),
+        // Finalized inclusion proof of the instantly confirmed SFX arrived, releasing the executor's bond.
+        InstantConfirmationFinalized(SideEffectId<T>, <T as frame_system::Config>::AccountId),
+        // Finalized inclusion proof of the instantly confirmed SFX didn't arrive in time, slashing the executor's bond.
+        InstantConfirmationSlashed(SideEffectId<T>, <T as frame_system::Config>::AccountId),
+        // SFX was sent to the sibling parachain as XCM program, awaiting the response to the query.
+        SideEffectDispatchedOverXcm(XExecSignalId<T>, SideEffectId<T>, xcm::latest::QueryId),
+        // SFX sent over XCM failed to execute on the sibling parachain.
+        SideEffectFailedOverXcm(XExecSignalId<T>, SideEffectId<T>, xcm::latest::QueryId),
+        // An executions SideEffect was confirmed.
+        DynamicDestinationDealReplaced(XExecSignalId<T>, SideEffectId<T>, T::AccountId, Sfx4bId),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and ready
+        XTransactionReadyForExec(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
+        XTransactionStepFinishedExec(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
+        XTransactionXtxFinishedExecAllSteps(XExecSignalId<T>),
+        // Listeners - users + SDK + +executors + attesters to know FSX is resolved
+        XTransactionFSXCommitted(XExecSignalId<T>),
+        // Listeners - users + SDK + +executors + attesters to know Xtx is fully resolved
+        XTransactionXtxCommitted(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
+        XTransactionXtxRevertedAfterTimeOut(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
+        XTransactionXtxDroppedAtBidding(XExecSignalId<T>),
+        // Listeners - executioners/relayers to know new challenges and perform offline risk/reward calc
+        //  of whether side effect is worth picking up
+        NewSideEffectsAvailable(
+            <T as frame_system::Config>::AccountId,
+            XExecSignalId<T>,
+            Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+            Vec<SideEffectId<T>>,
+        ),
+        // Listeners - executioners/relayers to know that certain SideEffects are no longer valid
+        // ToDo: Implement Xtx timeout!
+        CancelledSideEffects(
+            <T as frame_system::Config>::AccountId,
+            XtxId<T>,
+            Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+        ),
+        // Listeners - executioners/relayers to know whether they won the confirmation challenge
+        SideEffectsConfirmed(
+            XtxId<T>,
+            Vec<
+                Vec<
+                    FullSideEffect<
+                        <T as frame_system::Config>::AccountId,
+                        BlockNumberFor<T>,
+                        BalanceOf<T>,
                     >,
                 >,
             >,
@@ -1217,6 +1862,18 @@ pub mod pallet {
         UnsuccessfulFSXCommitAttestationRequest(H256),
         SuccessfulFSXRevertAttestationRequest(H256),
         UnsuccessfulFSXRevertAttestationRequest(H256),
+        // DLQ entry of the Xtx was retried; true if the Xtx has left DLQ
+        XtxDLQRetried(XExecSignalId<T>, bool),
+        // Xtx stuck in DLQ was reverted by root
+        XtxDLQForceReverted(XExecSignalId<T>),
+        // Xtx left DLQ with the extended emergency timeout
+        XtxDLQTimeoutExtended(XExecSignalId<T>, BlockNumberFor<T>),
+        // Contract that submitted the resolved Xtx through 3VM was called back, consuming the weight
+        XtxCallbackDispatched(XExecSignalId<T>, T::AccountId, Weight),
+        // Callback of the resolved Xtx to its contract has failed and its effects were discarded, consuming the weight
+        XtxCallbackFailed(XExecSignalId<T>, T::AccountId, Weight),
+        // DLQ entries of Xtx missing their XExecSignals records were dropped by the storage migration
+        DLQEntriesDroppedOnMigration(u32),
     }
 
     #[pallet::error]
@@ -1228,6 +1885,8 @@ pub mod pallet {
         UpdateXtxTriggeredWithUnexpectedStatus,
         ConfirmationFailed,
         InvalidOrderOrigin,
+        InvalidStepOutputPlaceholder,
+        StepOutputPlaceholderUnresolved,
         ApplyTriggeredWithUnexpectedStatus,
         BidderNotEnoughBalance,
         RequesterNotEnoughBalance,
@@ -1250,6 +1909,15 @@ pub mod pallet {
         BiddingRejectedInsuranceTooLow,
         BiddingRejectedBetterBidFound,
         BiddingRejectedFailedToDepositBidderBond,
+        BiddingRejectedSealedBidAuction,
+        SealedBidAuctionNotFound,
+        SealedBidCommitPhaseClosed,
+        SealedBidAlreadyCommitted,
+        SealedBidRevealPhaseNotOpen,
+        SealedBidRevealPhaseClosed,
+        SealedBidCommitmentNotFound,
+        SealedBidAlreadyRevealed,
+        SealedBidCommitmentMismatch,
         BiddingFailedExecutorsBalanceTooLowToReserve,
         InsuranceBondAlreadyDeposited,
         InvalidFTXStateEmptyBidForReadyXtx,
@@ -1301,11 +1969,21 @@ pub mod pallet {
         ArithmeticErrorUnderflow,
         ArithmeticErrorDivisionByZero,
         ABIOnSelectedTargetNotFoundForSubmittedSFX,
-        ForNowOnlySingleRewardAssetSupportedForMultiSFX,
         TargetAppearsNotToBeActiveAndDoesntHaveFinalizedHeight,
         SideEffectsValidationFailedAgainstABI,
         XtxChargeFailedOnEscrowFee,
         FailedToPerformDynamicDestinationDealHotSwap,
+        DLQEntryNotFound,
+        DLQActionUnauthorized,
+        DLQTargetsStillInactive,
+        DLQForceRevertFailed,
+        DLQTimeoutExtensionNotApplicable,
+        InstantCommitLogInvalid,
+        InstantCommitSignatureInvalid,
+        InstantConfirmationNotFound,
+        XcmResponderMismatch,
+        BiddingRejectedExecutorNotActive,
+        InstantConfirmationExecutorMismatch,
     }
 }
 
@@ -1348,6 +2026,21 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    fn emit_step_fsx(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        let step_fsx = Machine::<T>::read_current_step_fsx(local_ctx);
+        if !step_fsx.is_empty() {
+            Self::deposit_event(Event::NewSideEffectsAvailable(
+                local_ctx.xtx.requester.clone(),
+                local_ctx.xtx_id,
+                step_fsx.iter().map(|fsx| fsx.input.clone()).collect(),
+                step_fsx
+                    .iter()
+                    .map(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id))
+                    .collect::<Vec<SideEffectId<T>>>(),
+            ));
+        }
+    }
+
     fn emit_status_update(
         xtx_id: XExecSignalId<T>,
         maybe_xtx: Option<XExecSignal<T::AccountId, BlockNumberFor<T>>>,
@@ -1389,25 +2082,186 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Queues the callback of the contract which submitted the Xtx through 3VM, once the Xtx resolves.
+    ///     Callbacks are dispatched on initialize of the next blocks, within the per-block weight budget.
+    pub(crate) fn queue_xtx_callback(
+        xtx_id: XExecSignalId<T>,
+        status: &CircuitStatus,
+        full_side_effects: &[Vec<
+            FullSideEffect<
+                T::AccountId,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                BalanceOf<T>,
+            >,
+        >],
+    ) {
+        if !<XtxCallbacks<T>>::contains_key(xtx_id) {
+            return
+        }
+
+        let resolution = XtxResolution {
+            xtx_id,
+            status: status.clone(),
+            outputs: full_side_effects
+                .iter()
+                .flatten()
+                .filter_map(|fsx| fsx.confirmed.as_ref().and_then(|c| c.output.clone()))
+                .collect(),
+        };
+
+        Self::push_pending_xtx_callback(resolution);
+    }
+
+    /// Queues the resolution at the tail of PendingXtxCallbacks.
+    pub fn push_pending_xtx_callback(resolution: XtxResolution<T::Hash>) {
+        <PendingXtxCallbacksRange<T>>::mutate(|(_head, tail)| {
+            <PendingXtxCallbacks<T>>::insert(*tail, resolution);
+            *tail = tail.saturating_add(1);
+        });
+    }
+
+    /// Resolutions of Xtx whose contracts are yet to be called back, in order of resolution
+    pub fn get_pending_xtx_callbacks() -> Vec<XtxResolution<T::Hash>> {
+        let (head, tail) = <PendingXtxCallbacksRange<T>>::get();
+        (head..tail)
+            .filter_map(<PendingXtxCallbacks<T>>::get)
+            .collect()
+    }
+
+    /// Dispatches the queued Xtx callbacks in order, as long as another callback of the maximum weight
+    ///     fits into max_allowed_weight. The remaining callbacks are carried over to the next block.
+    pub fn process_xtx_callbacks(max_allowed_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let (mut head, tail) = <PendingXtxCallbacksRange<T>>::get();
+        let mut current_weight = db_weight.reads(1);
+        if head == tail {
+            return current_weight
+        }
+
+        let max_callback_weight =
+            T::MaxXtxCallbackWeight::get().saturating_add(db_weight.reads_writes(1, 1));
+        while head < tail
+            && current_weight
+                .saturating_add(max_callback_weight)
+                .all_lte(max_allowed_weight)
+        {
+            if let Some(resolution) = <PendingXtxCallbacks<T>>::take(head) {
+                current_weight =
+                    current_weight.saturating_add(Self::dispatch_xtx_callback(&resolution));
+            }
+            current_weight = current_weight.saturating_add(db_weight.reads_writes(1, 1));
+            head = head.saturating_add(1);
+        }
+
+        if head == tail {
+            <PendingXtxCallbacksRange<T>>::kill();
+        } else {
+            <PendingXtxCallbacksRange<T>>::put((head, tail));
+        }
+
+        current_weight.saturating_add(db_weight.writes(1))
+    }
+
+    /// Calls back the contract which submitted the resolved Xtx, returning the weight the callback consumed.
+    ///     Failing callbacks are discarded without affecting the resolution of the Xtx, yet pay for their weight.
+    fn dispatch_xtx_callback(resolution: &XtxResolution<T::Hash>) -> Weight {
+        let xtx_id = resolution.xtx_id;
+        let callback = match <XtxCallbacks<T>>::take(xtx_id) {
+            Some(callback) => callback,
+            None => return Weight::zero(),
+        };
+
+        match T::XtxCallbackDispatcher::dispatch_xtx_callback(
+            &callback,
+            resolution,
+            T::MaxXtxCallbackWeight::get(),
+        ) {
+            Ok(consumed) => {
+                Self::deposit_event(Event::XtxCallbackDispatched(
+                    xtx_id,
+                    callback.contract,
+                    consumed,
+                ));
+                consumed
+            },
+            Err(failure) => {
+                log::warn!(
+                    target: "runtime::circuit",
+                    "Callback of xtx {:?} to contract {:?} failed: {:?}",
+                    xtx_id,
+                    callback.contract,
+                    failure.error
+                );
+                Self::deposit_event(Event::XtxCallbackFailed(
+                    xtx_id,
+                    callback.contract,
+                    failure.consumed,
+                ));
+                failure.consumed
+            },
+        }
+    }
+
     fn do_on_extrinsic_trigger(
         requester: T::AccountId,
         side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
         speed_mode: SpeedMode,
         preferred_security_level: &SecurityLvl,
+        auction_mode: AuctionMode,
         maybe_call_origin: Option<T::AccountId>,
     ) -> Result<LocalXtxCtx<T, BalanceOf<T>>, Error<T>> {
+        Self::do_on_multi_step_extrinsic_trigger(
+            requester,
+            vec![OrderStep::new(side_effects)],
+            speed_mode,
+            preferred_security_level,
+            auction_mode,
+            maybe_call_origin,
+        )
+    }
+
+    fn do_on_multi_step_extrinsic_trigger(
+        requester: T::AccountId,
+        steps: Vec<OrderStep<SideEffect<T::AccountId, BalanceOf<T>>>>,
+        speed_mode: SpeedMode,
+        preferred_security_level: &SecurityLvl,
+        auction_mode: AuctionMode,
+        maybe_call_origin: Option<T::AccountId>,
+    ) -> Result<LocalXtxCtx<T, BalanceOf<T>>, Error<T>> {
+        Self::validate_step_output_placeholders(&steps)?;
+
+        let steps_side_effects = steps
+            .iter()
+            .map(|step| step.side_effects.clone())
+            .collect::<Vec<Vec<SideEffect<T::AccountId, BalanceOf<T>>>>>();
+
+        let mut adaptive_timeout = T::Xdns::estimate_adaptive_timeout_on_slowest_target(
+            steps_side_effects
+                .iter()
+                .flatten()
+                .map(|sfx| sfx.target)
+                .collect::<Vec<TargetId>>(),
+            &speed_mode,
+            T::XtxTimeoutDefault::get(),
+        );
+        // Each of the following steps re-opens bidding - extend timeouts by its bidding period.
+        let next_steps_bidding: BlockNumberFor<T> = T::SFXBiddingPeriod::get()
+            .saturating_mul((steps.len().saturating_sub(1) as u32).into());
+        adaptive_timeout.estimated_height_here = adaptive_timeout
+            .estimated_height_here
+            .saturating_add(next_steps_bidding);
+        adaptive_timeout.submit_by_height_here = adaptive_timeout
+            .submit_by_height_here
+            .saturating_add(next_steps_bidding);
+        adaptive_timeout.emergency_timeout_here = adaptive_timeout
+            .emergency_timeout_here
+            .saturating_add(next_steps_bidding);
+
         // Setup: new xtx context with SFX validation
-        let mut fresh_xtx = Machine::<T>::setup(
-            &side_effects,
+        let mut fresh_xtx = Machine::<T>::setup_steps(
+            &steps_side_effects,
             &requester,
-            Some(T::Xdns::estimate_adaptive_timeout_on_slowest_target(
-                side_effects
-                    .iter()
-                    .map(|sfx| sfx.target)
-                    .collect::<Vec<TargetId>>(),
-                &speed_mode,
-                T::XtxTimeoutDefault::get(),
-            )),
+            Some(adaptive_timeout),
             preferred_security_level,
         )?;
 
@@ -1426,17 +2280,53 @@ impl<T: Config> Pallet<T> {
                 // Emit: circuit events
                 let _call_origin = maybe_call_origin.unwrap_or(requester.clone());
 
-                Self::emit_sfx(local_ctx.xtx_id, &requester, &side_effects);
+                Self::emit_sfx(local_ctx.xtx_id, &requester, &steps_side_effects[0]);
                 Ok(())
             },
         )?;
 
+        if steps.len() > 1 {
+            <Self as Store>::StepOutputPlaceholders::insert(
+                fresh_xtx.xtx_id,
+                steps
+                    .iter()
+                    .map(|step| step.placeholders.clone())
+                    .collect::<Vec<Vec<StepOutputPlaceholder>>>(),
+            );
+        }
+
+        if auction_mode == AuctionMode::SealedBid {
+            Self::seal_bidding(fresh_xtx.xtx_id);
+        } else if let Some(updated_fsx) = XcmRoute::<T>::assign_circuit_bids(
+            Machine::<T>::read_current_step_fsx(&fresh_xtx),
+            &requester,
+            fresh_xtx.xtx_id,
+        ) {
+            // Bid SFX::Escrow on sibling parachains as Circuit - executed over XCM once Ready unless outbid
+            Machine::<T>::compile(
+                &mut fresh_xtx,
+                |_, _, _, _, _| Ok(PrecompileResult::TryUpdateFSX(updated_fsx)),
+                no_post_updates,
+            )?;
+        }
+
         #[cfg(feature = "test-skip-verification")]
         frame_system::Pallet::<T>::inc_account_nonce(requester);
 
         Ok(fresh_xtx)
     }
 
+    /// Open sealed-bid auction for freshly requested Xtx - commitments are accepted until the usual
+    ///     bidding timeout, followed by the reveal window after which the bids timeout triggers the resolution.
+    fn seal_bidding(xtx_id: XExecSignalId<T>) {
+        let commits_close_at =
+            T::SFXBiddingPeriod::get() + <frame_system::Pallet<T>>::block_number();
+        let reveals_close_at = commits_close_at + T::SFXBiddingPeriod::get();
+
+        <Self as Store>::SealedBidAuctions::insert(xtx_id, (commits_close_at, reveals_close_at));
+        Self::schedule_bids_timeout(xtx_id, reveals_close_at);
+    }
+
     fn authorize(
         origin: OriginFor<T>,
         role: CircuitRole,
@@ -1452,28 +2342,52 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Placeholders may only refer to SFX of the previous steps and replace existing arguments.
+    fn validate_step_output_placeholders(
+        steps: &[OrderStep<SideEffect<T::AccountId, BalanceOf<T>>>],
+    ) -> Result<(), Error<T>> {
+        ensure!(!steps.is_empty(), Error::<T>::SetupFailedEmptyXtx);
+        for (step_index, step) in steps.iter().enumerate() {
+            for placeholder in step.placeholders.iter() {
+                let source_sfx_cnt = match steps.get(placeholder.source_step as usize) {
+                    Some(source_step) if (placeholder.source_step as usize) < step_index =>
+                        source_step.side_effects.len(),
+                    _ => return Err(Error::<T>::InvalidStepOutputPlaceholder),
+                };
+                ensure!(
+                    (placeholder.source_sfx_index as usize) < source_sfx_cnt,
+                    Error::<T>::InvalidStepOutputPlaceholder
+                );
+                match step.side_effects.get(placeholder.sfx_index as usize) {
+                    Some(sfx) if (placeholder.arg_index as usize) < sfx.encoded_args.len() => {},
+                    _ => return Err(Error::<T>::InvalidStepOutputPlaceholder),
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate(
-        side_effects: &[SideEffect<T::AccountId, BalanceOf<T>>],
+        steps: &[Vec<SideEffect<T::AccountId, BalanceOf<T>>>],
         local_ctx: &mut LocalXtxCtx<T, BalanceOf<T>>,
         _preferred_security_lvl: &SecurityLvl,
     ) -> Result<(), Error<T>> {
-        let mut full_side_effects: Vec<
-            FullSideEffect<
-                T::AccountId,
-                frame_system::pallet_prelude::BlockNumberFor<T>,
-                BalanceOf<T>,
-            >,
-        > = vec![];
-
         // ToDo: Handle empty SFX case as error instead - must satisfy requirements of LocalTrigger
-        if side_effects.is_empty() {
+        if steps.iter().all(|side_effects| side_effects.is_empty()) {
             local_ctx.full_side_effects = vec![vec![]];
             return Ok(())
         }
 
+        // Each of multiple steps must carry SFX - empty step would never open for bidding.
+        ensure!(
+            steps.iter().all(|side_effects| !side_effects.is_empty()),
+            Error::<T>::SetupFailedEmptyXtx
+        );
+
         // Verify each requested asset is supported by the gateway
-        let all_targets = side_effects
+        let all_targets = steps
             .iter()
+            .flatten()
             .map(|sfx| sfx.target)
             .collect::<Vec<TargetId>>();
 
@@ -1482,7 +2396,39 @@ impl<T: Config> Pallet<T> {
             Error::<T>::GatewayNotActive
         );
 
-        for (index, sfx) in side_effects.iter().enumerate() {
+        local_ctx.full_side_effects = vec![];
+        // SFX index runs across all steps, keeping SFX ids unique within Xtx
+        let mut index: u32 = 0;
+        for side_effects in steps.iter() {
+            let full_side_effects = Self::validate_step(side_effects, &mut index)?;
+            local_ctx.full_side_effects.push(full_side_effects);
+        }
+
+        Ok(())
+    }
+
+    fn validate_step(
+        side_effects: &[SideEffect<T::AccountId, BalanceOf<T>>],
+        index: &mut u32,
+    ) -> Result<
+        Vec<
+            FullSideEffect<
+                T::AccountId,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                BalanceOf<T>,
+            >,
+        >,
+        Error<T>,
+    > {
+        let mut full_side_effects: Vec<
+            FullSideEffect<
+                T::AccountId,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                BalanceOf<T>,
+            >,
+        > = vec![];
+
+        for sfx in side_effects.iter() {
             let gateway_max_security_lvl =
                 <T as Config>::Xdns::get_gateway_max_security_lvl(&sfx.target);
 
@@ -1503,13 +2449,6 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::SideEffectsValidationFailedAgainstABI
             })?;
 
-            // if let Some(next) = side_effects.get(index + 1) {
-            //     if sfx.reward_asset_id != next.reward_asset_id {
-            //         // ToDo: Allow for remote orders
-            //         return Err(Error::<T>::ForNowOnlySingleRewardAssetSupportedForMultiSFX)
-            //     }
-            // }
-
             let submission_target_height = match T::Portal::get_finalized_height(sfx.target)
                 .map_err(|_| Error::<T>::TargetAppearsNotToBeActiveAndDoesntHaveFinalizedHeight)?
             {
@@ -1524,16 +2463,14 @@ impl<T: Config> Pallet<T> {
                 security_lvl,
                 submission_target_height,
                 best_bid: None,
-                index: index as u32,
+                index: *index,
             });
+            *index += 1;
         }
         // Skip automatic ordering of SFX for now, allow user to decide - consult PR#https://github.com/t3rn/t3rn/pull/1489
         full_side_effects.sort_by(|a, b| a.index.partial_cmp(&b.index).unwrap());
 
-        // Assign the full_side_effects to the local_ctx as they are
-        local_ctx.full_side_effects = vec![full_side_effects];
-
-        Ok(())
+        Ok(full_side_effects)
     }
 
     fn confirm(
@@ -1587,17 +2524,41 @@ impl<T: Config> Pallet<T> {
 
         log::debug!("Order confirmed!");
 
-        #[cfg(not(feature = "test-skip-verification"))]
         let xtx = Machine::<T>::load_xtx(xtx_id)?.xtx;
 
+        if xtx.speed_mode == SpeedMode::Instant {
+            return Self::confirm_instantly(xtx_id, sfx_id, &fsx, confirmation)
+        }
+
+        Self::verify_sfx_inclusion(
+            &fsx.input,
+            fsx.submission_target_height,
+            xtx.speed_mode,
+            &confirmation.inclusion_data,
+        )?;
+
+        log::debug!("Confirmation success");
+
+        Ok(())
+    }
+
+    /// Verify the inclusion of SFX execution on target with the light client of the target,
+    ///     and check the included payload against the SFX arguments.
+    #[cfg_attr(feature = "test-skip-verification", allow(unused_variables))]
+    fn verify_sfx_inclusion(
+        side_effect: &SideEffect<T::AccountId, BalanceOf<T>>,
+        submission_target_height: frame_system::pallet_prelude::BlockNumberFor<T>,
+        speed_mode: SpeedMode,
+        inclusion_data: &[u8],
+    ) -> Result<(), DispatchError> {
         // confirm the payload is included in the specified block, and return the SideEffect params as defined in XDNS.
         // this could be multiple events!
         #[cfg(not(feature = "test-skip-verification"))]
         let inclusion_receipt = <T as Config>::Portal::verify_event_inclusion(
-            fsx.input.target,
-            xtx.speed_mode,
+            side_effect.target,
+            speed_mode,
             None, //ToDo - load pallet index or contract address here
-            confirmation.inclusion_data.clone(),
+            inclusion_data.to_vec(),
         )
         .map_err(|_| DispatchError::Other("SideEffect confirmation of inclusion failed"))?;
 
@@ -1609,46 +2570,153 @@ impl<T: Config> Pallet<T> {
 
         log::debug!("Inclusion confirmed!");
 
-        let sfx_abi =
-            <T as Config>::Xdns::get_sfx_abi(&fsx.input.target, fsx.input.action).ok_or({
+        let sfx_abi = <T as Config>::Xdns::get_sfx_abi(&side_effect.target, side_effect.action)
+            .ok_or({
                 DispatchError::Other("Unable to find matching Side Effect descriptor in XDNS")
             })?;
 
         #[cfg(feature = "test-skip-verification")]
         let inclusion_receipt = InclusionReceipt::<BlockNumberFor<T>> {
-            message: confirmation.inclusion_data.clone(),
+            message: inclusion_data.to_vec(),
             including_header: [0u8; 32].encode(),
             height: frame_system::pallet_prelude::BlockNumberFor::<T>::zero(),
+            event_index: None,
         }; // Empty encoded_event_params for testing purposes
 
         #[cfg(not(feature = "test-skip-verification"))]
-        if inclusion_receipt.height < fsx.submission_target_height {
+        if inclusion_receipt.height < submission_target_height {
             log::error!(
-                "Inclusion height is higher than target {:?} < {:?}. Xtx Id: {:?}",
+                "Inclusion height is higher than target {:?} < {:?}. Target: {:?}",
                 inclusion_receipt.height,
-                fsx.submission_target_height,
-                xtx_id,
+                submission_target_height,
+                side_effect.target,
             );
             return Err(DispatchError::Other(
                 "SideEffect confirmation of inclusion failed - inclusion height is higher than target",
             ))
         }
 
-        let payload_codec = <T as Config>::Xdns::get_target_codec(&fsx.input.target)?;
+        let payload_codec = <T as Config>::Xdns::get_target_codec(&side_effect.target)?;
 
-        fsx.input.confirm(
+        side_effect.confirm(
             sfx_abi,
             inclusion_receipt.message,
             // todo: store the codec info in gateway's records and use it here
             &Codec::Scale,
             &payload_codec,
+        )
+    }
+
+    /// Provisionally confirm SFX of Xtx with SpeedMode::Instant with the OrderCommitted log emitted on target,
+    ///     before its inclusion is finalized. The log carries the ECDSA signature of the executor over the commitment,
+    ///     binding the executor's bid bond until the finalized proof arrives with confirm_instant_finality.
+    fn confirm_instantly(
+        xtx_id: XExecSignalId<T>,
+        sfx_id: &SideEffectId<T>,
+        fsx: &FullSideEffect<
+            T::AccountId,
+            frame_system::pallet_prelude::BlockNumberFor<T>,
+            BalanceOf<T>,
+        >,
+        confirmation: &ConfirmedSideEffect<
+            T::AccountId,
+            frame_system::pallet_prelude::BlockNumberFor<T>,
+            BalanceOf<T>,
+        >,
+    ) -> Result<(), DispatchError> {
+        let bid = fsx
+            .best_bid
+            .as_ref()
+            .ok_or(Error::<T>::InvalidFTXStateEmptyBidForReadyXtx)?;
+
+        let commit_log = recode_bytes_with_descriptor(
+            confirmation.inclusion_data.clone(),
+            get_instant_order_commit_abi_descriptor(),
+            Codec::Rlp,
+            Codec::Scale,
+        )
+        .ok()
+        .and_then(|recoded| RemoteEVMInstantOrderCommitLog::decode(&mut &recoded[..]).ok())
+        .ok_or(Error::<T>::InstantCommitLogInvalid)?;
+
+        ensure!(
+            commit_log.sfx_id.as_bytes() == sfx_id.as_ref(),
+            Error::<T>::InstantCommitLogInvalid
+        );
+        ensure!(
+            Self::recover_instant_commit_signer(&commit_log) == Some(bid.executor.clone()),
+            Error::<T>::InstantCommitSignatureInvalid
+        );
+
+        let sfx_abi =
+            <T as Config>::Xdns::get_sfx_abi(&fsx.input.target, fsx.input.action).ok_or({
+                DispatchError::Other("Unable to find matching Side Effect descriptor in XDNS")
+            })?;
+        let payload_codec = <T as Config>::Xdns::get_target_codec(&fsx.input.target)?;
+
+        // Signature takes place of the SFX arguments beyond the committed ones
+        fsx.input.confirm(
+            sfx_abi,
+            RemoteEVMInstantOrderCommitLog::trim_signature(confirmation.inclusion_data.clone())?,
+            &Codec::Scale,
+            &payload_codec,
         )?;
 
-        log::debug!("Confirmation success");
+        let now = <frame_system::Pallet<T>>::block_number();
+        let finality_timeout_at = now.saturating_add(T::XtxTimeoutDefault::get());
+        Self::schedule_instant_confirmation(
+            *sfx_id,
+            InstantConfirmation {
+                xtx_id,
+                bid_id: bid.generate_id::<SystemHashing<T>, T>(*sfx_id),
+                executor: bid.executor.clone(),
+                side_effect: fsx.input.clone(),
+                submission_target_height: fsx.submission_target_height,
+                confirmed_at: now,
+                finality_timeout_at,
+            },
+        );
+
+        Self::deposit_event(Event::SideEffectConfirmedInstantly(
+            *sfx_id,
+            bid.executor.clone(),
+            finality_timeout_at,
+        ));
+
+        log::debug!("Instant confirmation success");
 
         Ok(())
     }
 
+    /// Recover the account of the executor that signed the OrderCommitted log with its ECDSA key.
+    ///     Executor signs the Ethereum signed message of keccak256(sfxId ++ to ++ amount),
+    ///     and its account is the blake2_256 hash of the compressed public key.
+    pub fn recover_instant_commit_signer(
+        commit_log: &RemoteEVMInstantOrderCommitLog,
+    ) -> Option<T::AccountId> {
+        let mut commitment = commit_log.sfx_id.as_bytes().to_vec();
+        commitment.extend_from_slice(commit_log.to.as_bytes());
+        commitment.extend_from_slice(&<[u8; 32]>::from(commit_log.amount));
+
+        let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+        message.extend_from_slice(&sp_io::hashing::keccak_256(&commitment));
+
+        let signature: [u8; 65] = commit_log.signature.as_slice().try_into().ok()?;
+        let public_key = sp_io::crypto::secp256k1_ecdsa_recover_compressed(
+            &signature,
+            &sp_io::hashing::keccak_256(&message),
+        )
+        .ok()?;
+
+        T::AccountId::decode(&mut &sp_io::hashing::blake2_256(&public_key)[..]).ok()
+    }
+
+    /// Xtx has squared up its bonds - either it's gone or it has finished all of the steps.
+    fn is_squared_up(xtx_id: XExecSignalId<T>) -> bool {
+        <XExecSignals<T>>::get(xtx_id)
+            .map_or(true, |xtx| xtx.status >= CircuitStatus::FinishedAllSteps)
+    }
+
     pub fn get_all_xtx_targets(xtx_id: XExecSignalId<T>) -> Vec<TargetId> {
         // Get FSX of XTX
         let fsx_of_xtx = match <Pallet<T>>::get_fsx_of_xtx(xtx_id) {
@@ -1762,53 +2830,95 @@ impl<T: Config> Pallet<T> {
         kill_interval: frame_system::pallet_prelude::BlockNumberFor<T>,
         max_allowed_weight: Weight,
     ) -> Weight {
-        let mut current_weight = 0;
-        if kill_interval == frame_system::pallet_prelude::BlockNumberFor::<T>::zero() {
+        if kill_interval.is_zero() || !(n % kill_interval).is_zero() {
             return Weight::zero()
-        } else if n % kill_interval == frame_system::pallet_prelude::BlockNumberFor::<T>::zero() {
-            // Go over all unfinished Xtx to find those that should be killed
-            let _processed_xtx_revert_count = <PendingXtxBidsTimeoutsMap<T>>::iter()
-                .filter(|(_xtx_id, timeout_at)| timeout_at <= &n)
-                .map(|(xtx_id, _timeout_at)| {
-                    if current_weight <= max_allowed_weight.ref_time() {
-                        current_weight = current_weight
-                            .saturating_add(Self::process_tick_one(xtx_id).ref_time());
-                    }
-                })
-                .count();
+        }
+        let db_weight = T::DbWeight::get();
 
-            // Go over all unfinished Xtx to find those that should be killed
-            let _processed_xtx_commit_count = <FinalizedXtx<T>>::iter()
-                .map(|(xtx_id, _)| {
-                    if current_weight <= max_allowed_weight.ref_time() {
-                        current_weight = current_weight
-                            .saturating_add(Self::process_tick_two(xtx_id).ref_time());
+        // Resolve bidding of Xtx whose bids timeout passed
+        let current_weight =
+            Self::process_xtx_deadlines(XtxTimeoutKind::Bids, n, max_allowed_weight, |xtx_id| {
+                match <PendingXtxBidsTimeoutsMap<T>>::get(xtx_id) {
+                    Some(timeout_at) if timeout_at <= n => {
+                        let weight = Self::process_tick_one(xtx_id);
+                        if <PendingXtxBidsTimeoutsMap<T>>::contains_key(xtx_id) {
+                            Self::carry_over_xtx_deadline(XtxTimeoutKind::Bids, n, xtx_id);
+                        }
+                        weight.saturating_add(db_weight.reads(2))
+                    },
+                    _ => db_weight.reads(1),
+                }
+            });
+
+        // Commit Xtx that finished all steps
+        let current_weight = current_weight.saturating_add(Self::process_xtx_deadlines(
+            XtxTimeoutKind::Commit,
+            n,
+            max_allowed_weight.saturating_sub(current_weight),
+            |xtx_id| {
+                if !<FinalizedXtx<T>>::contains_key(xtx_id) {
+                    return db_weight.reads(1)
+                }
+                let weight = Self::process_tick_two(xtx_id);
+                if <XExecSignals<T>>::contains_key(xtx_id) {
+                    Self::carry_over_xtx_deadline(XtxTimeoutKind::Commit, n, xtx_id);
+                } else {
+                    <FinalizedXtx<T>>::remove(xtx_id);
+                }
+                weight.saturating_add(db_weight.reads_writes(2, 1))
+            },
+        ));
+
+        // Slash executors of instantly confirmed SFX whose finalized proof didn't arrive in time
+        current_weight.saturating_add(Self::process_xtx_deadlines(
+            XtxTimeoutKind::InstantFinality,
+            n,
+            max_allowed_weight.saturating_sub(current_weight),
+            |sfx_id| match <InstantConfirmations<T>>::get(sfx_id) {
+                Some(instant) if instant.finality_timeout_at <= n => {
+                    // Bond of Xtx yet to square up is still owed to SquareUp::finalize
+                    if !Self::is_squared_up(instant.xtx_id) {
+                        Self::carry_over_xtx_deadline(XtxTimeoutKind::InstantFinality, n, sfx_id);
+                        return db_weight.reads_writes(2, 1)
                     }
-                })
-                .count();
-        }
-        Weight::from_parts(current_weight, 0u64)
+                    <InstantConfirmations<T>>::remove(sfx_id);
+                    SquareUp::<T>::settle_instant_confirmation(
+                        sfx_id,
+                        &instant.side_effect,
+                        instant.bid_id,
+                        false,
+                    );
+                    Self::deposit_event(Event::InstantConfirmationSlashed(
+                        sfx_id,
+                        instant.executor,
+                    ));
+                    db_weight.reads_writes(3, 3)
+                },
+                _ => db_weight.reads(1),
+            },
+        ))
     }
 
     pub fn process_adaptive_xtx_timeout_queue(
         n: frame_system::pallet_prelude::BlockNumberFor<T>,
         _verifier: &GatewayVendor,
+        max_allowed_weight: Weight,
     ) -> Weight {
-        let mut current_weight: Weight = Zero::zero();
-
-        // Go over all unfinished Xtx to find those that timed out
-        let _processed_xtx_revert_count = <PendingXtxTimeoutsMap<T>>::iter()
-            .filter(|(_xtx_id, adaptive_timeout)| {
+        let db_weight = T::DbWeight::get();
+        // Revert Xtx whose adaptive timeout passed
+        Self::process_xtx_deadlines(XtxTimeoutKind::Adaptive, n, max_allowed_weight, |xtx_id| {
+            match <PendingXtxTimeoutsMap<T>>::get(xtx_id) {
                 // ToDo: consider filtering out by adaptive_timeout.verifier == verifier
-                adaptive_timeout.estimated_height_here < n
-            })
-            .map(|(xtx_id, _timeout_at)| {
-                // if current_weight <= max_allowed_weight {
-                current_weight = current_weight.saturating_add(Self::process_revert_one(xtx_id).0);
-                // }
-            })
-            .count();
-        current_weight
+                Some(adaptive_timeout) if adaptive_timeout.estimated_height_here < n => {
+                    let weight = Self::process_revert_one(xtx_id).0;
+                    if <PendingXtxTimeoutsMap<T>>::contains_key(xtx_id) {
+                        Self::carry_over_xtx_deadline(XtxTimeoutKind::Adaptive, n, xtx_id);
+                    }
+                    weight.saturating_add(db_weight.reads(2))
+                },
+                _ => db_weight.reads(1),
+            }
+        })
     }
 
     pub fn process_emergency_revert_xtx_queue(
@@ -1816,27 +2926,185 @@ impl<T: Config> Pallet<T> {
         revert_interval: frame_system::pallet_prelude::BlockNumberFor<T>,
         max_allowed_weight: Weight,
     ) -> Weight {
-        let mut current_weight: Weight = Default::default();
-        // Scenario 1: all the timeout s can be handled in the block space
-        // Scenario 2: all but 5 timeouts can be handled
-        //     - add the 5 timeouts to an immediate queue for the next block
-        if revert_interval == frame_system::pallet_prelude::BlockNumberFor::<T>::zero() {
-            return current_weight
-        } else if n % revert_interval == frame_system::pallet_prelude::BlockNumberFor::<T>::zero() {
-            // Go over all unfinished Xtx to find those that timed out
-            let _processed_xtx_revert_count = <PendingXtxTimeoutsMap<T>>::iter()
-                .filter(|(_xtx_id, adaptive_timeout)| adaptive_timeout.emergency_timeout_here <= n)
-                .map(|(xtx_id, _timeout_at)| {
-                    if current_weight.ref_time() <= max_allowed_weight.ref_time() {
-                        current_weight =
-                            current_weight.saturating_add(Self::process_revert_one(xtx_id).0);
+        if revert_interval.is_zero() || !(n % revert_interval).is_zero() {
+            return Weight::zero()
+        }
+        let db_weight = T::DbWeight::get();
+        // Revert Xtx whose emergency timeout passed
+        Self::process_xtx_deadlines(XtxTimeoutKind::Emergency, n, max_allowed_weight, |xtx_id| {
+            match <PendingXtxTimeoutsMap<T>>::get(xtx_id) {
+                Some(adaptive_timeout) if adaptive_timeout.emergency_timeout_here <= n => {
+                    let weight = Self::process_revert_one(xtx_id).0;
+                    if <PendingXtxTimeoutsMap<T>>::contains_key(xtx_id) {
+                        Self::carry_over_xtx_deadline(XtxTimeoutKind::Emergency, n, xtx_id);
                     }
-                })
-                .count();
+                    weight.saturating_add(db_weight.reads(2))
+                },
+                _ => db_weight.reads(1),
+            }
+        })
+    }
+
+    /// Processes the XtxTimeoutsWheel buckets of the deadline kind up to the block n, calling `on_due` for each Xtx.
+    ///     Once max_allowed_weight is reached, the remaining Xtx and buckets are carried over to the next tick.
+    fn process_xtx_deadlines(
+        kind: XtxTimeoutKind,
+        n: frame_system::pallet_prelude::BlockNumberFor<T>,
+        max_allowed_weight: Weight,
+        mut on_due: impl FnMut(XExecSignalId<T>) -> Weight,
+    ) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut current_weight = db_weight.reads(1);
+        let start = match <XtxTimeoutsWheelCursor<T>>::get(kind) {
+            Some(cursor) => cursor,
+            // Nothing has been scheduled yet
+            None => return current_weight,
+        };
+
+        let mut cursor = start;
+        'buckets: while cursor <= n {
+            // Draining removes the Xtx from the bucket only once taken, so the rest stays for the next tick
+            let mut bucket = <XtxTimeoutsWheel<T>>::drain_prefix((kind, cursor));
+            current_weight = current_weight.saturating_add(db_weight.reads(1));
+            loop {
+                if current_weight.ref_time() >= max_allowed_weight.ref_time() {
+                    break 'buckets
+                }
+                match bucket.next() {
+                    Some((xtx_id, ())) =>
+                        current_weight = current_weight
+                            .saturating_add(db_weight.writes(1))
+                            .saturating_add(on_due(xtx_id)),
+                    None => break,
+                }
+            }
+            cursor = cursor.saturating_add(One::one());
+        }
+
+        if cursor != start {
+            <XtxTimeoutsWheelCursor<T>>::insert(kind, cursor);
+            current_weight = current_weight.saturating_add(db_weight.writes(1));
         }
         current_weight
     }
 
+    /// Indexes the Xtx deadline in the XtxTimeoutsWheel. Past deadlines are due with the current block.
+    ///     The wheel's cursor is moved back to the deadline if it's ahead, so that the wheel never walks empty blocks before the first deadline.
+    fn schedule_xtx_deadline(
+        kind: XtxTimeoutKind,
+        at: BlockNumberFor<T>,
+        xtx_id: XExecSignalId<T>,
+    ) {
+        let at = at.max(<frame_system::Pallet<T>>::block_number());
+        if <XtxTimeoutsWheelCursor<T>>::get(kind).map_or(true, |cursor| at < cursor) {
+            <XtxTimeoutsWheelCursor<T>>::insert(kind, at);
+        }
+        <XtxTimeoutsWheel<T>>::insert((kind, at), xtx_id, ());
+    }
+
+    /// Retries the Xtx deadline that's still pending after being processed with the next block.
+    fn carry_over_xtx_deadline(
+        kind: XtxTimeoutKind,
+        n: BlockNumberFor<T>,
+        xtx_id: XExecSignalId<T>,
+    ) {
+        <XtxTimeoutsWheel<T>>::insert((kind, n.saturating_add(One::one())), xtx_id, ());
+    }
+
+    pub fn schedule_bids_timeout(xtx_id: XExecSignalId<T>, timeout_at: BlockNumberFor<T>) {
+        <PendingXtxBidsTimeoutsMap<T>>::insert(xtx_id, timeout_at);
+        Self::schedule_xtx_deadline(XtxTimeoutKind::Bids, timeout_at, xtx_id);
+    }
+
+    pub fn schedule_xtx_timeouts(
+        xtx_id: XExecSignalId<T>,
+        adaptive_timeout: &AdaptiveTimeout<BlockNumberFor<T>, TargetId>,
+    ) {
+        <PendingXtxTimeoutsMap<T>>::insert(xtx_id, adaptive_timeout);
+        Self::schedule_xtx_deadline(
+            XtxTimeoutKind::Adaptive,
+            adaptive_timeout
+                .estimated_height_here
+                .saturating_add(One::one()),
+            xtx_id,
+        );
+        Self::schedule_xtx_deadline(
+            XtxTimeoutKind::Emergency,
+            adaptive_timeout.emergency_timeout_here,
+            xtx_id,
+        );
+    }
+
+    pub fn schedule_commit(xtx_id: XExecSignalId<T>) {
+        let now = <frame_system::Pallet<T>>::block_number();
+        <FinalizedXtx<T>>::insert(xtx_id, now);
+        Self::schedule_xtx_deadline(XtxTimeoutKind::Commit, now, xtx_id);
+    }
+
+    /// Settles SFX whose finalized inclusion is verified, unless its Xtx is yet to square up -
+    ///     which settles the bond and requester's charge in SquareUp::finalize as of any other confirmed SFX.
+    pub fn finalize_instant_confirmation(
+        sfx_id: SideEffectId<T>,
+        instant: InstantConfirmation<T::AccountId, BlockNumberFor<T>, BalanceOf<T>, T::Hash>,
+    ) {
+        <InstantConfirmations<T>>::remove(sfx_id);
+        if Self::is_squared_up(instant.xtx_id) {
+            SquareUp::<T>::settle_instant_confirmation(
+                sfx_id,
+                &instant.side_effect,
+                instant.bid_id,
+                true,
+            );
+        }
+
+        Self::deposit_event(Event::InstantConfirmationFinalized(
+            sfx_id,
+            instant.executor,
+        ));
+    }
+
+    pub fn schedule_instant_confirmation(
+        sfx_id: SideEffectId<T>,
+        instant: InstantConfirmation<T::AccountId, BlockNumberFor<T>, BalanceOf<T>, T::Hash>,
+    ) {
+        Self::schedule_xtx_deadline(
+            XtxTimeoutKind::InstantFinality,
+            instant.finality_timeout_at,
+            sfx_id,
+        );
+        <InstantConfirmations<T>>::insert(sfx_id, instant);
+    }
+
+    /// Schedules the deadlines of the pending Xtx in XtxTimeoutsWheel, returning the weight of
+    ///     the deadlines moved.
+    fn migrate_xtx_timeouts_into_wheel() -> Weight {
+        let mut single_deadlines: u64 = 0;
+        for (xtx_id, timeout_at) in <PendingXtxBidsTimeoutsMap<T>>::iter() {
+            Self::schedule_xtx_deadline(XtxTimeoutKind::Bids, timeout_at, xtx_id);
+            single_deadlines += 1;
+        }
+        for (xtx_id, finalized_at) in <FinalizedXtx<T>>::iter() {
+            Self::schedule_xtx_deadline(XtxTimeoutKind::Commit, finalized_at, xtx_id);
+            single_deadlines += 1;
+        }
+        let mut adaptive_timeouts: u64 = 0;
+        for (xtx_id, adaptive_timeout) in <PendingXtxTimeoutsMap<T>>::iter() {
+            Self::schedule_xtx_timeouts(xtx_id, &adaptive_timeout);
+            adaptive_timeouts += 1;
+        }
+        // Scheduling a deadline reads the block number and the wheel cursor, writing the cursor
+        //  and the wheel. Adaptive timeouts schedule two deadlines and re-insert the timeout.
+        let db_weight = T::DbWeight::get();
+        db_weight
+            .reads_writes(3, 2)
+            .saturating_mul(single_deadlines)
+            .saturating_add(
+                db_weight
+                    .reads_writes(5, 5)
+                    .saturating_mul(adaptive_timeouts),
+            )
+    }
+
     pub fn get_adaptive_timeout(
         xtx_id: T::Hash,
         maybe_speed_mode: Option<SpeedMode>,
@@ -1854,28 +3122,41 @@ impl<T: Config> Pallet<T> {
     /// # Arguments
     ///
     /// * `xtx_id` - The ID of the Xtx to be added to the DLQ.
+    /// * `requester` - The requester of the Xtx.
     /// * `targets` - The targets of the Xtx.
     /// * `speed_mode` - The speed mode of the Xtx.
+    /// * `cause` - The reason of the Xtx landing in the DLQ.
+    /// * `last_status` - The status of the Xtx at the time of landing in the DLQ.
+    /// * `failed_sfx_ids` - The target and id of each SFX whose attestation request failed.
     ///
     /// # Returns
     ///
     /// A tuple containing the weight of the operation and a boolean indicating whether the operation was successful.
     pub fn add_xtx_to_dlq(
         xtx_id: T::Hash,
+        requester: T::AccountId,
         targets: Vec<TargetId>,
         speed_mode: SpeedMode,
+        cause: DLQCause,
+        last_status: CircuitStatus,
+        failed_sfx_ids: Vec<(TargetId, H256)>,
     ) -> (Weight, bool) {
         if <DLQ<T>>::contains_key(xtx_id) {
             return (T::DbWeight::get().reads(1), false)
         }
 
+        <DLQOfRequester<T>>::insert(&requester, xtx_id, ());
         <DLQ<T>>::insert(
             xtx_id,
-            (
-                <frame_system::Pallet<T>>::block_number(),
+            DLQEntry {
+                added_at: <frame_system::Pallet<T>>::block_number(),
+                requester,
                 targets,
                 speed_mode,
-            ),
+                cause,
+                last_status,
+                failed_sfx_ids,
+            },
         );
         <XExecSignals<T>>::mutate(xtx_id, |xtx| {
             if let Some(xtx) = xtx {
@@ -1894,11 +3175,20 @@ impl<T: Config> Pallet<T> {
         }
 
         (
-            T::DbWeight::get().reads_writes(2, 3), // 2 reads (DLQ, XExecSignals), 3 writes (DLQ, XExecSignals, PendingXtxTimeoutsMap)
+            T::DbWeight::get().reads_writes(2, 4), // 2 reads (DLQ, XExecSignals), 4 writes (DLQ, DLQOfRequester, XExecSignals, PendingXtxTimeoutsMap)
             true,
         )
     }
 
+    /// Takes the DLQ entry of the Xtx out of the DLQ and its requester's index.
+    pub(crate) fn take_dlq_entry(
+        xtx_id: T::Hash,
+    ) -> Option<DLQEntry<BlockNumberFor<T>, T::AccountId>> {
+        let entry = <DLQ<T>>::take(xtx_id)?;
+        <DLQOfRequester<T>>::remove(&entry.requester, xtx_id);
+        Some(entry)
+    }
+
     /// Removes a cross-chain transaction (Xtx) from the Dead Letter Queue (DLQ).
     ///
     /// # Arguments
@@ -1909,28 +3199,165 @@ impl<T: Config> Pallet<T> {
     ///
     /// A tuple containing the weight of the operation and a boolean indicating whether the operation was successful.
     pub fn remove_xtx_from_dlq(xtx_id: T::Hash) -> (Weight, bool) {
-        let dlq_entry = match <DLQ<T>>::take(xtx_id) {
+        let dlq_entry = match Self::take_dlq_entry(xtx_id) {
             Some(dlq_entry) => dlq_entry,
             None => return (T::DbWeight::get().reads(1), false),
         };
 
-        let adaptive_timeout = Self::get_adaptive_timeout(xtx_id, Some(dlq_entry.2));
-        <PendingXtxTimeoutsMap<T>>::insert(xtx_id, &adaptive_timeout);
+        let adaptive_timeout = Self::get_adaptive_timeout(xtx_id, Some(dlq_entry.speed_mode));
+        Self::reschedule_xtx_timeout(xtx_id, adaptive_timeout);
+
+        (
+            T::DbWeight::get().reads_writes(2, 4), // 2 reads (DLQ, XExecSignals), 4 writes (DLQ, DLQOfRequester, XExecSignals, PendingXtxTimeoutsMap)
+            true,
+        )
+    }
+
+    fn reschedule_xtx_timeout(
+        xtx_id: T::Hash,
+        adaptive_timeout: AdaptiveTimeout<BlockNumberFor<T>, TargetId>,
+    ) {
+        Self::schedule_xtx_timeouts(xtx_id, &adaptive_timeout);
 
         <XExecSignals<T>>::mutate(xtx_id, |xtx| {
             if let Some(xtx) = xtx {
                 xtx.timeouts_at = adaptive_timeout;
             } else {
                 log::error!(
-                    "Xtx not found in XExecSignals for xtx_id when remove_xtx_from_dlq: {:?}",
+                    "Xtx not found in XExecSignals for xtx_id when rescheduling its timeout: {:?}",
                     xtx_id
                 )
             }
         });
+    }
 
-        (
-            T::DbWeight::get().reads_writes(2, 3), // 2 reads (DLQ, XExecSignals), 3 writes (DLQ, XExecSignals, PendingXtxTimeoutsMap)
-            true,
+    /// Retries the DLQ entry of the Xtx, assuming all of its targets are active.
+    ///
+    /// Xtx with inactive targets are taken out of the DLQ with the timeout re-scheduled, while
+    /// only the attestation requests that failed are re-requested, landing the Xtx back in the DLQ
+    /// with the ones failing again.
+    ///
+    /// # Returns
+    ///
+    /// The weight of the operation.
+    pub fn retry_dlq_entry(
+        xtx_id: T::Hash,
+        entry: DLQEntry<BlockNumberFor<T>, T::AccountId>,
+    ) -> Weight {
+        match entry.cause {
+            DLQCause::InactiveTargets => Self::remove_xtx_from_dlq(xtx_id).0,
+            DLQCause::AttestationRequestFailed => {
+                Self::take_dlq_entry(xtx_id);
+                let requests = entry.failed_sfx_ids.len() as u64;
+                let still_failed_sfx_ids: Vec<(TargetId, H256)> = entry
+                    .failed_sfx_ids
+                    .into_iter()
+                    .filter(|(target, sfx_id)| {
+                        let requested =
+                            Self::request_fsx_attestation(*target, *sfx_id, &entry.last_status);
+                        Self::deposit_attestation_request_event(
+                            *sfx_id,
+                            &entry.last_status,
+                            requested.is_ok(),
+                        );
+                        requested.is_err()
+                    })
+                    .collect();
+                let mut weight = T::DbWeight::get().reads_writes(1 + requests, 2 + requests); // DLQ, DLQOfRequester + NextBatch & GMP per request
+                if !still_failed_sfx_ids.is_empty() {
+                    let mut failed_targets: Vec<TargetId> = still_failed_sfx_ids
+                        .iter()
+                        .map(|(target, _sfx_id)| *target)
+                        .collect();
+                    failed_targets.sort();
+                    failed_targets.dedup();
+                    weight = weight.saturating_add(
+                        Self::add_xtx_to_dlq(
+                            xtx_id,
+                            entry.requester,
+                            failed_targets,
+                            entry.speed_mode,
+                            DLQCause::AttestationRequestFailed,
+                            entry.last_status,
+                            still_failed_sfx_ids,
+                        )
+                        .0,
+                    );
+                }
+                weight
+            },
+        }
+    }
+
+    /// Ensures the origin is either root or the requester of the Xtx and returns its DLQ entry.
+    fn authorize_dlq_action(
+        origin: OriginFor<T>,
+        xtx_id: T::Hash,
+    ) -> Result<DLQEntry<BlockNumberFor<T>, T::AccountId>, DispatchError> {
+        let entry = <DLQ<T>>::get(xtx_id).ok_or(Error::<T>::DLQEntryNotFound)?;
+        if ensure_root(origin.clone()).is_err() {
+            let who = ensure_signed(origin)?;
+            ensure!(who == entry.requester, Error::<T>::DLQActionUnauthorized);
+        }
+        Ok(entry)
+    }
+
+    /// Lists the DLQ entries of all Xtx submitted by the requester.
+    pub fn get_dlq_entries_of_requester(
+        requester: &T::AccountId,
+    ) -> Vec<(XExecSignalId<T>, DLQEntry<BlockNumberFor<T>, T::AccountId>)> {
+        <DLQOfRequester<T>>::iter_key_prefix(requester)
+            .filter_map(|xtx_id| <DLQ<T>>::get(xtx_id).map(|entry| (xtx_id, entry)))
+            .collect()
+    }
+
+    /// Translates the DLQ entries stored as (added_at, targets, speed_mode) into DLQEntry,
+    ///     indexing them by the requester. Entries of Xtx no longer found have no requester to
+    ///     be refunded and are dropped, logged and counted by DLQEntriesDroppedOnMigration.
+    ///
+    /// Returns the weight of the entries translated and dropped.
+    fn migrate_dlq_entries() -> Weight {
+        let mut translated: u64 = 0;
+        let mut dropped: u32 = 0;
+        <DLQ<T>>::translate(
+            |xtx_id, (added_at, targets, speed_mode): (BlockNumberFor<T>, Vec<TargetId>, SpeedMode)| {
+                let Some(xtx) = <XExecSignals<T>>::get(xtx_id) else {
+                    log::warn!(
+                        target: "runtime::circuit",
+                        "Dropping DLQ entry of Xtx {:?} missing its XExecSignals record",
+                        xtx_id
+                    );
+                    dropped += 1;
+                    return None
+                };
+                translated += 1;
+                <DLQOfRequester<T>>::insert(&xtx.requester, xtx_id, ());
+                Some(DLQEntry {
+                    added_at,
+                    requester: xtx.requester,
+                    targets,
+                    speed_mode,
+                    // Until now the DLQ was only ever entered on timeouts with inactive targets
+                    cause: DLQCause::InactiveTargets,
+                    last_status: xtx.status,
+                    failed_sfx_ids: vec![],
+                })
+            },
+        );
+        if dropped > 0 {
+            log::warn!(
+                target: "runtime::circuit",
+                "Dropped {} DLQ entries of Xtx missing their XExecSignals records",
+                dropped
+            );
+            Self::deposit_event(Event::DLQEntriesDroppedOnMigration(dropped));
+        }
+        // Each entry reads DLQ and XExecSignals, writing DLQ and DLQOfRequester if translated
+        //  or removing it from DLQ if dropped.
+        let dropped = u64::from(dropped);
+        T::DbWeight::get().reads_writes(
+            translated.saturating_add(dropped).saturating_mul(2),
+            translated.saturating_mul(2).saturating_add(dropped),
         )
     }
 
@@ -1945,9 +3372,9 @@ impl<T: Config> Pallet<T> {
     /// The total weight of the operation.
     pub fn process_dlq(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
         <DLQ<T>>::iter()
-            .map(|(xtx_id, (_block_number, targets, _speed_mode))| {
-                if Self::ensure_all_gateways_are_active(targets) {
-                    Self::remove_xtx_from_dlq(xtx_id).0
+            .map(|(xtx_id, entry)| {
+                if Self::ensure_all_gateways_are_active(entry.targets.clone()) {
+                    Self::retry_dlq_entry(xtx_id, entry)
                 } else {
                     T::DbWeight::get().reads(1)
                 }
@@ -1966,11 +3393,20 @@ impl<T: Config> Pallet<T> {
     ///
     /// A tuple containing the weight of the operation and a boolean indicating whether the operation was successful.
     pub fn process_revert_one(xtx_id: XExecSignalId<T>) -> (Weight, bool) {
-        const REVERT_WRITES: u64 = 2;
-        const REVERT_READS: u64 = 1;
         let all_targets = Self::get_all_xtx_targets(xtx_id);
         if !Self::ensure_all_gateways_are_active(all_targets.clone()) {
-            return Self::add_xtx_to_dlq(xtx_id, all_targets, SpeedMode::Finalized)
+            return match <XExecSignals<T>>::get(xtx_id) {
+                Some(xtx) => Self::add_xtx_to_dlq(
+                    xtx_id,
+                    xtx.requester,
+                    all_targets,
+                    SpeedMode::Finalized,
+                    DLQCause::InactiveTargets,
+                    xtx.status,
+                    vec![],
+                ),
+                None => (T::DbWeight::get().reads(1), false),
+            }
         }
 
         let success: bool =
@@ -1980,70 +3416,116 @@ impl<T: Config> Pallet<T> {
             });
 
         (
-            T::DbWeight::get().reads_writes(REVERT_READS, REVERT_WRITES),
+            T::WeightInfo::process_revert_one(MAX_BENCHMARKED_PENDING_XTX),
             success,
         )
     }
 
+    /// Requests attestations of the Xtx outcome for its SFX::Escrow, moving the Xtx to the DLQ if any of the requests fail.
+    ///     Escrow of the steps executed before the current one is committed or reverted along with it.
     pub fn request_sfx_attestation(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
-        Machine::<T>::read_current_step_fsx(local_ctx)
+        let (current_step, _) = local_ctx.xtx.steps_cnt;
+        Self::request_steps_attestation(local_ctx, current_step.saturating_add(1) as usize)
+    }
+
+    /// Requests attestations of the Xtx outcome for SFX::Escrow of the given number of leading steps.
+    fn request_steps_attestation(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>, steps: usize) {
+        let mut failed_sfx_ids: Vec<(TargetId, H256)> = vec![];
+        local_ctx
+            .full_side_effects
             .iter()
+            .take(steps)
+            .flatten()
             .for_each(|fsx| {
                 if fsx.security_lvl == SecurityLvl::Escrow {
                     let sfx_id: H256 = H256::from_slice(
                         fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id)
                             .as_ref(),
                     );
-                    match local_ctx.xtx.status {
-                        CircuitStatus::Reverted(_) =>
-                            match T::Attesters::request_sfx_attestation_revert(
-                                fsx.input.target,
-                                sfx_id,
-                            ) {
-                                Ok(_) => {
-                                    Self::deposit_event(
-                                        Event::SuccessfulFSXRevertAttestationRequest(sfx_id),
-                                    );
-                                },
-                                Err(_) => {
-                                    Self::deposit_event(
-                                        Event::UnsuccessfulFSXRevertAttestationRequest(sfx_id),
-                                    );
-                                },
-                            },
-                        CircuitStatus::FinishedAllSteps | CircuitStatus::Committed =>
-                            match T::Attesters::request_sfx_attestation_commit(
-                                fsx.input.target,
-                                sfx_id,
-                                <Self as CircuitSubmitAPI<T, BalanceOf<T>>>::get_gmp_payload(
-                                    sfx_id,
-                                ),
-                            ) {
-                                Ok(_) => {
-                                    Self::deposit_event(
-                                        Event::SuccessfulFSXCommitAttestationRequest(sfx_id),
-                                    );
-                                },
-                                Err(_) => {
-                                    Self::deposit_event(
-                                        Event::UnsuccessfulFSXCommitAttestationRequest(sfx_id),
-                                    );
-                                },
-                            },
-                        _ => {},
+                    let requested = Self::request_fsx_attestation(
+                        fsx.input.target,
+                        sfx_id,
+                        &local_ctx.xtx.status,
+                    );
+                    Self::deposit_attestation_request_event(
+                        sfx_id,
+                        &local_ctx.xtx.status,
+                        requested.is_ok(),
+                    );
+                    if requested.is_err() {
+                        failed_sfx_ids.push((fsx.input.target, sfx_id));
                     }
                 }
             });
+
+        if !failed_sfx_ids.is_empty() {
+            let mut failed_targets: Vec<TargetId> = failed_sfx_ids
+                .iter()
+                .map(|(target, _sfx_id)| *target)
+                .collect();
+            failed_targets.sort();
+            failed_targets.dedup();
+            Self::add_xtx_to_dlq(
+                local_ctx.xtx_id,
+                local_ctx.xtx.requester.clone(),
+                failed_targets,
+                local_ctx.xtx.speed_mode.clone(),
+                DLQCause::AttestationRequestFailed,
+                local_ctx.xtx.status.clone(),
+                failed_sfx_ids,
+            );
+        }
     }
 
-    pub fn process_tick_one(xtx_id: XExecSignalId<T>) -> Weight {
-        const KILL_WRITES: u64 = 4;
-        const KILL_READS: u64 = 1;
+    /// Requests the attestation of SFX::Escrow commit or revert on its target, following the Xtx status.
+    ///     Attestation already requested with the next batch counts as requested.
+    fn request_fsx_attestation(
+        target: TargetId,
+        sfx_id: H256,
+        xtx_status: &CircuitStatus,
+    ) -> Result<(), DispatchError> {
+        let requested = match xtx_status {
+            CircuitStatus::Reverted(_) =>
+                T::Attesters::request_sfx_attestation_revert(target, sfx_id),
+            CircuitStatus::FinishedAllSteps | CircuitStatus::Committed =>
+                T::Attesters::request_sfx_attestation_commit(
+                    target,
+                    sfx_id,
+                    <Self as CircuitSubmitAPI<T, BalanceOf<T>>>::get_gmp_payload(sfx_id),
+                ),
+            _ => return Ok(()),
+        };
+        match requested {
+            Err(DispatchError::Module(ModuleError {
+                message: Some("SfxAlreadyRequested"),
+                ..
+            }))
+            | Err(DispatchError::Other("SfxAlreadyRequested")) => Ok(()),
+            requested => requested,
+        }
+    }
 
+    fn deposit_attestation_request_event(sfx_id: H256, xtx_status: &CircuitStatus, success: bool) {
+        match (xtx_status, success) {
+            (CircuitStatus::Reverted(_), true) =>
+                Self::deposit_event(Event::SuccessfulFSXRevertAttestationRequest(sfx_id)),
+            (CircuitStatus::Reverted(_), false) =>
+                Self::deposit_event(Event::UnsuccessfulFSXRevertAttestationRequest(sfx_id)),
+            (CircuitStatus::FinishedAllSteps | CircuitStatus::Committed, true) =>
+                Self::deposit_event(Event::SuccessfulFSXCommitAttestationRequest(sfx_id)),
+            (CircuitStatus::FinishedAllSteps | CircuitStatus::Committed, false) =>
+                Self::deposit_event(Event::UnsuccessfulFSXCommitAttestationRequest(sfx_id)),
+            _ => {},
+        }
+    }
+
+    pub fn process_tick_one(xtx_id: XExecSignalId<T>) -> Weight {
         Machine::<T>::compile_infallible(
             &mut Machine::<T>::load_xtx(xtx_id).expect("xtx_id corresponds to a valid Xtx when reading from PendingXtxBidsTimeoutsMap storage"),
             |current_fsx, _local_state, _steps_cnt, status, _requester| {
                 match status {
+                    CircuitStatus::PendingBidding if <Self as Store>::SealedBidAuctions::contains_key(xtx_id) =>
+                        PrecompileResult::TryAwardSealedBids,
                     CircuitStatus::InBidding => match current_fsx.iter().all(|fsx| fsx.best_bid.is_some()) {
                         true => PrecompileResult::ForceUpdateStatus(CircuitStatus::Ready),
                         false => PrecompileResult::TryKill(Cause::Timeout)
@@ -2052,17 +3534,44 @@ impl<T: Config> Pallet<T> {
                 }
 
             },
-            |_status_change, local_ctx| {
+            |status_change, local_ctx| {
                 // Account fees and charges happens internally in Machine::apply
                 Self::emit_status_update(
                     local_ctx.xtx_id,
                     Some(local_ctx.xtx.clone()),
                     None,
                 );
+                if status_change.1 == CircuitStatus::Ready {
+                    Self::dispatch_over_xcm(local_ctx);
+                }
+                // Escrow of the steps executed before the one failing in bidding is reverted on target
+                if let CircuitStatus::Reverted(_) = status_change.1 {
+                    let (current_step, _) = local_ctx.xtx.steps_cnt;
+                    Self::request_steps_attestation(local_ctx, current_step as usize);
+                }
             },
         );
 
-        T::DbWeight::get().reads_writes(KILL_READS, KILL_WRITES)
+        T::WeightInfo::process_tick_one(MAX_BENCHMARKED_PENDING_XTX)
+    }
+
+    /// Send SFX awarded to Circuit to sibling parachains. Failed dispatches are left to the Xtx timeout to revert.
+    fn dispatch_over_xcm(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        match XcmRoute::<T>::dispatch(local_ctx) {
+            Ok(dispatched) =>
+                for (sfx_id, query_id) in dispatched {
+                    Self::deposit_event(Event::SideEffectDispatchedOverXcm(
+                        local_ctx.xtx_id,
+                        sfx_id,
+                        query_id,
+                    ));
+                },
+            Err(e) => log::error!(
+                "dispatch_over_xcm: failed to dispatch SFX of xtx {:?} -- {:?}",
+                local_ctx.xtx_id,
+                e
+            ),
+        }
     }
 
     pub fn process_tick_two(xtx_id: XExecSignalId<T>) -> Weight {
@@ -2088,7 +3597,7 @@ impl<T: Config> Pallet<T> {
             },
         );
 
-        T::DbWeight::get().reads_writes(KILL_READS, KILL_WRITES)
+        T::WeightInfo::process_tick_one(MAX_BENCHMARKED_PENDING_XTX)
     }
 
     // TODO: we also want to save some space for timeouts, split the weight distribution 50-50
@@ -29,7 +29,13 @@ pub fn infallible_no_post_updates<T: Config>(
 pub fn validate_fsx_against_xtx<T: Config>(
     local_ctx: &LocalXtxCtx<T, BalanceOf<T>>,
 ) -> Result<(), Error<T>> {
-    for fsx_step in local_ctx.full_side_effects.iter() {
+    let (current_step, _) = local_ctx.xtx.steps_cnt;
+    // Steps following the current one haven't been opened for bidding yet.
+    for fsx_step in local_ctx
+        .full_side_effects
+        .iter()
+        .take(current_step.saturating_add(1) as usize)
+    {
         for fsx in fsx_step.iter() {
             if local_ctx.xtx.status >= CircuitStatus::Ready {
                 ensure!(
@@ -43,6 +43,7 @@ pub enum PrecompileResult<T: Config> {
         >,
     ),
     TryRequest,
+    TryAwardSealedBids,
     Continue,
     ForceUpdateStatus(CircuitStatus),
     TryKill(Cause),
@@ -63,6 +64,21 @@ impl<T: Config> Machine<T> {
         requester: &T::AccountId,
         maybe_adaptive_timeout: Option<AdaptiveTimeout<BlockNumberFor<T>, TargetId>>,
         preferred_security_lvl: &SecurityLvl,
+    ) -> Result<LocalXtxCtx<T, BalanceOf<T>>, Error<T>> {
+        Self::setup_steps(
+            &[side_effects.to_vec()],
+            requester,
+            maybe_adaptive_timeout,
+            preferred_security_lvl,
+        )
+    }
+
+    // Same as setup, but for SFX ordered in sequential steps - step N+1 opens for bidding after step N is finished.
+    pub fn setup_steps(
+        steps: &[Vec<SideEffect<T::AccountId, BalanceOf<T>>>],
+        requester: &T::AccountId,
+        maybe_adaptive_timeout: Option<AdaptiveTimeout<BlockNumberFor<T>, TargetId>>,
+        preferred_security_lvl: &SecurityLvl,
     ) -> Result<LocalXtxCtx<T, BalanceOf<T>>, Error<T>> {
         // ToDo: Introduce default delay
         let (timeouts_at, delay_steps_at): (
@@ -99,7 +115,7 @@ impl<T: Config> Machine<T> {
             full_side_effects: vec![],
         };
 
-        pallet::Pallet::<T>::validate(side_effects, &mut local_xtx_ctx, preferred_security_lvl)?;
+        pallet::Pallet::<T>::validate(steps, &mut local_xtx_ctx, preferred_security_lvl)?;
 
         Ok(local_xtx_ctx)
     }
@@ -274,6 +290,25 @@ impl<T: Config> Machine<T> {
                     _ => return Err(Error::<T>::BiddingInactive),
                 }
             },
+            PrecompileResult::TryAwardSealedBids => {
+                match status {
+                    CircuitStatus::PendingBidding => {
+                        // Award each SFX to the lowest valid reveal, settling the collateral of the remaining sealed bids.
+                        let awarded_fsx =
+                            Bids::<T>::award_sealed_bids(&mut current_fsx, local_ctx.xtx_id);
+
+                        Self::update_current_step_fsx(local_ctx, &awarded_fsx);
+
+                        if awarded_fsx.iter().all(|fsx| fsx.best_bid.is_some()) {
+                            SquareUp::<T>::bind_bidders(local_ctx);
+                            Some(CircuitStatus::Ready)
+                        } else {
+                            Some(CircuitStatus::Killed(Cause::Timeout))
+                        }
+                    },
+                    _ => return Err(Error::<T>::BiddingInactive),
+                }
+            },
             PrecompileResult::Continue => None,
             // Assume kill attempt with fallible post_update to be intended as infallible cleanup to kill op
             //  in case fallible post_update passes, proceed with kill op
@@ -288,6 +323,13 @@ impl<T: Config> Machine<T> {
             PrecompileResult::Revert(cause) => Some(CircuitStatus::Reverted(cause)),
         };
         let status_change = Self::update_status(local_ctx, enforced_new_status)?;
+        if let (
+            CircuitStatus::Ready | CircuitStatus::PendingExecution,
+            CircuitStatus::PendingBidding,
+        ) = status_change
+        {
+            Self::pipe_step_outputs(local_ctx)?;
+        }
         post_update(status_change.clone(), local_ctx)?;
         Ok(Self::apply(local_ctx, status_change))
     }
@@ -347,6 +389,70 @@ impl<T: Config> Machine<T> {
     }
 
     // Following methods aren't exposed to Pallet - internal use by compile only
+    // Resolve arguments of the freshly opened step from outputs of the confirmed SFX of previous steps.
+    //  Since SFX ids are derived from arguments, the requester charges and SFX links are re-keyed.
+    fn pipe_step_outputs(local_ctx: &mut LocalXtxCtx<T, BalanceOf<T>>) -> Result<(), Error<T>> {
+        let (current_step, _) = local_ctx.xtx.steps_cnt;
+        let placeholders =
+            match <pallet::Pallet<T> as Store>::StepOutputPlaceholders::get(local_ctx.xtx_id)
+                .and_then(|steps| steps.get(current_step as usize).cloned())
+            {
+                Some(placeholders) if !placeholders.is_empty() => placeholders,
+                _ => return Ok(()),
+            };
+
+        let mut current_fsx = Self::read_current_step_fsx(local_ctx).clone();
+        let previous_sfx_ids = current_fsx
+            .iter()
+            .map(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id))
+            .collect::<Vec<SideEffectId<T>>>();
+
+        for placeholder in placeholders.iter() {
+            let output = local_ctx
+                .full_side_effects
+                .get(placeholder.source_step as usize)
+                .and_then(|step| step.get(placeholder.source_sfx_index as usize))
+                .and_then(|source_fsx| source_fsx.confirmed.as_ref())
+                .and_then(|confirmed| confirmed.output.clone())
+                .ok_or(Error::<T>::StepOutputPlaceholderUnresolved)?;
+            let arg = current_fsx
+                .get_mut(placeholder.sfx_index as usize)
+                .and_then(|fsx| {
+                    fsx.input
+                        .encoded_args
+                        .get_mut(placeholder.arg_index as usize)
+                })
+                .ok_or(Error::<T>::InvalidStepOutputPlaceholder)?;
+            *arg = output;
+        }
+
+        for (fsx, previous_sfx_id) in current_fsx.iter().zip(previous_sfx_ids) {
+            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+            if sfx_id == previous_sfx_id {
+                continue
+            }
+            let sfx_abi = <T as Config>::Xdns::get_sfx_abi(&fsx.input.target, fsx.input.action)
+                .ok_or(Error::<T>::ABIOnSelectedTargetNotFoundForSubmittedSFX)?;
+            fsx.input.validate(sfx_abi, &Codec::Scale).map_err(|e| {
+                log::error!("piped sfx.validate against ABI failed: {:?}", e);
+                Error::<T>::SideEffectsValidationFailedAgainstABI
+            })?;
+            <T as Config>::AccountManager::transfer_deposit(
+                previous_sfx_id,
+                sfx_id,
+                None,
+                None,
+                None,
+            )
+            .map_err(|_e| Error::<T>::StepOutputPlaceholderUnresolved)?;
+            <pallet::Pallet<T> as Store>::SFX2XTXLinksMap::remove(previous_sfx_id);
+            <pallet::Pallet<T> as Store>::SFX2XTXLinksMap::insert(sfx_id, local_ctx.xtx_id);
+        }
+
+        Self::update_current_step_fsx(local_ctx, &current_fsx);
+        Ok(())
+    }
+
     fn check_bump_steps(
         local_ctx: &LocalXtxCtx<T, BalanceOf<T>>,
         status_change: (CircuitStatus, CircuitStatus),
@@ -360,7 +466,10 @@ impl<T: Config> Machine<T> {
                 | CircuitStatus::PendingBidding
                 | CircuitStatus::InBidding,
             ) => (0, local_ctx.full_side_effects.len() as u32),
-            (CircuitStatus::Ready | CircuitStatus::PendingExecution, CircuitStatus::Finished) => {
+            (
+                CircuitStatus::Ready | CircuitStatus::PendingExecution,
+                CircuitStatus::Finished | CircuitStatus::PendingBidding,
+            ) => {
                 let (current_step, steps_cnt) = local_ctx.xtx.steps_cnt;
                 (current_step + 1, steps_cnt)
             },
@@ -398,6 +507,13 @@ impl<T: Config> Machine<T> {
         )
         .map_err(|_e| Error::<T>::UpdateStateTransitionDisallowed)?;
 
+        // Past the first step, previous steps are already executed - revert instead of dropping Xtx.
+        if let CircuitStatus::Killed(cause) = new_status {
+            if local_ctx.xtx.steps_cnt.0 > 0 {
+                new_status = CircuitStatus::Reverted(cause);
+            }
+        }
+
         local_ctx.xtx.steps_cnt =
             Self::check_bump_steps(local_ctx, (current_status.clone(), new_status.clone()));
         local_ctx.xtx.status = new_status.clone();
@@ -422,7 +538,7 @@ impl<T: Config> Machine<T> {
             return false
         }
 
-        match (old_status, new_status) {
+        match (old_status, new_status.clone()) {
             (CircuitStatus::Requested, CircuitStatus::Reserved | CircuitStatus::PendingBidding) => {
                 let steps_side_effects_ids: Vec<(
                     usize,
@@ -476,14 +592,11 @@ impl<T: Config> Machine<T> {
                     local_ctx.xtx_id,
                     local_ctx.local_state.clone(),
                 );
-                <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::insert(
+                pallet::Pallet::<T>::schedule_xtx_timeouts(
                     local_ctx.xtx_id,
                     &local_ctx.xtx.timeouts_at,
                 );
-                <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::insert::<
-                    XExecSignalId<T>,
-                    frame_system::pallet_prelude::BlockNumberFor<T>,
-                >(
+                pallet::Pallet::<T>::schedule_bids_timeout(
                     local_ctx.xtx_id,
                     T::SFXBiddingPeriod::get() + frame_system::Pallet::<T>::block_number(),
                 );
@@ -512,7 +625,7 @@ impl<T: Config> Machine<T> {
 
                 true
             },
-            (CircuitStatus::InBidding, CircuitStatus::Ready) => {
+            (CircuitStatus::PendingBidding | CircuitStatus::InBidding, CircuitStatus::Ready) => {
                 <pallet::Pallet<T> as Store>::FullSideEffects::mutate(local_ctx.xtx_id, |x| {
                     *x = Some(local_ctx.full_side_effects.clone())
                 });
@@ -521,6 +634,25 @@ impl<T: Config> Machine<T> {
                 });
                 // Always clean temporary PendingSFXBids and TimeoutsMap after bidding
                 <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::SealedBidAuctions::remove(local_ctx.xtx_id);
+
+                true
+            },
+            // next step opens for bidding
+            (
+                CircuitStatus::Ready | CircuitStatus::PendingExecution,
+                CircuitStatus::PendingBidding,
+            ) => {
+                <pallet::Pallet<T> as Store>::FullSideEffects::mutate(local_ctx.xtx_id, |x| {
+                    *x = Some(local_ctx.full_side_effects.clone())
+                });
+                <pallet::Pallet<T> as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
+                    *x = Some(local_ctx.xtx.clone())
+                });
+                pallet::Pallet::<T>::schedule_bids_timeout(
+                    local_ctx.xtx_id,
+                    T::SFXBiddingPeriod::get() + frame_system::Pallet::<T>::block_number(),
+                );
 
                 true
             },
@@ -529,6 +661,7 @@ impl<T: Config> Machine<T> {
                 CircuitStatus::Killed(_cause),
             ) => {
                 // Clean all associated Xtx entries
+                <pallet::Pallet<T> as Store>::StepOutputPlaceholders::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::XExecSignals::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
@@ -543,23 +676,61 @@ impl<T: Config> Machine<T> {
                 }
                 // Always clean temporary PendingXtxBidsTimeoutsMap after bidding
                 <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::SealedBidAuctions::remove(local_ctx.xtx_id);
 
                 SquareUp::<T>::kill(local_ctx);
 
+                Pallet::<T>::queue_xtx_callback(
+                    local_ctx.xtx_id,
+                    &new_status,
+                    &local_ctx.full_side_effects,
+                );
+
+                true
+            },
+            // bidding of the next step has failed - revert the steps executed so far
+            (
+                CircuitStatus::PendingBidding | CircuitStatus::InBidding,
+                CircuitStatus::Reverted(_cause),
+            ) => {
+                <pallet::Pallet<T> as Store>::StepOutputPlaceholders::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::XExecSignals::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::FullSideEffects::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
+                Pallet::<T>::take_dlq_entry(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::SealedBidAuctions::remove(local_ctx.xtx_id);
+
+                SquareUp::<T>::revert_in_bidding(local_ctx);
+
+                Pallet::<T>::queue_xtx_callback(
+                    local_ctx.xtx_id,
+                    &new_status,
+                    &local_ctx.full_side_effects,
+                );
+
                 true
             },
             (
                 CircuitStatus::Ready | CircuitStatus::PendingExecution | CircuitStatus::Finished,
                 CircuitStatus::Reverted(_cause),
             ) => {
+                <pallet::Pallet<T> as Store>::StepOutputPlaceholders::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::XExecSignals::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::FullSideEffects::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
-                <pallet::Pallet<T> as Store>::DLQ::remove(local_ctx.xtx_id);
+                Pallet::<T>::take_dlq_entry(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
 
                 SquareUp::<T>::finalize(local_ctx);
 
+                Pallet::<T>::queue_xtx_callback(
+                    local_ctx.xtx_id,
+                    &new_status,
+                    &local_ctx.full_side_effects,
+                );
+
                 true
             },
             (CircuitStatus::FinishedAllSteps, CircuitStatus::Committed) => {
@@ -567,6 +738,12 @@ impl<T: Config> Machine<T> {
                 <pallet::Pallet<T> as Store>::FullSideEffects::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
 
+                Pallet::<T>::queue_xtx_callback(
+                    local_ctx.xtx_id,
+                    &new_status,
+                    &local_ctx.full_side_effects,
+                );
+
                 true
             },
             (
@@ -578,7 +755,8 @@ impl<T: Config> Machine<T> {
                 });
 
                 <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
-                <pallet::Pallet<T> as Store>::DLQ::remove(local_ctx.xtx_id);
+                Pallet::<T>::take_dlq_entry(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::StepOutputPlaceholders::remove(local_ctx.xtx_id);
 
                 // Update set of full side effects - only makes sense for Xtx with single SFX.
                 //  for the rest FSX are updated in sequence
@@ -587,10 +765,7 @@ impl<T: Config> Machine<T> {
                     *x = Some(local_ctx.full_side_effects.clone())
                 });
 
-                <pallet::Pallet<T> as Store>::FinalizedXtx::insert(
-                    local_ctx.xtx_id,
-                    <frame_system::Pallet<T>>::block_number(),
-                );
+                pallet::Pallet::<T>::schedule_commit(local_ctx.xtx_id);
 
                 SquareUp::<T>::finalize(local_ctx);
 
@@ -1,12 +1,18 @@
 #[cfg(test)]
 pub mod test {
     use circuit_mock_runtime::{
-        AccountId, Attesters, Balance, Balances, BlockNumber, Circuit, ExtBuilder, Hash, Runtime,
+        AccountId, AccountManager, AssetId, Attesters, Balance, Balances, BlockNumber, Circuit,
+        ExtBuilder, Hash, Runtime,
+    };
+    use circuit_runtime_pallets::pallet_circuit::{
+        machine::{Machine, PrecompileResult},
+        Error, PendingXtxBidsTimeoutsMap, SFX2XTXLinksMap, StepOutputPlaceholders, SystemHashing,
     };
-    use circuit_runtime_pallets::pallet_circuit::machine::{Machine, PrecompileResult};
     use frame_support::{assert_err, assert_ok};
     use sp_runtime::{DispatchError, ModuleError};
-    use t3rn_primitives::circuit::traits::ReadSFX;
+    use t3rn_primitives::{
+        account_manager::AccountManager as AccountManagerInterface, circuit::traits::ReadSFX,
+    };
 
     use crate::{
         machine::test_extra::*,
@@ -14,12 +20,12 @@ pub mod test {
             EXECUTOR_1, EXECUTOR_10, EXECUTOR_2, EXECUTOR_3, EXECUTOR_4, EXECUTOR_5, EXECUTOR_6,
             EXECUTOR_7, EXECUTOR_8, EXECUTOR_9, INITIAL_BALANCE, REQUESTER_1,
         },
-        tests::ESCROW_ACCOUNT,
+        tests::{ALICE, ESCROW_ACCOUNT},
     };
     use hex_literal::hex;
     use sp_core::H512;
     use t3rn_primitives::circuit::{AdaptiveTimeout, Cause, CircuitStatus};
-    use t3rn_types::fsx::SecurityLvl;
+    use t3rn_types::{fsx::SecurityLvl, sfx::ConfirmedSideEffect};
     #[test]
     fn attesters_api_receives_sfx_after_finalized_all_steps_for_escrow_security() {
         ExtBuilder::default()
@@ -902,4 +908,146 @@ pub mod test {
                 assert_eq!(Balances::free_balance(&ESCROW_ACCOUNT), 0);
             });
     }
+
+    #[test]
+    fn machine_opens_next_step_with_piped_output_once_previous_step_is_finished() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                stage_single();
+
+                let piped_amount = vec![2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+                let mut local_ctx = setup_two_step_xtx_with_piped_amount();
+                let placeholder_sfx_id = local_ctx.full_side_effects[1][0]
+                    .calc_sfx_id::<SystemHashing<Runtime>, Runtime>(local_ctx.xtx_id);
+
+                bid_and_confirm_current_step_sfx(&mut local_ctx, Some(piped_amount.clone()));
+
+                assert_eq!(local_ctx.xtx.status, CircuitStatus::PendingBidding);
+                assert_eq!(local_ctx.xtx.steps_cnt, (1, 2));
+                assert_eq!(
+                    local_ctx.full_side_effects[1][0].input.encoded_args[1],
+                    piped_amount
+                );
+
+                // Requester charge and SFX link follow the SFX id re-generated from the piped args
+                let piped_sfx_id = local_ctx.full_side_effects[1][0]
+                    .calc_sfx_id::<SystemHashing<Runtime>, Runtime>(local_ctx.xtx_id);
+                assert!(<AccountManager as AccountManagerInterface<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::get_charge_or_fail(placeholder_sfx_id)
+                .is_err());
+                assert!(<AccountManager as AccountManagerInterface<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::get_charge_or_fail(piped_sfx_id)
+                .is_ok());
+                assert_eq!(SFX2XTXLinksMap::<Runtime>::get(placeholder_sfx_id), None);
+                assert_eq!(
+                    SFX2XTXLinksMap::<Runtime>::get(piped_sfx_id),
+                    Some(local_ctx.xtx_id)
+                );
+                assert!(PendingXtxBidsTimeoutsMap::<Runtime>::get(local_ctx.xtx_id).is_some());
+
+                bid_and_confirm_current_step_sfx(&mut local_ctx, None);
+
+                assert_eq!(local_ctx.xtx.status, CircuitStatus::FinishedAllSteps);
+                assert_eq!(local_ctx.xtx.steps_cnt, (2, 2));
+                assert_eq!(
+                    StepOutputPlaceholders::<Runtime>::get(local_ctx.xtx_id),
+                    None
+                );
+            });
+    }
+
+    #[test]
+    fn machine_fails_to_open_next_step_without_output_to_pipe() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                stage_single();
+
+                let mut local_ctx = setup_two_step_xtx_with_piped_amount();
+                let sfx_id = get_mocked_transfer_sfx_id(local_ctx.xtx_id);
+
+                assert_ok!(Machine::<Runtime>::compile(
+                    &mut local_ctx,
+                    |_, _, _, _, _| Ok(PrecompileResult::TryBid((sfx_id, 2, BOB_RELAYER))),
+                    no_post_updates,
+                ));
+                assert_ok!(Machine::<Runtime>::compile(
+                    &mut local_ctx,
+                    |_, _, _, _, _| Ok(PrecompileResult::ForceUpdateStatus(CircuitStatus::Ready)),
+                    no_post_updates,
+                ));
+
+                assert_err!(
+                    Machine::<Runtime>::compile(
+                        &mut local_ctx,
+                        |_, _, _, _, _| Ok(PrecompileResult::TryConfirm(
+                            sfx_id,
+                            ConfirmedSideEffect {
+                                err: None,
+                                output: None,
+                                inclusion_data: vec![1, 2, 3],
+                                executioner: BOB_RELAYER,
+                                received_at: 2,
+                                cost: None,
+                            }
+                        )),
+                        no_post_updates,
+                    ),
+                    Error::<Runtime>::StepOutputPlaceholderUnresolved
+                );
+            });
+    }
+
+    #[test]
+    fn machine_reverts_multi_step_xtx_if_next_step_bidding_times_out() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                stage_single();
+
+                let mut local_ctx = setup_two_step_xtx_with_piped_amount();
+
+                bid_and_confirm_current_step_sfx(
+                    &mut local_ctx,
+                    Some(vec![2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+                );
+
+                assert_eq!(local_ctx.xtx.status, CircuitStatus::PendingBidding);
+
+                // Kill on bidding timeout turns into revert once the first step was executed
+                assert!(Machine::<Runtime>::kill(
+                    local_ctx.xtx_id,
+                    Cause::Timeout,
+                    infallible_no_post_updates,
+                ));
+
+                check_all_state_revert(local_ctx.xtx_id, vec![], 0);
+                assert_eq!(
+                    StepOutputPlaceholders::<Runtime>::get(local_ctx.xtx_id),
+                    None
+                );
+
+                // check requester is charged the max reward of the executed first step only
+                assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE_10 - 2);
+                // check honest executor of the first step has its balance returned in full
+                assert_eq!(Balances::free_balance(&BOB_RELAYER), INITIAL_BALANCE_10);
+            });
+    }
 }
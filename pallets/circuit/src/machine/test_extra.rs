@@ -14,7 +14,10 @@ use circuit_runtime_pallets::pallet_circuit::{
     state::{CircuitStatus, LocalXtxCtx},
     Config, Error, FullSideEffects, LocalXtxStates, XExecSignal,
 };
-use t3rn_primitives::{circuit::AdaptiveTimeout, SpeedMode};
+use t3rn_primitives::{
+    circuit::{AdaptiveTimeout, StepOutputPlaceholder},
+    SpeedMode,
+};
 
 use t3rn_types::sfx::{ConfirmedSideEffect, FullSideEffect, SecurityLvl};
 
@@ -242,6 +245,90 @@ pub fn setup_single_sfx_xtx_and_confirm() -> H256 {
     local_ctx.xtx_id
 }
 
+pub fn setup_two_step_xtx_with_piped_amount() -> LocalXtxCtx<Runtime, Balance> {
+    let mut local_ctx = Machine::<Runtime>::setup_steps(
+        &[
+            vec![get_mocked_transfer_sfx()],
+            vec![get_mocked_transfer_sfx()],
+        ],
+        &ALICE,
+        None,
+        &SecurityLvl::Optimistic,
+    )
+    .unwrap();
+
+    // Amount transferred at the second step is piped from the output of the first step
+    StepOutputPlaceholders::<Runtime>::insert(
+        local_ctx.xtx_id,
+        vec![
+            vec![],
+            vec![StepOutputPlaceholder {
+                sfx_index: 0,
+                arg_index: 1,
+                source_step: 0,
+                source_sfx_index: 0,
+            }],
+        ],
+    );
+
+    Machine::<Runtime>::compile(
+        &mut local_ctx,
+        |_, _, _, _, _| Ok(PrecompileResult::TryRequest),
+        no_post_updates,
+    )
+    .unwrap();
+
+    assert_eq!(local_ctx.xtx.status, CircuitStatus::PendingBidding);
+    assert_eq!(local_ctx.xtx.steps_cnt, (0, 2));
+
+    local_ctx
+}
+
+pub fn bid_and_confirm_current_step_sfx(
+    local_ctx: &mut LocalXtxCtx<Runtime, Balance>,
+    output: Option<Vec<u8>>,
+) -> H256 {
+    let sfx_id = Machine::<Runtime>::read_current_step_fsx(local_ctx)[0]
+        .calc_sfx_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>, Runtime>(
+        local_ctx.xtx_id,
+    );
+
+    Machine::<Runtime>::compile(
+        local_ctx,
+        |_, _, _, _, _| Ok(PrecompileResult::TryBid((sfx_id, 2, BOB_RELAYER))),
+        no_post_updates,
+    )
+    .unwrap();
+
+    Machine::<Runtime>::compile(
+        local_ctx,
+        |_, _, _, _, _| Ok(PrecompileResult::ForceUpdateStatus(CircuitStatus::Ready)),
+        no_post_updates,
+    )
+    .unwrap();
+
+    assert_eq!(local_ctx.xtx.status, CircuitStatus::Ready);
+
+    Machine::<Runtime>::compile(
+        local_ctx,
+        |_, _, _, _, _| {
+            let confirmation = ConfirmedSideEffect::<AccountId, BlockNumber, Balance> {
+                err: None,
+                output,
+                inclusion_data: vec![1, 2, 3],
+                executioner: BOB_RELAYER,
+                received_at: 2,
+                cost: None,
+            };
+            Ok(PrecompileResult::TryConfirm(sfx_id, confirmation))
+        },
+        no_post_updates,
+    )
+    .unwrap();
+
+    sfx_id
+}
+
 pub fn check_all_state_clean(xtx_id: H256) {
     assert_eq!(XExecSignals::<Runtime>::get(xtx_id), None);
     assert_eq!(PendingXtxTimeoutsMap::<Runtime>::get(xtx_id), None);
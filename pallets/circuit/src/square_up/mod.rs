@@ -61,23 +61,45 @@ impl<T: Config> SquareUp<T> {
     }
 
     /// Fallible lock requester' max rewards for Xtx.
+    /// Max rewards of all steps are locked upfront, since the later steps open for bidding
+    ///     only once the previous ones are finished.
     pub fn try_request(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> DispatchResult {
-        let fsx_array = Machine::<T>::read_current_step_fsx(local_ctx);
+        let fsx_array = local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .collect::<Vec<_>>();
         let requester = local_ctx.xtx.requester.clone();
 
-        if !fsx_array.iter().all(|fsx| {
-            <T as Config>::AccountManager::can_withdraw(
+        // SFX rewards may be funded in different assets - check the requester holds the totals per each asset.
+        let mut max_rewards_per_asset: Vec<(Option<u32>, BalanceOf<T>)> = vec![];
+        for fsx in fsx_array.iter() {
+            if let Some(position) = max_rewards_per_asset
+                .iter()
+                .position(|(asset_id, _)| *asset_id == fsx.input.reward_asset_id)
+            {
+                let (_, total_max_reward) = &mut max_rewards_per_asset[position];
+                *total_max_reward = total_max_reward
+                    .checked_add(&fsx.input.max_reward)
+                    .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
+            } else {
+                max_rewards_per_asset.push((fsx.input.reward_asset_id, fsx.input.max_reward));
+            }
+        }
+
+        for (asset_id, total_max_reward) in max_rewards_per_asset.iter() {
+            if !<T as Config>::AccountManager::can_withdraw(
                 &requester,
-                fsx.input.max_reward,
-                fsx.input.reward_asset_id,
-            )
-        }) {
-            log::error!(
-                "AssetsFailedToWithdraw for asset id {:?} and max reward {:?} ",
-                fsx_array[0].input.reward_asset_id,
-                fsx_array[0].input.max_reward
-            );
-            return Err(Error::<T>::AssetsFailedToWithdraw.into())
+                *total_max_reward,
+                *asset_id,
+            ) {
+                log::error!(
+                    "AssetsFailedToWithdraw for asset id {:?} and total max reward {:?} ",
+                    asset_id,
+                    total_max_reward
+                );
+                return Err(Error::<T>::AssetsFailedToWithdraw.into())
+            }
         }
 
         let request_charges = fsx_array
@@ -177,6 +199,31 @@ impl<T: Config> SquareUp<T> {
         }
     }
 
+    /// Infallible settlement of sealed bids after the auction resolved - release the collateral of revealed bids
+    ///     that lost and slash commitments that were never revealed. The winner's collateral stays deposited.
+    pub fn settle_sealed_bids(
+        sfx_id: T::Hash,
+        sealed_bids: &[SealedSFXBid<T::Hash, T::AccountId, BalanceOf<T>, u32>],
+        winning_bid: Option<&SFXBid<T::AccountId, BalanceOf<T>, u32>>,
+    ) {
+        for sealed_bid in sealed_bids.iter() {
+            if winning_bid.map(|bid| &bid.executor) == Some(&sealed_bid.bid.executor) {
+                continue
+            }
+            let bid_id = sealed_bid.bid.generate_id::<SystemHashing<T>, T>(sfx_id);
+            let settled = match sealed_bid.revealed {
+                true => <T as Config>::AccountManager::cancel_deposit(bid_id),
+                false => <T as Config>::AccountManager::finalize_infallible(bid_id, Outcome::Slash),
+            };
+            if !settled {
+                log::error!(
+                    "settle_sealed_bids: expect settlement to succeed for bid_id: {:?}",
+                    bid_id
+                );
+            }
+        }
+    }
+
     /// Infallible re-balance requesters locked rewards after possibly lower bids are posted.
     pub fn bind_bidders(local_ctx: &mut LocalXtxCtx<T, BalanceOf<T>>) -> bool {
         let mut res: bool = false;
@@ -216,7 +263,7 @@ impl<T: Config> SquareUp<T> {
     /// Drop Xtx and unlock requester and all executors that posted bids - without penalties.
     pub fn kill(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> bool {
         let mut killed = false;
-        for fsx in Machine::<T>::read_current_step_fsx(local_ctx).iter() {
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
             let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
             if !<T as Config>::AccountManager::cancel_deposit(sfx_id) {
                 log::error!(
@@ -236,56 +283,124 @@ impl<T: Config> SquareUp<T> {
                     );
                 }
             }
+            // Sealed bids of unresolved auctions are released without penalties too.
+            for sealed_bid in <pallet::Pallet<T> as Store>::SealedBids::take(sfx_id) {
+                let bid_id = sealed_bid.bid.generate_id::<SystemHashing<T>, T>(sfx_id);
+                if !<T as Config>::AccountManager::cancel_deposit(bid_id) {
+                    log::error!(
+                        "kill: expect cancel_deposit to succeed for sealed bid_id: {:?}",
+                        bid_id
+                    );
+                }
+            }
         }
         killed
     }
 
     /// Finalize Xtx after successful run.
     pub fn finalize(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> bool {
+        let (current_step, _) = local_ctx.xtx.steps_cnt;
+        Self::settle_steps(local_ctx, current_step.saturating_add(1) as usize)
+    }
+
+    /// Revert Xtx with the current step still in bidding - release the bids of the current step
+    ///     without penalties and settle the steps executed so far.
+    pub fn revert_in_bidding(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> bool {
+        for fsx in Machine::<T>::read_current_step_fsx(local_ctx).iter() {
+            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+            if let Some(bid) = &fsx.best_bid {
+                let bid_id = bid.generate_id::<SystemHashing<T>, T>(sfx_id);
+                if !<T as Config>::AccountManager::cancel_deposit(bid_id) {
+                    log::error!(
+                        "revert_in_bidding: expect cancel_deposit to succeed for bid_id: {:?}",
+                        bid_id
+                    );
+                }
+            }
+        }
+        let (current_step, _) = local_ctx.xtx.steps_cnt;
+        Self::settle_steps(local_ctx, current_step as usize)
+    }
+
+    /// Settle bids of the executed steps and requester charges of all steps.
+    ///     Requester is charged for the steps executed and confirmed, and refunded for the rest.
+    ///     Escrow of the executed steps is committed only if all of the steps were executed,
+    ///         otherwise it's reverted along with the revert attestations requested on target.
+    fn settle_steps(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>, executed_steps: usize) -> bool {
         let mut finalized = true;
 
-        let mut step_outcome = Outcome::Commit;
+        // Steps with at least one SFX not confirmed are reverted for requester
+        let mut failed_steps: Vec<bool> = (0..local_ctx.full_side_effects.len())
+            .map(|step| step >= executed_steps)
+            .collect();
 
         // Release all Insurance deposits
-        for fsx in Machine::<T>::read_current_step_fsx(local_ctx).iter() {
-            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
-            match &fsx.best_bid {
-                Some(bid) => {
-                    let outcome = match &fsx.confirmed {
-                        // Revert deposits for honest SFX resolution
-                        Some(_confirmed) => Outcome::Revert,
-                        // Slash dishonest SFX resolution to Escrow Account
-                        None => Outcome::Slash,
-                    };
-                    // If at least one SFX is not confirmed, then the whole XTX is reverted for requester
-                    if outcome == Outcome::Slash {
-                        step_outcome = Outcome::Revert;
-                    }
-                    if !<T as Config>::AccountManager::finalize_infallible(
-                        bid.generate_id::<SystemHashing<T>, T>(sfx_id),
-                        outcome.clone(),
-                    ) {
+        for (step, fsx_step) in local_ctx
+            .full_side_effects
+            .iter()
+            .enumerate()
+            .take(executed_steps)
+        {
+            for fsx in fsx_step.iter() {
+                let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+                // Bonds of instantly confirmed SFX stay locked until the finalized proof or its timeout
+                if <pallet::Pallet<T> as Store>::InstantConfirmations::contains_key(sfx_id) {
+                    continue
+                }
+                match &fsx.best_bid {
+                    Some(bid) => {
+                        let outcome = match &fsx.confirmed {
+                            // Revert deposits for honest SFX resolution
+                            Some(_confirmed) => Outcome::Revert,
+                            // Slash dishonest SFX resolution to Escrow Account
+                            None => Outcome::Slash,
+                        };
+                        if outcome == Outcome::Slash {
+                            failed_steps[step] = true;
+                        }
+                        if !<T as Config>::AccountManager::finalize_infallible(
+                            bid.generate_id::<SystemHashing<T>, T>(sfx_id),
+                            outcome.clone(),
+                        ) {
+                            log::error!(
+                                "squareUp::finalize: expect finalize_infallible to succeed for bid_id: {:?}",
+                                bid.generate_id::<SystemHashing<T>, T>(sfx_id)
+                            );
+                            finalized = false;
+                        }
+                    },
+                    None => {
                         log::error!(
-                            "squareUp::finalize: expect finalize_infallible to succeed for bid_id: {:?}",
-                            bid.generate_id::<SystemHashing<T>, T>(sfx_id)
+                            "squareUp::finalize: disallowed state: reverting without fsx.best_bid assigned {:?}",
+                            sfx_id
                         );
                         finalized = false;
-                    }
-                },
-                None => {
-                    log::error!(
-                        "squareUp::finalize: disallowed state: reverting without fsx.best_bid assigned {:?}",
-                        sfx_id
-                    );
-                    finalized = false;
-                },
+                    },
+                }
             }
         }
-        // Finalize XTX for requester - charge all deposits or return all max_reward deposits back to requester.
-        Machine::<T>::read_current_step_fsx(local_ctx)
-            .iter()
-            .for_each(|fsx| {
+
+        // Escrow is only released to the recipients once the whole Xtx is executed
+        let escrow_outcome = match failed_steps.contains(&true) {
+            true => Outcome::Revert,
+            false => Outcome::Commit,
+        };
+
+        // Finalize XTX for requester - charge deposits of the executed steps and return max_reward deposits of the rest back to requester.
+        for (step, fsx_step) in local_ctx.full_side_effects.iter().enumerate() {
+            let step_outcome = match failed_steps[step] {
+                true => Outcome::Revert,
+                false => Outcome::Commit,
+            };
+            for fsx in fsx_step.iter() {
                 let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+                // Requester's charge of instantly confirmed SFX is held together with the bond
+                //  until the finalized proof commits it or the finality timeout refunds it.
+                if step_outcome == Outcome::Commit
+                    && <pallet::Pallet<T> as Store>::InstantConfirmations::contains_key(sfx_id)
+                {
+                    continue
+                }
                 if !<T as Config>::AccountManager::finalize_infallible(sfx_id, step_outcome.clone())
                 {
                     log::error!(
@@ -295,13 +410,19 @@ impl<T: Config> SquareUp<T> {
                     finalized = false;
                 }
 
-                // Finalize Escrow settlements if associated with SFX
+                // Finalize Escrow settlements if associated with escrowed SFX of executed steps
                 // Standardize escrow_account IDs as re-hash of sfx_id with 3333
-                if fsx.security_lvl == SecurityLvl::Escrow {
+                if step < executed_steps
+                    && fsx.security_lvl == SecurityLvl::Escrow
+                    && fsx.confirmed.is_some()
+                {
                     let escrow_id = fsx
                         .input
                         .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
-                    if !<T as Config>::AccountManager::finalize_infallible(escrow_id, step_outcome.clone()) {
+                    if !<T as Config>::AccountManager::finalize_infallible(
+                        escrow_id,
+                        escrow_outcome.clone(),
+                    ) {
                         log::error!(
                             "squareUp::finalize: expect finalize_infallible to succeed for escrow_id: {:?}",
                             escrow_id
@@ -309,10 +430,40 @@ impl<T: Config> SquareUp<T> {
                         finalized = false;
                     }
                 }
-            });
+            }
+        }
         finalized
     }
 
+    /// Settle SFX confirmed under SpeedMode::Instant once its finalized inclusion proof arrives or the finality timeout passes.
+    /// Finalized: release the bid bond and commit the requester's charge held since the provisional confirmation.
+    /// Timed out: slash the bid bond and refund the requester's charge.
+    pub fn settle_instant_confirmation(
+        sfx_id: SideEffectId<T>,
+        side_effect: &SideEffect<T::AccountId, BalanceOf<T>>,
+        bid_id: T::Hash,
+        finalized: bool,
+    ) -> bool {
+        let (bond_outcome, requester_outcome) = match finalized {
+            true => (Outcome::Revert, Outcome::Commit),
+            false => (Outcome::Slash, Outcome::Revert),
+        };
+        if !<T as Config>::AccountManager::finalize_infallible(bid_id, bond_outcome) {
+            log::error!(
+                "squareUp::settle_instant_confirmation: expect finalize_infallible to succeed for bid_id: {:?}",
+                bid_id
+            );
+            return false
+        }
+        // Charges are no longer pending if the Xtx was reverted for requester by SquareUp::finalize
+        <T as Config>::AccountManager::finalize_infallible(sfx_id, requester_outcome.clone());
+        <T as Config>::AccountManager::finalize_infallible(
+            side_effect.generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333),
+            requester_outcome,
+        );
+        true
+    }
+
     /// Finalize Xtx after successful run - reward Escrow executors.
     pub fn commit(_local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {}
 }
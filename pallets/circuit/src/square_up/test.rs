@@ -6,8 +6,8 @@ pub mod test {
         SFXBid,
     };
     use circuit_mock_runtime::{
-        AccountId, AccountManager, AssetId, Balance, Balances, BlockNumber, ExtBuilder, Hash,
-        Runtime, System,
+        AccountId, AccountManager, AssetId, Assets, Balance, Balances, BlockNumber, ExtBuilder,
+        Hash, Runtime, RuntimeOrigin, System,
     };
     use circuit_runtime_pallets::pallet_circuit::{
         machine::Machine, square_up::SquareUp, state::LocalXtxCtx,
@@ -21,7 +21,7 @@ pub mod test {
         },
         claimable::{BenefitSource, CircuitRole},
     };
-    use t3rn_types::sfx::SecurityLvl;
+    use t3rn_types::sfx::{SecurityLvl, SideEffect};
 
     use crate::tests::ESCROW_ACCOUNT;
     use sp_runtime::DispatchResult;
@@ -209,6 +209,107 @@ pub mod test {
             });
     }
 
+    const USDT: AssetId = 1;
+
+    fn mint_reward_asset(beneficiary: &AccountId, amount: Balance) {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            USDT,
+            sp_runtime::MultiAddress::Id(BOB),
+            true,
+            1
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(BOB),
+            USDT,
+            sp_runtime::MultiAddress::Id(beneficiary.clone()),
+            amount
+        ));
+    }
+
+    fn get_mocked_transfer_sfx_rewarded_in_usdt() -> SideEffect<AccountId, Balance> {
+        SideEffect {
+            reward_asset_id: Some(USDT),
+            ..get_mocked_transfer_sfx()
+        }
+    }
+
+    #[test]
+    fn square_up_locks_up_and_releases_requester_rewards_per_asset_of_multi_asset_xtx() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+
+                let _ = Balances::deposit_creating(&REQUESTER, INITIAL_BALANCE);
+                mint_reward_asset(&REQUESTER, INITIAL_BALANCE);
+
+                let local_ctx = Machine::<Runtime>::setup(
+                    &[
+                        get_mocked_transfer_sfx(),
+                        get_mocked_transfer_sfx_rewarded_in_usdt(),
+                    ],
+                    &REQUESTER,
+                    None,
+                    &SecurityLvl::Optimistic,
+                )
+                .unwrap();
+
+                assert_ok!(SquareUp::<Runtime>::try_request(&local_ctx));
+                assert_eq!(
+                    Balances::free_balance(&REQUESTER),
+                    INITIAL_BALANCE - get_mocked_transfer_sfx().max_reward
+                );
+                assert_eq!(
+                    Assets::balance(USDT, &REQUESTER),
+                    INITIAL_BALANCE - get_mocked_transfer_sfx_rewarded_in_usdt().max_reward
+                );
+
+                assert!(SquareUp::<Runtime>::kill(&local_ctx));
+
+                assert_eq!(Balances::free_balance(&REQUESTER), INITIAL_BALANCE);
+                assert_eq!(Assets::balance(USDT, &REQUESTER), INITIAL_BALANCE);
+            });
+    }
+
+    #[test]
+    fn square_up_fails_lock_up_requester_without_enough_of_one_reward_asset() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+
+                let _ = Balances::deposit_creating(&REQUESTER, INITIAL_BALANCE);
+                // Enough USDT for each of the SFX on its own, but not for both of them
+                mint_reward_asset(&REQUESTER, 3);
+
+                let local_ctx = Machine::<Runtime>::setup(
+                    &[
+                        get_mocked_transfer_sfx(),
+                        get_mocked_transfer_sfx_rewarded_in_usdt(),
+                        get_mocked_transfer_sfx_rewarded_in_usdt(),
+                    ],
+                    &REQUESTER,
+                    None,
+                    &SecurityLvl::Optimistic,
+                )
+                .unwrap();
+
+                assert_err!(
+                    SquareUp::<Runtime>::try_request(&local_ctx),
+                    circuit_runtime_pallets::pallet_circuit::Error::<Runtime>::AssetsFailedToWithdraw
+                );
+
+                // Balances stay unchanged
+                assert_eq!(Balances::free_balance(&REQUESTER), INITIAL_BALANCE);
+                assert_eq!(Assets::balance(USDT, &REQUESTER), 3);
+            });
+    }
+
     #[test]
     fn square_up_locks_up_first_bidder_with_enough_native_currency() {
         ExtBuilder::default()
@@ -45,7 +45,11 @@ use std::fs;
 use t3rn_types::sfx::*;
 
 use t3rn_primitives::{
-    circuit::{AdaptiveTimeout, LocalStateExecutionView, LocalTrigger, OnLocalTrigger},
+    circuit::{
+        AdaptiveTimeout, LocalStateExecutionView, LocalTrigger, OnLocalTrigger, OrderStep,
+        StepOutputPlaceholder, XtxTimeoutKind,
+    },
+    threevm::{ContractVm, XtxCallback, XtxResolution},
     volatile::LocalState,
     Balance,
 };
@@ -683,7 +687,177 @@ fn circuit_handles_dropped_at_bidding() {
         })
 }
 
-const SINGLE_XTX_DEL_WEIGHT: Weight = Weight::from_parts(450000000, 0);
+#[test]
+fn circuit_discards_failing_callback_of_xtx_dropped_at_bidding() {
+    let origin = Origin::signed(ALICE);
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 3);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, _side_effect_a_id) = set_ids(
+                valid_transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            // DJANGO has no contract deployed, so calling it back fails
+            pallet_circuit::XtxCallbacks::<Runtime>::insert(
+                xtx_id,
+                XtxCallback {
+                    contract: DJANGO,
+                    vm: ContractVm::Wasm,
+                },
+            );
+
+            System::reset_events();
+            advance_to_block(4);
+            <Clock as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(4);
+
+            // Callback is queued until the next block
+            assert_eq!(Circuit::get_x_exec_signals(xtx_id), None);
+            assert_eq!(Circuit::get_pending_xtx_callbacks().len(), 1);
+
+            System::set_block_number(5);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(5);
+            let events = System::events();
+
+            assert!(events.iter().any(|record| matches!(
+                record.event,
+                Event::Circuit(pallet_circuit::Event::<Runtime>::XtxCallbackFailed(id, ref contract, _))
+                    if id == xtx_id && contract == &DJANGO
+            )));
+            assert_eq!(Circuit::get_xtx_callback(xtx_id), None);
+            assert!(Circuit::get_pending_xtx_callbacks().is_empty());
+        })
+}
+
+#[test]
+fn circuit_carries_over_xtx_callbacks_exceeding_block_budget() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let first_xtx_id = sp_core::H256::repeat_byte(1);
+            let second_xtx_id = sp_core::H256::repeat_byte(2);
+
+            // DJANGO has no contract deployed, so calling it back fails
+            for xtx_id in [first_xtx_id, second_xtx_id] {
+                pallet_circuit::XtxCallbacks::<Runtime>::insert(
+                    xtx_id,
+                    XtxCallback {
+                        contract: DJANGO,
+                        vm: ContractVm::Wasm,
+                    },
+                );
+                Circuit::push_pending_xtx_callback(XtxResolution {
+                    xtx_id,
+                    status: CircuitStatus::Committed,
+                    outputs: vec![],
+                });
+            }
+
+            // Budget fits a single callback of the maximum weight
+            let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+            let budget = <Runtime as pallet_circuit::Config>::MaxXtxCallbackWeight::get()
+                .saturating_add(db_weight.reads_writes(2, 1));
+
+            let consumed = Circuit::process_xtx_callbacks(budget);
+
+            assert!(consumed.all_lte(budget));
+            assert_eq!(Circuit::get_xtx_callback(first_xtx_id), None);
+            assert!(Circuit::get_xtx_callback(second_xtx_id).is_some());
+            assert_eq!(
+                Circuit::get_pending_xtx_callbacks()
+                    .into_iter()
+                    .map(|resolution| resolution.xtx_id)
+                    .collect::<Vec<_>>(),
+                vec![second_xtx_id]
+            );
+
+            Circuit::process_xtx_callbacks(budget);
+
+            assert_eq!(Circuit::get_xtx_callback(second_xtx_id), None);
+            assert!(Circuit::get_pending_xtx_callbacks().is_empty());
+        })
+}
+
+#[test]
+fn sdk_registers_callback_of_submitting_contract() {
+    let origin = Origin::signed(ALICE);
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 50);
+
+            let res = setup_fresh_state(&origin);
+
+            let callback = XtxCallback {
+                contract: DJANGO,
+                vm: ContractVm::Wasm,
+            };
+            let trigger = LocalTrigger::new(
+                DJANGO,
+                vec![Chain::<_, u128, [u8; 32]>::Polkadot(Operation::Transfer {
+                    caller: ALICE,
+                    to: CHARLIE,
+                    amount: 50,
+                    insurance: None,
+                })
+                .encode()],
+                SpeedMode::Finalized,
+                Some(res.xtx_id),
+            )
+            .with_callback(callback.clone());
+
+            advance_to_block(1);
+            brute_seed_block_1(*b"pdot");
+
+            assert_ok!(
+                <Circuit as OnLocalTrigger<Runtime, BalanceOf>>::on_local_trigger(&origin, trigger)
+            );
+
+            assert_eq!(Circuit::get_xtx_callback(res.xtx_id), Some(callback));
+        });
+}
+
+/// Clock's jobs bookkeeping and the walk over the XtxTimeoutsWheel, next to the benchmarked kill of the single Xtx.
+fn single_xtx_del_weight() -> Weight {
+    use frame_support::weights::constants::RocksDbWeight;
+    use pallet_circuit::weights::WeightInfo;
+
+    RocksDbWeight::get()
+        .reads_writes(17, 6)
+        .saturating_add(<() as WeightInfo>::process_tick_one(
+            pallet_circuit::MAX_BENCHMARKED_PENDING_XTX,
+        ))
+}
 
 #[test]
 fn circuit_updates_weight_after_killing_xtx_in_on_initialize_hook() {
@@ -711,9 +885,102 @@ fn circuit_updates_weight_after_killing_xtx_in_on_initialize_hook() {
             let weight =
                 <Clock as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(1 + 4);
 
-            assert_eq!(weight, SINGLE_XTX_DEL_WEIGHT);
+            assert_eq!(weight, single_xtx_del_weight());
+
+            assert_eq!(Circuit::get_x_exec_signals(xtx_id), None);
+        });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn benchmarked_xtx_queues_process_the_single_due_xtx() {
+    use pallet_circuit::benchmarking::{force_pending_execution, request_pending_xtx};
+
+    // The benchmarks go up to MAX_BENCHMARKED_PENDING_XTX, a fraction of which keeps the test quick
+    const PENDING_XTX: u32 = 100;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let killed_xtx_id = request_pending_xtx::<Runtime>(PENDING_XTX);
+            let n = Circuit::get_pending_xtx_bids_timeouts(killed_xtx_id).unwrap();
+            System::set_block_number(n);
+            Circuit::process_xtx_tick_queue(n, 1, Weight::MAX);
+
+            assert_eq!(Circuit::get_x_exec_signals(killed_xtx_id), None);
+            assert_eq!(
+                pallet_circuit::XExecSignals::<Runtime>::iter().count() as u32,
+                PENDING_XTX - 1
+            );
+        });
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let reverted_xtx_id = request_pending_xtx::<Runtime>(PENDING_XTX);
+            force_pending_execution::<Runtime>(reverted_xtx_id);
+            let n = Circuit::get_active_timing_links(reverted_xtx_id)
+                .unwrap()
+                .emergency_timeout_here;
+            System::set_block_number(n);
+            Circuit::process_emergency_revert_xtx_queue(n, 1, Weight::MAX);
+
+            assert_eq!(Circuit::get_x_exec_signals(reverted_xtx_id), None);
+        });
+}
+
+#[test]
+fn benchmarked_instant_confirmation_settles_bond_and_charge() {
+    use pallet_circuit::benchmarking::schedule_instant_confirmation;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let (sfx_id, instant) = schedule_instant_confirmation::<Runtime>();
+        let bid_id = instant.bid_id;
+        Circuit::finalize_instant_confirmation(sfx_id, instant);
+
+        assert_eq!(Circuit::get_instant_confirmation(sfx_id), None);
+        assert_eq!(AccountManager::pending_charges_per_round(bid_id), None);
+        assert_eq!(AccountManager::pending_charges_per_round(sfx_id), None);
+    });
+}
+
+#[test]
+fn circuit_carries_over_xtx_timeouts_wheel_when_out_of_weight() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id = crate::machine::test_extra::setup_empty_xtx_and_force_set_status(None);
+
+            // Bids timeout is indexed at 1 + SFXBiddingPeriod
+            assert_eq!(
+                Circuit::get_xtx_timeouts_wheel_cursor(XtxTimeoutKind::Bids),
+                Some(4)
+            );
+
+            Circuit::process_xtx_tick_queue(5, 1, Weight::zero());
+
+            assert!(Circuit::get_x_exec_signals(xtx_id).is_some());
+            assert_eq!(
+                Circuit::get_xtx_timeouts_wheel_cursor(XtxTimeoutKind::Bids),
+                Some(4)
+            );
+
+            Circuit::process_xtx_tick_queue(6, 1, Weight::MAX);
 
             assert_eq!(Circuit::get_x_exec_signals(xtx_id), None);
+            assert_eq!(
+                Circuit::get_xtx_timeouts_wheel_cursor(XtxTimeoutKind::Bids),
+                Some(7)
+            );
         });
 }
 
@@ -858,6 +1125,16 @@ fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
                 circuit_error::<Runtime>::BiddingRejectedBetterBidFound,
             );
 
+            // Once executors are selected, bids are only accepted from the active set
+            circuit_runtime_pallets::pallet_executors::ActiveSet::<Runtime>::put(vec![BID_LOOSER]);
+            assert_err!(
+                Circuit::bid_sfx(Origin::signed(BID_WINNER), side_effect_a_id, BID_AMOUNT_A,),
+                circuit_error::<Runtime>::BiddingRejectedExecutorNotActive,
+            );
+            circuit_runtime_pallets::pallet_executors::ActiveSet::<Runtime>::put(vec![
+                BID_WINNER, BID_LOOSER,
+            ]);
+
             // Bob submits the winning bid
             assert_ok!(Circuit::bid_sfx(
                 Origin::signed(BID_WINNER),
@@ -1955,16 +2232,11 @@ fn into_se_from_chain() {
             insurance: 0,
             action: [116, 114, 97, 110],
             encoded_args: vec![
-                vec![
-                    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-                    1, 1, 1, 1, 1, 1
-                ],
                 vec![
                     3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
                     3, 3, 3, 3, 3, 3
                 ],
                 vec![50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-                vec![]
             ],
             signature: vec![],
             enforce_executor: None,
@@ -2467,12 +2739,14 @@ fn test_storage_migration_v130_to_v140_for_fsx_map_with_updated_encoded_action_f
             // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
             let consumed_weight =
                 <Circuit as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
-            let max_weight =
-                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(10, 10);
-            assert_eq!(consumed_weight, max_weight);
+            // Storage migration version and the single FSX entry translated
+            assert_eq!(
+                consumed_weight,
+                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 2)
+            );
 
             // Check that storage version has been updated.
-            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 1);
+            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 3);
 
             // Check if the migrated data is as expected.
             let fsx =
@@ -2492,3 +2766,679 @@ fn test_storage_migration_v130_to_v140_for_fsx_map_with_updated_encoded_action_f
             }
         });
 }
+
+#[test]
+fn failed_attestation_request_moves_xtx_to_dlq_listed_for_requester() {
+    // No attestation targets registered - attestation requests fail
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+            let mut local_ctx =
+                pallet_circuit::machine::Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            local_ctx.xtx.status = CircuitStatus::Reverted(Cause::Timeout);
+            local_ctx.full_side_effects[0][0].security_lvl = SecurityLvl::Escrow;
+
+            Circuit::request_sfx_attestation(&local_ctx);
+
+            let entry = Circuit::get_dlq(xtx_id).expect("Xtx should land in DLQ");
+            assert_eq!(entry.cause, DLQCause::AttestationRequestFailed);
+            assert_eq!(entry.last_status, CircuitStatus::Reverted(Cause::Timeout));
+            assert_eq!(entry.targets, vec![[0u8; 4]]);
+
+            assert_eq!(
+                Circuit::get_dlq_entries_of_requester(&ALICE),
+                vec![(xtx_id, entry)]
+            );
+            assert_eq!(Circuit::get_dlq_entries_of_requester(&BOB), vec![]);
+        });
+}
+
+#[test]
+fn retry_of_failed_attestation_requests_only_the_failed_sfx() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let target = [0u8; 4];
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+            let mut local_ctx =
+                pallet_circuit::machine::Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            local_ctx.xtx.status = CircuitStatus::Reverted(Cause::Timeout);
+            local_ctx.full_side_effects[0][0].security_lvl = SecurityLvl::Escrow;
+
+            // Next batch of the target isn't open - attestation request fails
+            let next_batch =
+                circuit_runtime_pallets::pallet_attesters::NextBatch::<Runtime>::take(target)
+                    .unwrap();
+            Circuit::request_sfx_attestation(&local_ctx);
+
+            let entry = Circuit::get_dlq(xtx_id).expect("Xtx should land in DLQ");
+            assert_eq!(entry.failed_sfx_ids.len(), 1);
+            let (failed_target, sfx_id) = entry.failed_sfx_ids[0];
+            assert_eq!(failed_target, target);
+
+            // Revert of the SFX has been requested with the batch in the meantime
+            circuit_runtime_pallets::pallet_attesters::NextBatch::<Runtime>::insert(
+                target, next_batch,
+            );
+            assert_ok!(
+                <Attesters as t3rn_primitives::attesters::AttestersWriteApi<
+                    AccountId32,
+                    sp_runtime::DispatchError,
+                >>::request_sfx_attestation_revert(target, sfx_id)
+            );
+
+            Circuit::retry_dlq_entry(xtx_id, entry);
+
+            assert!(Circuit::get_dlq(xtx_id).is_none());
+            assert_eq!(Circuit::get_dlq_entries_of_requester(&ALICE), vec![]);
+            assert_eq!(
+                Attesters::next_batches(target).unwrap().reverted_sfx,
+                Some(vec![sfx_id])
+            );
+        });
+}
+
+#[test]
+fn dlq_actions_are_restricted_to_requester_and_root() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+
+            assert_err!(
+                Circuit::retry_dlq(Origin::signed(ALICE), xtx_id),
+                circuit_error::<Runtime>::DLQEntryNotFound
+            );
+
+            Circuit::add_xtx_to_dlq(
+                xtx_id,
+                ALICE,
+                vec![[0u8; 4]],
+                SpeedMode::Finalized,
+                DLQCause::AttestationRequestFailed,
+                CircuitStatus::Ready,
+                vec![],
+            );
+
+            assert_err!(
+                Circuit::retry_dlq(Origin::signed(BOB), xtx_id),
+                circuit_error::<Runtime>::DLQActionUnauthorized
+            );
+            assert_err!(
+                Circuit::extend_dlq_timeout(Origin::signed(BOB), xtx_id, 100),
+                circuit_error::<Runtime>::DLQActionUnauthorized
+            );
+            assert_err!(
+                Circuit::force_revert_dlq(Origin::signed(ALICE), xtx_id),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            // Only Xtx still awaiting their timeout can have it extended
+            assert_err!(
+                Circuit::extend_dlq_timeout(Origin::signed(ALICE), xtx_id, 100),
+                circuit_error::<Runtime>::DLQTimeoutExtensionNotApplicable
+            );
+        });
+}
+
+#[test]
+fn extend_dlq_timeout_reschedules_xtx_with_inactive_targets() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+            Circuit::add_xtx_to_dlq(
+                xtx_id,
+                ALICE,
+                vec![[0u8; 4]],
+                SpeedMode::Finalized,
+                DLQCause::InactiveTargets,
+                CircuitStatus::Ready,
+                vec![],
+            );
+            assert!(!pallet_circuit::PendingXtxTimeoutsMap::<Runtime>::contains_key(xtx_id));
+
+            let expected_timeout =
+                Circuit::get_adaptive_timeout(xtx_id, Some(SpeedMode::Finalized))
+                    .emergency_timeout_here
+                    + 100;
+
+            assert_ok!(Circuit::extend_dlq_timeout(
+                Origin::signed(ALICE),
+                xtx_id,
+                100
+            ));
+
+            assert!(Circuit::get_dlq(xtx_id).is_none());
+            let timeout = pallet_circuit::PendingXtxTimeoutsMap::<Runtime>::get(xtx_id)
+                .expect("Xtx timeout should be re-scheduled");
+            assert_eq!(timeout.emergency_timeout_here, expected_timeout);
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().timeouts_at,
+                timeout
+            );
+        });
+}
+
+#[test]
+fn force_revert_dlq_reverts_stuck_xtx() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+            Circuit::add_xtx_to_dlq(
+                xtx_id,
+                ALICE,
+                vec![[0u8; 4]],
+                SpeedMode::Finalized,
+                DLQCause::InactiveTargets,
+                CircuitStatus::Ready,
+                vec![],
+            );
+
+            assert_ok!(Circuit::force_revert_dlq(Origin::root(), xtx_id));
+
+            assert!(Circuit::get_dlq(xtx_id).is_none());
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::Reverted(Cause::IntentionalKill)
+            );
+            // Already reverted - nothing to force anymore
+            Circuit::add_xtx_to_dlq(
+                xtx_id,
+                ALICE,
+                vec![[0u8; 4]],
+                SpeedMode::Finalized,
+                DLQCause::InactiveTargets,
+                CircuitStatus::Reverted(Cause::IntentionalKill),
+                vec![],
+            );
+            assert_err!(
+                Circuit::force_revert_dlq(Origin::root(), xtx_id),
+                circuit_error::<Runtime>::DLQForceRevertFailed
+            );
+        });
+}
+
+#[test]
+fn runtime_upgrade_migrates_dlq_entries_with_cause_and_last_status() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            crate::machine::test_extra::stage_single();
+            let xtx_id =
+                crate::machine::test_extra::setup_single_sfx_xtx_and_post_bid_and_set_to_ready(
+                    None,
+                );
+            let old_entry: (BlockNumber, Vec<TargetId>, SpeedMode) =
+                (1, vec![[0u8; 4]], SpeedMode::Finalized);
+            frame_support::storage::unhashed::put(
+                &pallet_circuit::DLQ::<Runtime>::hashed_key_for(xtx_id),
+                &old_entry,
+            );
+            pallet_circuit::StorageMigrations::<Runtime>::put(1);
+            let single_deadlines = (pallet_circuit::PendingXtxBidsTimeoutsMap::<Runtime>::iter()
+                .count()
+                + pallet_circuit::FinalizedXtx::<Runtime>::iter().count())
+                as u64;
+            let adaptive_timeouts =
+                pallet_circuit::PendingXtxTimeoutsMap::<Runtime>::iter().count() as u64;
+
+            let consumed_weight =
+                <Circuit as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+            let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+            assert_eq!(
+                consumed_weight,
+                db_weight
+                    .reads_writes(3, 3)
+                    .saturating_add(
+                        db_weight
+                            .reads_writes(3, 2)
+                            .saturating_mul(single_deadlines)
+                    )
+                    .saturating_add(
+                        db_weight
+                            .reads_writes(5, 5)
+                            .saturating_mul(adaptive_timeouts)
+                    )
+            );
+            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 3);
+            assert_eq!(
+                Circuit::get_dlq(xtx_id),
+                Some(DLQEntry {
+                    added_at: 1,
+                    requester: ALICE,
+                    targets: vec![[0u8; 4]],
+                    speed_mode: SpeedMode::Finalized,
+                    cause: DLQCause::InactiveTargets,
+                    last_status: CircuitStatus::Ready,
+                    failed_sfx_ids: vec![],
+                })
+            );
+            assert_eq!(Circuit::get_dlq_entries_of_requester(&ALICE).len(), 1);
+        });
+}
+
+#[test]
+fn runtime_upgrade_drops_dlq_entries_of_missing_xtx_with_event() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let xtx_id = H256::repeat_byte(7);
+            let old_entry: (BlockNumber, Vec<TargetId>, SpeedMode) =
+                (1, vec![[0u8; 4]], SpeedMode::Finalized);
+            frame_support::storage::unhashed::put(
+                &pallet_circuit::DLQ::<Runtime>::hashed_key_for(xtx_id),
+                &old_entry,
+            );
+            pallet_circuit::StorageMigrations::<Runtime>::put(1);
+
+            <Circuit as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 3);
+            assert_eq!(Circuit::get_dlq(xtx_id), None);
+            assert!(System::events().iter().any(|record| record.event
+                == Event::Circuit(
+                    circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::DLQEntriesDroppedOnMigration(1)
+                )));
+        });
+}
+
+#[test]
+fn circuit_rejects_multi_step_order_with_placeholder_not_referring_to_previous_step() {
+    let origin = Origin::signed(ALICE);
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_err!(
+                Circuit::on_multi_step_extrinsic_trigger(
+                    origin,
+                    vec![
+                        OrderStep::new(vec![valid_transfer_side_effect.clone()]),
+                        OrderStep {
+                            side_effects: vec![valid_transfer_side_effect],
+                            placeholders: vec![StepOutputPlaceholder {
+                                sfx_index: 0,
+                                arg_index: 1,
+                                source_step: 1,
+                                source_sfx_index: 0,
+                            }],
+                        },
+                    ],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                ),
+                circuit_error::<Runtime>::InvalidStepOutputPlaceholder
+            );
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            assert_eq!(Circuit::get_x_exec_signals(xtx_id), None);
+        });
+}
+
+fn sign_instant_commit_log(
+    pair: &sp_core::ecdsa::Pair,
+    commit_log: &mut t3rn_abi::evm_ingress_logs::RemoteEVMInstantOrderCommitLog,
+) {
+    use sp_core::Pair;
+
+    let mut commitment = commit_log.sfx_id.as_bytes().to_vec();
+    commitment.extend_from_slice(commit_log.to.as_bytes());
+    commitment.extend_from_slice(&<[u8; 32]>::from(commit_log.amount));
+
+    let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+    message.extend_from_slice(&sp_io::hashing::keccak_256(&commitment));
+
+    commit_log.signature = pair
+        .sign_prehashed(&sp_io::hashing::keccak_256(&message))
+        .as_ref()
+        .to_vec();
+}
+
+#[test]
+fn instant_commit_log_signer_is_recovered_as_executor_account() {
+    use sp_core::Pair;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let pair = sp_core::ecdsa::Pair::from_seed(&[7u8; 32]);
+        let executor = AccountId32::new(sp_io::hashing::blake2_256(pair.public().as_ref()));
+
+        let mut commit_log = t3rn_abi::evm_ingress_logs::RemoteEVMInstantOrderCommitLog {
+            sfx_id: H256::repeat_byte(9),
+            to: sp_core::H160::repeat_byte(1),
+            amount: sp_core::U256::from(100u128),
+            signature: vec![],
+        };
+        sign_instant_commit_log(&pair, &mut commit_log);
+
+        assert_eq!(
+            Circuit::recover_instant_commit_signer(&commit_log),
+            Some(executor.clone())
+        );
+
+        // Signature doesn't commit to the altered amount
+        commit_log.amount = sp_core::U256::from(1000u128);
+        assert_ne!(
+            Circuit::recover_instant_commit_signer(&commit_log),
+            Some(executor)
+        );
+    });
+}
+
+#[test]
+fn circuit_slashes_executor_of_instant_confirmation_not_finalized_in_time() {
+    use t3rn_primitives::{
+        account_manager::{AccountManager as AccountManagerExt, RequestCharge},
+        claimable::{BenefitSource, CircuitRole},
+    };
+
+    const BOND: Balance = 10;
+    const MAX_REWARD: Balance = 5;
+
+    let transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&BOB_RELAYER, BOND + ED);
+        let _ = Balances::deposit_creating(&ALICE, MAX_REWARD + ED);
+        advance_to_block(1);
+
+        let sfx_id = H256::repeat_byte(9);
+        let bid_id = H256::repeat_byte(10);
+        assert_ok!(<AccountManager as AccountManagerExt<
+            AccountId32,
+            Balance,
+            H256,
+            BlockNumber,
+            u32,
+        >>::deposit(
+            bid_id,
+            RequestCharge {
+                payee: BOB_RELAYER,
+                offered_reward: BOND,
+                charge_fee: 0,
+                source: BenefitSource::TrafficRewards,
+                role: CircuitRole::Executor,
+                recipient: None,
+                maybe_asset_id: None,
+            }
+        ));
+        assert_ok!(<AccountManager as AccountManagerExt<
+            AccountId32,
+            Balance,
+            H256,
+            BlockNumber,
+            u32,
+        >>::deposit(
+            sfx_id,
+            RequestCharge {
+                payee: ALICE,
+                offered_reward: MAX_REWARD,
+                charge_fee: 0,
+                source: BenefitSource::TrafficFees,
+                role: CircuitRole::Requester,
+                recipient: None,
+                maybe_asset_id: None,
+            }
+        ));
+
+        Circuit::schedule_instant_confirmation(
+            sfx_id,
+            InstantConfirmation {
+                xtx_id: H256::repeat_byte(1),
+                bid_id,
+                executor: BOB_RELAYER,
+                side_effect: transfer_side_effect,
+                submission_target_height: 0,
+                confirmed_at: 1,
+                finality_timeout_at: 5,
+            },
+        );
+
+        assert_err!(
+            Circuit::confirm_instant_finality(
+                Origin::signed(BOB_RELAYER),
+                H256::repeat_byte(11),
+                ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![],
+                    executioner: BOB_RELAYER,
+                    received_at: 0,
+                    cost: None,
+                },
+            ),
+            circuit_error::<Runtime>::InstantConfirmationNotFound
+        );
+        // Only the executor of the provisional confirmation can finalize it
+        assert_err!(
+            Circuit::confirm_instant_finality(
+                Origin::signed(ALICE),
+                sfx_id,
+                ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![],
+                    executioner: ALICE,
+                    received_at: 0,
+                    cost: None,
+                },
+            ),
+            circuit_error::<Runtime>::InstantConfirmationExecutorMismatch
+        );
+
+        advance_to_block(4);
+        assert!(Circuit::get_instant_confirmation(sfx_id).is_some());
+
+        let escrow_balance_before = Balances::free_balance(&ESCROW_ACCOUNT);
+        advance_to_block(5);
+
+        assert_eq!(Circuit::get_instant_confirmation(sfx_id), None);
+        assert_eq!(Balances::free_balance(&BOB_RELAYER), ED);
+        assert_eq!(
+            Balances::free_balance(&ESCROW_ACCOUNT),
+            escrow_balance_before + BOND
+        );
+        // Requester's charge held since the provisional confirmation is refunded
+        assert_eq!(Balances::free_balance(&ALICE), MAX_REWARD + ED);
+        assert_eq!(AccountManager::pending_charges_per_round(sfx_id), None);
+        assert!(System::events().iter().any(|record| record.event
+            == Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::InstantConfirmationSlashed(
+                sfx_id,
+                BOB_RELAYER,
+            ))));
+    });
+}
+
+fn encode_instant_commit_log_as_rlp(
+    commit_log: &t3rn_abi::evm_ingress_logs::RemoteEVMInstantOrderCommitLog,
+) -> Vec<u8> {
+    // Log of 3 topics and 192 bytes of data: amount, signature offset, signature length and the padded signature
+    let mut log = hex!("f9013c94842c115d11539c5af7f1dc171296f44f20ce7fdcf863").to_vec();
+    log.push(0xa0);
+    log.extend_from_slice(&hex!(
+        "839abeb554b654da2186e0f964e98da21e676b0c3177f2560b4c97671a2f7ea0"
+    ));
+    log.push(0xa0);
+    log.extend_from_slice(commit_log.sfx_id.as_bytes());
+    log.push(0xa0);
+    log.extend_from_slice(&[0u8; 12]);
+    log.extend_from_slice(commit_log.to.as_bytes());
+    log.extend_from_slice(&hex!("b8c0"));
+    log.extend_from_slice(&<[u8; 32]>::from(commit_log.amount));
+    log.extend_from_slice(&<[u8; 32]>::from(sp_core::U256::from(64u8)));
+    log.extend_from_slice(&<[u8; 32]>::from(sp_core::U256::from(65u8)));
+    log.extend_from_slice(&commit_log.signature);
+    log.extend_from_slice(&[0u8; 31]);
+    log
+}
+
+#[test]
+fn circuit_holds_requester_charge_of_instantly_confirmed_sfx_until_finality_timeout() {
+    use sp_core::Pair;
+
+    const AMOUNT: Balance = 100;
+    const INSURANCE: Balance = 1;
+    const MAX_REWARD: Balance = 1;
+    const BID_AMOUNT: Balance = 1;
+    const INITIAL_BALANCE: Balance = 10;
+
+    let pair = sp_core::ecdsa::Pair::from_seed(&[7u8; 32]);
+    let executor = AccountId32::new(sp_io::hashing::blake2_256(pair.public().as_ref()));
+    let to = sp_core::H160::repeat_byte(1);
+
+    let mut to_account_32b = [0u8; 32];
+    to_account_32b[12..].copy_from_slice(to.as_bytes());
+
+    let transfer_asset_side_effect = SideEffect::<AccountId32, Balance> {
+        target: [0, 0, 0, 0],
+        max_reward: MAX_REWARD,
+        action: *b"tass",
+        encoded_args: vec![0u32.encode(), to_account_32b.to_vec(), AMOUNT.encode()],
+        signature: vec![],
+        insurance: INSURANCE,
+        enforce_executor: None,
+        reward_asset_id: None,
+    };
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Target emits RLP encoded logs of EVM
+            circuit_runtime_pallets::pallet_xdns::Gateways::<Runtime>::mutate(
+                [0, 0, 0, 0],
+                |record| {
+                    if let Some(record) = record {
+                        record.codec = t3rn_abi::Codec::Rlp;
+                    }
+                },
+            );
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&executor, INITIAL_BALANCE);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![transfer_asset_side_effect.clone()],
+                SpeedMode::Instant,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, sfx_id) = set_ids(
+                transfer_asset_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            place_winning_bid_and_advance_3_blocks(executor.clone(), xtx_id, sfx_id, BID_AMOUNT);
+
+            let mut commit_log = t3rn_abi::evm_ingress_logs::RemoteEVMInstantOrderCommitLog {
+                sfx_id,
+                to,
+                amount: sp_core::U256::from(AMOUNT),
+                signature: vec![],
+            };
+            sign_instant_commit_log(&pair, &mut commit_log);
+
+            assert_ok!(Circuit::confirm_side_effect(
+                Origin::signed(executor.clone()),
+                sfx_id,
+                ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+                    err: None,
+                    output: None,
+                    inclusion_data: encode_instant_commit_log_as_rlp(&commit_log),
+                    executioner: executor.clone(),
+                    received_at: System::block_number(),
+                    cost: None,
+                },
+            ));
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::FinishedAllSteps
+            );
+            let instant = Circuit::get_instant_confirmation(sfx_id).unwrap();
+            assert_eq!(instant.executor, executor);
+
+            // Requester's charge isn't committed before the finalized inclusion proof
+            assert!(AccountManager::pending_charges_per_round(sfx_id).is_some());
+            let requester_balance_after_confirmation = Balances::free_balance(&ALICE);
+
+            advance_to_block(instant.finality_timeout_at);
+
+            assert_eq!(Circuit::get_instant_confirmation(sfx_id), None);
+            assert_eq!(AccountManager::pending_charges_per_round(sfx_id), None);
+            assert_eq!(
+                Balances::free_balance(&ALICE),
+                requester_balance_after_confirmation + MAX_REWARD
+            );
+            assert!(System::events().iter().any(|record| record.event
+                == Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::InstantConfirmationSlashed(
+                    sfx_id,
+                    executor.clone(),
+                ))));
+        });
+}
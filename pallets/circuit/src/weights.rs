@@ -31,10 +31,17 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn on_local_trigger() -> Weight;
     fn on_extrinsic_trigger() -> Weight;
+    fn on_multi_step_extrinsic_trigger(s: u32, n: u32) -> Weight;
     fn bid_sfx() -> Weight;
     fn cancel_xtx() -> Weight;
     fn confirm_side_effect() -> Weight;
     fn execute_side_effects_with_xbi() -> Weight;
+    fn retry_dlq() -> Weight;
+    fn force_revert_dlq() -> Weight;
+    fn extend_dlq_timeout() -> Weight;
+    fn process_tick_one(p: u32) -> Weight;
+    fn process_revert_one(p: u32) -> Weight;
+    fn confirm_instant_finality() -> Weight;
 }
 
 /// Storage: `XDNS::Gateways` (r:2 w:0)
@@ -78,6 +85,71 @@ fn single_order_weight<T: frame_system::Config>() -> Weight {
         .saturating_add(T::DbWeight::get().writes(9))
 }
 
+/// Storage: `Circuit::DLQ` (r:1 w:1)
+/// Proof: `Circuit::DLQ` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::DLQOfRequester` (r:0 w:1)
+/// Proof: `Circuit::DLQOfRequester` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `XDNS::GatewaysOverviewStore` (r:1 w:0)
+/// Proof: `XDNS::GatewaysOverviewStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::XExecSignals` (r:1 w:1)
+/// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::PendingXtxTimeoutsMap` (r:0 w:1)
+/// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::XtxTimeoutsWheel` (r:0 w:3)
+/// Proof: `Circuit::XtxTimeoutsWheel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::GMP` (r:4 w:0)
+/// Proof: `Circuit::GMP` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Attesters::NextBatch` (r:4 w:4)
+/// Proof: `Attesters::NextBatch` (`max_values`: None, `max_size`: None, mode: `Measured`)
+fn dlq_retry_weight<T: frame_system::Config>() -> Weight {
+    // Estimated from the storage accessed, until regenerated with the `retry_dlq` benchmark.
+    // Re-requesting the attestations of the failed SFX counts the GMP and NextBatch of up to four targets.
+    Weight::from_parts(74_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(11))
+        .saturating_add(T::DbWeight::get().writes(11))
+}
+
+/// Storage: `Circuit::DLQ` (r:1 w:1)
+/// Proof: `Circuit::DLQ` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::DLQOfRequester` (r:0 w:1)
+/// Proof: `Circuit::DLQOfRequester` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::XExecSignals` (r:1 w:1)
+/// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::PendingXtxTimeoutsMap` (r:0 w:1)
+/// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::XtxTimeoutsWheel` (r:0 w:3)
+/// Proof: `Circuit::XtxTimeoutsWheel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+fn dlq_extend_timeout_weight<T: frame_system::Config>() -> Weight {
+    // Estimated from the storage accessed, until regenerated with the `extend_dlq_timeout` benchmark.
+    Weight::from_parts(45_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(2))
+        .saturating_add(T::DbWeight::get().writes(7))
+}
+
+/// Storage: `Circuit::InstantConfirmations` (r:1 w:1)
+/// Proof: `Circuit::InstantConfirmations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `XDNS::SFXABIRegistry` (r:1 w:0)
+/// Proof: `XDNS::SFXABIRegistry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `XDNS::Gateways` (r:1 w:0)
+/// Proof: `XDNS::Gateways` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Circuit::XExecSignals` (r:1 w:0)
+/// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `AccountManager::PendingCharges` (r:3 w:2)
+/// Proof: `AccountManager::PendingCharges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `Clock::CurrentRound` (r:1 w:0)
+/// Proof: `Clock::CurrentRound` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+/// Storage: `AccountManager::SettlementsPerRound` (r:0 w:1)
+/// Proof: `AccountManager::SettlementsPerRound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+/// Storage: `System::Account` (r:1 w:1)
+/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+fn instant_finality_weight<T: frame_system::Config>() -> Weight {
+    // Estimated from the storage accessed, until regenerated with the `finalize_instant_confirmation` benchmark.
+    // Verifying the finalized proof through the Portal adds the two reads of the target's light client.
+    Weight::from_parts(82_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(11))
+        .saturating_add(T::DbWeight::get().writes(5))
+}
+
 /// Weights for pallet_circuit_circuit_portal using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
@@ -89,6 +161,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
         single_order_weight::<T>()
     }
 
+    /// Each of n SFX is validated and linked as the one of a single order, while each of s steps stores its own FSX
+    fn on_multi_step_extrinsic_trigger(s: u32, n: u32) -> Weight {
+        single_order_weight::<T>()
+            .saturating_mul(n.max(1) as u64)
+            .saturating_add(T::DbWeight::get().writes(s as u64))
+    }
+
     fn confirm_side_effect() -> Weight {
         single_order_weight::<T>()
     }
@@ -104,6 +183,83 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn execute_side_effects_with_xbi() -> Weight {
         single_order_weight::<T>()
     }
+
+    fn retry_dlq() -> Weight {
+        dlq_retry_weight::<T>()
+    }
+
+    /// Reverting the Xtx refunds the requester and executors in the same way its cancel does
+    fn force_revert_dlq() -> Weight {
+        single_order_weight::<T>().saturating_add(T::DbWeight::get().reads_writes(1, 2))
+    }
+
+    fn extend_dlq_timeout() -> Weight {
+        dlq_extend_timeout_weight::<T>()
+    }
+
+    /// Storage: `Circuit::XtxTimeoutsWheelCursor` (r:1 w:1)
+    /// Proof: `Circuit::XtxTimeoutsWheelCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XtxTimeoutsWheel` (r:1 w:1)
+    /// Proof: `Circuit::XtxTimeoutsWheel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxBidsTimeoutsMap` (r:1 w:1)
+    /// Proof: `Circuit::PendingXtxBidsTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XExecSignals` (r:1 w:1)
+    /// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::FullSideEffects` (r:1 w:1)
+    /// Proof: `Circuit::FullSideEffects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::LocalXtxStates` (r:1 w:1)
+    /// Proof: `Circuit::LocalXtxStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::SealedBidAuctions` (r:1 w:1)
+    /// Proof: `Circuit::SealedBidAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::StepOutputPlaceholders` (r:0 w:1)
+    /// Proof: `Circuit::StepOutputPlaceholders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxTimeoutsMap` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `AccountManager::PendingCharges` (r:1 w:1)
+    /// Proof: `AccountManager::PendingCharges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// The range of component `p` is `[1, 10000]`.
+    /// Estimated from the storage accessed, until regenerated with the `process_tick_one` benchmark.
+    fn process_tick_one(_p: u32) -> Weight {
+        Weight::from_parts(104_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(10))
+    }
+
+    /// Storage: `Circuit::XtxTimeoutsWheelCursor` (r:1 w:1)
+    /// Proof: `Circuit::XtxTimeoutsWheelCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XtxTimeoutsWheel` (r:1 w:1)
+    /// Proof: `Circuit::XtxTimeoutsWheel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxTimeoutsMap` (r:1 w:1)
+    /// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::GatewaysOverviewStore` (r:1 w:0)
+    /// Proof: `XDNS::GatewaysOverviewStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XExecSignals` (r:1 w:1)
+    /// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::FullSideEffects` (r:1 w:1)
+    /// Proof: `Circuit::FullSideEffects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::LocalXtxStates` (r:1 w:1)
+    /// Proof: `Circuit::LocalXtxStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::DLQ` (r:1 w:1)
+    /// Proof: `Circuit::DLQ` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::StepOutputPlaceholders` (r:0 w:1)
+    /// Proof: `Circuit::StepOutputPlaceholders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxCallbacksRange` (r:1 w:1)
+    /// Proof: `Circuit::PendingXtxCallbacksRange` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxCallbacks` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxCallbacks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `AccountManager::PendingCharges` (r:1 w:1)
+    /// Proof: `AccountManager::PendingCharges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// The range of component `p` is `[1, 10000]`.
+    /// Estimated from the storage accessed, until regenerated with the `process_revert_one` benchmark.
+    fn process_revert_one(_p: u32) -> Weight {
+        Weight::from_parts(121_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(11))
+    }
+
+    fn confirm_instant_finality() -> Weight {
+        instant_finality_weight::<T>()
+    }
 }
 
 // For backwards compatibility and tests
@@ -116,6 +272,10 @@ impl WeightInfo for () {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
 
+    fn on_multi_step_extrinsic_trigger(_s: u32, n: u32) -> Weight {
+        Weight::from_parts(60_000_000_u64, 0u64).saturating_mul(n.max(1) as u64)
+    }
+
     fn confirm_side_effect() -> Weight {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
@@ -131,4 +291,28 @@ impl WeightInfo for () {
     fn execute_side_effects_with_xbi() -> Weight {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
+
+    fn retry_dlq() -> Weight {
+        Weight::from_parts(74_000_000_u64, 0u64)
+    }
+
+    fn force_revert_dlq() -> Weight {
+        Weight::from_parts(60_000_000_u64, 0u64)
+    }
+
+    fn extend_dlq_timeout() -> Weight {
+        Weight::from_parts(45_000_000_u64, 0u64)
+    }
+
+    fn process_tick_one(_p: u32) -> Weight {
+        Weight::from_parts(104_000_000_u64, 0u64)
+    }
+
+    fn process_revert_one(_p: u32) -> Weight {
+        Weight::from_parts(121_000_000_u64, 0u64)
+    }
+
+    fn confirm_instant_finality() -> Weight {
+        Weight::from_parts(82_000_000_u64, 0u64)
+    }
 }
@@ -0,0 +1,379 @@
+use crate::{pallet::Error, square_up::SquareUp, *};
+use frame_support::sp_runtime::traits::{UniqueSaturatedInto, Zero};
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+// Re-exported for runtimes configuring XcmResponseOrigin without depending on xcm directly
+pub use xcm::latest::MultiLocation;
+
+#[cfg(test)]
+pub mod test;
+
+/// Proof size budget for Transact-ed calls, since cgen only carries the ref_time limit
+pub const TRANSACT_PROOF_SIZE: u64 = 64 * 1024;
+
+/// Runtime's XCM facilities used by Circuit to dispatch SFX to sibling parachains.
+pub trait XcmSideEffectsRouter<Call, BlockNumber> {
+    /// Location of the asset as seen from the sibling parachain, if the asset is transferable over XCM.
+    fn asset_location(asset_id: u32, para_id: u32) -> Option<MultiLocation>;
+
+    /// Fee asset Circuit's sovereign account on the sibling parachain buys execution of transfers with, as seen from the sibling.
+    ///     Kept apart from the transferred asset, so that beneficiaries receive the full amount.
+    fn execution_fee(para_id: u32) -> Option<MultiAsset>;
+
+    /// Prepend the program with the error report appendix, registering a query which
+    ///     dispatches the notify call with the (query_id, response) once the responder reports back
+    ///     on behalf of the querier, relative to the local chain.
+    fn report_outcome_notify(
+        message: &mut Xcm<()>,
+        responder: MultiLocation,
+        notify: Call,
+        timeout: BlockNumber,
+        querier: MultiLocation,
+    ) -> Result<QueryId, XcmError>;
+
+    fn send(dest: MultiLocation, message: Xcm<()>) -> Result<XcmHash, SendError>;
+}
+
+impl<Call, BlockNumber> XcmSideEffectsRouter<Call, BlockNumber> for () {
+    fn asset_location(_asset_id: u32, _para_id: u32) -> Option<MultiLocation> {
+        None
+    }
+
+    fn execution_fee(_para_id: u32) -> Option<MultiAsset> {
+        None
+    }
+
+    fn report_outcome_notify(
+        _message: &mut Xcm<()>,
+        _responder: MultiLocation,
+        _notify: Call,
+        _timeout: BlockNumber,
+        _querier: MultiLocation,
+    ) -> Result<QueryId, XcmError> {
+        Err(XcmError::Unimplemented)
+    }
+
+    fn send(_dest: MultiLocation, _message: Xcm<()>) -> Result<XcmHash, SendError> {
+        Err(SendError::NotApplicable)
+    }
+}
+
+// A) SFX::Escrow on targets linked to a sibling parachain in XDNS are bid by Circuit itself @CircuitStatus::Requested
+//
+// B) bids of external executors may still outbid Circuit and take over the execution @CircuitStatus::InBidding
+//
+// C) SFX still awarded to Circuit are sent as XCM programs reporting back their outcome @CircuitStatus::Ready
+//
+// D) QueryResponse confirms the SFX on success or reverts the Xtx on error via on_xcm_trigger
+pub struct XcmRoute<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> XcmRoute<T> {
+    /// Sibling parachain executing the FSX over XCM, if the FSX is eligible for the XCM route.
+    pub fn sibling_para_id(
+        fsx: &FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+    ) -> Option<u32> {
+        if fsx.security_lvl != SecurityLvl::Escrow {
+            return None
+        }
+        match &fsx.input.action {
+            b"tran" | b"tass" | b"cgen" => T::Xdns::get_sibling_para_id(&fsx.input.target),
+            _ => None,
+        }
+    }
+
+    /// Check if the FSX is awarded to Circuit and therefore executed over XCM.
+    pub fn is_routed(fsx: &FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>) -> bool {
+        Self::sibling_para_id(fsx).is_some()
+            && fsx
+                .best_bid
+                .as_ref()
+                .map(|bid| bid.executor == T::SelfAccountId::get())
+                .unwrap_or(false)
+    }
+
+    /// Place Circuit's bids at max reward for all FSX eligible for the XCM route, depositing the same
+    ///     Escrow collateral as executors do, who can still outbid Circuit. FSX that Circuit can't collateralize
+    ///     are left to executors. Returns None if no FSX of the step was bid.
+    pub fn assign_circuit_bids(
+        step_fsx: &[FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>],
+        requester: &T::AccountId,
+        xtx_id: XExecSignalId<T>,
+    ) -> Option<Vec<FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>> {
+        let mut assigned = false;
+        let mut updated_fsx = step_fsx.to_vec();
+
+        for fsx in updated_fsx
+            .iter_mut()
+            .filter(|fsx| fsx.best_bid.is_none() && Self::sibling_para_id(fsx).is_some())
+        {
+            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id);
+            // Escrow bids are insured with the finality fee in native currency, same as executors' bids
+            let bid = SFXBid::new_none_optimistic(
+                fsx.input.max_reward,
+                T::Attesters::estimate_finality_fee(&fsx.input.target),
+                T::SelfAccountId::get(),
+                requester.clone(),
+                None,
+            );
+            if let Err(e) =
+                SquareUp::<T>::try_bid(sfx_id, requester, &T::SelfAccountId::get(), &bid, None)
+            {
+                log::warn!(
+                    "XcmRoute::assign_circuit_bids failed to deposit Circuit's bond, leaving SFX {:?} to executors -- {:?}",
+                    sfx_id,
+                    e
+                );
+                continue
+            }
+            fsx.best_bid = Some(bid);
+            assigned = true;
+        }
+
+        if assigned {
+            Some(updated_fsx)
+        } else {
+            None
+        }
+    }
+
+    /// Convert FSX into the XCM program executed on the sibling parachain:
+    ///     * "tran" - withdraw the sibling's native asset held by Circuit's sovereign account and deposit it to the beneficiary,
+    ///        paid out of the requester's funds escrowed on Circuit. Execution is bought with the separate fee asset of the sovereign account,
+    ///        covered by the reward of Circuit's bid, with the surplus refunded back to the sovereign account,
+    ///     * "tass" - reserve-backed transfer of the asset held by the sovereign account, escrowed and paid for alike,
+    ///     * "cgen" - Transact the input as the requester's account derived on the sibling, buying execution with the value
+    ///        out of that account. Descending into the requester's origin is prepended by dispatch.
+    pub fn to_xcm_program(
+        fsx: &FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        para_id: u32,
+        requester: &T::AccountId,
+    ) -> Result<Xcm<()>, Error<T>> {
+        match &fsx.input.action {
+            b"tran" | b"tass" => {
+                let (maybe_asset, beneficiary, amount) = Pallet::<T>::recover_escrow_arguments(fsx)
+                    .map_err(|e| {
+                        log::error!("XcmRoute::recover_escrow_arguments hit an error -- {:?}", e);
+                        Error::<T>::FailedToConvertSFX2XBI
+                    })?;
+                let asset_location = match maybe_asset {
+                    None => MultiLocation::here(),
+                    Some(asset_id) => T::XcmRouter::asset_location(asset_id, para_id)
+                        .ok_or(Error::<T>::FailedToConvertSFX2XBI)?,
+                };
+                let asset: MultiAsset = (
+                    asset_location,
+                    UniqueSaturatedInto::<u128>::unique_saturated_into(amount),
+                )
+                    .into();
+                let fees = T::XcmRouter::execution_fee(para_id)
+                    .ok_or(Error::<T>::FailedToConvertSFX2XBI)?;
+
+                Ok(Xcm(vec![
+                    WithdrawAsset(vec![asset.clone(), fees.clone()].into()),
+                    BuyExecution {
+                        fees,
+                        weight_limit: Unlimited,
+                    },
+                    DepositAsset {
+                        assets: Definite(asset.into()),
+                        beneficiary: Self::account_location(&beneficiary)?,
+                    },
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: Wild(AllCounted(2)),
+                        beneficiary: MultiLocation::new(1, X1(Parachain(T::SelfParaId::get()))),
+                    },
+                ]))
+            },
+            b"cgen" => {
+                let value = fsx
+                    .input
+                    .encoded_args
+                    .get(1)
+                    .and_then(|value| u128::decode(&mut &value[..]).ok())
+                    .ok_or(Error::<T>::FailedToConvertSFX2XBI)?;
+                let call = fsx
+                    .input
+                    .encoded_args
+                    .get(2)
+                    .ok_or(Error::<T>::FailedToConvertSFX2XBI)?;
+                let limit = fsx
+                    .input
+                    .encoded_args
+                    .get(3)
+                    .and_then(|limit| u128::decode(&mut &limit[..]).ok())
+                    .ok_or(Error::<T>::FailedToConvertSFX2XBI)?;
+                let fees: MultiAsset = (MultiLocation::here(), value).into();
+
+                Ok(Xcm(vec![
+                    WithdrawAsset(fees.clone().into()),
+                    BuyExecution {
+                        fees,
+                        weight_limit: Unlimited,
+                    },
+                    Transact {
+                        origin_kind: OriginKind::SovereignAccount,
+                        require_weight_at_most: Weight::from_parts(
+                            limit.unique_saturated_into(),
+                            TRANSACT_PROOF_SIZE,
+                        ),
+                        call: call.clone().into(),
+                    },
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: Wild(AllCounted(1)),
+                        beneficiary: MultiLocation::new(
+                            1,
+                            X2(
+                                Parachain(T::SelfParaId::get()),
+                                Self::account_junction(requester)?,
+                            ),
+                        ),
+                    },
+                ]))
+            },
+            _ => Err(Error::<T>::FailedToConvertSFX2XBI),
+        }
+    }
+
+    /// Send all FSX of the current step awarded to Circuit over XCM, correlating each with the query
+    ///     expecting the outcome reported back to on_xcm_trigger by the sibling. Transfers are escrowed
+    ///     out of the requester's funds before sending, settled with the rest of the Xtx.
+    pub fn dispatch(
+        local_ctx: &LocalXtxCtx<T, BalanceOf<T>>,
+    ) -> Result<Vec<(SideEffectId<T>, QueryId)>, Error<T>> {
+        let timeout =
+            <frame_system::Pallet<T>>::block_number() + <T as Config>::XtxTimeoutDefault::get();
+        let mut dispatched = vec![];
+
+        for fsx in Machine::<T>::read_current_step_fsx(local_ctx)
+            .iter()
+            .filter(|fsx| fsx.confirmed.is_none() && Self::is_routed(fsx))
+        {
+            let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+            let para_id = Self::sibling_para_id(fsx).ok_or(Error::<T>::FailedToConvertSFX2XBI)?;
+            let sibling = MultiLocation::new(1, X1(Parachain(para_id)));
+
+            let requester = &local_ctx.xtx.requester;
+
+            let mut message = Self::to_xcm_program(fsx, para_id, requester)?;
+            // Transacts are executed as the requester's account on the sibling, which also reports back their outcome
+            let descend_to_requester = fsx.input.action == *b"cgen";
+            let querier = match descend_to_requester {
+                true => Self::account_location(requester)?,
+                false => MultiLocation::here(),
+            };
+
+            let escrow_id = match descend_to_requester {
+                true => None,
+                false => Some(Self::escrow_requester_funds(fsx, sfx_id, requester)?),
+            };
+            let cancel_escrow = || {
+                if let Some(escrow_id) = escrow_id {
+                    T::AccountManager::cancel_deposit(escrow_id);
+                }
+            };
+
+            let query_id = T::XcmRouter::report_outcome_notify(
+                &mut message,
+                sibling,
+                Call::<T>::on_xcm_trigger {
+                    query_id: 0,
+                    response: Response::Null,
+                },
+                timeout,
+                querier,
+            )
+            .map_err(|e| {
+                log::error!("XcmRoute::report_outcome_notify hit an error -- {:?}", e);
+                cancel_escrow();
+                Error::<T>::FailedToCheckInOverXBI
+            })?;
+            if descend_to_requester {
+                message
+                    .0
+                    .insert(0, DescendOrigin(X1(Self::account_junction(requester)?)));
+            }
+
+            T::XcmRouter::send(sibling, message).map_err(|e| {
+                log::error!("XcmRoute::send hit an error -- {:?}", e);
+                cancel_escrow();
+                Error::<T>::FailedToEnterXBIPortal
+            })?;
+
+            <Pallet<T> as Store>::XcmQueries::insert(query_id, (local_ctx.xtx_id, sfx_id, sibling));
+            dispatched.push((sfx_id, query_id));
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Escrow the transferred amount out of the requester's funds, following the escrow IDs convention of executors' escrow.
+    ///     Commit of the Xtx pays it out to Circuit, whose sovereign account on the sibling funded the transfer,
+    ///     while revert returns it to the requester.
+    fn escrow_requester_funds(
+        fsx: &FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        sfx_id: SideEffectId<T>,
+        requester: &T::AccountId,
+    ) -> Result<T::Hash, Error<T>> {
+        let (escrow_asset, _beneficiary, escrow_amount) =
+            Pallet::<T>::recover_escrow_arguments(fsx).map_err(|e| {
+                log::error!("XcmRoute::recover_escrow_arguments hit an error -- {:?}", e);
+                Error::<T>::FailedToConvertSFX2XBI
+            })?;
+        // Standardize escrow_account IDs as re-hash of sfx_id with 3333
+        let escrow_id = fsx
+            .input
+            .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
+
+        T::AccountManager::deposit(
+            escrow_id,
+            RequestCharge {
+                payee: requester.clone(),
+                offered_reward: escrow_amount,
+                charge_fee: Zero::zero(),
+                source: BenefitSource::EscrowUnlock,
+                role: CircuitRole::Requester,
+                recipient: Some(T::SelfAccountId::get()),
+                maybe_asset_id: escrow_asset,
+            },
+        )
+        .map_err(|e| {
+            log::error!("XcmRoute::escrow_requester_funds hit an error -- {:?}", e);
+            Error::<T>::XtxChargeFailedRequesterBalanceTooLow
+        })?;
+
+        Ok(escrow_id)
+    }
+
+    /// Release the requester's escrow of transfer, which the sibling reported as failed.
+    pub fn release_escrow(
+        fsx: &FullSideEffect<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        sfx_id: SideEffectId<T>,
+    ) {
+        if fsx.input.action == *b"cgen" {
+            return
+        }
+        let escrow_id = fsx
+            .input
+            .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
+        if !T::AccountManager::finalize_infallible(escrow_id, Outcome::Revert) {
+            log::error!(
+                "XcmRoute::release_escrow: expect finalize_infallible to succeed for escrow_id: {:?}",
+                escrow_id
+            );
+        }
+    }
+
+    fn account_junction(account: &T::AccountId) -> Result<Junction, Error<T>> {
+        let id = <[u8; 32]>::decode(&mut &account.encode()[..])
+            .map_err(|_| Error::<T>::FailedToCreateXBIMetadataDueToWrongAccountConversion)?;
+        Ok(Junction::AccountId32 { network: None, id })
+    }
+
+    fn account_location(account: &T::AccountId) -> Result<MultiLocation, Error<T>> {
+        Ok(MultiLocation::new(0, X1(Self::account_junction(account)?)))
+    }
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+pub mod test {
+    use crate::tests::{ALICE, BOB, ESCROW_ACCOUNT};
+    use circuit_mock_runtime::{
+        test_utils::{generate_xtx_id, Hashing},
+        xcm_mock_net::{
+            sibling, sibling_account_id, CircuitPara, MockNet, SiblingPara, TestExt,
+            CIRCUIT_PARA_ID, INITIAL_BALANCE, SIBLING_PARA_ID,
+        },
+        AccountId, Balance, Balances, BlockNumber, Circuit, Clock, Hash, Runtime, RuntimeEvent,
+        RuntimeOrigin, SiblingExecutionFee, System, XDNS,
+    };
+    use circuit_runtime_pallets::{
+        pallet_circuit::{machine::Machine, xcm_route::XcmRoute, Error, SystemHashing, XcmQueries},
+        pallet_xcm,
+    };
+    use codec::Encode;
+    use frame_support::{assert_noop, assert_ok, traits::Currency};
+    use t3rn_primitives::{circuit::types::CircuitStatus, SpeedMode};
+    use t3rn_types::sfx::{SecurityLvl, SideEffect};
+    use xcm::latest::prelude::*;
+
+    const SIBLING_TARGET: [u8; 4] = [3, 3, 3, 3];
+    const TRANSFER_AMOUNT: Balance = 100;
+    const UNKNOWN_QUERY_ID: QueryId = 1_000;
+
+    fn advance_to_block(block: BlockNumber) {
+        System::set_block_number(block);
+        <Clock as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(block);
+    }
+
+    fn escrow_transfer_sfx(amount: Balance) -> SideEffect<AccountId, Balance> {
+        SideEffect {
+            target: SIBLING_TARGET,
+            max_reward: 10,
+            action: *b"tran",
+            encoded_args: vec![BOB.encode(), amount.encode()],
+            signature: vec![],
+            insurance: 1,
+            enforce_executor: None,
+            reward_asset_id: None,
+        }
+    }
+
+    /// Request the transfer on the target linked to the sibling parachain & advance past the bidding period.
+    ///     Returns the requester's balance before the transfer got escrowed and dispatched.
+    fn request_escrow_transfer_over_xcm(amount: Balance) -> (Hash, Balance) {
+        let xtx_id: Hash = generate_xtx_id::<Hashing>(ALICE, 0);
+
+        let requester_balance = CircuitPara::execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE * 4);
+            // Circuit collateralizes its bids like any other executor
+            let _ = Balances::deposit_creating(&ESCROW_ACCOUNT, INITIAL_BALANCE);
+            advance_to_block(1);
+
+            assert_ok!(XDNS::link_sibling_parachain(
+                RuntimeOrigin::root(),
+                SIBLING_TARGET,
+                Some(SIBLING_PARA_ID),
+            ));
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                RuntimeOrigin::signed(ALICE),
+                vec![escrow_transfer_sfx(amount)],
+                SpeedMode::Finalized,
+                SecurityLvl::Escrow,
+            ));
+
+            let local_ctx = Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            let fsx = &local_ctx.full_side_effects[0][0];
+            assert_eq!(fsx.best_bid.as_ref().unwrap().executor, ESCROW_ACCOUNT);
+            assert!(XcmRoute::<Runtime>::is_routed(fsx));
+            assert_eq!(local_ctx.xtx.status, CircuitStatus::InBidding);
+
+            let requester_balance = Balances::free_balance(&ALICE);
+
+            // Bidding period elapses with Circuit's bid as the best one - Ready & dispatched over XCM
+            advance_to_block(4);
+
+            assert!(System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::Circuit(
+                    circuit_runtime_pallets::pallet_circuit::Event::SideEffectDispatchedOverXcm(
+                        id,
+                        _,
+                        _
+                    )
+                ) if id == xtx_id
+            )));
+
+            requester_balance
+        });
+
+        (xtx_id, requester_balance)
+    }
+
+    #[test]
+    fn escrow_transfer_is_executed_on_sibling_and_confirmed_by_query_response() {
+        MockNet::reset();
+
+        let (xtx_id, requester_balance) = request_escrow_transfer_over_xcm(TRANSFER_AMOUNT);
+
+        SiblingPara::execute_with(|| {
+            assert_eq!(sibling::Balances::free_balance(&BOB), TRANSFER_AMOUNT,);
+            assert_eq!(
+                sibling::Balances::free_balance(&sibling_account_id(CIRCUIT_PARA_ID)),
+                INITIAL_BALANCE - TRANSFER_AMOUNT,
+            );
+        });
+
+        CircuitPara::execute_with(|| {
+            let local_ctx = Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            let confirmed = local_ctx.full_side_effects[0][0]
+                .confirmed
+                .as_ref()
+                .expect("SFX should be confirmed by the query response");
+            assert_eq!(confirmed.executioner, ESCROW_ACCOUNT);
+            assert_eq!(local_ctx.xtx.status, CircuitStatus::FinishedAllSteps);
+            assert_eq!(XcmQueries::<Runtime>::iter().count(), 0);
+            // Transfer paid out by Circuit's sovereign account is escrowed out of requester's funds
+            assert_eq!(
+                Balances::free_balance(&ALICE),
+                requester_balance - TRANSFER_AMOUNT
+            );
+        });
+    }
+
+    #[test]
+    fn escrow_transfer_failing_on_sibling_reverts_xtx() {
+        MockNet::reset();
+
+        // Exceeds the balance of Circuit's sovereign account on the sibling
+        let (xtx_id, requester_balance) = request_escrow_transfer_over_xcm(INITIAL_BALANCE * 2);
+
+        SiblingPara::execute_with(|| {
+            assert_eq!(sibling::Balances::free_balance(&BOB), 0);
+        });
+
+        CircuitPara::execute_with(|| {
+            let local_ctx = Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            assert!(local_ctx.full_side_effects[0][0].confirmed.is_none());
+            assert!(matches!(local_ctx.xtx.status, CircuitStatus::Reverted(_)));
+            // Escrow of the failed transfer is returned to requester
+            assert_eq!(Balances::free_balance(&ALICE), requester_balance);
+            assert!(System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::Circuit(
+                    circuit_runtime_pallets::pallet_circuit::Event::SideEffectFailedOverXcm(
+                        id,
+                        _,
+                        _
+                    )
+                ) if id == xtx_id
+            )));
+        });
+    }
+
+    #[test]
+    fn escrow_on_targets_not_linked_to_sibling_is_not_bid_by_circuit() {
+        MockNet::reset();
+
+        CircuitPara::execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            advance_to_block(1);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                RuntimeOrigin::signed(ALICE),
+                vec![escrow_transfer_sfx(TRANSFER_AMOUNT)],
+                SpeedMode::Finalized,
+                SecurityLvl::Escrow,
+            ));
+
+            let xtx_id: Hash = generate_xtx_id::<Hashing>(ALICE, 0);
+            let local_ctx = Machine::<Runtime>::load_xtx(xtx_id).unwrap();
+            assert!(local_ctx.full_side_effects[0][0].best_bid.is_none());
+            assert_eq!(local_ctx.xtx.status, CircuitStatus::PendingBidding);
+        });
+    }
+
+    #[test]
+    fn transfer_converts_to_withdraw_buy_execution_and_deposit_program() {
+        MockNet::reset();
+
+        CircuitPara::execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            advance_to_block(1);
+
+            let local_ctx = Machine::<Runtime>::setup(
+                &[escrow_transfer_sfx(TRANSFER_AMOUNT)],
+                &ALICE,
+                None,
+                &SecurityLvl::Escrow,
+            )
+            .unwrap();
+            let fsx = &local_ctx.full_side_effects[0][0];
+            let asset: MultiAsset = (MultiLocation::here(), TRANSFER_AMOUNT).into();
+            let fees: MultiAsset = (MultiLocation::here(), SiblingExecutionFee::get()).into();
+
+            // Execution is bought with the fee asset, while the beneficiary receives the full transferred amount
+            assert_eq!(
+                XcmRoute::<Runtime>::to_xcm_program(fsx, SIBLING_PARA_ID, &ALICE),
+                Ok(Xcm(vec![
+                    WithdrawAsset(vec![asset.clone(), fees.clone()].into()),
+                    BuyExecution {
+                        fees,
+                        weight_limit: Unlimited,
+                    },
+                    DepositAsset {
+                        assets: Definite(asset.into()),
+                        beneficiary: MultiLocation::new(
+                            0,
+                            X1(Junction::AccountId32 {
+                                network: None,
+                                id: BOB.into(),
+                            })
+                        ),
+                    },
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: Wild(AllCounted(2)),
+                        beneficiary: MultiLocation::new(1, X1(Parachain(CIRCUIT_PARA_ID))),
+                    },
+                ]))
+            );
+            // Not linked yet - SFX stays with the executors
+            assert_eq!(XcmRoute::<Runtime>::sibling_para_id(fsx), None);
+        });
+    }
+
+    #[test]
+    fn query_response_from_other_than_dispatched_sibling_is_rejected() {
+        MockNet::reset();
+
+        let (xtx_id, _) = request_escrow_transfer_over_xcm(INITIAL_BALANCE * 2);
+
+        CircuitPara::execute_with(|| {
+            let sfx_id = Machine::<Runtime>::load_xtx(xtx_id)
+                .unwrap()
+                .full_side_effects[0][0]
+                .calc_sfx_id::<SystemHashing<Runtime>, Runtime>(xtx_id);
+            let dispatched_to = MultiLocation::new(1, X1(Parachain(SIBLING_PARA_ID)));
+            XcmQueries::<Runtime>::insert(UNKNOWN_QUERY_ID, (xtx_id, sfx_id, dispatched_to));
+
+            assert_noop!(
+                Circuit::on_xcm_trigger(
+                    pallet_xcm::Origin::Response(MultiLocation::new(
+                        1,
+                        X1(Parachain(SIBLING_PARA_ID + 1))
+                    ))
+                    .into(),
+                    UNKNOWN_QUERY_ID,
+                    Response::ExecutionResult(None),
+                ),
+                Error::<Runtime>::XcmResponderMismatch
+            );
+            assert_eq!(
+                XcmQueries::<Runtime>::get(UNKNOWN_QUERY_ID),
+                Some((xtx_id, sfx_id, dispatched_to))
+            );
+        });
+    }
+}
@@ -1,3 +1,11 @@
+//! # Vacuum pallet
+//!
+//! Orders side effects through Circuit on behalf of users and reports back their status.
+//! `read_order_status` and `read_all_pending_orders_status` emit order statuses as events for
+//! clients limited to extrinsics; `CircuitRuntimeApi::fetch_order_status` and
+//! `fetch_all_pending_orders_status`, served as `circuit_*` JSON-RPC methods, return the same
+//! without costing fees.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
@@ -14,7 +22,10 @@ use sp_runtime::traits::Keccak256;
 
 use sp_std::{convert::TryInto, prelude::*, vec::Vec};
 use t3rn_primitives::{
-    circuit::{traits::CircuitSubmitAPI, types::OrderSFX},
+    circuit::{
+        traits::CircuitSubmitAPI,
+        types::{OrderSFX, OrderStep},
+    },
     SpeedMode,
 };
 #[cfg(feature = "runtime-benchmarks")]
@@ -31,19 +42,12 @@ use t3rn_abi::{
     Codec,
 };
 use t3rn_primitives::circuit::{
-    AdaptiveTimeout, CircuitStatus, OrderOrigin, ReadSFX, SFXAction, SecurityLvl, SideEffect,
+    AuctionMode, CircuitStatus, OrderOrigin, ReadSFX, SFXAction, SecurityLvl, SideEffect,
 };
+pub use t3rn_primitives::circuit::{OrderStatusRead, ProcessedRemoteOrder, RemoteOrderPickup};
 use t3rn_types::sfx::TargetId;
 t3rn_primitives::reexport_currency_types!();
 
-#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
-pub struct OrderStatusRead<Hash, BlockNumber, Account> {
-    pub xtx_id: Hash,
-    pub status: CircuitStatus,
-    pub all_included_sfx: Vec<(Hash, CircuitStatus, Option<Account>)>,
-    pub timeouts_at: AdaptiveTimeout<BlockNumber, TargetId>,
-}
-
 #[derive(Debug, Clone, Eq, PartialEq, Encode, TypeInfo)]
 pub struct RemoteEVMOrderLocalized {
     pub from: H160,
@@ -123,6 +127,20 @@ pub mod pallet {
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
+    /// Ledger of remote orders already consumed by `remote_order`, keyed by the remote target,
+    ///     the SFX id indexed by the remote order contract and the order nonce.
+    #[pallet::storage]
+    #[pallet::getter(fn get_processed_remote_order)]
+    pub type ProcessedRemoteOrders<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, TargetId>,
+            NMapKey<Identity, H256>,        // remote order sfx_id
+            NMapKey<Blake2_128Concat, u32>, // remote order nonce
+        ),
+        ProcessedRemoteOrder<T::Hash, BlockNumberFor<T>, T::AccountId>,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -131,7 +149,14 @@ pub mod pallet {
 
     #[pallet::error]
     pub enum Error<T> {
-        // Define your errors here
+        /// Remote order of the given target and nonce has already been picked up
+        RemoteOrderAlreadyProcessed,
+        /// Remote target of the order isn't active
+        RemoteOrderTargetInactive,
+        /// Amount of the bridge order must be greater (rarely equal) than its max_reward
+        RemoteOrderAmountLowerThanMaxReward,
+        /// Asset of the bridge order isn't mintable on t3rn
+        RemoteOrderAssetNotMintable,
     }
 
     #[pallet::hooks]
@@ -139,6 +164,7 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn order(
             origin: OriginFor<T>,
@@ -147,21 +173,10 @@ pub mod pallet {
             >,
             speed_mode: SpeedMode,
         ) -> DispatchResultWithPostInfo {
-            let side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>> = sfx_actions
-                .into_iter()
-                .map(|sfx_action| sfx_action.try_into())
-                .collect::<Result<Vec<SideEffect<T::AccountId, BalanceOf<T>>>, DispatchError>>()?;
-
-            T::CircuitSubmitAPI::on_extrinsic_trigger(
-                origin,
-                side_effects,
-                speed_mode,
-                SecurityLvl::Optimistic,
-            )?;
-
-            Ok(().into())
+            Self::submit_order(origin, sfx_actions, speed_mode, AuctionMode::Open)
         }
 
+        #[pallet::call_index(1)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn dynamic_destination_deal(
             origin: OriginFor<T>,
@@ -226,6 +241,7 @@ pub mod pallet {
             T::CircuitSubmitAPI::bid(origin, ddd_sfx_id, min_insurance)
         }
 
+        #[pallet::call_index(2)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn single_order(
             origin: OriginFor<T>,
@@ -254,11 +270,13 @@ pub mod pallet {
                 sp_std::vec![side_effect],
                 speed_mode,
                 SecurityLvl::Optimistic,
+                AuctionMode::Open,
             )?;
 
             Ok(().into())
         }
 
+        #[pallet::call_index(3)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn remote_order(
             origin: OriginFor<T>,
@@ -308,10 +326,19 @@ pub mod pallet {
                 nonce: decoded_remote_order_log.nonce,
             };
 
-            assert!(T::Xdns::is_target_active(
-                remote_target_id.clone(),
-                &SecurityLvl::Optimistic
-            ));
+            ensure!(
+                !<ProcessedRemoteOrders<T>>::contains_key((
+                    remote_target_id,
+                    decoded_remote_order_log.sfx_id,
+                    decoded_remote_order.nonce
+                )),
+                Error::<T>::RemoteOrderAlreadyProcessed
+            );
+
+            ensure!(
+                T::Xdns::is_target_active(remote_target_id, &SecurityLvl::Optimistic),
+                Error::<T>::RemoteOrderTargetInactive
+            );
 
             let mut side_effect: SideEffect<T::AccountId, BalanceOf<T>> =
                 decoded_remote_order.clone().try_into()?;
@@ -351,11 +378,14 @@ pub mod pallet {
                     DispatchError::Other("Vacuum::remote_order -- error decoding amount")
                 })?;
 
-                assert!(amount >= max_reward, "Vacuum::remote_order -- amount must be greater (rarely equal) than max_reward for minting assets");
+                ensure!(
+                    amount >= max_reward,
+                    Error::<T>::RemoteOrderAmountLowerThanMaxReward
+                );
                 let asset = decoded_remote_order.asset;
-                assert!(
+                ensure!(
                     T::Xdns::check_asset_is_mintable([3, 3, 3, 3], asset),
-                    "Vacuum::remote_order -- asset is not mintable"
+                    Error::<T>::RemoteOrderAssetNotMintable
                 );
 
                 let target_account =
@@ -367,20 +397,47 @@ pub mod pallet {
                         })?;
 
                 // Mint wrapped assets on local chain.
-                T::Xdns::mint(asset, who, max_reward)?;
-                T::Xdns::mint(asset, target_account, max_reward.saturating_sub(amount))?;
+                T::Xdns::mint(asset, who.clone(), max_reward)?;
+                T::Xdns::mint(asset, target_account, amount.saturating_sub(max_reward))?;
+
+                Self::record_processed_remote_order(
+                    remote_target_id,
+                    decoded_remote_order.nonce,
+                    decoded_remote_order_log.sfx_id,
+                    who,
+                    RemoteOrderPickup::Minted,
+                );
                 Ok(().into())
             } else {
                 // For remote order + remote reward, assume on_remote_origin_trigger
-                T::CircuitSubmitAPI::on_remote_origin_trigger(
+                let post_info = T::CircuitSubmitAPI::on_remote_origin_trigger(
                     origin.clone(),
                     remote_origin.to_account_id(),
-                    vec![side_effect],
+                    vec![side_effect.clone()],
                     speed_mode,
-                )
+                )?;
+
+                let xtx_id = T::ReadSFX::recover_latest_submitted_xtx_id()?;
+                // Id of the single SFX as Circuit linked it to the Xtx
+                let sfx_id = T::ReadSFX::get_fsx_of_xtx(xtx_id)?.first().copied().ok_or(
+                    DispatchError::Other(
+                        "Vacuum::remote_order -- SFX of the submitted Xtx not found",
+                    ),
+                )?;
+
+                Self::record_processed_remote_order(
+                    remote_target_id,
+                    decoded_remote_order.nonce,
+                    decoded_remote_order_log.sfx_id,
+                    who,
+                    RemoteOrderPickup::Xtx { xtx_id, sfx_id },
+                );
+                Ok(post_info)
             }
         }
 
+        /// Emits the status of the given order as an event.
+        #[pallet::call_index(4)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn read_order_status(
             _origin: OriginFor<T>,
@@ -389,6 +446,8 @@ pub mod pallet {
             Self::emit_order_status(xtx_id)
         }
 
+        /// Emits the status of all pending orders as events.
+        #[pallet::call_index(5)]
         #[pallet::weight(100_000)]
         pub fn read_all_pending_orders_status(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             for xtx_id in T::ReadSFX::get_pending_xtx_ids() {
@@ -396,10 +455,99 @@ pub mod pallet {
             }
             Ok(().into())
         }
+
+        /// Order SFX in sequential steps as a single atomic Xtx - i.e. swap on one target and bridge
+        ///     the proceeds to another, piping the received amount through the step placeholders.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::multi_step_order(
+            steps.len() as u32,
+            steps.iter().map(|step| step.side_effects.len() as u32).sum(),
+        ))]
+        pub fn multi_step_order(
+            origin: OriginFor<T>,
+            steps: Vec<
+                OrderStep<
+                    OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+                >,
+            >,
+            speed_mode: SpeedMode,
+            auction_mode: AuctionMode,
+        ) -> DispatchResultWithPostInfo {
+            let steps: Vec<OrderStep<SideEffect<T::AccountId, BalanceOf<T>>>> =
+                steps
+                    .into_iter()
+                    .map(|step| {
+                        Ok(OrderStep {
+                            side_effects: step
+                                .side_effects
+                                .into_iter()
+                                .map(|sfx_action| {
+                                    sfx_action.try_into_side_effect_via_xdns::<T, T::Xdns>()
+                                })
+                                .collect::<Result<
+                                    Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
+                                    DispatchError,
+                                >>()?,
+                            placeholders: step.placeholders,
+                        })
+                    })
+                    .collect::<Result<_, DispatchError>>()?;
+
+            T::CircuitSubmitAPI::on_multi_step_extrinsic_trigger(
+                origin,
+                steps,
+                speed_mode,
+                SecurityLvl::Optimistic,
+                auction_mode,
+            )?;
+
+            Ok(().into())
+        }
+
+        /// Order SFX auctioned in AuctionMode::SealedBid, for which executors commit to their bids
+        ///     first and reveal them once committing has closed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
+        pub fn sealed_bid_order(
+            origin: OriginFor<T>,
+            sfx_actions: Vec<
+                OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+            >,
+            speed_mode: SpeedMode,
+        ) -> DispatchResultWithPostInfo {
+            Self::submit_order(origin, sfx_actions, speed_mode, AuctionMode::SealedBid)
+        }
     }
 
     impl<T: Config> Pallet<T> {
-        pub fn emit_order_status(xtx_id: T::Hash) -> DispatchResultWithPostInfo {
+        fn submit_order(
+            origin: OriginFor<T>,
+            sfx_actions: Vec<
+                OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+            >,
+            speed_mode: SpeedMode,
+            auction_mode: AuctionMode,
+        ) -> DispatchResultWithPostInfo {
+            let side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>> = sfx_actions
+                .into_iter()
+                .map(|sfx_action| sfx_action.try_into_side_effect_via_xdns::<T, T::Xdns>())
+                .collect::<Result<Vec<SideEffect<T::AccountId, BalanceOf<T>>>, DispatchError>>()?;
+
+            T::CircuitSubmitAPI::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                speed_mode,
+                SecurityLvl::Optimistic,
+                auction_mode,
+            )?;
+
+            Ok(().into())
+        }
+
+        pub fn fetch_order_status(
+            xtx_id: T::Hash,
+        ) -> Result<OrderStatusRead<T::Hash, BlockNumberFor<T>, T::AccountId>, DispatchError>
+        {
             let (status, timeouts_at) = T::ReadSFX::get_xtx_status(xtx_id)?;
             let sfx_of_xtx = T::ReadSFX::get_fsx_of_xtx(xtx_id)?;
             let all_included_sfx = sfx_of_xtx
@@ -411,36 +559,72 @@ pub mod pallet {
                 })
                 .collect::<Result<Vec<(T::Hash, CircuitStatus, Option<T::AccountId>)>, DispatchError>>()?;
 
-            Self::deposit_event(Event::OrderStatusRead(OrderStatusRead {
+            Ok(OrderStatusRead {
                 xtx_id,
                 status,
                 all_included_sfx,
                 timeouts_at,
-            }));
+            })
+        }
+
+        pub fn fetch_all_pending_orders_status(
+        ) -> Vec<OrderStatusRead<T::Hash, BlockNumberFor<T>, T::AccountId>> {
+            T::ReadSFX::get_pending_xtx_ids()
+                .into_iter()
+                .filter_map(|xtx_id| Self::fetch_order_status(xtx_id).ok())
+                .collect()
+        }
+
+        pub fn emit_order_status(xtx_id: T::Hash) -> DispatchResultWithPostInfo {
+            let order_status = Self::fetch_order_status(xtx_id)?;
+            Self::deposit_event(Event::OrderStatusRead(order_status));
 
             Ok(().into())
         }
+
+        fn record_processed_remote_order(
+            remote_target_id: TargetId,
+            nonce: u32,
+            remote_sfx_id: H256,
+            processed_by: T::AccountId,
+            pickup: RemoteOrderPickup<T::Hash>,
+        ) {
+            <ProcessedRemoteOrders<T>>::insert(
+                (remote_target_id, remote_sfx_id, nonce),
+                ProcessedRemoteOrder {
+                    remote_target_id,
+                    nonce,
+                    remote_sfx_id,
+                    processed_by,
+                    processed_at: frame_system::Pallet::<T>::block_number(),
+                    pickup,
+                },
+            );
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{Error as VacuumError, RemoteOrderPickup};
     use codec::Encode;
     use t3rn_primitives::circuit::OrderOrigin;
 
     use frame_support::{assert_err, assert_ok, traits::Hooks};
     use hex_literal::hex;
     use sp_core::H256;
-    use sp_runtime::{traits::Keccak256, AccountId32};
+    use sp_runtime::{
+        traits::{Hash as HashT, Keccak256},
+        AccountId32, DispatchError,
+    };
     use sp_std::convert::TryInto;
     pub use t3rn_mini_mock_runtime::{
         activate_all_light_clients, hotswap_latest_receipt_header_root,
         initialize_eth2_with_3rd_epoch, prepare_ext_builder_playground, AccountId, AssetId, Assets,
         Balance, Balances, BlockNumber, Circuit, CircuitError, CircuitEvent, Clock, ConfigVacuum,
-        EthereumEventInclusionProof, GlobalOnInitQueues, Hash, MiniRuntime, MockedAssetEvent,
-        OrderStatusRead, Portal, Rewards, RuntimeEvent as Event, RuntimeOrigin, System, Vacuum,
-        VacuumEvent, ASSET_ASTAR, ASSET_DOT, ASSET_ETH, ASSET_USDT, ASTAR_TARGET, ETHEREUM_TARGET,
-        POLKADOT_TARGET, XDNS,
+        EthereumEventInclusionProof, Hash, MiniRuntime, MockedAssetEvent, OrderStatusRead, Portal,
+        Rewards, RuntimeEvent as Event, RuntimeOrigin, System, Vacuum, VacuumEvent, ASSET_ASTAR,
+        ASSET_DOT, ASSET_ETH, ASSET_USDT, ASTAR_TARGET, ETHEREUM_TARGET, POLKADOT_TARGET, XDNS,
     };
 
     use t3rn_primitives::{
@@ -449,7 +633,7 @@ mod tests {
             CircuitSubmitAPI,
         },
         claimable::CircuitRole,
-        clock::OnHookQueues,
+        clock::HOURLY_JOB,
         light_client::LightClientAsyncAPI,
         monetary::TRN,
         portal::Portal as PortalT,
@@ -794,6 +978,8 @@ mod tests {
             let order_status_read = expect_last_event_to_read_order_status();
 
             assert_eq!(order_status_read.status, CircuitStatus::FinishedAllSteps);
+            // Runtime API reads the same status without emitting events
+            assert_eq!(Vacuum::fetch_order_status(xtx_id), Ok(order_status_read));
 
             // Verify balances of requester and executor have refunded insurances + expected amount on their accounts
             assert_eq!(Assets::balance(ASSET_DOT, &requester), 201u128);
@@ -1091,6 +1277,252 @@ mod tests {
         });
     }
 
+    #[test]
+    fn call_order_is_rejected_on_target_not_allowing_call_of_its_execution_vendor() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            let requester = AccountId32::from([2u8; 32]);
+            let contract_on_dest = AccountId32::from([3u8; 32]);
+
+            // Astar is Substrate-based, therefore the call routes to ink! contracts via wasm SFX,
+            //  which isn't listed among its allowed side effects.
+            let sfx_order = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+                sfx_action: SFXAction::Call(
+                    ASTAR_TARGET,
+                    contract_on_dest,
+                    0u128,
+                    100_000u128,
+                    vec![1u8, 2, 3, 4],
+                ),
+                max_reward: 200u128,
+                insurance: 50u128,
+                reward_asset: ASSET_ASTAR,
+                remote_origin_nonce: None,
+            };
+
+            activate_all_light_clients();
+
+            assert_err!(
+                Vacuum::order(
+                    RuntimeOrigin::signed(requester),
+                    vec![sfx_order],
+                    SpeedMode::Fast,
+                ),
+                DispatchError::Other(
+                    "OrderSFX::call action derived for the target isn't allowed there"
+                )
+            );
+        });
+    }
+
+    fn seal_bid(sfx_id: Hash, executor: &AccountId32, amount: Balance, salt: Hash) -> Hash {
+        <MiniRuntime as frame_system::Config>::Hashing::hash_of(&(sfx_id, executor, amount, salt))
+    }
+
+    fn order_sealed_bid_sfx(
+        requester: AccountId32,
+        requester_on_dest: AccountId32,
+    ) -> (Hash, Hash) {
+        let sfx_order = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+            sfx_action: SFXAction::Transfer(POLKADOT_TARGET, ASSET_DOT, requester_on_dest, 100u128),
+            max_reward: 200u128,
+            insurance: 50u128,
+            reward_asset: ASSET_DOT,
+            remote_origin_nonce: None,
+        };
+
+        assert_ok!(Vacuum::sealed_bid_order(
+            RuntimeOrigin::signed(requester),
+            vec![sfx_order.clone()],
+            SpeedMode::Fast,
+        ));
+
+        let xtx_id = expect_last_event_to_emit_xtx_id();
+        let sfx: SideEffect<AccountId32, Balance> = sfx_order.try_into().unwrap();
+
+        (xtx_id, sfx.generate_id::<Keccak256>(xtx_id.0.as_slice(), 0))
+    }
+
+    #[test]
+    fn sealed_bid_order_awards_sfx_to_lowest_reveal_and_slashes_unrevealed_commitment() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            let requester = AccountId32::from([2u8; 32]);
+            let requester_on_dest = AccountId32::from([3u8; 32]);
+            let executors = [
+                AccountId32::from([4u8; 32]),
+                AccountId32::from([5u8; 32]),
+                AccountId32::from([6u8; 32]),
+            ];
+            for executor in executors.iter() {
+                mint_required_assets_for_optimistic_actors(
+                    requester.clone(),
+                    executor.clone(),
+                    200u128,
+                    50u128,
+                    ASSET_DOT,
+                );
+            }
+
+            activate_all_light_clients();
+
+            let (xtx_id, sfx_id) = order_sealed_bid_sfx(requester, requester_on_dest);
+
+            // Open bids are rejected for sealed-bid auctions
+            assert_err!(
+                Circuit::bid_sfx(
+                    RuntimeOrigin::signed(executors[0].clone()),
+                    sfx_id,
+                    150 as Balance,
+                ),
+                CircuitError::<MiniRuntime>::BiddingRejectedSealedBidAuction
+            );
+
+            let salt = Hash::repeat_byte(7u8);
+            for (executor, amount) in executors.iter().zip([150 as Balance, 120, 100]) {
+                assert_ok!(Circuit::commit_sealed_bid(
+                    RuntimeOrigin::signed(executor.clone()),
+                    sfx_id,
+                    seal_bid(sfx_id, executor, amount, salt),
+                ));
+                // Insurance is held from commitment on
+                assert_eq!(
+                    Assets::balance(ASSET_DOT, executor),
+                    EXISTENTIAL_DEPOSIT as Balance
+                );
+            }
+
+            assert_err!(
+                Circuit::reveal_sealed_bid(
+                    RuntimeOrigin::signed(executors[0].clone()),
+                    sfx_id,
+                    150 as Balance,
+                    salt,
+                ),
+                CircuitError::<MiniRuntime>::SealedBidRevealPhaseNotOpen
+            );
+
+            // Close commitments
+            System::set_block_number(System::block_number() + 3);
+            Clock::on_initialize(System::block_number());
+
+            assert_err!(
+                Circuit::commit_sealed_bid(
+                    RuntimeOrigin::signed(AccountId32::from([7u8; 32])),
+                    sfx_id,
+                    H256::zero(),
+                ),
+                CircuitError::<MiniRuntime>::SealedBidCommitPhaseClosed
+            );
+            assert_err!(
+                Circuit::reveal_sealed_bid(
+                    RuntimeOrigin::signed(executors[1].clone()),
+                    sfx_id,
+                    110 as Balance,
+                    salt,
+                ),
+                CircuitError::<MiniRuntime>::SealedBidCommitmentMismatch
+            );
+
+            assert_ok!(Circuit::reveal_sealed_bid(
+                RuntimeOrigin::signed(executors[0].clone()),
+                sfx_id,
+                150 as Balance,
+                salt,
+            ));
+            assert_ok!(Circuit::reveal_sealed_bid(
+                RuntimeOrigin::signed(executors[1].clone()),
+                sfx_id,
+                120 as Balance,
+                salt,
+            ));
+            // The lowest sealed bid is never revealed
+
+            // Close reveals and resolve the auction
+            System::set_block_number(System::block_number() + 3);
+            Clock::on_initialize(System::block_number());
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::Ready
+            );
+            let fsx = Circuit::get_full_side_effects(xtx_id).unwrap()[0][0].clone();
+            let best_bid = fsx.best_bid.unwrap();
+            assert_eq!(best_bid.executor, executors[1]);
+            assert_eq!(best_bid.amount, 120 as Balance);
+            assert_eq!(fsx.input.enforce_executor, Some(executors[1].clone()));
+
+            assert!(Circuit::get_sealed_bids(sfx_id).is_empty());
+            assert_eq!(Circuit::get_sealed_bid_auctions(xtx_id), None);
+
+            // Outbid executor gets insurance back, the unrevealed commitment is slashed
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &executors[0]),
+                EXISTENTIAL_DEPOSIT as Balance + 50 as Balance
+            );
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &executors[1]),
+                EXISTENTIAL_DEPOSIT as Balance
+            );
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &executors[2]),
+                EXISTENTIAL_DEPOSIT as Balance
+            );
+        });
+    }
+
+    #[test]
+    fn sealed_bid_order_without_reveals_is_dropped_at_bidding() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            let executor = AccountId32::from([4u8; 32]);
+            let requester = AccountId32::from([2u8; 32]);
+            let requester_on_dest = AccountId32::from([3u8; 32]);
+
+            mint_required_assets_for_optimistic_actors(
+                requester.clone(),
+                executor.clone(),
+                200u128,
+                50u128,
+                ASSET_DOT,
+            );
+
+            activate_all_light_clients();
+
+            let (xtx_id, sfx_id) = order_sealed_bid_sfx(requester.clone(), requester_on_dest);
+
+            let salt = Hash::repeat_byte(7u8);
+            assert_ok!(Circuit::commit_sealed_bid(
+                RuntimeOrigin::signed(executor.clone()),
+                sfx_id,
+                seal_bid(sfx_id, &executor, 150 as Balance, salt),
+            ));
+            assert_err!(
+                Circuit::commit_sealed_bid(
+                    RuntimeOrigin::signed(executor.clone()),
+                    sfx_id,
+                    seal_bid(sfx_id, &executor, 140 as Balance, salt),
+                ),
+                CircuitError::<MiniRuntime>::SealedBidAlreadyCommitted
+            );
+
+            System::set_block_number(System::block_number() + 6);
+            Clock::on_initialize(System::block_number());
+
+            assert_eq!(Circuit::get_x_exec_signals(xtx_id), None);
+            assert_eq!(Circuit::get_sealed_bid_auctions(xtx_id), None);
+            // Requester is refunded, the unrevealed commitment is slashed
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &requester),
+                EXISTENTIAL_DEPOSIT as Balance + 200 as Balance
+            );
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &executor),
+                EXISTENTIAL_DEPOSIT as Balance
+            );
+        });
+    }
+
     fn make_whole_vacuum_trip_including_minting_and_confirmation(
         reward_and_requested_asset: AssetId,
         executor: AccountId32,
@@ -1194,7 +1626,7 @@ mod tests {
             );
 
             System::set_block_number(300);
-            GlobalOnInitQueues::process_hourly(300, Weight::MAX);
+            Clock::run_job(HOURLY_JOB, 300, Weight::MAX);
 
             // Claim via Rewards
             let _claim_res = Rewards::claim(
@@ -1218,7 +1650,7 @@ mod tests {
                 requester_on_dest.clone(),
             );
 
-            GlobalOnInitQueues::process_hourly(600, Weight::MAX);
+            Clock::run_job(HOURLY_JOB, 600, Weight::MAX);
 
             // Claim via Rewards
             let _claim_res = Rewards::claim(
@@ -1459,7 +1891,7 @@ mod tests {
                 EXISTENTIAL_DEPOSIT as Balance + 50 as Balance
             );
 
-            GlobalOnInitQueues::process_hourly(300, Weight::MAX);
+            Clock::run_job(HOURLY_JOB, 300, Weight::MAX);
 
             // Claim via Rewards
             let _claim_res = Rewards::claim(
@@ -1661,7 +2093,7 @@ mod tests {
                 EXISTENTIAL_DEPOSIT as Balance + 50 as Balance
             );
 
-            GlobalOnInitQueues::process_hourly(300, Weight::MAX);
+            Clock::run_job(HOURLY_JOB, 300, Weight::MAX);
 
             // Claim via Rewards
             let _claim_res = Rewards::claim(
@@ -1753,6 +2185,26 @@ mod tests {
                     "ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5"
                 ))
             );
+
+            // sfxId is the first indexed topic of the RemoteOrderIndexed log
+            let remote_order_sfx_id = H256::from(hex!(
+                "e2da230e52caecf528190bb0f28767e3e02a2df185bcd070c3f019537e4d5844"
+            ));
+            let processed_order = Vacuum::get_processed_remote_order((
+                ETHEREUM_TARGET,
+                remote_order_sfx_id,
+                0u32,
+            ))
+                .expect("remote order should be recorded as processed");
+            let sfx_id = match processed_order.pickup {
+                RemoteOrderPickup::Xtx { xtx_id: picked_by_xtx, sfx_id } => {
+                    assert_eq!(picked_by_xtx, xtx_id);
+                    sfx_id
+                },
+                pickup => panic!("remote order should be picked up by Xtx, got {:?}", pickup),
+            };
+            // sfx_id is the one Circuit linked to the Xtx
+            assert_eq!(Circuit::get_sfx_2_xtx_links(sfx_id), Some(xtx_id));
         });
     }
 
@@ -1836,6 +2288,31 @@ mod tests {
             // executor: max_reward (net)
             assert_eq!(Assets::balance(1u32, &requester), 0);
             assert_eq!(Assets::balance(1u32, &executor), 100);
+
+            // sfxId is the first indexed topic of the RemoteOrderIndexed log
+            let remote_order_sfx_id = H256::from(hex!(
+                "e2da230e52caecf528190bb0f28767e3e02a2df185bcd070c3f019537e4d5844"
+            ));
+            let processed_order = Vacuum::get_processed_remote_order((
+                ETHEREUM_TARGET,
+                remote_order_sfx_id,
+                0u32,
+            ))
+                .expect("remote order should be recorded as processed");
+            assert_eq!(processed_order.processed_by, executor);
+            assert_eq!(processed_order.pickup, RemoteOrderPickup::Minted);
+
+            // Replaying the same inclusion proof must not mint the assets again
+            assert_err!(
+                Vacuum::remote_order(
+                    RuntimeOrigin::signed(executor.clone()),
+                    rlp_encoded_remote_order_local_reward_event.encode(),
+                    ETHEREUM_TARGET,
+                    SpeedMode::Fast,
+                ),
+                VacuumError::<MiniRuntime>::RemoteOrderAlreadyProcessed
+            );
+            assert_eq!(Assets::balance(1u32, &executor), 100);
         });
     }
 
@@ -36,6 +36,7 @@ use core::marker::PhantomData;
 
 pub trait WeightInfo {
     fn single_order() -> Weight;
+    fn multi_step_order(s: u32, n: u32) -> Weight;
 }
 pub struct SubstrateWeight<T>(PhantomData<T>);
 
@@ -81,4 +82,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(11))
             .saturating_add(T::DbWeight::get().writes(9))
     }
+    /// Each of n SFX is converted and submitted as the one of a single order, while each of s steps stores its own FSX
+    fn multi_step_order(s: u32, n: u32) -> Weight {
+        Self::single_order()
+            .saturating_mul(n.max(1) as u64)
+            .saturating_add(T::DbWeight::get().writes(s as u64))
+    }
 }
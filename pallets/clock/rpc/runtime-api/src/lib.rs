@@ -0,0 +1,22 @@
+//! Runtime API definition required by the clock jobs registry.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to report the state of on_initialize jobs:
+//! their schedule, last run, consumed weight and backlog.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use t3rn_primitives::clock::{ClockJob, ClockJobStatus};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with the clock pallet
+    pub trait ClockRuntimeApi<BlockNumber> where
+        BlockNumber: Codec,
+    {
+        /// Returns the registered jobs in order of priority, with their last run, consumed weight and backlog
+        fn jobs() -> Vec<(Vec<u8>, ClockJob<BlockNumber>, ClockJobStatus<BlockNumber>)>;
+    }
+}
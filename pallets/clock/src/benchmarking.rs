@@ -1 +1,35 @@
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
 
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use t3rn_primitives::clock::DAILY_JOB;
+
+benchmarks! {
+
+    set_job {
+        // Worst case appends the job back to the standard registry, checking every share.
+        <Jobs<T>>::put(
+            StandardClockJobs::<T>::get()
+                .into_iter()
+                .filter(|(name, _)| name.as_slice() != DAILY_JOB)
+                .collect::<Vec<_>>(),
+        );
+        let job = ClockJob {
+            period: 1u32.into(),
+            weight_share: Percent::from_percent(10),
+            priority: 5,
+        };
+    }: _(RawOrigin::Root, DAILY_JOB.to_vec(), job.clone())
+    verify {
+        assert!(<Jobs<T>>::get().contains(&(DAILY_JOB.to_vec(), job)));
+    }
+
+    remove_job {
+        <Jobs<T>>::put(StandardClockJobs::<T>::get());
+        <JobStatuses<T>>::insert(DAILY_JOB.to_vec(), ClockJobStatus::default());
+    }: _(RawOrigin::Root, DAILY_JOB.to_vec())
+    verify {
+        assert!(!<Jobs<T>>::get().iter().any(|(name, _)| name.as_slice() == DAILY_JOB));
+    }
+}
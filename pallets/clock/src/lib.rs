@@ -10,11 +10,18 @@
 
 pub use crate::pallet::*;
 use frame_support::{pallet_prelude::Weight, traits::Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Zero, Percent};
+use sp_std::{marker::PhantomData, prelude::*};
 
 pub use t3rn_primitives::{
-    account_manager::AccountManager, claimable::ClaimableArtifacts, clock::Clock,
-    common::RoundInfo, executors::Executors, gateway::GatewayABIConfig, ChainId, EscrowTrait,
-    GatewayGenesisConfig, GatewayType, GatewayVendor,
+    account_manager::AccountManager,
+    claimable::ClaimableArtifacts,
+    clock::{Clock, ClockJob, ClockJobStatus},
+    common::RoundInfo,
+    executors::Executors,
+    gateway::GatewayABIConfig,
+    ChainId, EscrowTrait, GatewayGenesisConfig, GatewayType, GatewayVendor,
 };
 
 #[cfg(test)]
@@ -23,7 +30,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod weights;
+pub mod weights;
 
 // Definition of the pallet logic, to be aggregated at runtime definition through
 // `construct_runtime`.
@@ -35,7 +42,23 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
-    use t3rn_primitives::clock::OnHookQueues;
+    pub use crate::weights::WeightInfo;
+    use t3rn_primitives::clock::{
+        ClockJobs, OnHookQueues, StandardJobQueues, AUTHOR_REWARDS_JOB, BI_WEEKLY_JOB, DAILY_JOB,
+        HOURLY_JOB, REVERT_XTX_QUEUE_JOB, SIGNAL_QUEUE_JOB, WEEKLY_JOB, XTX_TICK_QUEUE_JOB,
+    };
+
+    /// Jobs run by the clock itself over `Config::JobQueues`.
+    pub const STANDARD_JOBS: [&[u8]; 8] = [
+        SIGNAL_QUEUE_JOB,
+        XTX_TICK_QUEUE_JOB,
+        REVERT_XTX_QUEUE_JOB,
+        AUTHOR_REWARDS_JOB,
+        HOURLY_JOB,
+        DAILY_JOB,
+        WEEKLY_JOB,
+        BI_WEEKLY_JOB,
+    ];
 
     const FIVE: u64 = 5;
 
@@ -47,11 +70,18 @@ pub mod pallet {
         #[pallet::constant]
         type RoundDuration: Get<BlockNumberFor<Self>>;
 
-        /// Description of on_initialize queues and their max. consumption of % of total on_init weight.
-        /// The first element of the tuple is the queue name, the second is the max. % of total on_init weight.}
-        type OnInitializeQueues: OnHookQueues<Self>;
+        /// Queues of the runtime's pallets run by the standard jobs.
+        type JobQueues: StandardJobQueues<Self>;
+
+        /// Runs the runtime's own jobs scheduled in the `Jobs` registry, besides the standard ones.
+        type OnInitializeJobs: ClockJobs<Self>;
+
+        /// Jobs registry used until governance sets its own with `set_job` or `remove_job`.
+        type DefaultJobs: Get<Vec<(Vec<u8>, ClockJob<BlockNumberFor<Self>>)>>;
 
         type OnFinalizeQueues: OnHookQueues<Self>;
+
+        type WeightInfo: WeightInfo;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -65,7 +95,167 @@ pub mod pallet {
     /// Information on the current round.
     pub type CurrentRound<T: Config> = StorageValue<_, RoundInfo<BlockNumberFor<T>>, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultJobsOnEmpty<T: Config>() -> Vec<(Vec<u8>, ClockJob<BlockNumberFor<T>>)> {
+        T::DefaultJobs::get()
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn jobs)]
+    /// Named periodic jobs run on initialize, with their period, weight share and priority.
+    pub type Jobs<T: Config> = StorageValue<
+        _,
+        Vec<(Vec<u8>, ClockJob<BlockNumberFor<T>>)>,
+        ValueQuery,
+        DefaultJobsOnEmpty<T>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn job_status)]
+    pub type JobStatuses<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, ClockJobStatus<BlockNumberFor<T>>, ValueQuery>;
+
     impl<T: Config> Pallet<T> {
+        /// Weight limit of the on initialize and on finalize hooks each.
+        pub fn max_hook_weight() -> Weight {
+            T::BlockWeights::get().max_block.saturating_div(FIVE)
+        }
+
+        /// Runs the due jobs in order of priority. Jobs which don't fit their weight share
+        /// into the remaining weight, or don't complete, are carried over to the next block.
+        pub fn process_jobs(n: BlockNumberFor<T>, hook_weight_limit: Weight) -> Weight {
+            let mut jobs = <Jobs<T>>::get();
+            jobs.sort_by_key(|(_, job)| job.priority);
+
+            let mut total_consumed = T::DbWeight::get().reads(1);
+            for (name, job) in jobs.iter() {
+                let mut status = <JobStatuses<T>>::get(name);
+                total_consumed = total_consumed.saturating_add(T::DbWeight::get().reads(1));
+
+                let is_due = !job.period.is_zero() && (n % job.period).is_zero();
+                if !is_due && status.backlog.is_zero() {
+                    continue
+                }
+
+                let job_weight_limit = job.weight_share * hook_weight_limit;
+                if hook_weight_limit
+                    .saturating_sub(total_consumed)
+                    .any_lt(job_weight_limit)
+                {
+                    status.backlog = status.backlog.saturating_add(1);
+                    log::debug!(
+                        "Clock::process_jobs out of weight, carrying over job {:?} with backlog: {:?}",
+                        sp_std::str::from_utf8(name),
+                        status.backlog
+                    );
+                } else {
+                    let (consumed, is_complete) = Self::run_job(name, n, job_weight_limit);
+                    log::debug!(
+                        "Clock::process_jobs job {:?} consumed: {:?}",
+                        sp_std::str::from_utf8(name),
+                        consumed
+                    );
+                    total_consumed = total_consumed.saturating_add(consumed);
+                    status.last_run = n;
+                    status.consumed_weight = consumed;
+                    status.backlog = if is_complete {
+                        0
+                    } else {
+                        status.backlog.saturating_add(1)
+                    };
+                }
+
+                <JobStatuses<T>>::insert(name, status);
+                total_consumed = total_consumed.saturating_add(T::DbWeight::get().writes(1));
+            }
+
+            total_consumed
+        }
+
+        /// Runs the named job within the weight limit, dispatching the standard jobs to
+        /// `Config::JobQueues` and any other to `Config::OnInitializeJobs`. Returns the consumed
+        /// weight and whether the job completed.
+        pub fn run_job(
+            name: &[u8],
+            n: BlockNumberFor<T>,
+            job_weight_limit: Weight,
+        ) -> (Weight, bool) {
+            // Queues are incomplete once they've used up their ref time limit.
+            let is_queue_complete =
+                |weight: Weight| weight.ref_time() < job_weight_limit.ref_time();
+
+            match name {
+                SIGNAL_QUEUE_JOB => {
+                    let weight = T::JobQueues::process_signal_queue(n, job_weight_limit);
+                    (weight, is_queue_complete(weight))
+                },
+                XTX_TICK_QUEUE_JOB => {
+                    let weight = T::JobQueues::process_xtx_tick_queue(n, job_weight_limit);
+                    (weight, is_queue_complete(weight))
+                },
+                REVERT_XTX_QUEUE_JOB => {
+                    let weight = T::JobQueues::process_revert_xtx_queue(n, job_weight_limit);
+                    (weight, is_queue_complete(weight))
+                },
+                AUTHOR_REWARDS_JOB => (T::JobQueues::process_author_rewards(n), true),
+                HOURLY_JOB => {
+                    let weight =
+                        T::JobQueues::process_hourly(n).saturating_add(Self::check_bump_round(n));
+                    Self::complete_periodic_job(name, weight, job_weight_limit)
+                },
+                DAILY_JOB => {
+                    let weight = T::JobQueues::process_daily(n);
+                    Self::complete_periodic_job(name, weight, job_weight_limit)
+                },
+                WEEKLY_JOB => {
+                    let weight = T::JobQueues::process_weekly(n);
+                    Self::complete_periodic_job(name, weight, job_weight_limit)
+                },
+                BI_WEEKLY_JOB => {
+                    let weight = T::JobQueues::process_bi_weekly(n);
+                    Self::complete_periodic_job(name, weight, job_weight_limit)
+                },
+                _ => T::OnInitializeJobs::run(name, n, job_weight_limit),
+            }
+        }
+
+        /// Periodic jobs always run to completion, reports those which consumed more than their limit.
+        fn complete_periodic_job(
+            name: &[u8],
+            consumed: Weight,
+            job_weight_limit: Weight,
+        ) -> (Weight, bool) {
+            if consumed.all_gte(job_weight_limit) {
+                log::error!(
+                    "Clock::run_job {:?} consumed more than the limit: {:?}",
+                    sp_std::str::from_utf8(name),
+                    consumed
+                );
+            }
+            (consumed, true)
+        }
+
+        /// Whether the named job is one of the standard jobs or known to `Config::OnInitializeJobs`.
+        pub fn is_known_job(name: &[u8]) -> bool {
+            STANDARD_JOBS.contains(&name) || T::OnInitializeJobs::is_known(name)
+        }
+
+        /// Registered jobs in order of priority, with their last run, consumed weight and backlog.
+        pub fn jobs_overview() -> Vec<(
+            Vec<u8>,
+            ClockJob<BlockNumberFor<T>>,
+            ClockJobStatus<BlockNumberFor<T>>,
+        )> {
+            let mut jobs = <Jobs<T>>::get();
+            jobs.sort_by_key(|(_, job)| job.priority);
+            jobs.into_iter()
+                .map(|(name, job)| {
+                    let status = <JobStatuses<T>>::get(&name);
+                    (name, job, status)
+                })
+                .collect()
+        }
+
         pub fn check_bump_round(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
             let past_round = <CurrentRound<T>>::get();
             let term = T::RoundDuration::get();
@@ -98,7 +288,7 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         // `on_finalize` is executed at the end of block after all extrinsic are dispatched.
         fn on_finalize(n: frame_system::pallet_prelude::BlockNumberFor<T>) {
-            let max_on_finalize_weight = T::BlockWeights::get().max_block.saturating_div(FIVE);
+            let max_on_finalize_weight = Self::max_hook_weight();
             log::debug!(
                 "Clock::on_finalize process hooks with max_on_finalize_weight: {:?}",
                 max_on_finalize_weight
@@ -111,13 +301,13 @@ pub mod pallet {
         //
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
         fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            let max_on_initialize_weight = T::BlockWeights::get().max_block.saturating_div(FIVE);
+            let max_on_initialize_weight = Self::max_hook_weight();
             log::debug!(
                 "Clock::on_initialize process hooks with max_on_initialize_weight: {:?} and block number: {:?}",
                 max_on_initialize_weight,
                 n
             );
-            T::OnInitializeQueues::process(n, max_on_initialize_weight)
+            Self::process_jobs(n, max_on_initialize_weight)
         }
 
         // A runtime code run after every block and have access to extended set of APIs.
@@ -132,6 +322,56 @@ pub mod pallet {
         }
     }
 
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Registers a new job or reschedules an existing one.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_job())]
+        pub fn set_job(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            job: ClockJob<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Self::is_known_job(&name), Error::<T>::UnknownJob);
+            ensure!(!job.period.is_zero(), Error::<T>::JobPeriodZero);
+
+            let mut jobs = <Jobs<T>>::get();
+            match jobs.iter_mut().find(|(job_name, _)| *job_name == name) {
+                Some((_, scheduled)) => *scheduled = job.clone(),
+                None => jobs.push((name.clone(), job.clone())),
+            }
+
+            let total_share: u16 = jobs
+                .iter()
+                .map(|(_, job)| job.weight_share.deconstruct() as u16)
+                .sum();
+            ensure!(total_share <= 100, Error::<T>::JobSharesExceed100);
+
+            <Jobs<T>>::put(jobs);
+            Self::deposit_event(Event::JobSet { name, job });
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_job())]
+        pub fn remove_job(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut jobs = <Jobs<T>>::get();
+            let index = jobs
+                .iter()
+                .position(|(job_name, _)| *job_name == name)
+                .ok_or(Error::<T>::JobNotFound)?;
+            jobs.remove(index);
+
+            <Jobs<T>>::put(jobs);
+            <JobStatuses<T>>::remove(&name);
+            Self::deposit_event(Event::JobRemoved { name });
+            Ok(())
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -140,10 +380,22 @@ pub mod pallet {
             head: frame_system::pallet_prelude::BlockNumberFor<T>,
             term: frame_system::pallet_prelude::BlockNumberFor<T>,
         },
+        JobSet {
+            name: Vec<u8>,
+            job: ClockJob<BlockNumberFor<T>>,
+        },
+        JobRemoved {
+            name: Vec<u8>,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        JobPeriodZero,
+        JobSharesExceed100,
+        JobNotFound,
+        UnknownJob,
+    }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
@@ -167,5 +419,46 @@ pub mod pallet {
         fn round_duration() -> frame_system::pallet_prelude::BlockNumberFor<T> {
             T::RoundDuration::get()
         }
+
+        fn job_weight_limit(name: &[u8]) -> Weight {
+            <Jobs<T>>::get()
+                .iter()
+                .find(|(job_name, _)| job_name.as_slice() == name)
+                .map(|(_, job)| job.weight_share * Self::max_hook_weight())
+                .unwrap_or_else(Weight::zero)
+        }
+    }
+}
+
+/// The jobs each runtime used to run on initialize: queue processing every block and the
+/// hourly, daily, weekly and bi-weekly queues.
+pub struct StandardClockJobs<T>(PhantomData<T>);
+
+impl<T: Config> Get<Vec<(Vec<u8>, ClockJob<BlockNumberFor<T>>)>> for StandardClockJobs<T> {
+    fn get() -> Vec<(Vec<u8>, ClockJob<BlockNumberFor<T>>)> {
+        use t3rn_primitives::{
+            clock::{
+                AUTHOR_REWARDS_JOB, BI_WEEKLY_JOB, BLOCKS_PER_2_WEEKS, BLOCKS_PER_WEEK, DAILY_JOB,
+                HOURLY_JOB, REVERT_XTX_QUEUE_JOB, SIGNAL_QUEUE_JOB, WEEKLY_JOB, XTX_TICK_QUEUE_JOB,
+            },
+            common::{BLOCKS_PER_DAY, BLOCKS_PER_HOUR},
+        };
+
+        let job = |period: u32, weight_share: u8, priority: u8| ClockJob {
+            period: period.into(),
+            weight_share: Percent::from_percent(weight_share),
+            priority,
+        };
+
+        sp_std::vec![
+            (SIGNAL_QUEUE_JOB.to_vec(), job(1, 5, 0)),
+            (XTX_TICK_QUEUE_JOB.to_vec(), job(1, 30, 1)),
+            (REVERT_XTX_QUEUE_JOB.to_vec(), job(1, 5, 2)),
+            (AUTHOR_REWARDS_JOB.to_vec(), job(1, 0, 3)),
+            (HOURLY_JOB.to_vec(), job(BLOCKS_PER_HOUR, 20, 4)),
+            (DAILY_JOB.to_vec(), job(BLOCKS_PER_DAY, 10, 5)),
+            (WEEKLY_JOB.to_vec(), job(BLOCKS_PER_WEEK, 20, 6)),
+            (BI_WEEKLY_JOB.to_vec(), job(BLOCKS_PER_2_WEEKS, 10, 7)),
+        ]
     }
 }
@@ -1,7 +1,15 @@
 #[cfg(test)]
 pub mod clock_test {
-    use t3rn_mini_mock_runtime::{BlockNumber, Clock, ExtBuilder, MiniRuntime, System};
-    use t3rn_primitives::common::RoundInfo;
+    use crate::{ClockJob, Error};
+    use frame_support::{assert_noop, assert_ok, pallet_prelude::Weight, traits::OnInitialize};
+    use sp_runtime::Percent;
+    use t3rn_mini_mock_runtime::{
+        BlockNumber, Clock, ExtBuilder, MiniRuntime, RuntimeOrigin, System,
+    };
+    use t3rn_primitives::{
+        clock::{DAILY_JOB, HOURLY_JOB, SIGNAL_QUEUE_JOB, XTX_TICK_QUEUE_JOB},
+        common::RoundInfo,
+    };
 
     #[test]
     fn check_bump_round_doesnt_tick_below_300() {
@@ -47,4 +55,140 @@ pub mod clock_test {
             assert_eq!(current_round, expected_round_next);
         });
     }
+
+    #[test]
+    fn on_initialize_runs_due_jobs_from_default_registry() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let job_names = Clock::jobs_overview()
+                .into_iter()
+                .map(|(name, _, _)| name)
+                .collect::<Vec<_>>();
+            assert_eq!(job_names[0], SIGNAL_QUEUE_JOB);
+            assert_eq!(job_names[1], XTX_TICK_QUEUE_JOB);
+
+            System::set_block_number(300u32);
+            <Clock as OnInitialize<BlockNumber>>::on_initialize(300u32);
+
+            assert_eq!(Clock::job_status(HOURLY_JOB.to_vec()).last_run, 300);
+            assert_eq!(Clock::job_status(XTX_TICK_QUEUE_JOB.to_vec()).last_run, 300);
+            assert_eq!(Clock::job_status(DAILY_JOB.to_vec()).last_run, 0);
+            assert_eq!(Clock::current_round().index, 2);
+        });
+    }
+
+    #[test]
+    fn incomplete_jobs_are_carried_over_to_next_block() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            Clock::process_jobs(1u32, Weight::zero());
+            assert_eq!(Clock::job_status(XTX_TICK_QUEUE_JOB.to_vec()).backlog, 1);
+
+            Clock::process_jobs(2u32, Weight::MAX);
+            let status = Clock::job_status(XTX_TICK_QUEUE_JOB.to_vec());
+            assert_eq!(status.backlog, 0);
+            assert_eq!(status.last_run, 2);
+            assert_eq!(Clock::job_status(HOURLY_JOB.to_vec()).last_run, 0);
+        });
+    }
+
+    #[test]
+    fn set_job_reschedules_and_checks_shares() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let hourly = ClockJob {
+                period: 10u32,
+                weight_share: Percent::from_percent(20),
+                priority: 4,
+            };
+            assert_ok!(Clock::set_job(
+                RuntimeOrigin::root(),
+                HOURLY_JOB.to_vec(),
+                hourly.clone()
+            ));
+            assert!(Clock::jobs().contains(&(HOURLY_JOB.to_vec(), hourly)));
+
+            Clock::process_jobs(10u32, Weight::MAX);
+            assert_eq!(Clock::job_status(HOURLY_JOB.to_vec()).last_run, 10);
+
+            assert_noop!(
+                Clock::set_job(
+                    RuntimeOrigin::root(),
+                    HOURLY_JOB.to_vec(),
+                    ClockJob {
+                        period: 10u32,
+                        weight_share: Percent::from_percent(21),
+                        priority: 4,
+                    }
+                ),
+                Error::<MiniRuntime>::JobSharesExceed100
+            );
+            assert_noop!(
+                Clock::set_job(
+                    RuntimeOrigin::root(),
+                    DAILY_JOB.to_vec(),
+                    ClockJob {
+                        period: 0u32,
+                        weight_share: Percent::from_percent(10),
+                        priority: 5,
+                    }
+                ),
+                Error::<MiniRuntime>::JobPeriodZero
+            );
+        });
+    }
+
+    #[test]
+    fn set_job_rejects_jobs_unknown_to_runtime() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_noop!(
+                Clock::set_job(
+                    RuntimeOrigin::root(),
+                    b"extra".to_vec(),
+                    ClockJob {
+                        period: 1u32,
+                        weight_share: Percent::from_percent(0),
+                        priority: 9,
+                    }
+                ),
+                Error::<MiniRuntime>::UnknownJob
+            );
+
+            // Removed standard jobs can be scheduled again.
+            assert_ok!(Clock::remove_job(RuntimeOrigin::root(), DAILY_JOB.to_vec()));
+            assert_ok!(Clock::set_job(
+                RuntimeOrigin::root(),
+                DAILY_JOB.to_vec(),
+                ClockJob {
+                    period: 1u32,
+                    weight_share: Percent::from_percent(10),
+                    priority: 5,
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn remove_job_unschedules_default_job() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_ok!(Clock::remove_job(
+                RuntimeOrigin::root(),
+                HOURLY_JOB.to_vec()
+            ));
+            assert!(!Clock::jobs()
+                .iter()
+                .any(|(name, _)| name.as_slice() == HOURLY_JOB));
+
+            System::set_block_number(300u32);
+            <Clock as OnInitialize<BlockNumber>>::on_initialize(300u32);
+            assert_eq!(Clock::job_status(HOURLY_JOB.to_vec()).last_run, 0);
+
+            assert_noop!(
+                Clock::remove_job(RuntimeOrigin::root(), HOURLY_JOB.to_vec()),
+                Error::<MiniRuntime>::JobNotFound
+            );
+        });
+    }
 }
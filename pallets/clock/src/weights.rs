@@ -1,4 +1,7 @@
-// STUB
+//! Weights for pallet_clock
+//!
+//! The jobs registry only holds jobs known to the runtime, so the calls are weighed over the
+//! standard jobs registry.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -9,12 +12,49 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
-/// Weight functions needed for pallet_xdns.
-pub trait WeightInfo {}
+/// Weight functions needed for pallet_clock.
+pub trait WeightInfo {
+    fn set_job() -> Weight;
+    fn remove_job() -> Weight;
+}
 
-/// Weights for pallet_xdns using the Substrate node and recommended hardware.
+/// Weights for pallet_clock using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {}
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Clock Jobs (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `set_job` benchmark.
+    fn set_job() -> Weight {
+        Weight::from_parts(16_240_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock Jobs (r:1 w:1)
+    /// Storage: Clock JobStatuses (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `remove_job` benchmark.
+    fn remove_job() -> Weight {
+        Weight::from_parts(15_870_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
 
 // For backwards compatibility and tests
-impl WeightInfo for () {}
+impl WeightInfo for () {
+    /// Storage: Clock Jobs (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `set_job` benchmark.
+    fn set_job() -> Weight {
+        Weight::from_parts(16_240_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock Jobs (r:1 w:1)
+    /// Storage: Clock JobStatuses (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `remove_job` benchmark.
+    fn remove_job() -> Weight {
+        Weight::from_parts(15_870_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Config, Determinism, Origin, Schedule};
+use crate::{CollectEvents, Config, DebugInfo, Determinism, Origin, Pallet, Schedule};
 use codec::{Decode, Encode, MaxEncodedLen};
 
 use frame_support::{dispatch::RawOrigin, pallet_prelude::Weight};
@@ -15,19 +15,25 @@ use sp_runtime::{
 
 use sp_std::vec::Vec;
 use t3rn_primitives::{
+    portal::PrecompileArgs as PortalPrecompileArgs,
     threevm::{
-        GetState, ModuleOperations, Precompile, PrecompileArgs, PrecompileInvocation, ThreeVm,
+        ContractVm, GetState, InvokeXtxCallback, ModuleOperations, Precompile, PrecompileArgs,
+        PrecompileInvocation, ThreeVm, XtxCallback, XtxCallbackFailure,
     },
     SpeedMode,
 };
 use t3rn_sdk_primitives::{
-    signal::ExecutionSignal, state::SideEffects, GET_STATE_FUNCTION_CODE,
-    POST_SIGNAL_FUNCTION_CODE, SUBMIT_FUNCTION_CODE,
+    portal::{PortalRead, PortalVerify},
+    signal::ExecutionSignal,
+    state::SideEffects,
+    ABI_VERSION, ABI_VERSION_FUNCTION_CODE, GET_STATE_FUNCTION_CODE, PORTAL_READ_FUNCTION_CODE,
+    PORTAL_VERIFY_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE, SUBMIT_FUNCTION_CODE,
 };
 
 const CONTRACTS_LOG_TARGET: &str = "runtime::contracts::chain_extension";
 const GET_STATE_LOG_TARGET: &str = "runtime::contracts::get_state";
 const SIGNAL_LOG_TARGET: &str = "runtime::contracts::signal";
+const PORTAL_LOG_TARGET: &str = "runtime::contracts::portal";
 
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ComposableExecReturnValue {
@@ -88,6 +94,37 @@ impl<AccountId, Balance: Default, EventRecord> Contracts<AccountId, Balance, Eve
     }
 }
 
+impl<T: Config> InvokeXtxCallback<T::AccountId> for Pallet<T> {
+    fn invoke_xtx_callback(
+        caller: &T::AccountId,
+        contract: &T::AccountId,
+        input: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure> {
+        let outcome = Pallet::<T>::bare_call(
+            caller.clone(),
+            contract.clone(),
+            Zero::zero(),
+            gas_limit,
+            None,
+            input,
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+            Determinism::Enforced,
+        );
+
+        let consumed = outcome.gas_consumed.min(gas_limit);
+        match outcome.result {
+            Ok(exec_return_value) if !exec_return_value.did_revert() => Ok(consumed),
+            Ok(_) => Err(XtxCallbackFailure {
+                consumed,
+                error: "XtxCallbackReverted".into(),
+            }),
+            Err(error) => Err(XtxCallbackFailure { consumed, error }),
+        }
+    }
+}
+
 // Chain extensions
 use crate::BalanceOf;
 
@@ -168,10 +205,17 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
                     Origin::Root => RawOrigin::Root,
                 };
 
+                // The submitting contract is called back once its xtx resolves
+                let callback = XtxCallback {
+                    contract: env.ext().address().clone(),
+                    vm: ContractVm::Wasm,
+                };
+
                 <C as Config>::ThreeVm::invoke(PrecompileArgs::SubmitSideEffects(
                     C::RuntimeOrigin::from(raw_origin),
                     arg.0,
                     arg.1,
+                    Some(callback),
                 ))?;
                 Ok(RetVal::Converging(0))
             },
@@ -191,6 +235,44 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
                 ))?;
                 Ok(RetVal::Converging(0))
             },
+            ABI_VERSION_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+                env.write(&ABI_VERSION.encode(), false, None)?;
+                Ok(RetVal::Converging(0))
+            },
+            PORTAL_READ_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+
+                let read: PortalRead = read_from_environment(&mut env)?;
+                log::debug!(target: PORTAL_LOG_TARGET, "reading {:?}", read);
+
+                let bytes = invoke_portal::<C>(PortalPrecompileArgs::from(read))?;
+                env.write(&bytes[..], false, None)?;
+
+                Ok(RetVal::Converging(0))
+            },
+            PORTAL_VERIFY_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+
+                // Proofs aren't bounded, so charge for their size before reading them in
+                let in_len = env.in_len();
+                let proof_weight = proof_size_to_weight(env.ext().schedule(), in_len);
+                env.charge_weight(proof_weight)?;
+                let verify = PortalVerify::decode(&mut &env.read(in_len)?[..]).map_err(|e| {
+                    log::error!(target: PORTAL_LOG_TARGET, "decoding proof failed {:?}", e);
+                    DispatchError::from("PortalVerify::DecodingFailed")
+                })?;
+                log::debug!(
+                    target: PORTAL_LOG_TARGET,
+                    "verifying proof of {:?} bytes",
+                    verify.proof().len()
+                );
+
+                let bytes = invoke_portal::<C>(PortalPrecompileArgs::try_from(verify)?)?;
+                env.write(&bytes[..], false, None)?;
+
+                Ok(RetVal::Converging(0))
+            },
             n => {
                 log::error!(
                     target: CONTRACTS_LOG_TARGET,
@@ -229,6 +311,25 @@ fn size_to_weight<T: Encode>(encodable: &T) -> Weight {
     Weight::from_parts(encodable.encoded_size() as u64, Zero::zero())
 }
 
+// Reading a proof in costs as much as contract input, verifying it hashes all of its bytes,
+// and every byte stays in the PoV
+fn proof_size_to_weight<C: Config>(schedule: &Schedule<C>, len: u32) -> Weight {
+    schedule
+        .host_fn_weights
+        .input_per_byte
+        .saturating_add(schedule.host_fn_weights.hash_blake2_256_per_byte)
+        .saturating_mul(len as u64)
+        .saturating_add(Weight::from_parts(Zero::zero(), len as u64))
+}
+
+/// Invokes the portal through 3VM, returning the encoded height, header or inclusion receipt
+fn invoke_portal<C: Config>(args: PortalPrecompileArgs) -> Result<Vec<u8>, DispatchError> {
+    match <C as Config>::ThreeVm::invoke(PrecompileArgs::Portal(args))? {
+        PrecompileInvocation::Portal(execution) => Ok(execution.into()),
+        _ => Err("NoPortalExecutionReturned".into()),
+    }
+}
+
 // Used in src/lib.rs
 pub fn try_instantiate_from_contracts_registry<T: Config>(
     origin: &T::AccountId,
@@ -274,6 +375,7 @@ pub fn try_submit_side_effects<T: Config>(
                 RawOrigin::Signed(caller.clone()).into(),
                 side_effects,
                 speed_mode,
+                None,
             ))
         },
         Err(_) => {
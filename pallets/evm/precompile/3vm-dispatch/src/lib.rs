@@ -2,15 +2,55 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
 use fp_evm::{
     ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
     PrecompileOutput, PrecompileResult,
 };
-use t3rn_primitives::threevm::Precompile;
+use pallet_evm::GasWeightMapping;
+use t3rn_primitives::{
+    threevm::{Precompile, ABI_VERSION, GET_STATE, POST_SIGNAL, SUBMIT},
+    T3rnCodec,
+};
+
+// Selectors of the Solidity interface, `bytes4(keccak256(signature))`
+/// version()
+pub const VERSION_SELECTOR: [u8; 4] = [0x54, 0xfd, 0x4d, 0x50];
+/// getState(bytes32)
+pub const GET_STATE_SELECTOR: [u8; 4] = [0x09, 0x64, 0x8a, 0x9d];
+/// submit(bytes,uint8)
+pub const SUBMIT_SELECTOR: [u8; 4] = [0x68, 0xb8, 0x40, 0xb3];
+/// signal(bytes32,uint32,uint8,uint8)
+pub const SIGNAL_SELECTOR: [u8; 4] = [0x04, 0x7f, 0x31, 0x75];
+
+const SOLIDITY_WORD: usize = 32;
 
-// TODO: build this for the next phase of evm artifacts.
+/// Gas charged per byte of input, as Ethereum charges a non-zero byte of calldata (EIP-2028)
+pub const GAS_PER_INPUT_BYTE: u64 = 16;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Exposes 3VM to EVM contracts via the Solidity interface:
+///
+/// ```solidity
+/// interface ThreeVm {
+///     function version() external view returns (uint32);
+///     function getState(bytes32 xtxId) external view returns (bytes memory);
+///     function submit(bytes calldata sideEffects, uint8 speedMode) external returns (bytes memory);
+///     function signal(bytes32 xtxId, uint32 step, uint8 kind, uint8 killReason) external;
+/// }
+/// ```
+///
+/// `sideEffects` hold the SCALE encoded SDK `SideEffects`, while the returned `bytes`
+/// hold the SCALE encoded `LocalStateExecutionView`. Zeroed `xtxId` stands for a fresh Xtx.
+///
+/// Calls are charged the weight of their 3VM dispatch and their input bytes upfront.
+/// 3VM acts on behalf of the caller, so delegating to the precompile is rejected,
+/// and so is submitting or signalling in a static context.
 pub struct ThreeVmDispatch<T> {
     _marker: PhantomData<T>,
 }
@@ -20,22 +60,84 @@ where
     T: pallet_evm::Config,
 {
     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-        let input = handle.input();
-        let _target_gas = handle.gas_limit();
-        let _context = handle.context();
+        if handle.context().address != handle.code_address() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other(
+                    "cannot be called with DELEGATECALL or CALLCODE".into(),
+                ),
+            })
+        }
 
-        let mut output = Vec::new();
-        T::ThreeVm::invoke_raw(&55_u8, input, &mut output); // TODO: dummy ptr for now
+        let input = handle.input().to_vec();
+        let caller = handle.context().caller;
+        handle.record_cost(GAS_PER_INPUT_BYTE.saturating_mul(input.len() as u64))?;
 
-        if !output.is_empty() {
-            Ok(PrecompileOutput {
-                exit_status: ExitSucceed::Stopped,
-                output,
+        if input.len() < 4 {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("missing selector".into()),
             })
-        } else {
-            Err(PrecompileFailure::Error {
-                exit_status: ExitError::Other("invalid output".into()),
+        }
+        let (selector, args) = input.split_at(4);
+
+        let precompile = match [selector[0], selector[1], selector[2], selector[3]] {
+            VERSION_SELECTOR =>
+                return Ok(PrecompileOutput {
+                    exit_status: ExitSucceed::Returned,
+                    output: encode_uint32(ABI_VERSION),
+                }),
+            GET_STATE_SELECTOR => GET_STATE,
+            SUBMIT_SELECTOR => SUBMIT,
+            SIGNAL_SELECTOR => POST_SIGNAL,
+            _ =>
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other("unknown selector".into()),
+                }),
+        };
+
+        if precompile != GET_STATE && handle.is_static() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("cannot modify state in static context".into()),
             })
         }
+        handle.record_cost(T::GasWeightMapping::weight_to_gas(
+            T::ThreeVm::invoke_raw_weight(&precompile),
+        ))?;
+
+        let restructured_args = [&[T3rnCodec::Rlp.into()][..], caller.as_bytes(), args].concat();
+
+        let mut output = Vec::new();
+        T::ThreeVm::invoke_raw(&precompile, &restructured_args, &mut output);
+
+        match output.split_first() {
+            Some((0, result)) => Ok(PrecompileOutput {
+                exit_status: ExitSucceed::Returned,
+                output: if precompile == POST_SIGNAL {
+                    vec![]
+                } else {
+                    encode_bytes(result)
+                },
+            }),
+            Some(_) => Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("invalid output".into()),
+            }),
+            None => Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Empty buffer".into()),
+            }),
+        }
     }
 }
+
+fn encode_uint32(value: u32) -> Vec<u8> {
+    let mut word = vec![0u8; SOLIDITY_WORD];
+    word[SOLIDITY_WORD - 4..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let padded_len = (bytes.len() + SOLIDITY_WORD - 1) / SOLIDITY_WORD * SOLIDITY_WORD;
+    let mut output = encode_uint32(SOLIDITY_WORD as u32); // offset
+    output.extend(encode_uint32(bytes.len() as u32)); // length
+    output.extend_from_slice(bytes);
+    output.resize(2 * SOLIDITY_WORD + padded_len, 0);
+    output
+}
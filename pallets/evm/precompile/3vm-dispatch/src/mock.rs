@@ -0,0 +1,303 @@
+//! Test mock for unit tests
+
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64, FindAuthor},
+    weights::Weight,
+    ConsensusEngineId,
+};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    DispatchError, DispatchResult,
+};
+use std::{cell::RefCell, str::FromStr};
+
+use fp_evm::{ExitError, ExitReason, Transfer};
+use pallet_evm::{
+    Context, EnsureAddressNever, EnsureAddressRoot, FeeCalculator, IdentityAddressMapping,
+    PrecompileHandle,
+};
+use t3rn_primitives::{
+    account_manager::Outcome,
+    contract_metadata::ContractType,
+    contracts_registry::{AuthorInfo, RegistryContract},
+    threevm::{
+        ModuleOperations, NoopThreeVm, Precompile, PrecompileArgs, PrecompileInvocation,
+        Remunerated, Remuneration, SignalOpcode, ThreeVm,
+    },
+};
+use t3rn_sdk_primitives::signal::{ExecutionSignal, Signaller};
+
+frame_support::construct_runtime! {
+    pub enum Test {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+        EVM: pallet_evm::{Pallet, Call, Storage, Config<T>, Event<T>},
+    }
+}
+
+impl frame_system::Config for Test {
+    type AccountData = pallet_balances::AccountData<u64>;
+    type AccountId = H160;
+    type BaseCallFilter = frame_support::traits::Everything;
+    type Block = frame_system::mocking::MockBlock<Self>;
+    type BlockHashCount = ConstU64<250>;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type Nonce = u64;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type Balance = u64;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<0>;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type MaxHolds = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = ();
+    type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+    type MinimumPeriod = ConstU64<1000>;
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type WeightInfo = ();
+}
+
+pub struct FixedGasPrice;
+impl FeeCalculator for FixedGasPrice {
+    fn min_gas_price() -> (U256, Weight) {
+        (1_000_000_000u128.into(), Weight::from_parts(7u64, 0))
+    }
+}
+
+pub struct FindAuthorTruncated;
+impl FindAuthor<H160> for FindAuthorTruncated {
+    fn find_author<'a, I>(_digests: I) -> Option<H160>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Some(H160::from_str("1234500000000000000000000000000000000000").unwrap())
+    }
+}
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::max_value();
+    pub WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+impl pallet_evm::Config for Test {
+    type AddressMapping = IdentityAddressMapping;
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type CallOrigin = EnsureAddressRoot<Self::AccountId>;
+    type ChainId = ();
+    type Currency = Balances;
+    type FeeCalculator = FixedGasPrice;
+    type FindAuthor = FindAuthorTruncated;
+    type GasLimitPovSizeRatio = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type OnChargeTransaction = ();
+    type OnCreate = ();
+    type PrecompilesType = ();
+    type PrecompilesValue = ();
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type RuntimeEvent = RuntimeEvent;
+    type ThreeVm = MockThreeVm;
+    type Timestamp = Timestamp;
+    type WeightInfo = ();
+    type WeightPerGas = WeightPerGas;
+    type WithdrawOrigin = EnsureAddressNever<Self::AccountId>;
+}
+
+/// Weight of every 3VM dispatch, 100 gas at `WeightPerGas`
+pub const DISPATCH_WEIGHT: Weight = Weight::from_parts(2_000_000, 0);
+
+thread_local! {
+    /// Precompiles invoked through 3VM
+    pub static INVOKED: RefCell<Vec<u8>> = RefCell::new(vec![]);
+}
+
+/// 3VM recording the invoked precompiles, each succeeding with an empty result
+pub struct MockThreeVm;
+
+impl Precompile<Test, u64> for MockThreeVm {
+    fn lookup(dest: &H256) -> Option<u8> {
+        <NoopThreeVm as Precompile<Test, u64>>::lookup(dest)
+    }
+
+    fn invoke_raw(precompile: &u8, _args: &[u8], output: &mut Vec<u8>) {
+        INVOKED.with(|invoked| invoked.borrow_mut().push(*precompile));
+        output.push(0);
+    }
+
+    fn invoke_raw_weight(_precompile: &u8) -> Weight {
+        DISPATCH_WEIGHT
+    }
+
+    fn invoke(
+        args: PrecompileArgs<Test, u64>,
+    ) -> Result<PrecompileInvocation<Test, u64>, DispatchError> {
+        <NoopThreeVm as Precompile<Test, u64>>::invoke(args)
+    }
+}
+
+impl Signaller<H256> for MockThreeVm {
+    type Result = Result<SignalOpcode, DispatchError>;
+
+    fn signal(signal: &ExecutionSignal<H256>) -> Self::Result {
+        <NoopThreeVm as Signaller<H256>>::signal(signal)
+    }
+}
+
+impl Remuneration<Test, u64> for MockThreeVm {
+    fn try_remunerate<Module: ModuleOperations<Test, u64>>(
+        payee: &H160,
+        module: &Module,
+    ) -> Result<Remunerated<H256>, DispatchError> {
+        <NoopThreeVm as Remuneration<Test, u64>>::try_remunerate(payee, module)
+    }
+
+    fn try_remunerate_exact<Module: ModuleOperations<Test, u64>>(
+        payee: &H160,
+        amount: u64,
+        module: &Module,
+    ) -> Result<Remunerated<H256>, DispatchError> {
+        <NoopThreeVm as Remuneration<Test, u64>>::try_remunerate_exact(payee, amount, module)
+    }
+
+    fn try_finalize(ledger_id: H256, outcome: Outcome) -> DispatchResult {
+        <NoopThreeVm as Remuneration<Test, u64>>::try_finalize(ledger_id, outcome)
+    }
+}
+
+impl ThreeVm<Test, u64> for MockThreeVm {
+    fn peek_registry(id: &H256) -> Result<RegistryContract<H256, H160, u64, u64>, DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::peek_registry(id)
+    }
+
+    fn from_registry<Module, ModuleGen>(
+        id: &H256,
+        module_generator: ModuleGen,
+    ) -> Result<Module, DispatchError>
+    where
+        Module: ModuleOperations<Test, u64>,
+        ModuleGen: Fn(Vec<u8>) -> Module,
+    {
+        <NoopThreeVm as ThreeVm<Test, u64>>::from_registry(id, module_generator)
+    }
+
+    fn instantiate_check(kind: &ContractType) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::instantiate_check(kind)
+    }
+
+    fn storage_check(kind: &ContractType) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::storage_check(kind)
+    }
+
+    fn volatile_check(kind: &ContractType) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::volatile_check(kind)
+    }
+
+    fn remunerable_check(kind: &ContractType) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::remunerable_check(kind)
+    }
+
+    fn try_persist_author(
+        contract: &H160,
+        author: Option<&AuthorInfo<H160, u64>>,
+    ) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::try_persist_author(contract, author)
+    }
+
+    fn try_remove_author(contract: &H160) -> Result<(), DispatchError> {
+        <NoopThreeVm as ThreeVm<Test, u64>>::try_remove_author(contract)
+    }
+}
+
+pub(crate) struct MockHandle {
+    pub input: Vec<u8>,
+    pub context: Context,
+    pub code_address: H160,
+    pub is_static: bool,
+    pub gas_used: u64,
+}
+
+impl PrecompileHandle for MockHandle {
+    fn call(
+        &mut self,
+        _: H160,
+        _: Option<Transfer>,
+        _: Vec<u8>,
+        _: Option<u64>,
+        _: bool,
+        _: &Context,
+    ) -> (ExitReason, Vec<u8>) {
+        unimplemented!()
+    }
+
+    fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+        self.gas_used += cost;
+        Ok(())
+    }
+
+    fn record_external_cost(
+        &mut self,
+        _ref_time: Option<u64>,
+        _proof_size: Option<u64>,
+    ) -> Result<(), ExitError> {
+        Ok(())
+    }
+
+    fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
+
+    fn remaining_gas(&self) -> u64 {
+        unimplemented!()
+    }
+
+    fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
+        unimplemented!()
+    }
+
+    fn code_address(&self) -> H160 {
+        self.code_address
+    }
+
+    fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    fn context(&self) -> &Context {
+        &self.context
+    }
+
+    fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        None
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::mock::*;
+
+use fp_evm::Context;
+use sp_core::{H160, U256};
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(41)
+}
+
+fn alice() -> H160 {
+    H160::from_low_u64_be(1)
+}
+
+fn handle(selector: [u8; 4], args: &[u8]) -> MockHandle {
+    MockHandle {
+        input: [&selector[..], args].concat(),
+        context: Context {
+            address: precompile_address(),
+            caller: alice(),
+            apparent_value: U256::zero(),
+        },
+        code_address: precompile_address(),
+        is_static: false,
+        gas_used: 0,
+    }
+}
+
+fn execute(handle: &mut MockHandle) -> PrecompileResult {
+    INVOKED.with(|invoked| invoked.borrow_mut().clear());
+    ThreeVmDispatch::<Test>::execute(handle)
+}
+
+fn invoked() -> Vec<u8> {
+    INVOKED.with(|invoked| invoked.borrow().clone())
+}
+
+fn error(reason: &'static str) -> PrecompileFailure {
+    PrecompileFailure::Error {
+        exit_status: ExitError::Other(reason.into()),
+    }
+}
+
+#[test]
+fn dispatch_is_charged_its_weight_and_input_bytes() {
+    let side_effects = [7u8; 100];
+    let mut submit = handle(SUBMIT_SELECTOR, &side_effects);
+
+    assert!(execute(&mut submit).is_ok());
+    assert_eq!(invoked(), vec![SUBMIT]);
+    assert_eq!(
+        submit.gas_used,
+        GAS_PER_INPUT_BYTE * 104
+            + <Test as pallet_evm::Config>::GasWeightMapping::weight_to_gas(DISPATCH_WEIGHT)
+    );
+    assert_eq!(
+        <Test as pallet_evm::Config>::GasWeightMapping::weight_to_gas(DISPATCH_WEIGHT),
+        100
+    );
+}
+
+#[test]
+fn submit_and_signal_in_static_context_are_rejected() {
+    for selector in [SUBMIT_SELECTOR, SIGNAL_SELECTOR] {
+        let mut call = handle(selector, &[0u8; 128]);
+        call.is_static = true;
+
+        assert_eq!(
+            execute(&mut call),
+            Err(error("cannot modify state in static context"))
+        );
+        assert!(invoked().is_empty());
+    }
+
+    // Reading the state stays available to views
+    let mut get_state = handle(GET_STATE_SELECTOR, &[0u8; 32]);
+    get_state.is_static = true;
+    assert!(execute(&mut get_state).is_ok());
+    assert_eq!(invoked(), vec![GET_STATE]);
+}
+
+#[test]
+fn delegatecall_is_rejected() {
+    // A contract delegating to the precompile runs in its own context, with its caller as the caller
+    let mut submit = handle(SUBMIT_SELECTOR, &[7u8; 100]);
+    submit.context.address = H160::from_low_u64_be(2);
+
+    assert_eq!(
+        execute(&mut submit),
+        Err(error("cannot be called with DELEGATECALL or CALLCODE"))
+    );
+    assert!(invoked().is_empty());
+    assert_eq!(submit.gas_used, 0);
+}
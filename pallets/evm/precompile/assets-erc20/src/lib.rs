@@ -0,0 +1,395 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{
+    ExitRevert, ExitSucceed, IsPrecompileResult, PrecompileFailure, PrecompileHandle,
+    PrecompileOutput, PrecompileResult, PrecompileSet,
+};
+use frame_support::{
+    storage::with_storage_layer,
+    traits::{
+        fungibles::{approvals, metadata, Inspect, Mutate},
+        tokens::Preservation,
+        Get,
+    },
+};
+use hex_literal::hex;
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::StaticLookup, DispatchError, DispatchResult};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+pub use t3rn_primitives::threevm::Erc20Mapping;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// Selectors of the ERC-20 interface, `bytes4(keccak256(signature))`
+/// totalSupply()
+pub const TOTAL_SUPPLY_SELECTOR: [u8; 4] = [0x18, 0x16, 0x0d, 0xdd];
+/// balanceOf(address)
+pub const BALANCE_OF_SELECTOR: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+/// allowance(address,address)
+pub const ALLOWANCE_SELECTOR: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e];
+/// transfer(address,uint256)
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+/// approve(address,uint256)
+pub const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+/// transferFrom(address,address,uint256)
+pub const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+/// name()
+pub const NAME_SELECTOR: [u8; 4] = [0x06, 0xfd, 0xde, 0x03];
+/// symbol()
+pub const SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+/// decimals()
+pub const DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+
+/// Transfer(address,address,uint256)
+pub const TRANSFER_TOPIC: H256 = H256(hex!(
+    "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+));
+/// Approval(address,address,uint256)
+pub const APPROVAL_TOPIC: H256 = H256(hex!(
+    "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+));
+
+/// Error(string), used to encode revert reasons the way Solidity does
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+const SOLIDITY_WORD: usize = 32;
+
+// Ethereum gas schedule for LOG opcodes
+const LOG_GAS: u64 = 375;
+const LOG_TOPIC_GAS: u64 = 375;
+const LOG_DATA_GAS: u64 = 8;
+
+type Assets<R, I> = pallet_assets::Pallet<R, I>;
+type AssetIdOf<R, I> = <R as pallet_assets::Config<I>>::AssetId;
+type BalanceOf<R, I> = <R as pallet_assets::Config<I>>::Balance;
+type AccountIdOf<R> = <R as frame_system::Config>::AccountId;
+
+/// Exposes every asset of `pallet_assets` instance `I` at the address the runtime's
+/// `Erc20Mapping` encodes its id to, via the ERC-20 interface:
+///
+/// ```solidity
+/// interface IERC20 {
+///     function name() external view returns (string memory);
+///     function symbol() external view returns (string memory);
+///     function decimals() external view returns (uint8);
+///     function totalSupply() external view returns (uint256);
+///     function balanceOf(address who) external view returns (uint256);
+///     function allowance(address owner, address spender) external view returns (uint256);
+///     function transfer(address to, uint256 value) external returns (bool);
+///     function approve(address spender, uint256 value) external returns (bool);
+///     function transferFrom(address from, address to, uint256 value) external returns (bool);
+/// }
+/// ```
+///
+/// H160 addresses are resolved to accounts through `pallet_evm::Config::AddressMapping`.
+/// Addresses `Erc20Mapping` doesn't decode, or of assets that don't exist, are not precompiles.
+pub struct Erc20AssetsPrecompileSet<R, I = ()>(PhantomData<(R, I)>);
+
+impl<R, I> Erc20AssetsPrecompileSet<R, I> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R, I> Default for Erc20AssetsPrecompileSet<R, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, I: 'static> PrecompileSet for Erc20AssetsPrecompileSet<R, I>
+where
+    R: pallet_evm::Config + pallet_assets::Config<I> + Erc20Mapping,
+    AssetIdOf<R, I>: From<u32>,
+    BalanceOf<R, I>: Into<U256> + TryFrom<U256>,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        let asset_id: AssetIdOf<R, I> = R::decode_evm_address(handle.code_address())?.into();
+        if !<Assets<R, I> as Inspect<_>>::asset_exists(asset_id.clone()) {
+            return None
+        }
+
+        if let Err(failure) = ensure_direct_call(handle) {
+            return Some(Err(failure))
+        }
+
+        Some(Self::execute_erc20(asset_id, handle))
+    }
+
+    fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+        IsPrecompileResult::Answer {
+            is_precompile: R::decode_evm_address(address)
+                .map(|asset_id| <Assets<R, I> as Inspect<_>>::asset_exists(asset_id.into()))
+                .unwrap_or(false),
+            extra_cost: R::GasWeightMapping::weight_to_gas(
+                <R as frame_system::Config>::DbWeight::get().reads(1),
+            ),
+        }
+    }
+}
+
+impl<R, I: 'static> Erc20AssetsPrecompileSet<R, I>
+where
+    R: pallet_evm::Config + pallet_assets::Config<I> + Erc20Mapping,
+    AssetIdOf<R, I>: From<u32>,
+    BalanceOf<R, I>: Into<U256> + TryFrom<U256>,
+{
+    fn execute_erc20(
+        asset_id: AssetIdOf<R, I>,
+        handle: &mut impl PrecompileHandle,
+    ) -> PrecompileResult {
+        // Asset existence check
+        record_db_cost::<R>(handle, 1, 0)?;
+
+        let input = handle.input().to_vec();
+        if input.len() < 4 {
+            return Err(revert("missing selector"))
+        }
+        let (selector, args) = input.split_at(4);
+
+        let output = match [selector[0], selector[1], selector[2], selector[3]] {
+            TOTAL_SUPPLY_SELECTOR => {
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_u256(<Assets<R, I> as Inspect<_>>::total_issuance(asset_id).into())
+            },
+            BALANCE_OF_SELECTOR => {
+                let who = R::AddressMapping::into_account_id(read_address(args, 0)?);
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_u256(<Assets<R, I> as Inspect<_>>::balance(asset_id, &who).into())
+            },
+            ALLOWANCE_SELECTOR => {
+                let owner = R::AddressMapping::into_account_id(read_address(args, 0)?);
+                let spender = R::AddressMapping::into_account_id(read_address(args, 1)?);
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_u256(
+                    <Assets<R, I> as approvals::Inspect<_>>::allowance(asset_id, &owner, &spender)
+                        .into(),
+                )
+            },
+            NAME_SELECTOR => {
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_string(&<Assets<R, I> as metadata::Inspect<_>>::name(asset_id))
+            },
+            SYMBOL_SELECTOR => {
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_string(&<Assets<R, I> as metadata::Inspect<_>>::symbol(asset_id))
+            },
+            DECIMALS_SELECTOR => {
+                record_db_cost::<R>(handle, 1, 0)?;
+                encode_u256(<Assets<R, I> as metadata::Inspect<_>>::decimals(asset_id).into())
+            },
+            TRANSFER_SELECTOR => {
+                ensure_mutable(handle)?;
+                let from = handle.context().caller;
+                let to = read_address(args, 0)?;
+                let value = read_u256(args, 1)?;
+                record_db_cost::<R>(handle, 3, 2)?;
+                record_log_cost(handle, 3)?;
+
+                <Assets<R, I> as Mutate<_>>::transfer(
+                    asset_id,
+                    &R::AddressMapping::into_account_id(from),
+                    &R::AddressMapping::into_account_id(to),
+                    into_balance::<R, I>(value)?,
+                    Preservation::Expendable,
+                )
+                .map_err(dispatch_revert)?;
+
+                log_transfer(handle, from, to, value)?;
+                encode_bool(true)
+            },
+            APPROVE_SELECTOR => {
+                ensure_mutable(handle)?;
+                let owner = handle.context().caller;
+                let spender = read_address(args, 0)?;
+                let value = read_u256(args, 1)?;
+                record_db_cost::<R>(handle, 3, 2)?;
+                record_log_cost(handle, 3)?;
+
+                Self::set_allowance(
+                    asset_id,
+                    R::AddressMapping::into_account_id(owner),
+                    R::AddressMapping::into_account_id(spender),
+                    into_balance::<R, I>(value)?,
+                )?;
+
+                let address = handle.code_address();
+                handle.log(
+                    address,
+                    vec![APPROVAL_TOPIC, owner.into(), spender.into()],
+                    encode_u256(value),
+                )?;
+                encode_bool(true)
+            },
+            TRANSFER_FROM_SELECTOR => {
+                ensure_mutable(handle)?;
+                let spender = handle.context().caller;
+                let from = read_address(args, 0)?;
+                let to = read_address(args, 1)?;
+                let value = read_u256(args, 2)?;
+                record_db_cost::<R>(handle, 4, 3)?;
+                record_log_cost(handle, 3)?;
+
+                <Assets<R, I> as approvals::Mutate<_>>::transfer_from(
+                    asset_id,
+                    &R::AddressMapping::into_account_id(from),
+                    &R::AddressMapping::into_account_id(spender),
+                    &R::AddressMapping::into_account_id(to),
+                    into_balance::<R, I>(value)?,
+                )
+                .map_err(dispatch_revert)?;
+
+                log_transfer(handle, from, to, value)?;
+                encode_bool(true)
+            },
+            _ => return Err(revert("unknown selector")),
+        };
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+
+    /// ERC-20 `approve` overwrites the allowance, while `pallet_assets` adds up approvals,
+    /// so the existing approval is cancelled first - in one storage layer, so that a failed
+    /// approval doesn't leave the previous one cancelled.
+    fn set_allowance(
+        asset_id: AssetIdOf<R, I>,
+        owner: AccountIdOf<R>,
+        spender: AccountIdOf<R>,
+        value: BalanceOf<R, I>,
+    ) -> Result<(), PrecompileFailure> {
+        with_storage_layer(|| -> DispatchResult {
+            let current = <Assets<R, I> as approvals::Inspect<_>>::allowance(
+                asset_id.clone(),
+                &owner,
+                &spender,
+            );
+            if current != Default::default() {
+                Assets::<R, I>::cancel_approval(
+                    frame_system::RawOrigin::Signed(owner.clone()).into(),
+                    asset_id.clone().into(),
+                    R::Lookup::unlookup(spender.clone()),
+                )?;
+            }
+            if value != Default::default() {
+                <Assets<R, I> as approvals::Mutate<_>>::approve(asset_id, &owner, &spender, value)?;
+            }
+            Ok(())
+        })
+        .map_err(dispatch_revert)
+    }
+}
+
+fn record_db_cost<R: pallet_evm::Config>(
+    handle: &mut impl PrecompileHandle,
+    reads: u64,
+    writes: u64,
+) -> Result<(), PrecompileFailure> {
+    handle.record_cost(R::GasWeightMapping::weight_to_gas(
+        <R as frame_system::Config>::DbWeight::get().reads_writes(reads, writes),
+    ))?;
+    Ok(())
+}
+
+fn record_log_cost(
+    handle: &mut impl PrecompileHandle,
+    topics: u64,
+) -> Result<(), PrecompileFailure> {
+    handle.record_cost(LOG_GAS + LOG_TOPIC_GAS * topics + LOG_DATA_GAS * SOLIDITY_WORD as u64)?;
+    Ok(())
+}
+
+fn log_transfer(
+    handle: &mut impl PrecompileHandle,
+    from: H160,
+    to: H160,
+    value: U256,
+) -> Result<(), PrecompileFailure> {
+    let address = handle.code_address();
+    handle.log(
+        address,
+        vec![TRANSFER_TOPIC, from.into(), to.into()],
+        encode_u256(value),
+    )?;
+    Ok(())
+}
+
+// The caller owns the tokens of the asset it called - delegating to the asset would let
+// a contract move the tokens of its own caller. Nothing is payable either.
+fn ensure_direct_call(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+    if handle.context().address != handle.code_address() {
+        return Err(revert("cannot be called with DELEGATECALL or CALLCODE"))
+    }
+    if !handle.context().apparent_value.is_zero() {
+        return Err(revert("function is not payable"))
+    }
+    Ok(())
+}
+
+fn ensure_mutable(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+    if handle.is_static() {
+        return Err(revert("cannot modify state in static context"))
+    }
+    Ok(())
+}
+
+fn into_balance<R, I: 'static>(value: U256) -> Result<BalanceOf<R, I>, PrecompileFailure>
+where
+    R: pallet_assets::Config<I>,
+    BalanceOf<R, I>: TryFrom<U256>,
+{
+    BalanceOf::<R, I>::try_from(value).map_err(|_| revert("value out of bounds"))
+}
+
+fn read_word(args: &[u8], index: usize) -> Result<&[u8], PrecompileFailure> {
+    args.get(index * SOLIDITY_WORD..(index + 1) * SOLIDITY_WORD)
+        .ok_or_else(|| revert("input too short"))
+}
+
+fn read_address(args: &[u8], index: usize) -> Result<H160, PrecompileFailure> {
+    let word = read_word(args, index)?;
+    if word[..SOLIDITY_WORD - 20].iter().any(|b| *b != 0) {
+        return Err(revert("invalid address"))
+    }
+    Ok(H160::from_slice(&word[SOLIDITY_WORD - 20..]))
+}
+
+fn read_u256(args: &[u8], index: usize) -> Result<U256, PrecompileFailure> {
+    Ok(U256::from_big_endian(read_word(args, index)?))
+}
+
+fn encode_u256(value: U256) -> Vec<u8> {
+    let mut word = vec![0u8; SOLIDITY_WORD];
+    value.to_big_endian(&mut word);
+    word
+}
+
+fn encode_bool(value: bool) -> Vec<u8> {
+    encode_u256(U256::from(value as u8))
+}
+
+fn encode_string(bytes: &[u8]) -> Vec<u8> {
+    let padded_len = (bytes.len() + SOLIDITY_WORD - 1) / SOLIDITY_WORD * SOLIDITY_WORD;
+    let mut output = encode_u256(U256::from(SOLIDITY_WORD)); // offset
+    output.extend(encode_u256(U256::from(bytes.len()))); // length
+    output.extend_from_slice(bytes);
+    output.resize(2 * SOLIDITY_WORD + padded_len, 0);
+    output
+}
+
+fn revert(reason: &str) -> PrecompileFailure {
+    PrecompileFailure::Revert {
+        exit_status: ExitRevert::Reverted,
+        output: [&ERROR_SELECTOR[..], &encode_string(reason.as_bytes())].concat(),
+    }
+}
+
+fn dispatch_revert(error: DispatchError) -> PrecompileFailure {
+    revert(error.into())
+}
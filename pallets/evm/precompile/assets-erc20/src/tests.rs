@@ -0,0 +1,345 @@
+use super::*;
+use crate::mock::*;
+
+use fp_evm::Context;
+use sp_core::{H160, U256};
+use sp_runtime::BuildStorage;
+
+const ASSET_ID: u32 = 1;
+const MISSING_ASSET_ID: u32 = 2;
+
+fn alice() -> H160 {
+    H160::from_low_u64_be(1)
+}
+
+fn bob() -> H160 {
+    H160::from_low_u64_be(2)
+}
+
+fn charlie() -> H160 {
+    H160::from_low_u64_be(3)
+}
+
+fn asset_id_to_address(asset_id: u32) -> H160 {
+    <Test as Erc20Mapping>::encode_evm_address(asset_id).expect("every asset id has an address")
+}
+
+fn address_to_asset_id(address: H160) -> Option<u32> {
+    <Test as Erc20Mapping>::decode_evm_address(address)
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(alice(), 1_000), (bob(), 1_000)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(ASSET_ID, alice(), true, 1)],
+        metadata: vec![(ASSET_ID, b"Tether USD".to_vec(), b"USDT".to_vec(), 6)],
+        accounts: vec![(ASSET_ID, alice(), 500)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    storage.into()
+}
+
+fn handle(caller: H160, asset_id: u32, input: Vec<u8>) -> MockHandle {
+    MockHandle {
+        input,
+        context: Context {
+            address: asset_id_to_address(asset_id),
+            caller,
+            apparent_value: U256::zero(),
+        },
+        code_address: asset_id_to_address(asset_id),
+        is_static: false,
+        logs: vec![],
+    }
+}
+
+fn call(selector: [u8; 4], args: &[U256]) -> Vec<u8> {
+    let mut input = selector.to_vec();
+    for arg in args {
+        input.extend(encode_u256(*arg));
+    }
+    input
+}
+
+fn address_arg(address: H160) -> U256 {
+    U256::from_big_endian(address.as_bytes())
+}
+
+fn execute(handle: &mut MockHandle) -> PrecompileResult {
+    Erc20AssetsPrecompileSet::<Test>::new()
+        .execute(handle)
+        .expect("asset precompile exists")
+}
+
+fn balance_of(who: H160) -> U256 {
+    let mut handle = handle(
+        who,
+        ASSET_ID,
+        call(BALANCE_OF_SELECTOR, &[address_arg(who)]),
+    );
+    U256::from_big_endian(&execute(&mut handle).unwrap().output)
+}
+
+fn allowance(owner: H160, spender: H160) -> U256 {
+    let mut handle = handle(
+        owner,
+        ASSET_ID,
+        call(
+            ALLOWANCE_SELECTOR,
+            &[address_arg(owner), address_arg(spender)],
+        ),
+    );
+    U256::from_big_endian(&execute(&mut handle).unwrap().output)
+}
+
+#[test]
+fn asset_address_roundtrips() {
+    let address = asset_id_to_address(0x0102_0304);
+
+    assert_eq!(
+        address,
+        H160(hex!("ffffffff00000000000000000000000001020304"))
+    );
+    assert_eq!(address_to_asset_id(address), Some(0x0102_0304));
+    assert_eq!(address_to_asset_id(alice()), None);
+    assert_eq!(
+        address_to_asset_id(H160(hex!("ffffffff00000000000000010000000001020304"))),
+        None
+    );
+}
+
+#[test]
+fn only_existing_assets_are_precompiles() {
+    new_test_ext().execute_with(|| {
+        let precompiles = Erc20AssetsPrecompileSet::<Test>::new();
+
+        assert!(matches!(
+            precompiles.is_precompile(asset_id_to_address(ASSET_ID), 0),
+            IsPrecompileResult::Answer {
+                is_precompile: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            precompiles.is_precompile(asset_id_to_address(MISSING_ASSET_ID), 0),
+            IsPrecompileResult::Answer {
+                is_precompile: false,
+                ..
+            }
+        ));
+
+        let mut missing = handle(alice(), MISSING_ASSET_ID, call(TOTAL_SUPPLY_SELECTOR, &[]));
+        assert!(precompiles.execute(&mut missing).is_none());
+    });
+}
+
+#[test]
+fn reads_metadata_and_supply() {
+    new_test_ext().execute_with(|| {
+        let mut name = handle(alice(), ASSET_ID, call(NAME_SELECTOR, &[]));
+        assert_eq!(
+            execute(&mut name).unwrap().output,
+            encode_string(b"Tether USD")
+        );
+
+        let mut symbol = handle(alice(), ASSET_ID, call(SYMBOL_SELECTOR, &[]));
+        assert_eq!(execute(&mut symbol).unwrap().output, encode_string(b"USDT"));
+
+        let mut decimals = handle(alice(), ASSET_ID, call(DECIMALS_SELECTOR, &[]));
+        assert_eq!(
+            execute(&mut decimals).unwrap().output,
+            encode_u256(6.into())
+        );
+
+        let mut supply = handle(alice(), ASSET_ID, call(TOTAL_SUPPLY_SELECTOR, &[]));
+        assert_eq!(
+            execute(&mut supply).unwrap().output,
+            encode_u256(500.into())
+        );
+
+        assert_eq!(balance_of(alice()), 500.into());
+        assert_eq!(balance_of(bob()), 0.into());
+    });
+}
+
+#[test]
+fn transfer_moves_balance_and_logs() {
+    new_test_ext().execute_with(|| {
+        let mut transfer = handle(
+            alice(),
+            ASSET_ID,
+            call(TRANSFER_SELECTOR, &[address_arg(bob()), 200.into()]),
+        );
+
+        assert_eq!(execute(&mut transfer).unwrap().output, encode_bool(true));
+        assert_eq!(balance_of(alice()), 300.into());
+        assert_eq!(balance_of(bob()), 200.into());
+        assert_eq!(
+            transfer.logs,
+            vec![(
+                asset_id_to_address(ASSET_ID),
+                vec![TRANSFER_TOPIC, alice().into(), bob().into()],
+                encode_u256(200.into()),
+            )]
+        );
+    });
+}
+
+#[test]
+fn transfer_above_balance_reverts() {
+    new_test_ext().execute_with(|| {
+        let mut transfer = handle(
+            bob(),
+            ASSET_ID,
+            call(TRANSFER_SELECTOR, &[address_arg(alice()), 1.into()]),
+        );
+
+        assert!(matches!(
+            execute(&mut transfer),
+            Err(PrecompileFailure::Revert { .. })
+        ));
+        assert!(transfer.logs.is_empty());
+    });
+}
+
+#[test]
+fn transfer_in_static_context_reverts() {
+    new_test_ext().execute_with(|| {
+        let mut transfer = handle(
+            alice(),
+            ASSET_ID,
+            call(TRANSFER_SELECTOR, &[address_arg(bob()), 1.into()]),
+        );
+        transfer.is_static = true;
+
+        assert_eq!(
+            execute(&mut transfer),
+            Err(revert("cannot modify state in static context"))
+        );
+        assert_eq!(balance_of(bob()), 0.into());
+    });
+}
+
+#[test]
+fn transfer_through_delegatecall_reverts() {
+    new_test_ext().execute_with(|| {
+        // A contract delegating to the asset runs in its own context, with its caller as the caller
+        let mut transfer = handle(
+            alice(),
+            ASSET_ID,
+            call(TRANSFER_SELECTOR, &[address_arg(charlie()), 1.into()]),
+        );
+        transfer.context.address = bob();
+
+        assert_eq!(
+            execute(&mut transfer),
+            Err(revert("cannot be called with DELEGATECALL or CALLCODE"))
+        );
+        assert_eq!(balance_of(alice()), 500.into());
+        assert_eq!(balance_of(charlie()), 0.into());
+    });
+}
+
+#[test]
+fn call_with_value_reverts() {
+    new_test_ext().execute_with(|| {
+        let mut transfer = handle(
+            alice(),
+            ASSET_ID,
+            call(TRANSFER_SELECTOR, &[address_arg(bob()), 1.into()]),
+        );
+        transfer.context.apparent_value = 1.into();
+
+        assert_eq!(
+            execute(&mut transfer),
+            Err(revert("function is not payable"))
+        );
+        assert_eq!(balance_of(bob()), 0.into());
+    });
+}
+
+#[test]
+fn approve_overwrites_allowance_and_logs() {
+    new_test_ext().execute_with(|| {
+        for value in [100u64, 40] {
+            let mut approve = handle(
+                alice(),
+                ASSET_ID,
+                call(APPROVE_SELECTOR, &[address_arg(bob()), value.into()]),
+            );
+
+            assert_eq!(execute(&mut approve).unwrap().output, encode_bool(true));
+            assert_eq!(
+                approve.logs,
+                vec![(
+                    asset_id_to_address(ASSET_ID),
+                    vec![APPROVAL_TOPIC, alice().into(), bob().into()],
+                    encode_u256(value.into()),
+                )]
+            );
+        }
+
+        assert_eq!(allowance(alice(), bob()), 40.into());
+    });
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+    new_test_ext().execute_with(|| {
+        let mut approve = handle(
+            alice(),
+            ASSET_ID,
+            call(APPROVE_SELECTOR, &[address_arg(bob()), 100.into()]),
+        );
+        execute(&mut approve).unwrap();
+
+        let mut transfer_from = handle(
+            bob(),
+            ASSET_ID,
+            call(
+                TRANSFER_FROM_SELECTOR,
+                &[address_arg(alice()), address_arg(charlie()), 60.into()],
+            ),
+        );
+
+        assert_eq!(
+            execute(&mut transfer_from).unwrap().output,
+            encode_bool(true)
+        );
+        assert_eq!(balance_of(alice()), 440.into());
+        assert_eq!(balance_of(charlie()), 60.into());
+        assert_eq!(allowance(alice(), bob()), 40.into());
+        assert_eq!(
+            transfer_from.logs,
+            vec![(
+                asset_id_to_address(ASSET_ID),
+                vec![TRANSFER_TOPIC, alice().into(), charlie().into()],
+                encode_u256(60.into()),
+            )]
+        );
+
+        let mut overspend = handle(
+            bob(),
+            ASSET_ID,
+            call(
+                TRANSFER_FROM_SELECTOR,
+                &[address_arg(alice()), address_arg(charlie()), 41.into()],
+            ),
+        );
+        assert!(matches!(
+            execute(&mut overspend),
+            Err(PrecompileFailure::Revert { .. })
+        ));
+    });
+}
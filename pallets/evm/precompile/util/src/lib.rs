@@ -6,13 +6,15 @@ pub use pallet_3vm_evm_primitives::{
 };
 
 use pallet_3vm_evm_primitives::{ExitError, PrecompileFailure};
+use pallet_evm_precompile_3vm_dispatch::ThreeVmDispatch;
+pub use pallet_evm_precompile_assets_erc20::{Erc20AssetsPrecompileSet, Erc20Mapping};
 pub use pallet_evm_precompile_modexp::Modexp;
 pub use pallet_evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
 pub use pallet_evm_precompile_simple::{
     ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256,
 };
 use portal_precompile::PortalPrecompile;
-use sp_core::H160;
+use sp_core::{H160, U256};
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
 pub enum KnownPrecompile<T: pallet_3vm_evm::Config> {
     // Ethereum precompiles:
@@ -27,6 +29,7 @@ pub enum KnownPrecompile<T: pallet_3vm_evm::Config> {
     ECRecoverPublicKey,
     // T3rn precompiles:
     Portal,
+    ThreeVm,
     Noop(T),
 }
 
@@ -45,6 +48,7 @@ impl<T: pallet_3vm_evm::Config> KnownPrecompile<T> {
             KnownPrecompile::ECRecoverPublicKey =>
                 <ECRecoverPublicKey as Precompile>::execute(handle),
             KnownPrecompile::Portal => PortalPrecompile::<T>::execute(handle),
+            KnownPrecompile::ThreeVm => ThreeVmDispatch::<T>::execute(handle),
             KnownPrecompile::Noop(_) => PrecompileResult::Err(PrecompileFailure::from(
                 ExitError::Other("Noop precompile".into()),
             )),
@@ -91,6 +95,45 @@ impl<T: pallet_3vm_evm::Config> PrecompileSet for Precompiles<T> {
     }
 }
 
+/// Known precompiles at fixed addresses, falling back to the ERC-20 adapters
+/// of `pallet_assets` tokens at the addresses of the runtime's `Erc20Mapping`.
+pub struct PrecompilesWithAssets<T: pallet_3vm_evm::Config> {
+    pub known: Precompiles<T>,
+    pub assets: Erc20AssetsPrecompileSet<T>,
+}
+
+impl<T: pallet_3vm_evm::Config> PrecompilesWithAssets<T> {
+    pub fn new(known: Precompiles<T>) -> Self {
+        Self {
+            known,
+            assets: Erc20AssetsPrecompileSet::new(),
+        }
+    }
+}
+
+impl<T> PrecompileSet for PrecompilesWithAssets<T>
+where
+    T: pallet_3vm_evm::Config + pallet_assets::Config + Erc20Mapping,
+    <T as pallet_assets::Config>::AssetId: From<u32>,
+    <T as pallet_assets::Config>::Balance: Into<U256> + TryFrom<U256>,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        self.known
+            .execute(handle)
+            .or_else(|| self.assets.execute(handle))
+    }
+
+    fn is_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult {
+        match self.known.is_precompile(address, gas) {
+            IsPrecompileResult::Answer {
+                is_precompile: false,
+                ..
+            } => self.assets.is_precompile(address, gas),
+            known => known,
+        }
+    }
+}
+
 fn hash(a: &u64) -> H160 {
     H160::from_low_u64_be(*a)
 }
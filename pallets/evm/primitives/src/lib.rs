@@ -57,6 +57,26 @@ pub struct Vicinity {
     pub origin: H160,
 }
 
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Outcome of a signed Ethereum transaction executed in a block.
+pub struct TransactionStatus {
+    /// Keccak hash of the signed transaction.
+    pub transaction_hash: H256,
+    /// Index of the extrinsic executing the transaction.
+    pub transaction_index: u32,
+    /// Signer of the transaction.
+    pub from: H160,
+    /// Called address, `None` for contract creations.
+    pub to: Option<H160>,
+    /// Address of the created contract.
+    pub contract_address: Option<H160>,
+    /// Gas used by the execution.
+    pub used_gas: U256,
+    /// Whether the execution succeeded, `false` if it reverted or failed.
+    pub succeeded: bool,
+}
+
 /// `System::Account` 16(hash) + 20 (key) + 60 (AccountInfo::max_encoded_len)
 pub const ACCOUNT_BASIC_PROOF_SIZE: u64 = 96;
 /// `AccountCodesMetadata` read, temptatively 16 (hash) + 20 (key) + 40 (CodeMetadata).
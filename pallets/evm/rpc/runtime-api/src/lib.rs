@@ -0,0 +1,64 @@
+//! Runtime API definition required by the Ethereum-compatible RPC extension.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to serve `eth_*` methods for the 3VM EVM:
+//! dry-runs, account and storage reads, logs, blocks with their transactions and receipts,
+//! and raw transaction submission.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+pub use ethereum::TransactionV2 as Transaction;
+pub use fp_evm::{Account, CallInfo, CreateInfo, ExitReason, Log, TransactionStatus};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with the 3VM EVM pallet
+    pub trait EvmRuntimeApi {
+        /// Returns the EIP-155 chain id
+        fn chain_id() -> u64;
+        /// Returns the current minimum gas price
+        fn gas_price() -> U256;
+        /// Returns the EVM view of an account: balance and nonce
+        fn account_basic(address: H160) -> Account;
+        /// Returns the code deployed at the address
+        fn account_code_at(address: H160) -> Vec<u8>;
+        /// Returns the storage slot of the contract at the address
+        fn storage_at(address: H160, index: U256) -> H256;
+        /// Dry-runs a call, with gas estimation if `estimate`
+        fn call(
+            from: H160,
+            to: H160,
+            data: Vec<u8>,
+            value: U256,
+            gas_limit: U256,
+            max_fee_per_gas: Option<U256>,
+            nonce: Option<U256>,
+            estimate: bool,
+        ) -> Result<CallInfo, DispatchError>;
+        /// Dry-runs a contract creation, with gas estimation if `estimate`
+        fn create(
+            from: H160,
+            data: Vec<u8>,
+            value: U256,
+            gas_limit: U256,
+            max_fee_per_gas: Option<U256>,
+            nonce: Option<U256>,
+            estimate: bool,
+        ) -> Result<CreateInfo, DispatchError>;
+        /// Returns the logs emitted in the block, each with the index of its extrinsic
+        fn block_logs() -> Vec<(u32, Log)>;
+        /// Returns the Ethereum transactions executed in the block, with their statuses
+        fn block_transactions() -> Vec<(Transaction, TransactionStatus)>;
+        /// Returns the EVM address of the block author
+        fn block_author() -> H160;
+        /// Returns the timestamp of the block, in milliseconds
+        fn block_timestamp() -> u64;
+        /// Returns the gas limit of a block
+        fn block_gas_limit() -> U256;
+        /// Wraps a signed Ethereum transaction into an extrinsic for the transaction pool
+        fn convert_transaction(transaction: Transaction) -> <Block as sp_runtime::traits::Block>::Extrinsic;
+    }
+}
@@ -0,0 +1,683 @@
+//! Ethereum-compatible JSON-RPC for the 3VM EVM, so that wallets and tooling
+//! (MetaMask, Hardhat, ethers) can talk to the chain.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use ethereum::EnvelopedDecodable;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto},
+};
+
+pub use pallet_evm_rpc_runtime_api::EvmRuntimeApi;
+use pallet_evm_rpc_runtime_api::{ExitReason, Transaction, TransactionStatus};
+
+pub mod types;
+use types::{
+    logs_bloom, AccessListItem, Block, BlockNumberOrTag, BlockTransactions, CallRequest, Filter,
+    Log, Receipt, EMPTY_UNCLES_HASH,
+};
+
+/// Maximum number of blocks a single `eth_getLogs` request scans
+pub const MAX_LOGS_BLOCK_RANGE: u64 = 1024;
+
+/// Number of blocks back from the best one `eth_getTransactionByHash` and
+/// `eth_getTransactionReceipt` look a transaction up in, as no index of transactions is kept
+pub const MAX_TRANSACTION_LOOKUP_BLOCKS: u64 = 1024;
+
+/// Error code of reverted executions, with the revert output as data
+const EXECUTION_REVERTED: i32 = 3;
+
+/// Hash and number of the block executing a transaction, the block's transactions
+/// and the position of the transaction among them
+type FoundTransaction = (H256, u64, Vec<(Transaction, TransactionStatus)>, usize);
+
+#[rpc(client, server)]
+pub trait EthApi {
+    /// Returns the EIP-155 chain id
+    #[method(name = "eth_chainId")]
+    fn chain_id(&self) -> RpcResult<U256>;
+
+    /// Returns the EIP-155 chain id as a decimal string
+    #[method(name = "net_version")]
+    fn net_version(&self) -> RpcResult<String>;
+
+    /// Returns the best block number
+    #[method(name = "eth_blockNumber")]
+    fn block_number(&self) -> RpcResult<U256>;
+
+    /// Returns the current minimum gas price
+    #[method(name = "eth_gasPrice")]
+    fn gas_price(&self) -> RpcResult<U256>;
+
+    /// Returns the balance of the address
+    #[method(name = "eth_getBalance")]
+    fn balance(&self, address: H160, number: Option<BlockNumberOrTag>) -> RpcResult<U256>;
+
+    /// Returns the nonce of the address
+    #[method(name = "eth_getTransactionCount")]
+    fn transaction_count(&self, address: H160, number: Option<BlockNumberOrTag>)
+        -> RpcResult<U256>;
+
+    /// Returns the code deployed at the address
+    #[method(name = "eth_getCode")]
+    fn code_at(&self, address: H160, number: Option<BlockNumberOrTag>) -> RpcResult<Bytes>;
+
+    /// Returns the storage slot of the contract at the address
+    #[method(name = "eth_getStorageAt")]
+    fn storage_at(
+        &self,
+        address: H160,
+        index: U256,
+        number: Option<BlockNumberOrTag>,
+    ) -> RpcResult<H256>;
+
+    /// Dry-runs the call and returns its output
+    #[method(name = "eth_call")]
+    fn call(&self, request: CallRequest, number: Option<BlockNumberOrTag>) -> RpcResult<Bytes>;
+
+    /// Dry-runs the call and returns the gas it uses
+    #[method(name = "eth_estimateGas")]
+    fn estimate_gas(
+        &self,
+        request: CallRequest,
+        number: Option<BlockNumberOrTag>,
+    ) -> RpcResult<U256>;
+
+    /// Returns the logs matching the filter
+    #[method(name = "eth_getLogs")]
+    fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+
+    /// Returns the block with the number, with full transactions if `full`
+    #[method(name = "eth_getBlockByNumber")]
+    fn block_by_number(&self, number: BlockNumberOrTag, full: bool) -> RpcResult<Option<Block>>;
+
+    /// Returns the block with the hash, with full transactions if `full`
+    #[method(name = "eth_getBlockByHash")]
+    fn block_by_hash(&self, hash: H256, full: bool) -> RpcResult<Option<Block>>;
+
+    /// Returns the transaction with the hash
+    #[method(name = "eth_getTransactionByHash")]
+    fn transaction_by_hash(&self, hash: H256) -> RpcResult<Option<types::Transaction>>;
+
+    /// Returns the receipt of the transaction with the hash
+    #[method(name = "eth_getTransactionReceipt")]
+    fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<Receipt>>;
+
+    /// Submits a signed Ethereum transaction and returns its hash
+    #[method(name = "eth_sendRawTransaction")]
+    async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<H256>;
+}
+
+/// A struct that implements the [`EthApi`].
+pub struct Eth<C, P, Block> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, P, Block> Eth<C, P, Block> {
+    /// Create new `Eth` with the given reference to the client and the transaction pool.
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self {
+            client,
+            pool,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, P, Block> Eth<C, P, Block>
+where
+    Block: BlockT<Hash = H256>,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EvmRuntimeApi<Block>,
+{
+    fn block_hash(&self, number: Option<BlockNumberOrTag>) -> RpcResult<H256> {
+        let info = self.client.info();
+        match number.unwrap_or_default() {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => Ok(info.best_hash),
+            BlockNumberOrTag::Safe | BlockNumberOrTag::Finalized => Ok(info.finalized_hash),
+            BlockNumberOrTag::Earliest => Ok(info.genesis_hash),
+            BlockNumberOrTag::Number(number) => self.hash_of(number),
+        }
+    }
+
+    fn block_number_of(&self, number: Option<BlockNumberOrTag>) -> u64 {
+        let info = self.client.info();
+        match number.unwrap_or_default() {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending =>
+                info.best_number.unique_saturated_into(),
+            BlockNumberOrTag::Safe | BlockNumberOrTag::Finalized =>
+                info.finalized_number.unique_saturated_into(),
+            BlockNumberOrTag::Earliest => 0,
+            BlockNumberOrTag::Number(number) => number,
+        }
+    }
+
+    fn hash_of(&self, number: u64) -> RpcResult<H256> {
+        self.client
+            .hash(number.unique_saturated_into())
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or_else(|| runtime_error_into_rpc_err("Unknown block"))
+    }
+
+    /// Logs emitted in the block, indexed over all of its logs.
+    fn block_logs(&self, block_hash: H256, number: u64) -> RpcResult<Vec<Log>> {
+        let block_logs = self
+            .client
+            .runtime_api()
+            .block_logs(block_hash)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(block_logs
+            .into_iter()
+            .enumerate()
+            .map(|(log_index, (transaction_index, log))| Log {
+                address: log.address,
+                topics: log.topics,
+                data: log.data.into(),
+                block_hash,
+                block_number: number.into(),
+                transaction_index: transaction_index.into(),
+                log_index: log_index.into(),
+                removed: false,
+            })
+            .collect())
+    }
+
+    fn block(&self, block_hash: H256, full: bool) -> RpcResult<Option<Block>> {
+        let header = match self
+            .client
+            .header(block_hash)
+            .map_err(runtime_error_into_rpc_err)?
+        {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let number: u64 = (*header.number()).unique_saturated_into();
+
+        let api = self.client.runtime_api();
+        let transactions = api
+            .block_transactions(block_hash)
+            .map_err(runtime_error_into_rpc_err)?;
+        let base_fee = api
+            .gas_price(block_hash)
+            .map_err(runtime_error_into_rpc_err)?;
+        let logs = self.block_logs(block_hash, number)?;
+
+        Ok(Some(Block {
+            hash: block_hash,
+            parent_hash: *header.parent_hash(),
+            number: number.into(),
+            state_root: *header.state_root(),
+            transactions_root: *header.extrinsics_root(),
+            sha3_uncles: EMPTY_UNCLES_HASH,
+            logs_bloom: logs_bloom(&logs),
+            miner: api
+                .block_author(block_hash)
+                .map_err(runtime_error_into_rpc_err)?,
+            difficulty: U256::zero(),
+            total_difficulty: U256::zero(),
+            nonce: Bytes(vec![0u8; 8]),
+            extra_data: Bytes(Vec::new()),
+            gas_limit: api
+                .block_gas_limit(block_hash)
+                .map_err(runtime_error_into_rpc_err)?,
+            gas_used: transactions.iter().fold(U256::zero(), |acc, (_, status)| {
+                acc.saturating_add(status.used_gas)
+            }),
+            base_fee_per_gas: base_fee,
+            timestamp: (api
+                .block_timestamp(block_hash)
+                .map_err(runtime_error_into_rpc_err)?
+                / 1000)
+                .into(),
+            transactions: if full {
+                BlockTransactions::Full(
+                    transactions
+                        .iter()
+                        .map(|(transaction, status)| {
+                            to_rpc_transaction(transaction, status, block_hash, number, base_fee)
+                        })
+                        .collect(),
+                )
+            } else {
+                BlockTransactions::Hashes(
+                    transactions
+                        .iter()
+                        .map(|(_, status)| status.transaction_hash)
+                        .collect(),
+                )
+            },
+            uncles: Vec::new(),
+        }))
+    }
+
+    /// Finds the block executing the transaction, looking back from the best block.
+    fn find_transaction(&self, hash: H256) -> RpcResult<Option<FoundTransaction>> {
+        let api = self.client.runtime_api();
+        let best = self.block_number_of(None);
+
+        for number in (best.saturating_sub(MAX_TRANSACTION_LOOKUP_BLOCKS - 1)..=best).rev() {
+            let block_hash = self.hash_of(number)?;
+            let transactions = api
+                .block_transactions(block_hash)
+                .map_err(runtime_error_into_rpc_err)?;
+            if let Some(position) = transactions
+                .iter()
+                .position(|(_, status)| status.transaction_hash == hash)
+            {
+                return Ok(Some((block_hash, number, transactions, position)))
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn dry_run(
+        &self,
+        request: CallRequest,
+        number: Option<BlockNumberOrTag>,
+        estimate: bool,
+    ) -> RpcResult<(Vec<u8>, U256)> {
+        let api = self.client.runtime_api();
+        let at = self.block_hash(number)?;
+
+        let from = request.from.unwrap_or_default();
+        let value = request.value.unwrap_or_default();
+        let gas_limit = request.gas.unwrap_or_else(U256::max_value);
+        let max_fee_per_gas = request.max_fee_per_gas();
+        let input = request.input();
+
+        match request.to {
+            Some(to) => {
+                let info = api
+                    .call(
+                        at,
+                        from,
+                        to,
+                        input,
+                        value,
+                        gas_limit,
+                        max_fee_per_gas,
+                        request.nonce,
+                        estimate,
+                    )
+                    .map_err(runtime_error_into_rpc_err)?
+                    .map_err(runtime_error_into_rpc_err)?;
+                ensure_succeeded(info.exit_reason, &info.value)?;
+                Ok((info.value, info.used_gas.standard))
+            },
+            None => {
+                let info = api
+                    .create(
+                        at,
+                        from,
+                        input,
+                        value,
+                        gas_limit,
+                        max_fee_per_gas,
+                        request.nonce,
+                        estimate,
+                    )
+                    .map_err(runtime_error_into_rpc_err)?
+                    .map_err(runtime_error_into_rpc_err)?;
+                ensure_succeeded(info.exit_reason, &[])?;
+                Ok((info.value.as_bytes().to_vec(), info.used_gas.standard))
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl<C, P, Block> EthApiServer for Eth<C, P, Block>
+where
+    Block: BlockT<Hash = H256>,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EvmRuntimeApi<Block>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    fn chain_id(&self) -> RpcResult<U256> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let chain_id = api.chain_id(at).map_err(runtime_error_into_rpc_err)?;
+
+        Ok(chain_id.into())
+    }
+
+    fn net_version(&self) -> RpcResult<String> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let chain_id = api.chain_id(at).map_err(runtime_error_into_rpc_err)?;
+
+        Ok(chain_id.to_string())
+    }
+
+    fn block_number(&self) -> RpcResult<U256> {
+        Ok(self.block_number_of(None).into())
+    }
+
+    fn gas_price(&self) -> RpcResult<U256> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        api.gas_price(at).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn balance(&self, address: H160, number: Option<BlockNumberOrTag>) -> RpcResult<U256> {
+        let api = self.client.runtime_api();
+        let at = self.block_hash(number)?;
+
+        let account = api
+            .account_basic(at, address)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(account.balance)
+    }
+
+    fn transaction_count(
+        &self,
+        address: H160,
+        number: Option<BlockNumberOrTag>,
+    ) -> RpcResult<U256> {
+        let api = self.client.runtime_api();
+        let at = self.block_hash(number)?;
+
+        let account = api
+            .account_basic(at, address)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(account.nonce)
+    }
+
+    fn code_at(&self, address: H160, number: Option<BlockNumberOrTag>) -> RpcResult<Bytes> {
+        let api = self.client.runtime_api();
+        let at = self.block_hash(number)?;
+
+        let code = api
+            .account_code_at(at, address)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(code.into())
+    }
+
+    fn storage_at(
+        &self,
+        address: H160,
+        index: U256,
+        number: Option<BlockNumberOrTag>,
+    ) -> RpcResult<H256> {
+        let api = self.client.runtime_api();
+        let at = self.block_hash(number)?;
+
+        api.storage_at(at, address, index)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn call(&self, request: CallRequest, number: Option<BlockNumberOrTag>) -> RpcResult<Bytes> {
+        let (output, _) = self.dry_run(request, number, false)?;
+
+        Ok(output.into())
+    }
+
+    fn estimate_gas(
+        &self,
+        request: CallRequest,
+        number: Option<BlockNumberOrTag>,
+    ) -> RpcResult<U256> {
+        let (_, used_gas) = self.dry_run(request, number, true)?;
+
+        Ok(used_gas)
+    }
+
+    fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+        let (from, to) = match filter.block_hash {
+            Some(hash) => {
+                let number: u64 = self
+                    .client
+                    .number(hash)
+                    .map_err(runtime_error_into_rpc_err)?
+                    .ok_or_else(|| runtime_error_into_rpc_err("Unknown block"))?
+                    .unique_saturated_into();
+                (number, number)
+            },
+            None => (
+                self.block_number_of(filter.from_block),
+                self.block_number_of(filter.to_block),
+            ),
+        };
+        if to.saturating_sub(from) >= MAX_LOGS_BLOCK_RANGE {
+            return Err(runtime_error_into_rpc_err(format!(
+                "Block range exceeds {MAX_LOGS_BLOCK_RANGE} blocks"
+            )))
+        }
+
+        let mut logs = Vec::new();
+        for number in from..=to {
+            let block_hash = self.hash_of(number)?;
+            logs.extend(
+                self.block_logs(block_hash, number)?
+                    .into_iter()
+                    .filter(|log| filter.matches(log)),
+            );
+        }
+
+        Ok(logs)
+    }
+
+    fn block_by_number(&self, number: BlockNumberOrTag, full: bool) -> RpcResult<Option<Block>> {
+        let block_hash = match number {
+            BlockNumberOrTag::Number(number) => self
+                .client
+                .hash(number.unique_saturated_into())
+                .map_err(runtime_error_into_rpc_err)?,
+            tag => Some(self.block_hash(Some(tag))?),
+        };
+
+        match block_hash {
+            Some(block_hash) => self.block(block_hash, full),
+            None => Ok(None),
+        }
+    }
+
+    fn block_by_hash(&self, hash: H256, full: bool) -> RpcResult<Option<Block>> {
+        self.block(hash, full)
+    }
+
+    fn transaction_by_hash(&self, hash: H256) -> RpcResult<Option<types::Transaction>> {
+        let (block_hash, number, transactions, position) = match self.find_transaction(hash)? {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let base_fee = self
+            .client
+            .runtime_api()
+            .gas_price(block_hash)
+            .map_err(runtime_error_into_rpc_err)?;
+        let (transaction, status) = &transactions[position];
+
+        Ok(Some(to_rpc_transaction(
+            transaction,
+            status,
+            block_hash,
+            number,
+            base_fee,
+        )))
+    }
+
+    fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<Receipt>> {
+        let (block_hash, number, transactions, position) = match self.find_transaction(hash)? {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let base_fee = self
+            .client
+            .runtime_api()
+            .gas_price(block_hash)
+            .map_err(runtime_error_into_rpc_err)?;
+        let (transaction, status) = &transactions[position];
+
+        let logs = self
+            .block_logs(block_hash, number)?
+            .into_iter()
+            .filter(|log| log.transaction_index == status.transaction_index.into())
+            .collect::<Vec<_>>();
+        let cumulative_gas_used = transactions[..=position]
+            .iter()
+            .fold(U256::zero(), |acc, (_, status)| {
+                acc.saturating_add(status.used_gas)
+            });
+        let rpc_transaction = to_rpc_transaction(transaction, status, block_hash, number, base_fee);
+
+        Ok(Some(Receipt {
+            transaction_hash: status.transaction_hash,
+            transaction_index: status.transaction_index.into(),
+            block_hash,
+            block_number: number.into(),
+            from: status.from,
+            to: status.to,
+            cumulative_gas_used,
+            gas_used: status.used_gas,
+            effective_gas_price: rpc_transaction.gas_price,
+            contract_address: status.contract_address,
+            logs_bloom: logs_bloom(&logs),
+            logs,
+            transaction_type: rpc_transaction.transaction_type,
+            status: u8::from(status.succeeded).into(),
+        }))
+    }
+
+    async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<H256> {
+        let transaction = <Transaction as EnvelopedDecodable>::decode(&bytes)
+            .map_err(|_| runtime_error_into_rpc_err("Invalid transaction encoding"))?;
+        let transaction_hash = transaction.hash();
+
+        let at = self.client.info().best_hash;
+        let extrinsic = self
+            .client
+            .runtime_api()
+            .convert_transaction(at, transaction)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        self.pool
+            .submit_one(&BlockId::hash(at), TransactionSource::External, extrinsic)
+            .await
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(transaction_hash)
+    }
+}
+
+/// Transaction object of a transaction executed in the block. Its `gas_price` is the price
+/// effectively paid, given the base fee of the block.
+fn to_rpc_transaction(
+    transaction: &Transaction,
+    status: &TransactionStatus,
+    block_hash: H256,
+    block_number: u64,
+    base_fee: U256,
+) -> types::Transaction {
+    let access_list = |items: &[ethereum::AccessListItem]| -> Option<Vec<AccessListItem>> {
+        Some(
+            items
+                .iter()
+                .map(|item| AccessListItem {
+                    address: item.address,
+                    storage_keys: item.storage_keys.clone(),
+                })
+                .collect(),
+        )
+    };
+    let (nonce, value, gas, input, action) = match transaction {
+        Transaction::Legacy(t) => (t.nonce, t.value, t.gas_limit, &t.input, t.action),
+        Transaction::EIP2930(t) => (t.nonce, t.value, t.gas_limit, &t.input, t.action),
+        Transaction::EIP1559(t) => (t.nonce, t.value, t.gas_limit, &t.input, t.action),
+    };
+    let to = match action {
+        ethereum::TransactionAction::Call(to) => Some(to),
+        ethereum::TransactionAction::Create => None,
+    };
+
+    let mut rpc_transaction = types::Transaction {
+        hash: status.transaction_hash,
+        nonce,
+        block_hash,
+        block_number: block_number.into(),
+        transaction_index: status.transaction_index.into(),
+        from: status.from,
+        to,
+        value,
+        gas,
+        gas_price: U256::zero(),
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        input: input.clone().into(),
+        access_list: None,
+        chain_id: None,
+        transaction_type: U256::zero(),
+        v: U256::zero(),
+        r: U256::zero(),
+        s: U256::zero(),
+    };
+    match transaction {
+        Transaction::Legacy(t) => {
+            rpc_transaction.gas_price = t.gas_price;
+            rpc_transaction.chain_id = t.signature.chain_id().map(U256::from);
+            rpc_transaction.v = t.signature.v().into();
+            rpc_transaction.r = U256::from_big_endian(t.signature.r().as_bytes());
+            rpc_transaction.s = U256::from_big_endian(t.signature.s().as_bytes());
+        },
+        Transaction::EIP2930(t) => {
+            rpc_transaction.gas_price = t.gas_price;
+            rpc_transaction.access_list = access_list(&t.access_list);
+            rpc_transaction.chain_id = Some(t.chain_id.into());
+            rpc_transaction.transaction_type = 1.into();
+            rpc_transaction.v = u8::from(t.odd_y_parity).into();
+            rpc_transaction.r = U256::from_big_endian(t.r.as_bytes());
+            rpc_transaction.s = U256::from_big_endian(t.s.as_bytes());
+        },
+        Transaction::EIP1559(t) => {
+            rpc_transaction.gas_price = t
+                .max_fee_per_gas
+                .min(base_fee.saturating_add(t.max_priority_fee_per_gas));
+            rpc_transaction.max_fee_per_gas = Some(t.max_fee_per_gas);
+            rpc_transaction.max_priority_fee_per_gas = Some(t.max_priority_fee_per_gas);
+            rpc_transaction.access_list = access_list(&t.access_list);
+            rpc_transaction.chain_id = Some(t.chain_id.into());
+            rpc_transaction.transaction_type = 2.into();
+            rpc_transaction.v = u8::from(t.odd_y_parity).into();
+            rpc_transaction.r = U256::from_big_endian(t.r.as_bytes());
+            rpc_transaction.s = U256::from_big_endian(t.s.as_bytes());
+        },
+    }
+
+    rpc_transaction
+}
+
+fn ensure_succeeded(exit_reason: ExitReason, output: &[u8]) -> RpcResult<()> {
+    match exit_reason {
+        ExitReason::Succeed(_) => Ok(()),
+        ExitReason::Revert(_) => Err(JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            EXECUTION_REVERTED,
+            "execution reverted",
+            Some(Bytes(output.to_vec())),
+        )))),
+        reason => Err(runtime_error_into_rpc_err(reason)),
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Custom(format!("{err:?}"))
+}
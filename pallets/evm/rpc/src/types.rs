@@ -0,0 +1,244 @@
+//! Ethereum JSON-RPC request and response types.
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use sp_core::{Bytes, H160, H256, U256};
+
+/// Block parameter of `eth_*` methods: a hex quantity or one of the tags.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BlockNumberOrTag {
+    #[default]
+    Latest,
+    Earliest,
+    Pending,
+    Safe,
+    Finalized,
+    Number(u64),
+}
+
+impl<'de> Deserialize<'de> for BlockNumberOrTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.as_str() {
+            "latest" => Ok(BlockNumberOrTag::Latest),
+            "earliest" => Ok(BlockNumberOrTag::Earliest),
+            "pending" => Ok(BlockNumberOrTag::Pending),
+            "safe" => Ok(BlockNumberOrTag::Safe),
+            "finalized" => Ok(BlockNumberOrTag::Finalized),
+            number => number
+                .strip_prefix("0x")
+                .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+                .map(BlockNumberOrTag::Number)
+                .ok_or_else(|| D::Error::custom(format!("invalid block number: {number}"))),
+        }
+    }
+}
+
+/// Transaction fields of `eth_call` and `eth_estimateGas`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+    pub from: Option<H160>,
+    pub to: Option<H160>,
+    pub gas: Option<U256>,
+    pub gas_price: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub value: Option<U256>,
+    pub data: Option<Bytes>,
+    pub input: Option<Bytes>,
+    pub nonce: Option<U256>,
+}
+
+impl CallRequest {
+    /// `input` takes precedence over the legacy `data` field.
+    pub fn input(&self) -> Vec<u8> {
+        self.input
+            .clone()
+            .or_else(|| self.data.clone())
+            .map(|bytes| bytes.0)
+            .unwrap_or_default()
+    }
+
+    pub fn max_fee_per_gas(&self) -> Option<U256> {
+        self.max_fee_per_gas.or(self.gas_price)
+    }
+}
+
+/// A single value or a list of values, as filter fields allow both.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ValueOrArray<T> {
+    Value(T),
+    Array(Vec<T>),
+}
+
+impl<T: PartialEq> ValueOrArray<T> {
+    pub fn contains(&self, item: &T) -> bool {
+        match self {
+            ValueOrArray::Value(value) => value == item,
+            ValueOrArray::Array(values) => values.contains(item),
+        }
+    }
+}
+
+/// Filter of `eth_getLogs`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    pub from_block: Option<BlockNumberOrTag>,
+    pub to_block: Option<BlockNumberOrTag>,
+    pub block_hash: Option<H256>,
+    pub address: Option<ValueOrArray<H160>>,
+    pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
+}
+
+impl Filter {
+    pub fn matches(&self, log: &Log) -> bool {
+        if let Some(address) = &self.address {
+            if !address.contains(&log.address) {
+                return false
+            }
+        }
+        self.topics
+            .iter()
+            .flatten()
+            .enumerate()
+            .all(|(position, topic)| match topic {
+                None => true,
+                Some(topic) => log
+                    .topics
+                    .get(position)
+                    .map_or(false, |t| topic.contains(t)),
+            })
+    }
+}
+
+/// Log entry of `eth_getLogs`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    pub address: H160,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub transaction_index: U256,
+    pub log_index: U256,
+    pub removed: bool,
+}
+
+/// Transaction object of `eth_getTransactionByHash` and full `eth_getBlockBy*` responses.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub hash: H256,
+    pub nonce: U256,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub transaction_index: U256,
+    pub from: H160,
+    pub to: Option<H160>,
+    pub value: U256,
+    pub gas: U256,
+    pub gas_price: U256,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub input: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<Vec<AccessListItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<U256>,
+    #[serde(rename = "type")]
+    pub transaction_type: U256,
+    pub v: U256,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// Access list entry of EIP-2930 and EIP-1559 transactions.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListItem {
+    pub address: H160,
+    pub storage_keys: Vec<H256>,
+}
+
+/// Receipt of `eth_getTransactionReceipt`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Receipt {
+    pub transaction_hash: H256,
+    pub transaction_index: U256,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub from: H160,
+    pub to: Option<H160>,
+    pub cumulative_gas_used: U256,
+    pub gas_used: U256,
+    pub effective_gas_price: U256,
+    pub contract_address: Option<H160>,
+    pub logs: Vec<Log>,
+    pub logs_bloom: Bytes,
+    #[serde(rename = "type")]
+    pub transaction_type: U256,
+    pub status: U256,
+}
+
+/// Transactions of a block, as hashes or as full objects.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum BlockTransactions {
+    Hashes(Vec<H256>),
+    Full(Vec<Transaction>),
+}
+
+/// Block object of `eth_getBlockByNumber` and `eth_getBlockByHash`. Fields without an
+/// equivalent in the Substrate block (uncles, difficulty, nonce) are returned empty.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub hash: H256,
+    pub parent_hash: H256,
+    pub number: U256,
+    pub state_root: H256,
+    pub transactions_root: H256,
+    pub receipts_root: H256,
+    pub sha3_uncles: H256,
+    pub logs_bloom: Bytes,
+    pub miner: H160,
+    pub difficulty: U256,
+    pub total_difficulty: U256,
+    pub nonce: Bytes,
+    pub extra_data: Bytes,
+    pub size: U256,
+    pub gas_limit: U256,
+    pub gas_used: U256,
+    pub base_fee_per_gas: U256,
+    pub timestamp: U256,
+    pub transactions: BlockTransactions,
+    pub uncles: Vec<H256>,
+}
+
+/// Keccak hash of the RLP encoding of an empty uncles list.
+pub const EMPTY_UNCLES_HASH: H256 = H256([
+    0x1d, 0xcc, 0x4d, 0xe8, 0xde, 0xc7, 0x5d, 0x7a, 0xab, 0x85, 0xb5, 0x67, 0xb6, 0xcc, 0xd4, 0x1a,
+    0xd3, 0x12, 0x45, 0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
+]);
+
+/// 2048-bit bloom filter of the logs' addresses and topics, as in Ethereum headers and receipts.
+pub fn logs_bloom<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Bytes {
+    let mut bloom = [0u8; 256];
+    for log in logs {
+        let topics = log.topics.iter().map(|topic| topic.as_bytes());
+        for input in std::iter::once(log.address.as_bytes()).chain(topics) {
+            let hash = sp_core::hashing::keccak_256(input);
+            for i in [0, 2, 4] {
+                let bit = ((usize::from(hash[i]) << 8) | usize::from(hash[i + 1])) & 2047;
+                bloom[255 - bit / 8] |= 1 << (bit % 8);
+            }
+        }
+    }
+    Bytes(bloom.to_vec())
+}
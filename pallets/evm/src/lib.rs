@@ -63,6 +63,7 @@ mod mock;
 pub mod runner;
 #[cfg(test)]
 mod tests;
+pub mod transaction;
 pub mod weights;
 
 pub use evm::{
@@ -88,25 +89,30 @@ use frame_system::RawOrigin;
 use sp_core::{Decode, Encode, Hasher, H160, H256, U256};
 use sp_runtime::{
     traits::{BadOrigin, NumberFor, Saturating, UniqueSaturatedInto, Zero},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
     AccountId32, DispatchErrorWithPostInfo,
 };
 use sp_std::{cmp::min, collections::btree_map::BTreeMap, vec::Vec};
 // Frontier
 use fp_account::AccountId20;
-use fp_evm::GenesisAccount;
 pub use fp_evm::{
     Account, CallInfo, CreateInfo, ExecutionInfoV2 as ExecutionInfo, FeeCalculator,
     InvalidEvmTransactionError, IsPrecompileResult, LinearCostPrecompile, Log, Precompile,
     PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult, PrecompileSet,
-    Vicinity,
+    TransactionStatus, Vicinity,
 };
-use t3rn_primitives::threevm::ThreeVm;
+use fp_evm::{CheckEvmTransaction, CheckEvmTransactionConfig, GenesisAccount};
+use t3rn_primitives::threevm::{InvokeXtxCallback, ThreeVm, XtxCallbackFailure};
 
 pub use self::{
     pallet::*,
     runner::{Runner, RunnerError},
+    transaction::{recover_signer, Transaction, TransactionData},
     weights::WeightInfo,
 };
+use transaction::InvalidTransactionWrapper;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -183,6 +189,16 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        // Ethereum transactions are served by the RPC from the state of their block,
+        // so the ones of the previous block are cleared out.
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            <BlockTransactions<T>>::kill();
+            T::DbWeight::get().writes(1)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Withdraw balance from EVM into currency/balances pallet.
@@ -453,6 +469,129 @@ pub mod pallet {
                 pays_fee: Pays::No,
             })
         }
+
+        /// Execute a signed Ethereum transaction (legacy, EIP-2930 or EIP-1559), with the signer
+        /// as source. Submitted unsigned, validity is checked by `ValidateUnsigned`.
+        #[pallet::call_index(4)]
+        #[pallet::weight({
+			let without_base_extrinsic_weight = true;
+			let gas_limit = transaction::gas_limit(transaction).unique_saturated_into();
+			T::GasWeightMapping::gas_to_weight(gas_limit, without_base_extrinsic_weight)
+		})]
+        pub fn transact(
+            origin: OriginFor<T>,
+            transaction: Transaction,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            let source = recover_signer(&transaction).ok_or(Error::<T>::InvalidSignature)?;
+            let data = TransactionData::from(&transaction);
+            let gas_limit: u64 = data
+                .gas_limit
+                .try_into()
+                .map_err(|_| Error::<T>::GasLimitTooHigh)?;
+
+            let is_transactional = true;
+            let validate = true;
+            let (used_gas, weight_info, contract_address, succeeded) = match data.target() {
+                Some(target) => {
+                    let info = T::Runner::call(
+                        source,
+                        target,
+                        data.input.clone(),
+                        data.value,
+                        gas_limit,
+                        Some(data.max_fee_per_gas()),
+                        data.max_priority_fee_per_gas(),
+                        Some(data.nonce),
+                        data.access_list.clone(),
+                        is_transactional,
+                        validate,
+                        None,
+                        None,
+                        T::config(),
+                    )
+                    .map_err(|e| DispatchErrorWithPostInfo {
+                        post_info: PostDispatchInfo {
+                            actual_weight: Some(e.weight),
+                            pays_fee: Pays::Yes,
+                        },
+                        error: e.error.into(),
+                    })?;
+
+                    let succeeded = matches!(info.exit_reason, ExitReason::Succeed(_));
+                    if succeeded {
+                        Pallet::<T>::deposit_event(Event::<T>::Executed { address: target });
+                    } else {
+                        Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed { address: target });
+                    }
+                    (info.used_gas, info.weight_info, None, succeeded)
+                },
+                None => {
+                    let info = T::Runner::create(
+                        source,
+                        data.input.clone(),
+                        data.value,
+                        gas_limit,
+                        Some(data.max_fee_per_gas()),
+                        data.max_priority_fee_per_gas(),
+                        Some(data.nonce),
+                        data.access_list.clone(),
+                        is_transactional,
+                        validate,
+                        None,
+                        None,
+                        T::config(),
+                    )
+                    .map_err(|e| DispatchErrorWithPostInfo {
+                        post_info: PostDispatchInfo {
+                            actual_weight: Some(e.weight),
+                            pays_fee: Pays::Yes,
+                        },
+                        error: e.error.into(),
+                    })?;
+
+                    let succeeded = matches!(info.exit_reason, ExitReason::Succeed(_));
+                    if succeeded {
+                        Pallet::<T>::deposit_event(Event::<T>::Created {
+                            address: info.value,
+                        });
+                    } else {
+                        Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
+                            address: info.value,
+                        });
+                    }
+                    (info.used_gas, info.weight_info, Some(info.value), succeeded)
+                },
+            };
+
+            let status = TransactionStatus {
+                transaction_hash: transaction.hash(),
+                transaction_index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+                from: source,
+                to: data.target(),
+                contract_address,
+                used_gas: used_gas.standard,
+                succeeded,
+            };
+            <BlockTransactions<T>>::append((transaction, status));
+
+            Ok(PostDispatchInfo {
+                actual_weight: {
+                    let mut gas_to_weight = T::GasWeightMapping::gas_to_weight(
+                        used_gas.standard.unique_saturated_into(),
+                        true,
+                    );
+                    if let Some(weight_info) = weight_info {
+                        if let Some(proof_size_usage) = weight_info.proof_size_usage {
+                            *gas_to_weight.proof_size_mut() = proof_size_usage;
+                        }
+                    }
+                    Some(gas_to_weight)
+                },
+                pays_fee: Pays::No,
+            })
+        }
     }
 
     #[pallet::event]
@@ -494,6 +633,20 @@ pub mod pallet {
         Reentrancy,
         /// EIP-3607,
         TransactionMustComeFromEOA,
+        /// Ethereum transaction signature can't be recovered
+        InvalidSignature,
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::transact { transaction } => Pallet::<T>::validate_transaction(transaction),
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
     }
 
     impl<T> From<InvalidEvmTransactionError> for Error<T> {
@@ -566,6 +719,11 @@ pub mod pallet {
     #[pallet::storage]
     pub type AccountStorages<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, H160, Blake2_128Concat, H256, H256, ValueQuery>;
+
+    /// Ethereum transactions executed in the current block, with their receipts.
+    #[pallet::storage]
+    pub type BlockTransactions<T: Config> =
+        StorageValue<_, Vec<(Transaction, TransactionStatus)>, ValueQuery>;
 }
 
 /// Type alias for currency balance.
@@ -984,6 +1142,148 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Validates a signed Ethereum transaction for the pool. Transactions with future nonces
+    /// are accepted and ordered by the `(source, nonce)` tags.
+    pub fn validate_transaction(transaction: &Transaction) -> TransactionValidity {
+        let source = recover_signer(transaction).ok_or(InvalidTransaction::BadProof)?;
+        let data = TransactionData::from(transaction);
+        let (base_fee, _) = T::FeeCalculator::min_gas_price();
+        let (who, _) = Self::account_basic(&source);
+
+        CheckEvmTransaction::<InvalidTransactionWrapper>::new(
+            CheckEvmTransactionConfig {
+                evm_config: T::config(),
+                block_gas_limit: T::BlockGasLimit::get(),
+                base_fee,
+                chain_id: T::ChainId::get(),
+                is_transactional: true,
+            },
+            data.clone().into(),
+            None,
+            None,
+        )
+        .validate_in_pool_for(&who)
+        .and_then(|v| v.with_chain_id())
+        .and_then(|v| v.with_base_fee())
+        .and_then(|v| v.with_balance_for(&who))
+        .map_err(|e| TransactionValidityError::Invalid(e.0))?;
+
+        let priority = data
+            .max_priority_fee_per_gas()
+            .unwrap_or_default()
+            .unique_saturated_into();
+        let mut builder = ValidTransaction::with_tag_prefix("EvmTransact")
+            .priority(priority)
+            .and_provides((source, data.nonce))
+            .propagate(true);
+        if data.nonce > who.nonce {
+            builder = builder.and_requires((source, data.nonce - 1));
+        }
+        builder.build()
+    }
+
+    /// Executes a call against the current state without persisting it, as `eth_call`
+    /// and `eth_estimateGas` dry-runs do.
+    pub fn dry_run_call(
+        from: H160,
+        to: H160,
+        data: Vec<u8>,
+        value: U256,
+        gas_limit: U256,
+        max_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        estimate: bool,
+    ) -> Result<CallInfo, sp_runtime::DispatchError> {
+        let mut config = T::config().clone();
+        config.estimate = estimate;
+
+        let is_transactional = false;
+        let validate = true;
+        T::Runner::call(
+            from,
+            to,
+            data,
+            value,
+            gas_limit
+                .min(T::BlockGasLimit::get())
+                .unique_saturated_into(),
+            max_fee_per_gas,
+            None,
+            nonce,
+            Vec::new(),
+            is_transactional,
+            validate,
+            None,
+            None,
+            &config,
+        )
+        .map_err(|e| e.error.into())
+    }
+
+    /// Executes a contract creation against the current state without persisting it.
+    pub fn dry_run_create(
+        from: H160,
+        data: Vec<u8>,
+        value: U256,
+        gas_limit: U256,
+        max_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        estimate: bool,
+    ) -> Result<CreateInfo, sp_runtime::DispatchError> {
+        let mut config = T::config().clone();
+        config.estimate = estimate;
+
+        let is_transactional = false;
+        let validate = true;
+        T::Runner::create(
+            from,
+            data,
+            value,
+            gas_limit
+                .min(T::BlockGasLimit::get())
+                .unique_saturated_into(),
+            max_fee_per_gas,
+            None,
+            nonce,
+            Vec::new(),
+            is_transactional,
+            validate,
+            None,
+            None,
+            &config,
+        )
+        .map_err(|e| e.error.into())
+    }
+
+    /// Get the storage slot of an EVM account.
+    pub fn storage_at(address: H160, index: U256) -> H256 {
+        let mut key = [0u8; 32];
+        index.to_big_endian(&mut key);
+        <AccountStorages<T>>::get(address, H256::from(key))
+    }
+
+    /// Logs emitted in the current block, each with the index of the extrinsic emitting it.
+    pub fn block_logs() -> Vec<(u32, Log)>
+    where
+        <T as Config>::RuntimeEvent: Clone + TryInto<Event<T>>,
+    {
+        frame_system::Pallet::<T>::read_events_no_consensus()
+            .filter_map(|record| {
+                let extrinsic_index = match record.phase {
+                    frame_system::Phase::ApplyExtrinsic(index) => index,
+                    _ => return None,
+                };
+                match <T as Config>::RuntimeEvent::from_ref(&record.event)
+                    .clone()
+                    .try_into()
+                {
+                    Ok(Event::<T>::Log { log }) => Some((extrinsic_index, log)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     /// Get the author using the FindAuthor trait.
     pub fn find_author() -> H160 {
         let digest = <frame_system::Pallet<T>>::digest();
@@ -993,6 +1293,66 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+/// 3VM sees EVM contracts as their 20 bytes address padded with zeroes into an account.
+fn evm_address_of<AccountId: Encode>(account: &AccountId) -> Option<H160> {
+    let bytes = account.encode();
+    if bytes.len() < 20 {
+        return None
+    }
+    Some(H160::from_slice(&bytes[..20]))
+}
+
+impl<T: Config> InvokeXtxCallback<T::AccountId> for Pallet<T> {
+    fn invoke_xtx_callback(
+        caller: &T::AccountId,
+        contract: &T::AccountId,
+        input: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure> {
+        let source = evm_address_of(caller)
+            .ok_or(sp_runtime::DispatchError::Other("XtxCallbackInvalidCaller"))?;
+        let target = evm_address_of(contract).ok_or(sp_runtime::DispatchError::Other(
+            "XtxCallbackInvalidContract",
+        ))?;
+
+        let is_transactional = false;
+        let validate = false;
+        let info = T::Runner::call(
+            source,
+            target,
+            input,
+            U256::zero(),
+            T::GasWeightMapping::weight_to_gas(gas_limit),
+            None,
+            None,
+            None,
+            Vec::new(),
+            is_transactional,
+            validate,
+            Some(gas_limit),
+            None,
+            T::config(),
+        )
+        .map_err(|e| XtxCallbackFailure {
+            consumed: e.weight.min(gas_limit),
+            error: e.error.into(),
+        })?;
+
+        let consumed = T::GasWeightMapping::gas_to_weight(
+            info.used_gas.standard.unique_saturated_into(),
+            true,
+        )
+        .min(gas_limit);
+        match info.exit_reason {
+            ExitReason::Succeed(_) => Ok(consumed),
+            _ => Err(XtxCallbackFailure {
+                consumed,
+                error: "XtxCallbackReverted".into(),
+            }),
+        }
+    }
+}
+
 /// Handle withdrawing, refunding and depositing of transaction fees.
 /// Similar to `OnChargeTransaction` of `pallet_transaction_payment`
 pub trait OnChargeEVMTransaction<T: Config> {
@@ -39,7 +39,7 @@ frame_support::construct_runtime! {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage},
-        EVM: crate::{Pallet, Call, Storage, Config<T>, Event<T>},
+        EVM: crate::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
     }
 }
 
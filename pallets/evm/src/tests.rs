@@ -1264,3 +1264,173 @@ fn metadata_empty_dont_code_gets_cached() {
         assert!(<AccountCodesMetadata<Test>>::get(address).is_none());
     });
 }
+
+mod transact_test {
+    use super::*;
+    use ethereum::{LegacyTransaction, LegacyTransactionMessage, TransactionSignature};
+    use frame_support::{
+        traits::{Currency as _, Hooks},
+        unsigned::{TransactionValidityError, ValidateUnsigned},
+    };
+    use sp_core::{ecdsa, Pair};
+    use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+    const GAS_PRICE: u64 = 1_000_000_000;
+
+    fn signed_transaction(
+        pair: &ecdsa::Pair,
+        nonce: u64,
+        chain_id: u64,
+        action: ethereum::TransactionAction,
+        value: u64,
+    ) -> Transaction {
+        let message = LegacyTransactionMessage {
+            nonce: nonce.into(),
+            gas_price: GAS_PRICE.into(),
+            gas_limit: 1_000_000u64.into(),
+            action,
+            value: value.into(),
+            input: vec![],
+            chain_id: Some(chain_id),
+        };
+        let signature = pair.sign_prehashed(&message.hash().0).0;
+
+        Transaction::Legacy(LegacyTransaction {
+            nonce: message.nonce,
+            gas_price: message.gas_price,
+            gas_limit: message.gas_limit,
+            action: message.action,
+            value: message.value,
+            input: message.input,
+            signature: TransactionSignature::new(
+                chain_id * 2 + 35 + signature[64] as u64,
+                H256::from_slice(&signature[0..32]),
+                H256::from_slice(&signature[32..64]),
+            )
+            .expect("valid signature"),
+        })
+    }
+
+    fn funded_signer() -> (ecdsa::Pair, H160) {
+        let pair = ecdsa::Pair::from_seed(&[7u8; 32]);
+        let probe = signed_transaction(&pair, 0, 0, ethereum::TransactionAction::Create, 0);
+        let source = recover_signer(&probe).expect("signature recovers");
+        Balances::make_free_balance_be(&source, 1_000_000_000_000_000_000);
+        (pair, source)
+    }
+
+    #[test]
+    fn transact_executes_signed_transaction_as_signer() {
+        new_test_ext().execute_with(|| {
+            let (pair, source) = funded_signer();
+            let target = H160::repeat_byte(0x11);
+            let transaction = signed_transaction(
+                &pair,
+                0,
+                0,
+                ethereum::TransactionAction::Call(target),
+                1_000,
+            );
+
+            assert_ok!(EVM::transact(RuntimeOrigin::none(), transaction));
+            assert_eq!(Balances::free_balance(target), 1_000);
+            assert_eq!(EVM::account_basic(&source).0.nonce, U256::from(1));
+        });
+    }
+
+    #[test]
+    fn transact_records_transaction_status_for_the_block() {
+        new_test_ext().execute_with(|| {
+            let (pair, source) = funded_signer();
+            let target = H160::repeat_byte(0x11);
+            let transaction = signed_transaction(
+                &pair,
+                0,
+                0,
+                ethereum::TransactionAction::Call(target),
+                1_000,
+            );
+
+            assert_ok!(EVM::transact(RuntimeOrigin::none(), transaction.clone()));
+            assert_eq!(
+                <BlockTransactions<Test>>::get(),
+                vec![(
+                    transaction.clone(),
+                    TransactionStatus {
+                        transaction_hash: transaction.hash(),
+                        transaction_index: 0,
+                        from: source,
+                        to: Some(target),
+                        contract_address: None,
+                        used_gas: U256::from(21_000),
+                        succeeded: true,
+                    }
+                )]
+            );
+
+            <EVM as Hooks<u64>>::on_initialize(2);
+            assert!(<BlockTransactions<Test>>::get().is_empty());
+        });
+    }
+
+    #[test]
+    fn transact_tags_transactions_by_signer_and_nonce() {
+        new_test_ext().execute_with(|| {
+            let (pair, source) = funded_signer();
+            let action = ethereum::TransactionAction::Call(H160::repeat_byte(0x11));
+
+            let ready = EVM::validate_unsigned(
+                TransactionSource::External,
+                &Call::transact {
+                    transaction: signed_transaction(&pair, 0, 0, action, 1),
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                ready.provides,
+                vec![("EvmTransact", (source, U256::from(0))).encode()]
+            );
+            assert!(ready.requires.is_empty());
+
+            let future = EVM::validate_unsigned(
+                TransactionSource::External,
+                &Call::transact {
+                    transaction: signed_transaction(&pair, 1, 0, action, 1),
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                future.provides,
+                vec![("EvmTransact", (source, U256::from(1))).encode()]
+            );
+            assert_eq!(
+                future.requires,
+                vec![("EvmTransact", (source, U256::from(0))).encode()]
+            );
+        });
+    }
+
+    #[test]
+    fn transact_rejects_foreign_chain_id() {
+        new_test_ext().execute_with(|| {
+            let (pair, _) = funded_signer();
+            let transaction = signed_transaction(
+                &pair,
+                0,
+                42,
+                ethereum::TransactionAction::Call(H160::repeat_byte(0x11)),
+                1,
+            );
+
+            assert_eq!(
+                EVM::validate_unsigned(
+                    TransactionSource::External,
+                    &Call::transact { transaction },
+                ),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadSigner
+                ))
+            );
+        });
+    }
+}
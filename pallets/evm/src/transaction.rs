@@ -0,0 +1,168 @@
+//! Signed Ethereum transactions dispatched through `Pallet::transact`, so that wallets and
+//! tooling (MetaMask, Hardhat, ethers) can submit raw transactions via `eth_sendRawTransaction`.
+
+use ethereum::{
+    EIP1559TransactionMessage, EIP2930TransactionMessage, LegacyTransactionMessage,
+    TransactionAction, TransactionV2,
+};
+use fp_evm::{CheckEvmTransactionInput, InvalidEvmTransactionError};
+use sp_core::{H160, H256, U256};
+use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_std::vec::Vec;
+
+pub use ethereum::TransactionV2 as Transaction;
+
+/// Fields shared by legacy, EIP-2930 and EIP-1559 transactions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionData {
+    pub action: TransactionAction,
+    pub input: Vec<u8>,
+    pub nonce: U256,
+    pub gas_limit: U256,
+    pub gas_price: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub value: U256,
+    pub chain_id: Option<u64>,
+    pub access_list: Vec<(H160, Vec<H256>)>,
+}
+
+impl TransactionData {
+    pub fn target(&self) -> Option<H160> {
+        match self.action {
+            TransactionAction::Call(target) => Some(target),
+            TransactionAction::Create => None,
+        }
+    }
+
+    /// Legacy transactions pay `gas_price` regardless of the base fee.
+    pub fn max_fee_per_gas(&self) -> U256 {
+        self.max_fee_per_gas.or(self.gas_price).unwrap_or_default()
+    }
+
+    pub fn max_priority_fee_per_gas(&self) -> Option<U256> {
+        self.max_priority_fee_per_gas.or(self.gas_price)
+    }
+}
+
+impl From<&Transaction> for TransactionData {
+    fn from(transaction: &Transaction) -> Self {
+        match transaction {
+            TransactionV2::Legacy(t) => TransactionData {
+                action: t.action,
+                input: t.input.clone(),
+                nonce: t.nonce,
+                gas_limit: t.gas_limit,
+                gas_price: Some(t.gas_price),
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                value: t.value,
+                chain_id: t.signature.chain_id(),
+                access_list: Vec::new(),
+            },
+            TransactionV2::EIP2930(t) => TransactionData {
+                action: t.action,
+                input: t.input.clone(),
+                nonce: t.nonce,
+                gas_limit: t.gas_limit,
+                gas_price: Some(t.gas_price),
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                value: t.value,
+                chain_id: Some(t.chain_id),
+                access_list: access_list(&t.access_list),
+            },
+            TransactionV2::EIP1559(t) => TransactionData {
+                action: t.action,
+                input: t.input.clone(),
+                nonce: t.nonce,
+                gas_limit: t.gas_limit,
+                gas_price: None,
+                max_fee_per_gas: Some(t.max_fee_per_gas),
+                max_priority_fee_per_gas: Some(t.max_priority_fee_per_gas),
+                value: t.value,
+                chain_id: Some(t.chain_id),
+                access_list: access_list(&t.access_list),
+            },
+        }
+    }
+}
+
+impl From<TransactionData> for CheckEvmTransactionInput {
+    fn from(data: TransactionData) -> Self {
+        CheckEvmTransactionInput {
+            chain_id: data.chain_id,
+            to: data.target(),
+            input: data.input,
+            nonce: data.nonce,
+            gas_limit: data.gas_limit,
+            gas_price: data.gas_price,
+            max_fee_per_gas: data.max_fee_per_gas,
+            max_priority_fee_per_gas: data.max_priority_fee_per_gas,
+            value: data.value,
+            access_list: data.access_list,
+        }
+    }
+}
+
+fn access_list(items: &[ethereum::AccessListItem]) -> Vec<(H160, Vec<H256>)> {
+    items
+        .iter()
+        .map(|item| (item.address, item.storage_keys.clone()))
+        .collect()
+}
+
+pub fn gas_limit(transaction: &Transaction) -> U256 {
+    match transaction {
+        TransactionV2::Legacy(t) => t.gas_limit,
+        TransactionV2::EIP2930(t) => t.gas_limit,
+        TransactionV2::EIP1559(t) => t.gas_limit,
+    }
+}
+
+/// Recovers the address that signed the transaction, `None` for an invalid signature.
+pub fn recover_signer(transaction: &Transaction) -> Option<H160> {
+    let mut sig = [0u8; 65];
+    let mut msg = [0u8; 32];
+    match transaction {
+        TransactionV2::Legacy(t) => {
+            sig[0..32].copy_from_slice(&t.signature.r()[..]);
+            sig[32..64].copy_from_slice(&t.signature.s()[..]);
+            sig[64] = t.signature.standard_v();
+            msg.copy_from_slice(&LegacyTransactionMessage::from(t.clone()).hash()[..]);
+        },
+        TransactionV2::EIP2930(t) => {
+            sig[0..32].copy_from_slice(&t.r[..]);
+            sig[32..64].copy_from_slice(&t.s[..]);
+            sig[64] = t.odd_y_parity as u8;
+            msg.copy_from_slice(&EIP2930TransactionMessage::from(t.clone()).hash()[..]);
+        },
+        TransactionV2::EIP1559(t) => {
+            sig[0..32].copy_from_slice(&t.r[..]);
+            sig[32..64].copy_from_slice(&t.s[..]);
+            sig[64] = t.odd_y_parity as u8;
+            msg.copy_from_slice(&EIP1559TransactionMessage::from(t.clone()).hash()[..]);
+        },
+    }
+    let pubkey = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &msg).ok()?;
+    Some(H160::from(H256::from(sp_io::hashing::keccak_256(&pubkey))))
+}
+
+/// Maps EVM transaction checks onto transaction pool validity errors.
+pub struct InvalidTransactionWrapper(pub InvalidTransaction);
+
+impl From<InvalidEvmTransactionError> for InvalidTransactionWrapper {
+    fn from(error: InvalidEvmTransactionError) -> Self {
+        InvalidTransactionWrapper(match error {
+            InvalidEvmTransactionError::GasLimitTooLow
+            | InvalidEvmTransactionError::GasLimitTooHigh => InvalidTransaction::ExhaustsResources,
+            InvalidEvmTransactionError::GasPriceTooLow
+            | InvalidEvmTransactionError::PriorityFeeTooHigh
+            | InvalidEvmTransactionError::BalanceTooLow
+            | InvalidEvmTransactionError::InvalidPaymentInput => InvalidTransaction::Payment,
+            InvalidEvmTransactionError::TxNonceTooLow => InvalidTransaction::Stale,
+            InvalidEvmTransactionError::TxNonceTooHigh => InvalidTransaction::Future,
+            InvalidEvmTransactionError::InvalidChainId => InvalidTransaction::BadSigner,
+        })
+    }
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::{traits::Saturating, Percent};
+use sp_std::prelude::*;
+
+/// Funds the account with a thousand times the largest of the bond and stake minimums.
+fn funded<T: Config>(who: &T::AccountId) {
+    let fixtures = StakingFixtures::<T>::get();
+    let balance = fixtures
+        .min_candidate_bond
+        .max(fixtures.min_atomic_stake)
+        .max(fixtures.min_total_stake)
+        .saturating_mul(1_000u32.into())
+        .saturating_add(T::Currency::minimum_balance());
+    T::Currency::make_free_balance_be(who, balance);
+}
+
+/// Registers the funded account as executor candidate with `bond`.
+fn registered_executor<T: Config>(who: &T::AccountId, bond: BalanceOf<T>) {
+    funded::<T>(who);
+    Pallet::<T>::register_executor(
+        RawOrigin::Signed(who.clone()).into(),
+        bond,
+        Percent::from_percent(0),
+        Percent::from_percent(0),
+    )
+    .expect("funded account registers as executor");
+}
+
+/// Smallest stake satisfying both the atomic and the total stake minimums.
+fn min_stake<T: Config>() -> BalanceOf<T> {
+    let fixtures = StakingFixtures::<T>::get();
+    fixtures.min_atomic_stake.max(fixtures.min_total_stake)
+}
+
+/// Nominates `executor` with `amount` staked by the funded account.
+fn staked<T: Config>(staker: &T::AccountId, executor: &T::AccountId, amount: BalanceOf<T>) {
+    funded::<T>(staker);
+    Pallet::<T>::stake(
+        RawOrigin::Signed(staker.clone()).into(),
+        executor.clone(),
+        amount,
+    )
+    .expect("funded account stakes on executor");
+}
+
+/// Schedules the staking request of `staker`, executable with the current round.
+fn due_staking_request<T: Config>(
+    staker: &T::AccountId,
+    executor: &T::AccountId,
+    action: StakingAction<BalanceOf<T>>,
+) {
+    ScheduledStakingRequests::<T>::insert(
+        executor,
+        vec![ScheduledStakingRequest {
+            staker: staker.clone(),
+            when_executable: T::Clock::current_round().index,
+            action,
+        }],
+    );
+}
+
+/// Inserts c candidates holding `min_executor_bond` and s stakes of `min_atomic_stake` each,
+///     along with the snapshots of the round falling out of retention at `round`.
+fn setup_candidates<T: Config>(c: u32, s: u32, round: RoundIndex) {
+    let fixtures = StakingFixtures::<T>::get();
+    for i in 0..c {
+        let executor: T::AccountId = account("executor", i, 0);
+        CandidateBonds::<T>::insert(&executor, fixtures.min_executor_bond);
+        CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Active);
+        for j in 0..s {
+            let staker: T::AccountId = account("staker", j, i);
+            Stakes::<T>::insert(&executor, &staker, fixtures.min_atomic_stake);
+        }
+        Snapshots::<T>::insert(
+            round.saturating_sub(T::SnapshotsRetention::get()),
+            &executor,
+            Pallet::<T>::compute_snapshot(&executor),
+        );
+    }
+    CandidateCount::<T>::put(c);
+}
+
+benchmarks! {
+
+    set_fixtures {
+        let fixtures = StakingFixtures::<T>::get();
+    }: _(RawOrigin::Root, fixtures.clone())
+    verify {
+        assert_eq!(StakingFixtures::<T>::get(), fixtures);
+    }
+
+    register_executor {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let bond = StakingFixtures::<T>::get().min_candidate_bond;
+    }: _(RawOrigin::Signed(caller.clone()), bond, Percent::from_percent(0), Percent::from_percent(0))
+    verify {
+        assert_eq!(CandidateBonds::<T>::get(&caller), Some(bond));
+    }
+
+    configure_executor {
+        let caller: T::AccountId = whitelisted_caller();
+        registered_executor::<T>(&caller, StakingFixtures::<T>::get().min_candidate_bond);
+    }: _(RawOrigin::Signed(caller.clone()), Percent::from_percent(0), Percent::from_percent(0))
+    verify {
+        assert!(PendingConfigurations::<T>::contains_key(&caller));
+    }
+
+    execute_configure_executor {
+        let caller: T::AccountId = whitelisted_caller();
+        registered_executor::<T>(&caller, StakingFixtures::<T>::get().min_candidate_bond);
+        PendingConfigurations::<T>::insert(
+            &caller,
+            ScheduledConfigurationRequest {
+                when_executable: T::Clock::current_round().index,
+                commission: Percent::from_percent(0),
+                risk: Percent::from_percent(0),
+            },
+        );
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!PendingConfigurations::<T>::contains_key(&caller));
+    }
+
+    executor_bond_more {
+        let caller: T::AccountId = whitelisted_caller();
+        let bond = StakingFixtures::<T>::get().min_candidate_bond;
+        registered_executor::<T>(&caller, bond);
+    }: _(RawOrigin::Signed(caller.clone()), bond)
+    verify {
+        assert_eq!(CandidateBonds::<T>::get(&caller), Some(bond.saturating_add(bond)));
+    }
+
+    schedule_executor_bond_less {
+        let caller: T::AccountId = whitelisted_caller();
+        let bond = StakingFixtures::<T>::get().min_candidate_bond;
+        registered_executor::<T>(&caller, bond.saturating_add(bond));
+    }: _(RawOrigin::Signed(caller.clone()), bond)
+    verify {
+        assert!(CandidateBondLessRequests::<T>::contains_key(&caller));
+    }
+
+    execute_executor_bond_less {
+        let caller: T::AccountId = whitelisted_caller();
+        let bond = StakingFixtures::<T>::get().min_candidate_bond;
+        registered_executor::<T>(&caller, bond.saturating_add(bond));
+        CandidateBondLessRequests::<T>::insert(
+            &caller,
+            CandidateBondLessRequest {
+                amount: bond,
+                when_executable: T::Clock::current_round().index,
+            },
+        );
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(CandidateBonds::<T>::get(&caller), Some(bond));
+    }
+
+    cancel_executor_bond_less {
+        let caller: T::AccountId = whitelisted_caller();
+        let bond = StakingFixtures::<T>::get().min_candidate_bond;
+        registered_executor::<T>(&caller, bond.saturating_add(bond));
+        CandidateBondLessRequests::<T>::insert(
+            &caller,
+            CandidateBondLessRequest {
+                amount: bond,
+                when_executable: T::Clock::current_round().index,
+            },
+        );
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!CandidateBondLessRequests::<T>::contains_key(&caller));
+    }
+
+    schedule_leave_executors {
+        let caller: T::AccountId = whitelisted_caller();
+        registered_executor::<T>(&caller, StakingFixtures::<T>::get().min_candidate_bond);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(matches!(CandidateStatuses::<T>::get(&caller), ExecutorStatus::Leaving(_)));
+    }
+
+    cancel_leave_executors {
+        let caller: T::AccountId = whitelisted_caller();
+        registered_executor::<T>(&caller, StakingFixtures::<T>::get().min_candidate_bond);
+        CandidateStatuses::<T>::insert(
+            &caller,
+            ExecutorStatus::Leaving(T::Clock::current_round().index),
+        );
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(CandidateStatuses::<T>::get(&caller), ExecutorStatus::Idle);
+    }
+
+    execute_leave_executors {
+        let s in 0 .. Pallet::<T>::max_stakes_per_candidate();
+
+        // Worst case unlocks s stakes of the executor leaving the active set.
+        let caller: T::AccountId = whitelisted_caller();
+        registered_executor::<T>(&caller, StakingFixtures::<T>::get().min_candidate_bond);
+        for i in 0..s {
+            let staker: T::AccountId = account("staker", i, 0);
+            staked::<T>(&staker, &caller, min_stake::<T>());
+        }
+        ActiveSet::<T>::put(vec![caller.clone()]);
+        CandidateStatuses::<T>::insert(
+            &caller,
+            ExecutorStatus::Leaving(T::Clock::current_round().index),
+        );
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!CandidateBonds::<T>::contains_key(&caller));
+        assert_eq!(Stakes::<T>::iter_prefix(&caller).count(), 0);
+    }
+
+    stake {
+        let executor: T::AccountId = account("executor", 0, 0);
+        registered_executor::<T>(&executor, StakingFixtures::<T>::get().min_candidate_bond);
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let amount = min_stake::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), executor.clone(), amount)
+    verify {
+        assert_eq!(Stakes::<T>::get(&executor, &caller), Some(amount));
+    }
+
+    schedule_revoke_stake {
+        let executor: T::AccountId = account("executor", 0, 0);
+        registered_executor::<T>(&executor, StakingFixtures::<T>::get().min_candidate_bond);
+        let caller: T::AccountId = whitelisted_caller();
+        staked::<T>(&caller, &executor, min_stake::<T>());
+    }: _(RawOrigin::Signed(caller.clone()), executor.clone())
+    verify {
+        assert_eq!(ScheduledStakingRequests::<T>::get(&executor).len(), 1);
+    }
+
+    schedule_decrease_stake {
+        let executor: T::AccountId = account("executor", 0, 0);
+        registered_executor::<T>(&executor, StakingFixtures::<T>::get().min_candidate_bond);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = min_stake::<T>();
+        staked::<T>(&caller, &executor, amount.saturating_add(amount));
+    }: _(RawOrigin::Signed(caller.clone()), executor.clone(), amount)
+    verify {
+        assert_eq!(ScheduledStakingRequests::<T>::get(&executor).len(), 1);
+    }
+
+    execute_staking_request {
+        // Worst case revokes the whole stake, removing it from every index.
+        let executor: T::AccountId = account("executor", 0, 0);
+        registered_executor::<T>(&executor, StakingFixtures::<T>::get().min_candidate_bond);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = min_stake::<T>();
+        staked::<T>(&caller, &executor, amount);
+        due_staking_request::<T>(&caller, &executor, StakingAction::Revoke(amount));
+    }: _(RawOrigin::Signed(caller.clone()), executor.clone())
+    verify {
+        assert_eq!(Stakes::<T>::get(&executor, &caller), None);
+        assert!(ScheduledStakingRequests::<T>::get(&executor).is_empty());
+    }
+
+    cancel_staking_request {
+        let executor: T::AccountId = account("executor", 0, 0);
+        registered_executor::<T>(&executor, StakingFixtures::<T>::get().min_candidate_bond);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = min_stake::<T>();
+        staked::<T>(&caller, &executor, amount);
+        due_staking_request::<T>(&caller, &executor, StakingAction::Revoke(amount));
+    }: _(RawOrigin::Signed(caller.clone()), executor.clone())
+    verify {
+        assert!(ScheduledStakingRequests::<T>::get(&executor).is_empty());
+    }
+
+    select_active_set_and_snapshot {
+        let c in 1 .. T::MaxCandidates::get();
+        let s in 0 .. Pallet::<T>::max_stakes_per_candidate();
+
+        // Worst case keeps every candidate in the active set.
+        let mut fixtures = StakingFixtures::<T>::get();
+        fixtures.active_set_size.max = u32::MAX;
+        StakingFixtures::<T>::put(fixtures);
+
+        let round = T::SnapshotsRetention::get().saturating_add(1);
+        setup_candidates::<T>(c, s, round);
+    }: {
+        Pallet::<T>::select_active_set_and_snapshot(round);
+    }
+    verify {
+        assert_eq!(ActiveSet::<T>::get().len() as u32, c);
+        assert_eq!(LastSnapshotRound::<T>::get(), round);
+        assert_eq!(Snapshots::<T>::iter_prefix(1).count(), 0);
+    }
+}
@@ -0,0 +1,1121 @@
+//! <!-- markdown-link-check-disable -->
+//! # Executors pallet
+//!
+//! Executor staking: candidates register with a minimum self-bond, stakers nominate them,
+//! bond-less, leave and unstake requests are scheduled with delays counted in `pallet_clock`
+//! rounds. At the start of every round the pallet snapshots the stake behind each selected
+//! executor, which is what the rest of Circuit reads through the [`Executors`] trait.
+//! </pre></p></details>
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::type_complexity)]
+
+pub use crate::pallet::*;
+
+pub use t3rn_primitives::{
+    clock::Clock,
+    common::RoundIndex,
+    executors::{
+        Bond, CandidateBondLessRequest, ExecutorInfo, ExecutorSnapshot, ExecutorStatus, Executors,
+        Fixtures, NominatedStake, ScheduledConfigurationRequest, ScheduledStakingRequest,
+        StakingAction, EXECUTOR_LOCK_ID,
+    },
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    pub use crate::weights::WeightInfo;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, LockableCurrency, ReservableCurrency, WithdrawReasons},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Percent,
+    };
+    use sp_std::prelude::*;
+
+    t3rn_primitives::reexport_currency_types!();
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Type representing the weight of this pallet
+        type WeightInfo: weights::WeightInfo;
+
+        /// Self-bonds and stakes are locked, bidding collateral is reserved.
+        type Currency: LockableCurrency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+        /// Source of rounds - all of the scheduled requests are delayed by rounds.
+        type Clock: Clock<Self>;
+
+        /// Staking fixtures used until governance overrides them with `set_fixtures`.
+        type DefaultFixtures: Get<Fixtures<BalanceOf<Self>>>;
+
+        /// Maximum number of executor candidates, bounding the active set selection of each round.
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// Number of rounds the snapshots are kept for before they're pruned.
+        #[pallet::constant]
+        type SnapshotsRetention: Get<RoundIndex>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::storage]
+    #[pallet::getter(fn fixtures)]
+    pub type StakingFixtures<T: Config> =
+        StorageValue<_, Fixtures<BalanceOf<T>>, ValueQuery, T::DefaultFixtures>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_bond)]
+    /// Self-bond of each executor candidate, locked under `EXECUTOR_LOCK_ID` together with
+    /// the stakes of the account.
+    pub type CandidateBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_count)]
+    pub type CandidateCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_status)]
+    pub type CandidateStatuses<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ExecutorStatus, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn executor_config)]
+    pub type ExecutorConfig<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ExecutorInfo>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_configuration)]
+    pub type PendingConfigurations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ScheduledConfigurationRequest>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_bond_less_request)]
+    pub type CandidateBondLessRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CandidateBondLessRequest<BalanceOf<T>>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn stakes_of)]
+    pub type Stakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Executor
+        Blake2_128Concat,
+        T::AccountId, // Staker
+        BalanceOf<T>,
+    >;
+
+    #[pallet::storage]
+    /// Executors nominated by each staker, indexing `Stakes` by staker.
+    pub type StakesOfStaker<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Staker
+        Blake2_128Concat,
+        T::AccountId, // Executor
+        (),
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn staker_stakes_count)]
+    /// Number of executors nominated by each staker, capped by `max_stakes_per_staker`.
+    pub type StakerStakesCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_stakes_count)]
+    /// Number of stakers nominating each executor, capped by the sum of
+    /// `max_top_stakes_per_candidate` and `max_bottom_stakes_per_candidate`.
+    pub type CandidateStakesCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn staker_total)]
+    /// Sum of all stakes of a staker, locked under `EXECUTOR_LOCK_ID` together with its self-bond.
+    pub type StakerTotals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_staking_requests)]
+    pub type ScheduledStakingRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Executor
+        Vec<ScheduledStakingRequest<T::AccountId, BalanceOf<T>>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_bonds)]
+    /// Collateral reserved by executors for the SFX they bid on.
+    pub type CollateralBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn active_set)]
+    pub type ActiveSet<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn snapshot)]
+    pub type Snapshots<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        ExecutorSnapshot<T::AccountId, BalanceOf<T>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_snapshot_round)]
+    pub type LastSnapshotRound<T: Config> = StorageValue<_, RoundIndex, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub (super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        FixturesUpdated {
+            fixtures: Fixtures<BalanceOf<T>>,
+        },
+        ExecutorRegistered {
+            executor: T::AccountId,
+            bond: BalanceOf<T>,
+            commission: Percent,
+            risk: Percent,
+        },
+        ExecutorConfigurationScheduled {
+            executor: T::AccountId,
+            request: ScheduledConfigurationRequest,
+        },
+        ExecutorConfigured {
+            executor: T::AccountId,
+            commission: Percent,
+            risk: Percent,
+        },
+        ExecutorBondIncreased {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+            new_bond: BalanceOf<T>,
+        },
+        ExecutorBondLessScheduled {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+            when_executable: RoundIndex,
+        },
+        ExecutorBondLessExecuted {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+            new_bond: BalanceOf<T>,
+        },
+        ExecutorBondLessCancelled {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ExecutorLeaveScheduled {
+            executor: T::AccountId,
+            when_executable: RoundIndex,
+        },
+        ExecutorLeaveCancelled {
+            executor: T::AccountId,
+        },
+        ExecutorLeft {
+            executor: T::AccountId,
+            unlocked_bond: BalanceOf<T>,
+            unlocked_stakes: BalanceOf<T>,
+        },
+        Staked {
+            staker: T::AccountId,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        StakingRequestScheduled {
+            staker: T::AccountId,
+            executor: T::AccountId,
+            request: ScheduledStakingRequest<T::AccountId, BalanceOf<T>>,
+        },
+        StakingRequestExecuted {
+            staker: T::AccountId,
+            executor: T::AccountId,
+            action: StakingAction<BalanceOf<T>>,
+        },
+        StakingRequestCancelled {
+            staker: T::AccountId,
+            executor: T::AccountId,
+            action: StakingAction<BalanceOf<T>>,
+        },
+        NewActiveSet {
+            round: RoundIndex,
+            executors: Vec<T::AccountId>,
+        },
+        CollateralReserved {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        CollateralUnreserved {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ExecutorSlashed {
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InvalidFixtures,
+        AlreadyExecutor,
+        NotExecutor,
+        CandidateBondBelowMin,
+        TooHighCommission,
+        TooHighRisk,
+        NotEnoughFreeBalance,
+        ConfigurationRequestAlreadyScheduled,
+        NoConfigurationRequest,
+        BondLessRequestAlreadyScheduled,
+        NoBondLessRequest,
+        BondLessRequestBelowMinBond,
+        ExecutorAlreadyLeaving,
+        ExecutorNotLeaving,
+        PendingRequestNotDueYet,
+        ExecutorIsLeaving,
+        StakeBelowMin,
+        StakerTotalBelowMin,
+        TooManyStakes,
+        TooManyStakesOnCandidate,
+        NoStakeFound,
+        StakingRequestAlreadyScheduled,
+        NoStakingRequest,
+        DecreaseExceedsStake,
+        DecreaseBelowMinStake,
+        TooManyCandidates,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        // Snapshots are taken once per round, when the clock reports a round the pallet
+        // hasn't seen yet.
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let round = T::Clock::current_round();
+            if round.index > LastSnapshotRound::<T>::get() {
+                Self::select_active_set_and_snapshot(round.index);
+                T::WeightInfo::select_active_set_and_snapshot(
+                    CandidateCount::<T>::get(),
+                    Self::max_stakes_per_candidate(),
+                )
+            } else {
+                T::DbWeight::get().reads(2)
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Override the staking fixtures. Root only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_fixtures())]
+        pub fn set_fixtures(
+            origin: OriginFor<T>,
+            fixtures: Fixtures<BalanceOf<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                fixtures.are_valid() && fixtures.active_set_size.is_valid(),
+                Error::<T>::InvalidFixtures
+            );
+            StakingFixtures::<T>::put(fixtures.clone());
+            Self::deposit_event(Event::FixturesUpdated { fixtures });
+            Ok(())
+        }
+
+        /// Join the executor candidates with `bond` locked as self-bond.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::register_executor())]
+        pub fn register_executor(
+            origin: OriginFor<T>,
+            bond: BalanceOf<T>,
+            commission: Percent,
+            risk: Percent,
+        ) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            ensure!(
+                !CandidateBonds::<T>::contains_key(&executor),
+                Error::<T>::AlreadyExecutor
+            );
+            let fixtures = StakingFixtures::<T>::get();
+            ensure!(
+                bond >= fixtures.min_candidate_bond,
+                Error::<T>::CandidateBondBelowMin
+            );
+            Self::ensure_valid_configuration(&fixtures, commission, risk)?;
+            ensure!(
+                CandidateCount::<T>::get() < T::MaxCandidates::get(),
+                Error::<T>::TooManyCandidates
+            );
+            ensure!(
+                T::Currency::free_balance(&executor)
+                    >= bond.saturating_add(StakerTotals::<T>::get(&executor)),
+                Error::<T>::NotEnoughFreeBalance
+            );
+
+            CandidateBonds::<T>::insert(&executor, bond);
+            CandidateCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            Self::update_lock(&executor);
+            CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Idle);
+            ExecutorConfig::<T>::insert(&executor, ExecutorInfo { commission, risk });
+
+            Self::deposit_event(Event::ExecutorRegistered {
+                executor,
+                bond,
+                commission,
+                risk,
+            });
+            Ok(())
+        }
+
+        /// Schedule a commission and risk change, executable after `configure_executor_delay` rounds.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::configure_executor())]
+        pub fn configure_executor(
+            origin: OriginFor<T>,
+            commission: Percent,
+            risk: Percent,
+        ) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            Self::ensure_executor(&executor)?;
+            ensure!(
+                !PendingConfigurations::<T>::contains_key(&executor),
+                Error::<T>::ConfigurationRequestAlreadyScheduled
+            );
+            let fixtures = StakingFixtures::<T>::get();
+            Self::ensure_valid_configuration(&fixtures, commission, risk)?;
+
+            let request = ScheduledConfigurationRequest {
+                when_executable: Self::round_after(fixtures.configure_executor_delay),
+                commission,
+                risk,
+            };
+            PendingConfigurations::<T>::insert(&executor, request.clone());
+
+            Self::deposit_event(Event::ExecutorConfigurationScheduled { executor, request });
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::execute_configure_executor())]
+        pub fn execute_configure_executor(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let request = PendingConfigurations::<T>::get(&executor)
+                .ok_or(Error::<T>::NoConfigurationRequest)?;
+            Self::ensure_due(request.when_executable)?;
+
+            ExecutorConfig::<T>::insert(
+                &executor,
+                ExecutorInfo {
+                    commission: request.commission,
+                    risk: request.risk,
+                },
+            );
+            PendingConfigurations::<T>::remove(&executor);
+
+            Self::deposit_event(Event::ExecutorConfigured {
+                executor,
+                commission: request.commission,
+                risk: request.risk,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::executor_bond_more())]
+        pub fn executor_bond_more(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let new_bond = Self::do_increase_bond(&executor, amount)?;
+            Self::deposit_event(Event::ExecutorBondIncreased {
+                executor,
+                amount,
+                new_bond,
+            });
+            Ok(())
+        }
+
+        /// Schedule lowering of the self-bond, executable after `candidate_bond_less_delay` rounds.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::schedule_executor_bond_less())]
+        pub fn schedule_executor_bond_less(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let bond = Self::ensure_executor(&executor)?;
+            ensure!(
+                !CandidateBondLessRequests::<T>::contains_key(&executor),
+                Error::<T>::BondLessRequestAlreadyScheduled
+            );
+            let fixtures = StakingFixtures::<T>::get();
+            ensure!(
+                bond.saturating_sub(amount) >= fixtures.min_candidate_bond,
+                Error::<T>::BondLessRequestBelowMinBond
+            );
+
+            let when_executable = Self::round_after(fixtures.candidate_bond_less_delay);
+            CandidateBondLessRequests::<T>::insert(
+                &executor,
+                CandidateBondLessRequest {
+                    amount,
+                    when_executable,
+                },
+            );
+
+            Self::deposit_event(Event::ExecutorBondLessScheduled {
+                executor,
+                amount,
+                when_executable,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::execute_executor_bond_less())]
+        pub fn execute_executor_bond_less(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let bond = Self::ensure_executor(&executor)?;
+            let request = CandidateBondLessRequests::<T>::get(&executor)
+                .ok_or(Error::<T>::NoBondLessRequest)?;
+            Self::ensure_due(request.when_executable)?;
+            ensure!(
+                bond.saturating_sub(request.amount)
+                    >= StakingFixtures::<T>::get().min_candidate_bond,
+                Error::<T>::BondLessRequestBelowMinBond
+            );
+
+            let new_bond = bond.saturating_sub(request.amount);
+            CandidateBonds::<T>::insert(&executor, new_bond);
+            Self::update_lock(&executor);
+            CandidateBondLessRequests::<T>::remove(&executor);
+
+            Self::deposit_event(Event::ExecutorBondLessExecuted {
+                executor,
+                amount: request.amount,
+                new_bond,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::cancel_executor_bond_less())]
+        pub fn cancel_executor_bond_less(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let request = CandidateBondLessRequests::<T>::take(&executor)
+                .ok_or(Error::<T>::NoBondLessRequest)?;
+            Self::deposit_event(Event::ExecutorBondLessCancelled {
+                executor,
+                amount: request.amount,
+            });
+            Ok(())
+        }
+
+        /// Schedule leaving the candidates, executable after `leave_candidates_delay` rounds.
+        /// Leaving executors are no longer selected into the active set.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::schedule_leave_executors())]
+        pub fn schedule_leave_executors(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            Self::ensure_executor(&executor)?;
+            ensure!(
+                !matches!(
+                    CandidateStatuses::<T>::get(&executor),
+                    ExecutorStatus::Leaving(_)
+                ),
+                Error::<T>::ExecutorAlreadyLeaving
+            );
+            let when_executable =
+                Self::round_after(StakingFixtures::<T>::get().leave_candidates_delay);
+            CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Leaving(when_executable));
+
+            Self::deposit_event(Event::ExecutorLeaveScheduled {
+                executor,
+                when_executable,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::cancel_leave_executors())]
+        pub fn cancel_leave_executors(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            Self::ensure_executor(&executor)?;
+            ensure!(
+                matches!(
+                    CandidateStatuses::<T>::get(&executor),
+                    ExecutorStatus::Leaving(_)
+                ),
+                Error::<T>::ExecutorNotLeaving
+            );
+            CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Idle);
+            Self::deposit_event(Event::ExecutorLeaveCancelled { executor });
+            Ok(())
+        }
+
+        /// Remove the executor, unlocking its self-bond and every stake nominating it.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::execute_leave_executors(
+            Pallet::<T>::max_stakes_per_candidate()
+        ))]
+        pub fn execute_leave_executors(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+            let bond = Self::ensure_executor(&executor)?;
+            match CandidateStatuses::<T>::get(&executor) {
+                ExecutorStatus::Leaving(when_executable) => Self::ensure_due(when_executable)?,
+                _ => return Err(Error::<T>::ExecutorNotLeaving.into()),
+            }
+
+            let mut unlocked_stakes: BalanceOf<T> = Zero::zero();
+            for (staker, amount) in Stakes::<T>::drain_prefix(&executor) {
+                StakesOfStaker::<T>::remove(&staker, &executor);
+                StakerStakesCount::<T>::mutate_exists(&staker, Self::decrement_count);
+                Self::reduce_staker_total(&staker, amount);
+                unlocked_stakes = unlocked_stakes.saturating_add(amount);
+            }
+            CandidateStakesCount::<T>::remove(&executor);
+            ScheduledStakingRequests::<T>::remove(&executor);
+
+            CandidateBonds::<T>::remove(&executor);
+            CandidateCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            Self::update_lock(&executor);
+            CandidateStatuses::<T>::remove(&executor);
+            CandidateBondLessRequests::<T>::remove(&executor);
+            ExecutorConfig::<T>::remove(&executor);
+            PendingConfigurations::<T>::remove(&executor);
+            ActiveSet::<T>::mutate(|active_set| active_set.retain(|a| a != &executor));
+
+            Self::deposit_event(Event::ExecutorLeft {
+                executor,
+                unlocked_bond: bond,
+                unlocked_stakes,
+            });
+            Ok(())
+        }
+
+        /// Nominate `executor` with `amount`, locked on the staker's account.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::stake())]
+        pub fn stake(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+            Self::ensure_executor(&executor)?;
+            ensure!(
+                !matches!(
+                    CandidateStatuses::<T>::get(&executor),
+                    ExecutorStatus::Leaving(_)
+                ),
+                Error::<T>::ExecutorIsLeaving
+            );
+            let fixtures = StakingFixtures::<T>::get();
+            ensure!(
+                amount >= fixtures.min_atomic_stake,
+                Error::<T>::StakeBelowMin
+            );
+
+            let current_stake = Stakes::<T>::get(&executor, &staker);
+            if current_stake.is_none() {
+                ensure!(
+                    StakerStakesCount::<T>::get(&staker) < fixtures.max_stakes_per_staker,
+                    Error::<T>::TooManyStakes
+                );
+                ensure!(
+                    CandidateStakesCount::<T>::get(&executor)
+                        < fixtures
+                            .max_top_stakes_per_candidate
+                            .saturating_add(fixtures.max_bottom_stakes_per_candidate),
+                    Error::<T>::TooManyStakesOnCandidate
+                );
+            }
+
+            let new_total = StakerTotals::<T>::get(&staker).saturating_add(amount);
+            ensure!(
+                new_total >= fixtures.min_total_stake,
+                Error::<T>::StakerTotalBelowMin
+            );
+            // Self-bond and stakes share the lock, so the staker's own self-bond can't back its stakes.
+            let own_bond = CandidateBonds::<T>::get(&staker).unwrap_or_else(Zero::zero);
+            ensure!(
+                T::Currency::free_balance(&staker) >= new_total.saturating_add(own_bond),
+                Error::<T>::NotEnoughFreeBalance
+            );
+
+            if current_stake.is_none() {
+                StakesOfStaker::<T>::insert(&staker, &executor, ());
+                StakerStakesCount::<T>::mutate(&staker, |count| *count = count.saturating_add(1));
+                CandidateStakesCount::<T>::mutate(&executor, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+            Stakes::<T>::insert(
+                &executor,
+                &staker,
+                current_stake
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_add(amount),
+            );
+            StakerTotals::<T>::insert(&staker, new_total);
+            Self::update_lock(&staker);
+
+            Self::deposit_event(Event::Staked {
+                staker,
+                executor,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Schedule revoking the whole stake, executable after `revoke_stake_delay` rounds.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::schedule_revoke_stake())]
+        pub fn schedule_revoke_stake(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+            let stake = Stakes::<T>::get(&executor, &staker).ok_or(Error::<T>::NoStakeFound)?;
+            let delay = StakingFixtures::<T>::get().revoke_stake_delay;
+            Self::schedule_staking_request(staker, executor, StakingAction::Revoke(stake), delay)
+        }
+
+        /// Schedule decreasing the stake by `amount`, executable after `leave_stakers_delay` rounds.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::schedule_decrease_stake())]
+        pub fn schedule_decrease_stake(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+            let stake = Stakes::<T>::get(&executor, &staker).ok_or(Error::<T>::NoStakeFound)?;
+            ensure!(stake > amount, Error::<T>::DecreaseExceedsStake);
+            let fixtures = StakingFixtures::<T>::get();
+            ensure!(
+                stake.saturating_sub(amount) >= fixtures.min_atomic_stake,
+                Error::<T>::DecreaseBelowMinStake
+            );
+            Self::schedule_staking_request(
+                staker,
+                executor,
+                StakingAction::Decrease(amount),
+                fixtures.leave_stakers_delay,
+            )
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::execute_staking_request())]
+        pub fn execute_staking_request(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+            let mut requests = ScheduledStakingRequests::<T>::get(&executor);
+            let index = requests
+                .iter()
+                .position(|request| request.staker == staker)
+                .ok_or(Error::<T>::NoStakingRequest)?;
+            Self::ensure_due(requests[index].when_executable)?;
+            let request = requests.remove(index);
+
+            let stake = Stakes::<T>::get(&executor, &staker).ok_or(Error::<T>::NoStakeFound)?;
+            // Slashes may have shrunk the stake since the request was scheduled.
+            let amount = request.action.amount().min(stake);
+            let remaining = stake.saturating_sub(amount);
+            if remaining.is_zero() {
+                Stakes::<T>::remove(&executor, &staker);
+                StakesOfStaker::<T>::remove(&staker, &executor);
+                StakerStakesCount::<T>::mutate_exists(&staker, Self::decrement_count);
+                CandidateStakesCount::<T>::mutate_exists(&executor, Self::decrement_count);
+            } else {
+                Stakes::<T>::insert(&executor, &staker, remaining);
+            }
+            Self::reduce_staker_total(&staker, amount);
+            ScheduledStakingRequests::<T>::insert(&executor, requests);
+
+            Self::deposit_event(Event::StakingRequestExecuted {
+                staker,
+                executor,
+                action: request.action,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::cancel_staking_request())]
+        pub fn cancel_staking_request(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+            let mut requests = ScheduledStakingRequests::<T>::get(&executor);
+            let index = requests
+                .iter()
+                .position(|request| request.staker == staker)
+                .ok_or(Error::<T>::NoStakingRequest)?;
+            let request = requests.remove(index);
+            ScheduledStakingRequests::<T>::insert(&executor, requests);
+
+            Self::deposit_event(Event::StakingRequestCancelled {
+                staker,
+                executor,
+                action: request.action,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn ensure_executor(executor: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+            CandidateBonds::<T>::get(executor).ok_or_else(|| Error::<T>::NotExecutor.into())
+        }
+
+        fn ensure_valid_configuration(
+            fixtures: &Fixtures<BalanceOf<T>>,
+            commission: Percent,
+            risk: Percent,
+        ) -> DispatchResult {
+            ensure!(
+                commission <= fixtures.max_commission,
+                Error::<T>::TooHighCommission
+            );
+            ensure!(risk <= fixtures.max_risk, Error::<T>::TooHighRisk);
+            Ok(())
+        }
+
+        fn ensure_due(when_executable: RoundIndex) -> DispatchResult {
+            ensure!(
+                T::Clock::current_round().index >= when_executable,
+                Error::<T>::PendingRequestNotDueYet
+            );
+            Ok(())
+        }
+
+        fn round_after(delay: u32) -> RoundIndex {
+            T::Clock::current_round().index.saturating_add(delay)
+        }
+
+        fn schedule_staking_request(
+            staker: T::AccountId,
+            executor: T::AccountId,
+            action: StakingAction<BalanceOf<T>>,
+            delay: u32,
+        ) -> DispatchResult {
+            let request = ScheduledStakingRequest {
+                staker: staker.clone(),
+                when_executable: Self::round_after(delay),
+                action,
+            };
+            ScheduledStakingRequests::<T>::try_mutate(&executor, |requests| {
+                ensure!(
+                    !requests.iter().any(|r| r.staker == staker),
+                    Error::<T>::StakingRequestAlreadyScheduled
+                );
+                requests.push(request.clone());
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::StakingRequestScheduled {
+                staker,
+                executor,
+                request,
+            });
+            Ok(())
+        }
+
+        /// Upper bound of the stakes nominating a single executor.
+        pub fn max_stakes_per_candidate() -> u32 {
+            let fixtures = StakingFixtures::<T>::get();
+            fixtures
+                .max_top_stakes_per_candidate
+                .saturating_add(fixtures.max_bottom_stakes_per_candidate)
+        }
+
+        fn decrement_count(count: &mut Option<u32>) {
+            *count = count
+                .and_then(|count| count.checked_sub(1))
+                .filter(|count| *count > 0);
+        }
+
+        fn reduce_staker_total(staker: &T::AccountId, amount: BalanceOf<T>) {
+            let new_total = StakerTotals::<T>::get(staker).saturating_sub(amount);
+            if new_total.is_zero() {
+                StakerTotals::<T>::remove(staker);
+            } else {
+                StakerTotals::<T>::insert(staker, new_total);
+            }
+            Self::update_lock(staker);
+        }
+
+        /// Self-bond and stakes of an account are held by a single lock of their sum.
+        fn update_lock(who: &T::AccountId) {
+            let locked = CandidateBonds::<T>::get(who)
+                .unwrap_or_else(Zero::zero)
+                .saturating_add(StakerTotals::<T>::get(who));
+            if locked.is_zero() {
+                T::Currency::remove_lock(EXECUTOR_LOCK_ID, who);
+            } else {
+                T::Currency::set_lock(EXECUTOR_LOCK_ID, who, locked, WithdrawReasons::all());
+            }
+        }
+
+        fn do_increase_bond(
+            executor: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let bond = Self::ensure_executor(executor)?;
+            let new_bond = bond.saturating_add(amount);
+            ensure!(
+                T::Currency::free_balance(executor)
+                    >= new_bond.saturating_add(StakerTotals::<T>::get(executor)),
+                Error::<T>::NotEnoughFreeBalance
+            );
+            CandidateBonds::<T>::insert(executor, new_bond);
+            Self::update_lock(executor);
+            Ok(new_bond)
+        }
+
+        /// Snapshot of the stake behind `executor` as of now. Stakes scheduled to be revoked
+        /// or decreased are counted with the amount they will have left, and only the
+        /// `max_top_stakes_per_candidate` largest stakes are counted towards the total.
+        pub fn compute_snapshot(
+            executor: &T::AccountId,
+        ) -> ExecutorSnapshot<T::AccountId, BalanceOf<T>> {
+            let bond = CandidateBonds::<T>::get(executor).unwrap_or_else(Zero::zero);
+            let requests = ScheduledStakingRequests::<T>::get(executor);
+            let max_top_stakes = StakingFixtures::<T>::get().max_top_stakes_per_candidate;
+
+            let mut stakes = Stakes::<T>::iter_prefix(executor)
+                .map(|(staker, amount)| {
+                    let amount = match requests.iter().find(|r| r.staker == staker) {
+                        Some(request) => amount.saturating_sub(request.action.amount()),
+                        None => amount,
+                    };
+                    Bond {
+                        owner: staker,
+                        amount,
+                    }
+                })
+                .filter(|stake| !stake.amount.is_zero())
+                .collect::<Vec<_>>();
+            stakes.sort_by(|s1, s2| s2.amount.cmp(&s1.amount));
+            stakes.truncate(max_top_stakes as usize);
+
+            let total = stakes
+                .iter()
+                .fold(bond, |acc, stake| acc.saturating_add(stake.amount));
+
+            ExecutorSnapshot {
+                bond,
+                stakes,
+                total,
+            }
+        }
+
+        /// Selects the active set out of the candidates that aren't leaving and hold at least
+        /// `min_executor_bond`, ordered by the total stake behind them, and stores their
+        /// snapshots for `round`. Snapshots older than `SnapshotsRetention` rounds are pruned.
+        pub fn select_active_set_and_snapshot(round: RoundIndex) {
+            let fixtures = StakingFixtures::<T>::get();
+
+            let mut candidates = CandidateBonds::<T>::iter()
+                .filter(|(executor, bond)| {
+                    *bond >= fixtures.min_executor_bond
+                        && !matches!(
+                            CandidateStatuses::<T>::get(executor),
+                            ExecutorStatus::Leaving(_)
+                        )
+                })
+                .map(|(executor, _bond)| {
+                    let snapshot = Self::compute_snapshot(&executor);
+                    (executor, snapshot)
+                })
+                .collect::<Vec<_>>();
+
+            candidates.sort_by(|(_e1, s1), (_e2, s2)| s2.total.cmp(&s1.total));
+            candidates.truncate(fixtures.active_set_size.max as usize);
+
+            if (candidates.len() as u32) < fixtures.active_set_size.min {
+                log::warn!(
+                    "Executors::select_active_set_and_snapshot: only {:?} executors eligible, below the min. active set size of {:?}",
+                    candidates.len(),
+                    fixtures.active_set_size.min
+                );
+            }
+
+            for executor in ActiveSet::<T>::get() {
+                if !candidates.iter().any(|(e, _)| e == &executor)
+                    && CandidateStatuses::<T>::get(&executor) == ExecutorStatus::Active
+                {
+                    CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Idle);
+                }
+            }
+
+            let mut active_set = Vec::with_capacity(candidates.len());
+            for (executor, snapshot) in candidates {
+                CandidateStatuses::<T>::insert(&executor, ExecutorStatus::Active);
+                Snapshots::<T>::insert(round, &executor, snapshot);
+                active_set.push(executor);
+            }
+
+            // Snapshots are taken every round, so only the round falling out of retention is left to prune.
+            if let Some(expired_round) = round.checked_sub(T::SnapshotsRetention::get()) {
+                let _ = Snapshots::<T>::clear_prefix(expired_round, T::MaxCandidates::get(), None);
+            }
+
+            ActiveSet::<T>::put(active_set.clone());
+            LastSnapshotRound::<T>::put(round);
+
+            Self::deposit_event(Event::NewActiveSet {
+                round,
+                executors: active_set,
+            });
+        }
+
+        fn to_nominated_stake(
+            executor: T::AccountId,
+            staker: T::AccountId,
+            nominated_stake: BalanceOf<T>,
+        ) -> NominatedStake<T::AccountId, BalanceOf<T>> {
+            NominatedStake {
+                executor,
+                staker,
+                nominated_stake,
+            }
+        }
+    }
+
+    impl<T: Config> Executors<T, BalanceOf<T>> for Pallet<T> {
+        fn active_set() -> Vec<T::AccountId> {
+            ActiveSet::<T>::get()
+        }
+
+        fn reserve_bond(
+            executor: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            Self::ensure_executor(executor)?;
+            T::Currency::reserve(executor, amount)?;
+            let collateral = CollateralBonds::<T>::mutate(executor, |collateral| {
+                *collateral = collateral.saturating_add(amount);
+                *collateral
+            });
+            Self::deposit_event(Event::CollateralReserved {
+                executor: executor.clone(),
+                amount,
+            });
+            Ok(collateral)
+        }
+
+        fn unreserve_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let amount = amount.min(CollateralBonds::<T>::get(executor));
+            let not_unreserved = T::Currency::unreserve(executor, amount);
+            let unreserved = amount.saturating_sub(not_unreserved);
+            CollateralBonds::<T>::mutate_exists(executor, |collateral| {
+                let left = collateral
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_sub(unreserved);
+                *collateral = if left.is_zero() { None } else { Some(left) };
+            });
+            Self::deposit_event(Event::CollateralUnreserved {
+                executor: executor.clone(),
+                amount: unreserved,
+            });
+            unreserved
+        }
+
+        /// Slashes the reserved collateral first, the locked self-bond after that.
+        fn slash_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let collateral = CollateralBonds::<T>::get(executor);
+            let from_collateral = amount.min(collateral);
+            let (_imbalance, not_slashed) = T::Currency::slash_reserved(executor, from_collateral);
+            let slashed_collateral = from_collateral.saturating_sub(not_slashed);
+            CollateralBonds::<T>::insert(executor, collateral.saturating_sub(slashed_collateral));
+
+            let mut slashed_bond: BalanceOf<T> = Zero::zero();
+            let rest = amount.saturating_sub(slashed_collateral);
+            if !rest.is_zero() {
+                if let Some(bond) = CandidateBonds::<T>::get(executor) {
+                    let from_bond = rest.min(bond);
+                    let (_imbalance, not_slashed) = T::Currency::slash(executor, from_bond);
+                    slashed_bond = from_bond.saturating_sub(not_slashed);
+                    CandidateBonds::<T>::insert(executor, bond.saturating_sub(slashed_bond));
+                    Self::update_lock(executor);
+                }
+            }
+
+            let total_slashed = slashed_collateral.saturating_add(slashed_bond);
+            Self::deposit_event(Event::ExecutorSlashed {
+                executor: executor.clone(),
+                amount: total_slashed,
+            });
+            total_slashed
+        }
+
+        fn increase_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            match Self::do_increase_bond(executor, amount) {
+                Ok(new_bond) => new_bond,
+                Err(err) => {
+                    log::warn!("Executors::increase_bond failed with {:?}", err);
+                    Zero::zero()
+                },
+            }
+        }
+
+        fn collateral_bond(executor: &T::AccountId) -> BalanceOf<T> {
+            CollateralBonds::<T>::get(executor)
+        }
+
+        fn total_nominated_stake(executor: &T::AccountId) -> BalanceOf<T> {
+            Stakes::<T>::iter_prefix_values(executor)
+                .fold(Zero::zero(), |acc: BalanceOf<T>, amount| {
+                    acc.saturating_add(amount)
+                })
+        }
+
+        fn stakes(staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            StakesOfStaker::<T>::iter_key_prefix(staker)
+                .filter_map(|executor| {
+                    Stakes::<T>::get(&executor, staker)
+                        .map(|amount| Self::to_nominated_stake(executor, staker.clone(), amount))
+                })
+                .collect()
+        }
+
+        fn stakes_per_executor(
+            executor: &T::AccountId,
+        ) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            Stakes::<T>::iter_prefix(executor)
+                .map(|(staker, amount)| Self::to_nominated_stake(executor.clone(), staker, amount))
+                .collect()
+        }
+
+        fn recalculate_executors_stakes() {
+            Self::select_active_set_and_snapshot(T::Clock::current_round().index);
+        }
+    }
+}
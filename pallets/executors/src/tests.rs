@@ -0,0 +1,441 @@
+#[cfg(test)]
+pub mod executors_test {
+    use frame_support::{
+        assert_err, assert_noop, assert_ok,
+        traits::{Currency, Hooks},
+    };
+    use sp_runtime::{traits::Zero, AccountId32, Percent};
+    use t3rn_mini_mock_runtime::{
+        Balance, Balances, BlockNumber, CandidateBonds, Clock, CollateralBonds, ExecutorSnapshots,
+        Executors, ExecutorsActiveSet, ExecutorsError, ExtBuilder, MiniRuntime, RuntimeOrigin,
+        ScheduledStakingRequests, StakerTotals, Stakes, System,
+    };
+    use t3rn_primitives::executors::{ExecutorStatus, Executors as ExecutorsT, StakingAction};
+
+    const EXECUTOR_A: AccountId32 = AccountId32::new([10u8; 32]);
+    const EXECUTOR_B: AccountId32 = AccountId32::new([11u8; 32]);
+    const STAKER: AccountId32 = AccountId32::new([12u8; 32]);
+
+    fn fund(account: &AccountId32, amount: Balance) {
+        let _ = Balances::deposit_creating(account, amount);
+    }
+
+    fn register(executor: &AccountId32, bond: Balance) {
+        fund(executor, 10_000);
+        assert_ok!(Executors::register_executor(
+            RuntimeOrigin::signed(executor.clone()),
+            bond,
+            Percent::from_percent(10),
+            Percent::from_percent(10),
+        ));
+    }
+
+    // Rounds in mini-mock last 300 blocks.
+    fn next_round() {
+        let n: BlockNumber = Clock::current_round().head + 300;
+        System::set_block_number(n);
+        Clock::check_bump_round(n);
+        <Executors as Hooks<BlockNumber>>::on_initialize(n);
+    }
+
+    #[test]
+    fn register_executor_locks_self_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+
+            assert_eq!(CandidateBonds::<MiniRuntime>::get(&EXECUTOR_A), Some(1_000));
+            assert_eq!(
+                Executors::candidate_status(&EXECUTOR_A),
+                ExecutorStatus::Idle
+            );
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 9_000);
+        });
+    }
+
+    #[test]
+    fn register_executor_below_min_candidate_bond_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            fund(&EXECUTOR_A, 10_000);
+            assert_noop!(
+                Executors::register_executor(
+                    RuntimeOrigin::signed(EXECUTOR_A),
+                    499,
+                    Percent::from_percent(10),
+                    Percent::from_percent(10),
+                ),
+                ExecutorsError::<MiniRuntime>::CandidateBondBelowMin
+            );
+            assert_noop!(
+                Executors::register_executor(
+                    RuntimeOrigin::signed(EXECUTOR_A),
+                    1_000,
+                    Percent::from_percent(51),
+                    Percent::from_percent(10),
+                ),
+                ExecutorsError::<MiniRuntime>::TooHighCommission
+            );
+        });
+    }
+
+    #[test]
+    fn new_round_selects_active_set_by_total_stake_and_snapshots_it() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            register(&EXECUTOR_B, 2_000);
+            // Below min_executor_bond - stays out of the active set
+            register(&AccountId32::new([13u8; 32]), 600);
+
+            fund(&STAKER, 5_000);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                1_500
+            ));
+
+            next_round();
+            let round = Clock::current_round().index;
+
+            assert_eq!(
+                ExecutorsActiveSet::<MiniRuntime>::get(),
+                vec![EXECUTOR_A, EXECUTOR_B]
+            );
+            assert_eq!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::active_set(),
+                vec![EXECUTOR_A, EXECUTOR_B]
+            );
+            let snapshot = ExecutorSnapshots::<MiniRuntime>::get(round, &EXECUTOR_A)
+                .expect("snapshot taken at round start");
+            assert_eq!(snapshot.bond, 1_000);
+            assert_eq!(snapshot.total, 2_500);
+            assert_eq!(snapshot.stakes.len(), 1);
+            assert_eq!(
+                Executors::candidate_status(&EXECUTOR_A),
+                ExecutorStatus::Active
+            );
+            assert_eq!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::total_nominated_stake(&EXECUTOR_A),
+                1_500
+            );
+        });
+    }
+
+    #[test]
+    fn register_executor_is_limited_to_max_candidates() {
+        ExtBuilder::default().build().execute_with(|| {
+            for i in 0..4u8 {
+                register(&AccountId32::new([20 + i; 32]), 1_000);
+            }
+            assert_eq!(Executors::candidate_count(), 4);
+
+            fund(&EXECUTOR_A, 10_000);
+            assert_noop!(
+                Executors::register_executor(
+                    RuntimeOrigin::signed(EXECUTOR_A),
+                    1_000,
+                    Percent::from_percent(10),
+                    Percent::from_percent(10),
+                ),
+                ExecutorsError::<MiniRuntime>::TooManyCandidates
+            );
+        });
+    }
+
+    #[test]
+    fn snapshots_are_pruned_after_retention() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+
+            next_round();
+            let first_round = Clock::current_round().index;
+            assert!(ExecutorSnapshots::<MiniRuntime>::get(first_round, &EXECUTOR_A).is_some());
+
+            // Retention of mini-mock is 2 rounds
+            next_round();
+            assert!(ExecutorSnapshots::<MiniRuntime>::get(first_round, &EXECUTOR_A).is_some());
+            next_round();
+            assert_eq!(
+                ExecutorSnapshots::<MiniRuntime>::get(first_round, &EXECUTOR_A),
+                None
+            );
+            assert!(ExecutorSnapshots::<MiniRuntime>::get(
+                Clock::current_round().index,
+                &EXECUTOR_A
+            )
+            .is_some());
+        });
+    }
+
+    #[test]
+    fn self_bond_and_stakes_of_executor_share_one_lock() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            register(&EXECUTOR_B, 1_000);
+
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(EXECUTOR_A), EXECUTOR_B, 9_001),
+                ExecutorsError::<MiniRuntime>::NotEnoughFreeBalance
+            );
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(EXECUTOR_A),
+                EXECUTOR_B,
+                1_500
+            ));
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 7_500);
+
+            <Executors as ExecutorsT<MiniRuntime, Balance>>::slash_bond(&EXECUTOR_A, 200);
+            assert_eq!(Balances::total_balance(&EXECUTOR_A), 9_800);
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 7_500);
+        });
+    }
+
+    #[test]
+    fn stakes_are_limited_per_staker() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            register(&EXECUTOR_B, 1_000);
+            let executor_c = AccountId32::new([13u8; 32]);
+            register(&executor_c, 1_000);
+
+            fund(&STAKER, 5_000);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                100
+            ));
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_B,
+                100
+            ));
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), executor_c, 100),
+                ExecutorsError::<MiniRuntime>::TooManyStakes
+            );
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), EXECUTOR_A, 99),
+                ExecutorsError::<MiniRuntime>::StakeBelowMin
+            );
+            assert_eq!(StakerTotals::<MiniRuntime>::get(&STAKER), 200);
+            assert_eq!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::stakes(&STAKER).len(),
+                2
+            );
+        });
+    }
+
+    #[test]
+    fn stakes_are_limited_per_candidate_to_top_and_bottom_stakes() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+
+            // mini-mock fixtures allow 4 top and 4 bottom stakes per candidate
+            for i in 0..8u8 {
+                let staker = AccountId32::new([100 + i; 32]);
+                fund(&staker, 1_000);
+                assert_ok!(Executors::stake(
+                    RuntimeOrigin::signed(staker),
+                    EXECUTOR_A,
+                    100
+                ));
+            }
+            assert_eq!(Executors::candidate_stakes_count(&EXECUTOR_A), 8);
+
+            fund(&STAKER, 1_000);
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), EXECUTOR_A, 100),
+                ExecutorsError::<MiniRuntime>::TooManyStakesOnCandidate
+            );
+
+            // Stakers already nominating the candidate can still increase their stake
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(AccountId32::new([100; 32])),
+                EXECUTOR_A,
+                100
+            ));
+            assert_eq!(Executors::candidate_stakes_count(&EXECUTOR_A), 8);
+        });
+    }
+
+    #[test]
+    fn revoke_stake_is_executable_only_after_delay_and_unlocks_funds() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            fund(&STAKER, 5_000);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                1_000
+            ));
+            assert_eq!(Balances::usable_balance(&STAKER), 4_000);
+
+            assert_ok!(Executors::schedule_revoke_stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A
+            ));
+            assert_eq!(
+                ScheduledStakingRequests::<MiniRuntime>::get(&EXECUTOR_A)[0].action,
+                StakingAction::Revoke(1_000)
+            );
+            assert_noop!(
+                Executors::execute_staking_request(RuntimeOrigin::signed(STAKER), EXECUTOR_A),
+                ExecutorsError::<MiniRuntime>::PendingRequestNotDueYet
+            );
+
+            // Scheduled revokes no longer count towards the snapshot
+            next_round();
+            let snapshot =
+                ExecutorSnapshots::<MiniRuntime>::get(Clock::current_round().index, &EXECUTOR_A)
+                    .expect("snapshot taken at round start");
+            assert_eq!(snapshot.total, 1_000);
+
+            assert_ok!(Executors::execute_staking_request(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A
+            ));
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR_A, &STAKER), None);
+            assert_eq!(StakerTotals::<MiniRuntime>::get(&STAKER), 0);
+            assert_eq!(Balances::usable_balance(&STAKER), 5_000);
+        });
+    }
+
+    #[test]
+    fn decrease_stake_below_min_atomic_stake_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            fund(&STAKER, 5_000);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                150
+            ));
+            assert_noop!(
+                Executors::schedule_decrease_stake(RuntimeOrigin::signed(STAKER), EXECUTOR_A, 100),
+                ExecutorsError::<MiniRuntime>::DecreaseBelowMinStake
+            );
+            assert_ok!(Executors::schedule_decrease_stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                50
+            ));
+            assert_ok!(Executors::cancel_staking_request(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A
+            ));
+            assert!(ScheduledStakingRequests::<MiniRuntime>::get(&EXECUTOR_A).is_empty());
+        });
+    }
+
+    #[test]
+    fn executor_bond_less_respects_delay_and_min_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            assert_noop!(
+                Executors::schedule_executor_bond_less(RuntimeOrigin::signed(EXECUTOR_A), 600),
+                ExecutorsError::<MiniRuntime>::BondLessRequestBelowMinBond
+            );
+            assert_ok!(Executors::schedule_executor_bond_less(
+                RuntimeOrigin::signed(EXECUTOR_A),
+                400
+            ));
+            assert_noop!(
+                Executors::execute_executor_bond_less(RuntimeOrigin::signed(EXECUTOR_A)),
+                ExecutorsError::<MiniRuntime>::PendingRequestNotDueYet
+            );
+
+            next_round();
+
+            assert_ok!(Executors::execute_executor_bond_less(
+                RuntimeOrigin::signed(EXECUTOR_A)
+            ));
+            assert_eq!(CandidateBonds::<MiniRuntime>::get(&EXECUTOR_A), Some(600));
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 9_400);
+        });
+    }
+
+    #[test]
+    fn leaving_executor_unlocks_bond_and_stakes() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            fund(&STAKER, 5_000);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR_A,
+                1_000
+            ));
+            next_round();
+            assert_eq!(ExecutorsActiveSet::<MiniRuntime>::get(), vec![EXECUTOR_A]);
+
+            assert_ok!(Executors::schedule_leave_executors(RuntimeOrigin::signed(
+                EXECUTOR_A
+            )));
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), EXECUTOR_A, 100),
+                ExecutorsError::<MiniRuntime>::ExecutorIsLeaving
+            );
+
+            // Leaving executors are excluded from the next active set
+            next_round();
+            assert!(ExecutorsActiveSet::<MiniRuntime>::get().is_empty());
+            assert_noop!(
+                Executors::execute_leave_executors(RuntimeOrigin::signed(EXECUTOR_A)),
+                ExecutorsError::<MiniRuntime>::PendingRequestNotDueYet
+            );
+
+            next_round();
+            assert_ok!(Executors::execute_leave_executors(RuntimeOrigin::signed(
+                EXECUTOR_A
+            )));
+            assert_eq!(CandidateBonds::<MiniRuntime>::get(&EXECUTOR_A), None);
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR_A, &STAKER), None);
+            assert!(<Executors as ExecutorsT<MiniRuntime, Balance>>::stakes(&STAKER).is_empty());
+            assert_eq!(Executors::staker_stakes_count(&STAKER), 0);
+            assert_eq!(Executors::candidate_stakes_count(&EXECUTOR_A), 0);
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 10_000);
+            assert_eq!(Balances::usable_balance(&STAKER), 5_000);
+        });
+    }
+
+    #[test]
+    fn slash_bond_takes_collateral_first_then_self_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+
+            assert_err!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::reserve_bond(&EXECUTOR_B, 100),
+                ExecutorsError::<MiniRuntime>::NotExecutor
+            );
+            assert_ok!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::reserve_bond(&EXECUTOR_A, 300),
+                300
+            );
+            assert_eq!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::collateral_bond(&EXECUTOR_A),
+                300
+            );
+
+            let slashed =
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::slash_bond(&EXECUTOR_A, 500);
+            assert_eq!(slashed, 500);
+            assert!(CollateralBonds::<MiniRuntime>::get(&EXECUTOR_A).is_zero());
+            assert_eq!(CandidateBonds::<MiniRuntime>::get(&EXECUTOR_A), Some(800));
+            assert_eq!(Balances::total_balance(&EXECUTOR_A), 9_500);
+        });
+    }
+
+    #[test]
+    fn unreserve_bond_releases_collateral() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(&EXECUTOR_A, 1_000);
+            assert_ok!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::reserve_bond(&EXECUTOR_A, 300),
+                300
+            );
+            assert_eq!(
+                <Executors as ExecutorsT<MiniRuntime, Balance>>::unreserve_bond(&EXECUTOR_A, 500),
+                300
+            );
+            assert_eq!(Balances::reserved_balance(&EXECUTOR_A), 0);
+            assert_eq!(Balances::usable_balance(&EXECUTOR_A), 9_000);
+        });
+    }
+}
@@ -0,0 +1,482 @@
+//! Weights for pallet_executors
+//!
+//! Storage reads and writes are listed per call; calls unlocking stakes scale with the number of
+//! stakes nominating the executor, bounded by the staking fixtures.
+//!
+//! The execution times are provisional estimates, not benchmark output - regenerate this file
+//! from the benchmarks in `benchmarking.rs` before relying on them.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_executors.
+pub trait WeightInfo {
+    fn set_fixtures() -> Weight;
+    fn register_executor() -> Weight;
+    fn configure_executor() -> Weight;
+    fn execute_configure_executor() -> Weight;
+    fn executor_bond_more() -> Weight;
+    fn schedule_executor_bond_less() -> Weight;
+    fn execute_executor_bond_less() -> Weight;
+    fn cancel_executor_bond_less() -> Weight;
+    fn schedule_leave_executors() -> Weight;
+    fn cancel_leave_executors() -> Weight;
+    fn execute_leave_executors(s: u32) -> Weight;
+    fn stake() -> Weight;
+    fn schedule_revoke_stake() -> Weight;
+    fn schedule_decrease_stake() -> Weight;
+    fn execute_staking_request() -> Weight;
+    fn cancel_staking_request() -> Weight;
+    fn select_active_set_and_snapshot(c: u32, s: u32) -> Weight;
+}
+
+/// Weights for pallet_executors using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Executors StakingFixtures (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `set_fixtures` benchmark.
+    fn set_fixtures() -> Weight {
+        Weight::from_parts(18_420_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors StakerTotals (r:1 w:0)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors CandidateStatuses (r:0 w:1)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `register_executor` benchmark.
+    fn register_executor() -> Weight {
+        Weight::from_parts(64_310_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors PendingConfigurations (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `configure_executor` benchmark.
+    fn configure_executor() -> Weight {
+        Weight::from_parts(31_870_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors PendingConfigurations (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_configure_executor` benchmark.
+    fn execute_configure_executor() -> Weight {
+        Weight::from_parts(27_540_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:0)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `executor_bond_more` benchmark.
+    fn executor_bond_more() -> Weight {
+        Weight::from_parts(52_960_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_executor_bond_less` benchmark.
+    fn schedule_executor_bond_less() -> Weight {
+        Weight::from_parts(31_220_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_executor_bond_less` benchmark.
+    fn execute_executor_bond_less() -> Weight {
+        Weight::from_parts(55_180_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_executor_bond_less` benchmark.
+    fn cancel_executor_bond_less() -> Weight {
+        Weight::from_parts(21_650_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_leave_executors` benchmark.
+    fn schedule_leave_executors() -> Weight {
+        Weight::from_parts(30_480_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_leave_executors` benchmark.
+    fn cancel_leave_executors() -> Weight {
+        Weight::from_parts(24_730_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors ActiveSet (r:1 w:1)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Executors ScheduledStakingRequests (r:0 w:1)
+    /// Storage: Executors CandidateBondLessRequests (r:0 w:1)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Storage: Executors PendingConfigurations (r:0 w:1)
+    /// Storage: Executors CandidateStakesCount (r:0 w:1)
+    /// The range of component `s` is `[0, max_top_stakes_per_candidate + max_bottom_stakes_per_candidate]`.
+    /// Estimated from the storage accessed, until regenerated with the `execute_leave_executors` benchmark.
+    fn execute_leave_executors(s: u32) -> Weight {
+        Weight::from_parts(86_240_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(38_910_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s as u64)))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(s as u64)))
+    }
+
+    /// Storage: Executors CandidateBonds (r:2 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors CandidateStakesCount (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `stake` benchmark.
+    fn stake() -> Weight {
+        Weight::from_parts(78_650_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_revoke_stake` benchmark.
+    fn schedule_revoke_stake() -> Weight {
+        Weight::from_parts(33_940_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_decrease_stake` benchmark.
+    fn schedule_decrease_stake() -> Weight {
+        Weight::from_parts(34_610_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors CandidateStakesCount (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_staking_request` benchmark.
+    fn execute_staking_request() -> Weight {
+        Weight::from_parts(67_390_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_staking_request` benchmark.
+    fn cancel_staking_request() -> Weight {
+        Weight::from_parts(23_870_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors LastSnapshotRound (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors CandidateBonds (r:c w:0)
+    /// Storage: Executors CandidateStatuses (r:c w:c)
+    /// Storage: Executors ScheduledStakingRequests (r:c w:0)
+    /// Storage: Executors Stakes (r:c * s w:0)
+    /// Storage: Executors ActiveSet (r:1 w:1)
+    /// Storage: Executors Snapshots (r:0 w:2 * c)
+    /// The range of component `c` is `[1, MaxCandidates]`.
+    /// The range of component `s` is `[0, max_top_stakes_per_candidate + max_bottom_stakes_per_candidate]`.
+    /// Estimated from the storage accessed, until regenerated with the `select_active_set_and_snapshot` benchmark.
+    fn select_active_set_and_snapshot(c: u32, s: u32) -> Weight {
+        Weight::from_parts(21_460_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(14_380_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(
+                Weight::from_parts(1_120_000_u64, 0u64)
+                    .saturating_mul((c as u64).saturating_mul(s as u64)),
+            )
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c as u64)))
+            .saturating_add(T::DbWeight::get().reads((c as u64).saturating_mul(s as u64)))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c as u64)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    /// Storage: Executors StakingFixtures (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `set_fixtures` benchmark.
+    fn set_fixtures() -> Weight {
+        Weight::from_parts(18_420_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors StakerTotals (r:1 w:0)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors CandidateStatuses (r:0 w:1)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `register_executor` benchmark.
+    fn register_executor() -> Weight {
+        Weight::from_parts(64_310_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors PendingConfigurations (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `configure_executor` benchmark.
+    fn configure_executor() -> Weight {
+        Weight::from_parts(31_870_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors PendingConfigurations (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_configure_executor` benchmark.
+    fn execute_configure_executor() -> Weight {
+        Weight::from_parts(27_540_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:0)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `executor_bond_more` benchmark.
+    fn executor_bond_more() -> Weight {
+        Weight::from_parts(52_960_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_executor_bond_less` benchmark.
+    fn schedule_executor_bond_less() -> Weight {
+        Weight::from_parts(31_220_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_executor_bond_less` benchmark.
+    fn execute_executor_bond_less() -> Weight {
+        Weight::from_parts(55_180_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors CandidateBondLessRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_executor_bond_less` benchmark.
+    fn cancel_executor_bond_less() -> Weight {
+        Weight::from_parts(21_650_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_leave_executors` benchmark.
+    fn schedule_leave_executors() -> Weight {
+        Weight::from_parts(30_480_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_leave_executors` benchmark.
+    fn cancel_leave_executors() -> Weight {
+        Weight::from_parts(24_730_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors CandidateBonds (r:1 w:1)
+    /// Storage: Executors CandidateStatuses (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors ActiveSet (r:1 w:1)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Executors ScheduledStakingRequests (r:0 w:1)
+    /// Storage: Executors CandidateBondLessRequests (r:0 w:1)
+    /// Storage: Executors ExecutorConfig (r:0 w:1)
+    /// Storage: Executors PendingConfigurations (r:0 w:1)
+    /// Storage: Executors CandidateStakesCount (r:0 w:1)
+    /// The range of component `s` is `[0, max_top_stakes_per_candidate + max_bottom_stakes_per_candidate]`.
+    /// Estimated from the storage accessed, until regenerated with the `execute_leave_executors` benchmark.
+    fn execute_leave_executors(s: u32) -> Weight {
+        Weight::from_parts(86_240_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(38_910_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+            .saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(s as u64)))
+    }
+
+    /// Storage: Executors CandidateBonds (r:2 w:0)
+    /// Storage: Executors CandidateStatuses (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors CandidateStakesCount (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `stake` benchmark.
+    fn stake() -> Weight {
+        Weight::from_parts(78_650_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_revoke_stake` benchmark.
+    fn schedule_revoke_stake() -> Weight {
+        Weight::from_parts(33_940_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `schedule_decrease_stake` benchmark.
+    fn schedule_decrease_stake() -> Weight {
+        Weight::from_parts(34_610_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors Stakes (r:1 w:1)
+    /// Storage: Executors StakerTotals (r:1 w:1)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Storage: Executors StakerStakesCount (r:1 w:1)
+    /// Storage: Executors CandidateStakesCount (r:1 w:1)
+    /// Storage: Executors StakesOfStaker (r:0 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `execute_staking_request` benchmark.
+    fn execute_staking_request() -> Weight {
+        Weight::from_parts(67_390_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+
+    /// Storage: Executors ScheduledStakingRequests (r:1 w:1)
+    /// Estimated from the storage accessed, until regenerated with the `cancel_staking_request` benchmark.
+    fn cancel_staking_request() -> Weight {
+        Weight::from_parts(23_870_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock CurrentRound (r:1 w:0)
+    /// Storage: Executors LastSnapshotRound (r:1 w:1)
+    /// Storage: Executors StakingFixtures (r:1 w:0)
+    /// Storage: Executors CandidateBonds (r:c w:0)
+    /// Storage: Executors CandidateStatuses (r:c w:c)
+    /// Storage: Executors ScheduledStakingRequests (r:c w:0)
+    /// Storage: Executors Stakes (r:c * s w:0)
+    /// Storage: Executors ActiveSet (r:1 w:1)
+    /// Storage: Executors Snapshots (r:0 w:2 * c)
+    /// The range of component `c` is `[1, MaxCandidates]`.
+    /// The range of component `s` is `[0, max_top_stakes_per_candidate + max_bottom_stakes_per_candidate]`.
+    /// Estimated from the storage accessed, until regenerated with the `select_active_set_and_snapshot` benchmark.
+    fn select_active_set_and_snapshot(c: u32, s: u32) -> Weight {
+        Weight::from_parts(21_460_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(14_380_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(
+                Weight::from_parts(1_120_000_u64, 0u64)
+                    .saturating_mul((c as u64).saturating_mul(s as u64)),
+            )
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().reads((c as u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c as u64)))
+    }
+}
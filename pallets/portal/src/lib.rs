@@ -29,6 +29,14 @@ use t3rn_primitives::{
 pub mod weights;
 pub trait SelectLightClient<T: frame_system::Config> {
     fn select(vendor: GatewayVendor) -> Result<Box<dyn LightClient<T>>, Error<T>>;
+
+    /// Light client of the given gateway - by default the vendor's one, shared by all of its gateways.
+    fn select_for_gateway(
+        vendor: GatewayVendor,
+        _gateway_id: ChainId,
+    ) -> Result<Box<dyn LightClient<T>>, Error<T>> {
+        Self::select(vendor)
+    }
 }
 use t3rn_primitives::{light_client::LightClientHeartbeat, portal::InclusionReceipt};
 
@@ -168,7 +176,7 @@ pub fn match_light_client_by_gateway_id<T: Config>(
 ) -> Result<Box<dyn LightClient<T>>, Error<T>> {
     let vendor = <T as Config>::Xdns::get_verification_vendor(&gateway_id)
         .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
-    T::SelectLightClient::select(vendor)
+    T::SelectLightClient::select_for_gateway(vendor, gateway_id)
 }
 
 impl<T: Config> Portal<T> for Pallet<T> {
@@ -26,7 +26,7 @@ pub mod pallet {
         attesters::AttestersReadApi,
         circuit::{CircuitStatus, FullSideEffect},
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
-        clock::Clock as ClockTrait,
+        clock::{Clock as ClockTrait, StandardJobQueues},
         common::RoundInfo,
         rewards::RewardsWriteApi,
         TreasuryAccount, TreasuryAccountProvider,
@@ -221,7 +221,7 @@ pub mod pallet {
         // old, new kickbacks to executors (in percent of max_reward)
         NewMaxRewardExecutorsKickbackSet(Percent, Percent),
         Claimed(T::AccountId, Vec<(BalanceOf<T>, Option<u32>)>),
-        PendingClaim(T::AccountId, BalanceOf<T>),
+        PendingClaim(T::AccountId, BalanceOf<T>, Option<u32>),
     }
 
     #[pallet::error]
@@ -798,7 +798,11 @@ pub mod pallet {
                 *maybe_pending_claims = Some(pending_claims);
             });
 
-            Self::deposit_event(Event::PendingClaim(account.clone(), reward));
+            Self::deposit_event(Event::PendingClaim(
+                account.clone(),
+                reward,
+                non_native_asset_id,
+            ));
         }
 
         fn update_pending_claims(
@@ -819,7 +823,39 @@ pub mod pallet {
             let mut pending_claims = PendingClaims::<T>::get(account).unwrap_or_default();
             pending_claims.push(claim);
             PendingClaims::<T>::insert(account, pending_claims);
-            Self::deposit_event(Event::PendingClaim(account.clone(), reward));
+            Self::deposit_event(Event::PendingClaim(
+                account.clone(),
+                reward,
+                non_native_asset_id,
+            ));
+        }
+    }
+
+    impl<T: Config> StandardJobQueues<T> for Pallet<T> {
+        fn process_author_rewards(_n: BlockNumberFor<T>) -> Weight {
+            let (_success, weight) = Self::process_author();
+            log::debug!("Rewards::process_author consumed: {:?}", weight);
+            weight
+        }
+
+        fn process_hourly(_n: BlockNumberFor<T>) -> Weight {
+            let weight = Self::process_accumulated_settlements();
+            log::debug!(
+                "Rewards::process_accumulated_settlements consumed: {:?}",
+                weight
+            );
+            weight
+        }
+
+        fn process_bi_weekly(_n: BlockNumberFor<T>) -> Weight {
+            let weight = Self::distribute_inflation();
+            log::debug!("Rewards::distribute_inflation consumed: {:?}", weight);
+            let authors_weight = Self::process_authors_this_period();
+            log::debug!(
+                "Rewards::process_authors_this_period consumed: {:?}",
+                authors_weight
+            );
+            weight.saturating_add(authors_weight)
         }
     }
 
@@ -958,7 +994,7 @@ pub mod test {
 
     use sp_runtime::Percent;
     use t3rn_mini_mock_runtime::{
-        AccountId, Authors, AuthorsThisPeriod, Balance, Balances, Clock, ConfigRewards,
+        AccountId, Assets, Authors, AuthorsThisPeriod, Balance, Balances, Clock, ConfigRewards,
         DistributionHistory, ExtBuilder, MiniRuntime, PendingClaims, Rewards, RewardsError,
         RuntimeOrigin, SettlementsPerRound, System,
     };
@@ -1426,6 +1462,72 @@ pub mod test {
         });
     }
 
+    #[test]
+    fn test_claim_executor_rewards_aggregates_settlements_per_asset() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            const USDT: u32 = 1;
+            let executor = AccountId::from([1u8; 32]);
+            let requester = AccountId::from([101u8; 32]);
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                USDT,
+                requester.clone(),
+                true,
+                1
+            ));
+
+            // Single multi-SFX order rewarded with native currency and USDT on two separate legs
+            for (counter, (settlement_amount, maybe_asset_id)) in
+                [(100 as Balance, None), (50, Some(USDT)), (30, Some(USDT))]
+                    .into_iter()
+                    .enumerate()
+            {
+                SettlementsPerRound::<MiniRuntime>::insert(
+                    Clock::current_round(),
+                    H256::from([counter as u8; 32]),
+                    Settlement {
+                        requester: requester.clone(),
+                        recipient: executor.clone(),
+                        settlement_amount,
+                        outcome: Outcome::Commit,
+                        source: BenefitSource::TrafficRewards,
+                        role: CircuitRole::Executor,
+                        maybe_asset_id,
+                    },
+                );
+            }
+
+            Rewards::process_accumulated_settlements();
+
+            let pending_claims = Rewards::get_pending_claims(executor.clone()).unwrap();
+            assert_eq!(pending_claims.len(), 2);
+            assert!(pending_claims.contains(&ClaimableArtifacts {
+                beneficiary: executor.clone(),
+                role: CircuitRole::Executor,
+                total_round_claim: 100 as Balance,
+                benefit_source: BenefitSource::TrafficRewards,
+                non_native_asset_id: None,
+            }));
+            assert!(pending_claims.contains(&ClaimableArtifacts {
+                beneficiary: executor.clone(),
+                role: CircuitRole::Executor,
+                total_round_claim: 80 as Balance,
+                benefit_source: BenefitSource::TrafficRewards,
+                non_native_asset_id: Some(USDT),
+            }));
+
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(executor.clone()),
+                Some(CircuitRole::Executor),
+            ));
+
+            assert_eq!(Balances::free_balance(&executor), 100 as Balance);
+            assert_eq!(Assets::balance(USDT, &executor), 80 as Balance);
+            assert_eq!(Rewards::get_pending_claims(executor), Some(vec![]));
+        });
+    }
+
     #[test]
     fn test_claim_executor_rewards_without_inflation_extras() {
         let mut ext = ExtBuilder::default().build();
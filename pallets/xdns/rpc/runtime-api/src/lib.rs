@@ -10,12 +10,14 @@ use codec::Codec;
 use sp_runtime::sp_std;
 use sp_std::prelude::*;
 use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
-pub use t3rn_primitives::{gateway::GatewayABIConfig, ChainId};
+pub use t3rn_primitives::{gateway::GatewayABIConfig, xdns::SfxCostEstimate, ChainId};
+use t3rn_types::sfx::SideEffect;
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
-    pub trait XdnsRuntimeApi<AccountId> where
+    pub trait XdnsRuntimeApi<AccountId, Balance> where
         AccountId: Codec,
+        Balance: Codec,
     {
         /// Returns metadata for all known Blockchains
         fn fetch_records() -> Vec<GatewayRecord<AccountId>>;
@@ -26,5 +28,8 @@ sp_api::decl_runtime_apis! {
         fn fetch_abi(chain_id: ChainId) -> Option<GatewayABIConfig>;
 
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId;
+
+        /// Returns the suggested max_reward and insurance for each of the side effects, in native currency
+        fn estimate_costs(side_effects: Vec<SideEffect<AccountId, Balance>>) -> Vec<SfxCostEstimate<Balance>>;
     }
 }
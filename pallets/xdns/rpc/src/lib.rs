@@ -7,7 +7,7 @@ use jsonrpsee::{
     types::error::CallError,
 };
 pub use pallet_xdns_rpc_runtime_api::XdnsRuntimeApi;
-use pallet_xdns_rpc_runtime_api::{ChainId, GatewayABIConfig};
+use pallet_xdns_rpc_runtime_api::{ChainId, GatewayABIConfig, SfxCostEstimate};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::sp_std;
@@ -15,11 +15,12 @@ use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
 use sp_std::prelude::*;
 use std::sync::Arc;
 use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
+use t3rn_types::sfx::SideEffect;
 
 const RUNTIME_ERROR: i64 = 1;
 
 #[rpc(client, server)]
-pub trait XdnsApi<AccountId> {
+pub trait XdnsApi<AccountId, Balance> {
     /// Returns all known XDNS records
     #[method(name = "xdns_fetchRecords")]
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>>;
@@ -29,6 +30,13 @@ pub trait XdnsApi<AccountId> {
 
     #[method(name = "xdns_fetchFullRecords")]
     fn fetch_full_gateway_records(&self) -> RpcResult<Vec<FullGatewayRecord<AccountId>>>;
+
+    /// Returns the suggested max_reward and insurance for each of the side effects, in native currency
+    #[method(name = "xdns_estimateCosts")]
+    fn estimate_costs(
+        &self,
+        side_effects: Vec<SideEffect<AccountId, Balance>>,
+    ) -> RpcResult<Vec<SfxCostEstimate<Balance>>>;
 }
 
 /// A struct that implements the [`XdnsApiServer`].
@@ -48,12 +56,13 @@ impl<C, P> Xdns<C, P> {
 }
 
 #[async_trait]
-impl<C, Block, AccountId> XdnsApiServer<AccountId> for Xdns<C, Block>
+impl<C, Block, AccountId, Balance> XdnsApiServer<AccountId, Balance> for Xdns<C, Block>
 where
     AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: XdnsRuntimeApi<Block, AccountId>,
+    C::Api: XdnsRuntimeApi<Block, AccountId, Balance>,
 {
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>> {
         let api = self.client.runtime_api();
@@ -75,6 +84,20 @@ where
         Ok(result)
     }
 
+    fn estimate_costs(
+        &self,
+        side_effects: Vec<SideEffect<AccountId, Balance>>,
+    ) -> RpcResult<Vec<SfxCostEstimate<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .estimate_costs(at, side_effects)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
     fn fetch_abi(&self, chain_id: ChainId) -> RpcResult<GatewayABIConfig> {
         let api = self.client.runtime_api();
         let at = self.client.info().best_hash;
@@ -57,10 +57,12 @@ pub mod pallet {
     use t3rn_primitives::{
         attesters::AttestersReadApi,
         circuit::{AdaptiveTimeout, CircuitDLQ},
-        light_client::{LightClientAsyncAPI, LightClientHeartbeat},
+        clock::StandardJobQueues,
+        light_client::{LightClientAsyncAPI, LightClientHeartbeat, ScaleLayout},
         portal::Portal,
         xdns::{
-            EpochEstimate, FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, TokenRecord, Xdns,
+            EpochEstimate, FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, SfxCostEstimate,
+            TokenRecord, Xdns,
         },
         Bytes, ChainId, ExecutionVendor, FinalityVerifierActivity, GatewayActivity, GatewayType,
         GatewayVendor, SpeedMode, TokenInfo, TreasuryAccount, TreasuryAccountProvider,
@@ -68,9 +70,13 @@ pub mod pallet {
     };
     use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
 
-    use t3rn_types::{fsx::FullSideEffect, sfx::SecurityLvl};
+    use t3rn_types::{
+        fsx::FullSideEffect,
+        sfx::{SecurityLvl, SideEffect},
+    };
 
     pub const MAX_GATEWAY_OVERVIEW_RECORDS: u32 = 1000;
+    pub const MAX_ACCEPTED_BIDS_HISTORY: usize = 10;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -680,6 +686,8 @@ pub mod pallet {
                 });
 
                 <GatewayTokens<T>>::remove(gateway_id);
+                <SiblingParachains<T>>::remove(gateway_id);
+                let _ = <EventLayouts<T>>::clear_prefix(gateway_id, u32::MAX, None);
 
                 <AllGatewayIds<T>>::mutate(|all_gateway_ids| {
                     all_gateway_ids.retain(|&id| id != gateway_id);
@@ -794,6 +802,62 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Sets the gas / weight fee of executing a single SFX on the target, denominated in native currency. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_gateway_execution_fee())]
+        pub fn set_gateway_execution_fee(
+            origin: OriginFor<T>,
+            target_id: TargetId,
+            execution_fee: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(target_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+            <GatewayExecutionFees<T>>::insert(target_id, execution_fee);
+            Ok(().into())
+        }
+
+        /// Links the target to the sibling parachain reachable over XCM, or unlinks it when para_id is None. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::link_sibling_parachain())]
+        pub fn link_sibling_parachain(
+            origin: OriginFor<T>,
+            target_id: TargetId,
+            para_id: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(target_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+            match para_id {
+                Some(para_id) => <SiblingParachains<T>>::insert(target_id, para_id),
+                None => <SiblingParachains<T>>::remove(target_id),
+            }
+            Ok(().into())
+        }
+
+        /// Sets the SCALE layout of the fields of the target's runtime event at (pallet index, variant index),
+        ///     which light clients check the proven event of the target decodes by, or removes it when layout is None. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_event_layout())]
+        pub fn set_event_layout(
+            origin: OriginFor<T>,
+            target_id: TargetId,
+            event_index: [u8; 2],
+            layout: Option<Vec<ScaleLayout>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(target_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+            match layout {
+                Some(layout) => <EventLayouts<T>>::insert(target_id, event_index, layout),
+                None => <EventLayouts<T>>::remove(target_id, event_index),
+            }
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -965,6 +1029,28 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_execution_fees)]
+    pub type GatewayExecutionFees<T: Config> =
+        StorageMap<_, Identity, TargetId, BalanceOf<T>, ValueQuery>;
+
+    // Targets reachable as sibling parachains over XCM -> para id
+    #[pallet::storage]
+    #[pallet::getter(fn sibling_parachains)]
+    pub type SiblingParachains<T: Config> = StorageMap<_, Identity, TargetId, u32, OptionQuery>;
+
+    // Targets -> (pallet index, variant index) -> SCALE layout of the fields of the target's runtime event
+    #[pallet::storage]
+    #[pallet::getter(fn event_layouts)]
+    pub type EventLayouts<T: Config> =
+        StorageDoubleMap<_, Identity, TargetId, Identity, [u8; 2], Vec<ScaleLayout>, OptionQuery>;
+
+    // Keep last MAX_ACCEPTED_BIDS_HISTORY (amount, insurance) of winning bids, denominated in native currency
+    #[pallet::storage]
+    #[pallet::getter(fn accepted_bids_history)]
+    pub type AcceptedBidsHistory<T: Config> =
+        StorageMap<_, Identity, Sfx4bId, Vec<(BalanceOf<T>, BalanceOf<T>)>, ValueQuery>;
+
     // Keep last 10 epoch estimates
     #[pallet::storage]
     #[pallet::getter(fn epoch_history)]
@@ -1109,6 +1195,50 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Prices side effects before they are submitted, assuming the max security level of their targets.
+        pub fn estimate_side_effects_costs(
+            side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
+        ) -> Vec<SfxCostEstimate<BalanceOf<T>>> {
+            let fsx = side_effects
+                .into_iter()
+                .enumerate()
+                .map(|(index, sfx)| FullSideEffect {
+                    security_lvl: Self::get_gateway_max_security_lvl(&sfx.target),
+                    input: sfx,
+                    confirmed: None,
+                    submission_target_height: Zero::zero(),
+                    best_bid: None,
+                    index: index as u32,
+                })
+                .collect::<Vec<_>>();
+
+            Self::estimate_costs(&fsx)
+        }
+
+        fn average_accepted_bid(sfx_4b_id: Sfx4bId) -> (BalanceOf<T>, BalanceOf<T>) {
+            let history = <AcceptedBidsHistory<T>>::get(sfx_4b_id);
+            if history.is_empty() {
+                return (Zero::zero(), Zero::zero())
+            }
+            let count: BalanceOf<T> = (history.len() as u32).into();
+            let (total_amount, total_insurance) = history.iter().fold(
+                (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+                |(total_amount, total_insurance), (amount, insurance)| {
+                    (
+                        total_amount.saturating_add(*amount),
+                        total_insurance.saturating_add(*insurance),
+                    )
+                },
+            );
+
+            (
+                total_amount.checked_div(&count).unwrap_or_else(Zero::zero),
+                total_insurance
+                    .checked_div(&count)
+                    .unwrap_or_else(Zero::zero),
+            )
+        }
+
         pub fn update_epoch_history(
             verifier: &GatewayVendor,
             epoch_duration_in_remote_blocks: frame_system::pallet_prelude::BlockNumberFor<T>,
@@ -1153,6 +1283,17 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> StandardJobQueues<T> for Pallet<T> {
+        fn process_hourly(n: BlockNumberFor<T>) -> Weight {
+            let weight = Self::check_for_manual_verifier_overview_process(n);
+            log::debug!(
+                "XDNS::check_for_manual_verifier_overview_process consumed: {:?}",
+                weight
+            );
+            weight
+        }
+    }
+
     impl<T: Config> LightClientAsyncAPI<T> for Pallet<T> {
         fn on_new_epoch(
             verifier: GatewayVendor,
@@ -1166,6 +1307,10 @@ pub mod pallet {
                 current_hearbeat,
             );
         }
+
+        fn get_event_layout(gateway_id: ChainId, event_index: [u8; 2]) -> Option<Vec<ScaleLayout>> {
+            <EventLayouts<T>>::get(gateway_id, event_index)
+        }
     }
 
     impl<T: Config> Xdns<T, BalanceOf<T>> for Pallet<T> {
@@ -1481,6 +1626,13 @@ pub mod pallet {
             }
         }
 
+        fn get_execution_vendor(chain_id: &ChainId) -> Result<ExecutionVendor, DispatchError> {
+            match <Gateways<T>>::get(chain_id) {
+                Some(rec) => Ok(rec.execution_vendor),
+                None => Err(Error::<T>::XdnsRecordNotFound.into()),
+            }
+        }
+
         fn get_target_codec(chain_id: &ChainId) -> Result<Codec, DispatchError> {
             match <Gateways<T>>::get(chain_id) {
                 Some(rec) => Ok(rec.codec),
@@ -1498,6 +1650,10 @@ pub mod pallet {
             }
         }
 
+        fn get_sibling_para_id(chain_id: &ChainId) -> Option<u32> {
+            <SiblingParachains<T>>::get(chain_id)
+        }
+
         fn fetch_full_gateway_records() -> Vec<FullGatewayRecord<T::AccountId>> {
             Gateways::<T>::iter_values()
                 .map(|gateway| {
@@ -1681,7 +1837,45 @@ pub mod pallet {
         }
 
         fn estimate_costs(
-            _fsx: &Vec<
+            fsx: &Vec<
+                FullSideEffect<
+                    T::AccountId,
+                    frame_system::pallet_prelude::BlockNumberFor<T>,
+                    BalanceOf<T>,
+                >,
+            >,
+        ) -> Vec<SfxCostEstimate<BalanceOf<T>>> {
+            fsx.iter()
+                .map(|fsx| {
+                    let target = fsx.input.target;
+                    let execution_cost = <GatewayExecutionFees<T>>::get(target);
+                    let finality_fee = match fsx.security_lvl {
+                        SecurityLvl::Escrow => T::AttestersRead::estimate_finality_fee(&target),
+                        SecurityLvl::Optimistic => Zero::zero(),
+                    };
+                    let (avg_accepted_amount, avg_accepted_insurance) =
+                        Self::average_accepted_bid(fsx.input.action);
+
+                    // Executors won't bid below their execution costs, nor below the recent market price
+                    let max_reward = execution_cost.max(avg_accepted_amount);
+                    // Escrow bids are insured by the finality fee, Optimistic ones should at least cover the reward at stake
+                    let insurance = match fsx.security_lvl {
+                        SecurityLvl::Escrow => finality_fee,
+                        SecurityLvl::Optimistic => max_reward.max(avg_accepted_insurance),
+                    };
+
+                    SfxCostEstimate {
+                        execution_cost,
+                        finality_fee,
+                        max_reward,
+                        insurance,
+                    }
+                })
+                .collect()
+        }
+
+        fn note_accepted_bids(
+            fsx: &Vec<
                 FullSideEffect<
                     T::AccountId,
                     frame_system::pallet_prelude::BlockNumberFor<T>,
@@ -1689,7 +1883,19 @@ pub mod pallet {
                 >,
             >,
         ) {
-            todo!("estimate costs")
+            for fsx in fsx.iter() {
+                let accepted_bid = match &fsx.best_bid {
+                    // Only native bids are comparable with quotes given in native currency
+                    Some(bid) if bid.reward_asset_id.is_none() => bid,
+                    _ => continue,
+                };
+                <AcceptedBidsHistory<T>>::mutate(fsx.input.action, |history| {
+                    history.push((accepted_bid.amount, accepted_bid.insurance));
+                    if history.len() > MAX_ACCEPTED_BIDS_HISTORY {
+                        history.remove(0);
+                    }
+                });
+            }
         }
     }
 }
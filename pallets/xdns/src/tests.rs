@@ -28,9 +28,10 @@ use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::DispatchError;
 use t3rn_primitives::{
     circuit::SecurityLvl::{Escrow, Optimistic},
-    clock::OnHookQueues,
+    clock::HOURLY_JOB,
+    light_client::{LightClientAsyncAPI, ScaleLayout},
     portal::Portal as PortalT,
-    xdns::{FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, Xdns},
+    xdns::{FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, SfxCostEstimate, Xdns},
     EthereumToken, ExecutionVendor,
     ExecutionVendor::{Substrate, EVM},
     FinalityVerifierActivity, GatewayActivity, GatewayVendor,
@@ -44,7 +45,10 @@ use t3rn_primitives::{
     GatewayVendor::{Attesters, Sepolia, XBI},
 };
 
-use t3rn_types::fsx::SecurityLvl;
+use t3rn_types::{
+    fsx::{FullSideEffect, SecurityLvl},
+    sfx::SideEffect,
+};
 
 const DEFAULT_GATEWAYS_IN_STORAGE_COUNT: usize = 8;
 const STANDARD_SFX_ABI_COUNT: usize = 6;
@@ -1354,21 +1358,24 @@ fn on_initialize_should_update_update_verifiers_overview_no_more_often_than_each
             let last_reported_block = expected_verifier_overview_all_on[0].reported_at;
 
             System::set_block_number(last_reported_block + 1);
-            <GlobalOnInitQueues as OnHookQueues<Runtime>>::process_hourly(
+            Clock::run_job(
+                HOURLY_JOB,
                 System::block_number(),
                 Weight::from_parts(u64::MAX, 0),
             );
             assert_eq!(XDNS::verifier_overview(), expected_verifier_overview_all_on);
 
             System::set_block_number(last_reported_block + 5);
-            <GlobalOnInitQueues as OnHookQueues<Runtime>>::process_hourly(
+            Clock::run_job(
+                HOURLY_JOB,
                 System::block_number(),
                 Weight::from_parts(u64::MAX, 0),
             );
             assert_eq!(XDNS::verifier_overview(), expected_verifier_overview_all_on);
 
             System::set_block_number(System::block_number() + 52);
-            <GlobalOnInitQueues as OnHookQueues<Runtime>>::process_hourly(
+            Clock::run_job(
+                HOURLY_JOB,
                 System::block_number(),
                 Weight::from_parts(u64::MAX, 0),
             );
@@ -2076,3 +2083,243 @@ fn test_storage_migration_v144_to_v145_that_kills_old_xdns_records_entry() {
             );
         });
 }
+
+fn transfer_sfx_to_test_gateway() -> SideEffect<AccountId32, Balance> {
+    SideEffect {
+        target: *b"test",
+        max_reward: 0,
+        insurance: 0,
+        action: *b"tran",
+        encoded_args: vec![],
+        signature: vec![],
+        enforce_executor: None,
+        reward_asset_id: None,
+    }
+}
+
+fn fsx_with_accepted_bid(
+    amount: Balance,
+    insurance: Balance,
+    reward_asset_id: Option<u32>,
+) -> FullSideEffect<AccountId32, BlockNumber, Balance> {
+    FullSideEffect {
+        input: transfer_sfx_to_test_gateway(),
+        confirmed: None,
+        security_lvl: Optimistic,
+        submission_target_height: 0,
+        best_bid: Some(t3rn_types::bid::SFXBid::new_none_optimistic(
+            amount,
+            insurance,
+            BOB,
+            ALICE,
+            reward_asset_id,
+        )),
+        index: 0,
+    }
+}
+
+#[test]
+fn set_gateway_execution_fee_requires_root_and_registered_gateway() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            XDNS::set_gateway_execution_fee(Origin::root(), *b"test", 100),
+            Error::<Runtime>::GatewayRecordNotFound
+        );
+
+        assert_ok!(XDNS::add_new_gateway(
+            *b"test",
+            GatewayVendor::Rococo,
+            ExecutionVendor::Substrate,
+            t3rn_abi::Codec::Scale,
+            None,   // registrant
+            None,   // escrow_account
+            vec![], // allowed_side_effects
+        ));
+
+        assert_noop!(
+            XDNS::set_gateway_execution_fee(Origin::signed(ALICE), *b"test", 100),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XDNS::set_gateway_execution_fee(
+            Origin::root(),
+            *b"test",
+            100
+        ));
+        assert_eq!(XDNS::gateway_execution_fees(*b"test"), 100);
+    });
+}
+
+#[test]
+fn estimate_costs_quotes_execution_fee_without_accepted_bids_history() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(XDNS::add_new_gateway(
+            *b"test",
+            GatewayVendor::Rococo,
+            ExecutionVendor::Substrate,
+            t3rn_abi::Codec::Scale,
+            None,   // registrant
+            None,   // escrow_account
+            vec![], // allowed_side_effects
+        ));
+        assert_ok!(XDNS::set_gateway_execution_fee(
+            Origin::root(),
+            *b"test",
+            100
+        ));
+
+        assert_eq!(
+            XDNS::estimate_side_effects_costs(vec![transfer_sfx_to_test_gateway()]),
+            vec![SfxCostEstimate {
+                execution_cost: 100,
+                finality_fee: 0,
+                max_reward: 100,
+                insurance: 100,
+            }]
+        );
+    });
+}
+
+#[test]
+fn estimate_costs_follows_average_of_recently_accepted_native_bids() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(XDNS::add_new_gateway(
+            *b"test",
+            GatewayVendor::Rococo,
+            ExecutionVendor::Substrate,
+            t3rn_abi::Codec::Scale,
+            None,   // registrant
+            None,   // escrow_account
+            vec![], // allowed_side_effects
+        ));
+        assert_ok!(XDNS::set_gateway_execution_fee(
+            Origin::root(),
+            *b"test",
+            100
+        ));
+
+        XDNS::note_accepted_bids(&vec![
+            fsx_with_accepted_bid(200, 300, None),
+            fsx_with_accepted_bid(400, 500, None),
+            // Bids rewarded in other assets aren't comparable with native quotes
+            fsx_with_accepted_bid(10_000, 10_000, Some(1)),
+        ]);
+
+        assert_eq!(
+            XDNS::accepted_bids_history(*b"tran"),
+            vec![(200, 300), (400, 500)]
+        );
+        assert_eq!(
+            XDNS::estimate_side_effects_costs(vec![transfer_sfx_to_test_gateway()]),
+            vec![SfxCostEstimate {
+                execution_cost: 100,
+                finality_fee: 0,
+                max_reward: 300,
+                insurance: 400,
+            }]
+        );
+    });
+}
+
+#[test]
+fn note_accepted_bids_keeps_limited_history_per_sfx_4b_id() {
+    ExtBuilder::default().build().execute_with(|| {
+        for amount in 1..=(MAX_ACCEPTED_BIDS_HISTORY as Balance + 5) {
+            XDNS::note_accepted_bids(&vec![fsx_with_accepted_bid(amount, amount, None)]);
+        }
+
+        let history = XDNS::accepted_bids_history(*b"tran");
+        assert_eq!(history.len(), MAX_ACCEPTED_BIDS_HISTORY);
+        assert_eq!(history.first(), Some(&(6, 6)));
+    });
+}
+
+#[test]
+fn link_sibling_parachain_requires_root_and_registered_gateway() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                XDNS::link_sibling_parachain(
+                    Origin::signed(AccountId32::new([1u8; 32])),
+                    [3, 3, 3, 3],
+                    Some(2000)
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::link_sibling_parachain(Origin::root(), [9, 9, 9, 9], Some(2000)),
+                Error::<Runtime>::GatewayRecordNotFound
+            );
+
+            assert_ok!(XDNS::link_sibling_parachain(
+                Origin::root(),
+                [3, 3, 3, 3],
+                Some(2000)
+            ));
+            assert_eq!(XDNS::get_sibling_para_id(&[3, 3, 3, 3]), Some(2000));
+
+            assert_ok!(XDNS::link_sibling_parachain(
+                Origin::root(),
+                [3, 3, 3, 3],
+                None
+            ));
+            assert_eq!(XDNS::get_sibling_para_id(&[3, 3, 3, 3]), None);
+        });
+}
+
+#[test]
+fn set_event_layout_requires_root_and_is_served_to_light_clients() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let transfer_layout = vec![
+                ScaleLayout::Fixed(32),
+                ScaleLayout::Fixed(32),
+                ScaleLayout::Fixed(16),
+            ];
+            assert_noop!(
+                XDNS::set_event_layout(
+                    Origin::signed(AccountId32::new([1u8; 32])),
+                    [3, 3, 3, 3],
+                    [4, 2],
+                    Some(transfer_layout.clone())
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_event_layout(
+                    Origin::root(),
+                    [9, 9, 9, 9],
+                    [4, 2],
+                    Some(transfer_layout.clone())
+                ),
+                Error::<Runtime>::GatewayRecordNotFound
+            );
+
+            assert_ok!(XDNS::set_event_layout(
+                Origin::root(),
+                [3, 3, 3, 3],
+                [4, 2],
+                Some(transfer_layout.clone())
+            ));
+            assert_eq!(
+                <XDNS as LightClientAsyncAPI<Runtime>>::get_event_layout([3, 3, 3, 3], [4, 2]),
+                Some(transfer_layout)
+            );
+
+            assert_ok!(XDNS::set_event_layout(
+                Origin::root(),
+                [3, 3, 3, 3],
+                [4, 2],
+                None
+            ));
+            assert_eq!(
+                <XDNS as LightClientAsyncAPI<Runtime>>::get_event_layout([3, 3, 3, 3], [4, 2]),
+                None
+            );
+        });
+}
@@ -42,6 +42,9 @@ pub trait WeightInfo {
     fn purge_gateway() -> Weight;
     fn reboot_self_gateway() -> Weight;
     fn best_available() -> Weight;
+    fn set_gateway_execution_fee() -> Weight;
+    fn link_sibling_parachain() -> Weight;
+    fn set_event_layout() -> Weight;
 }
 
 /// Weights for pallet_xdns using the Substrate node and recommended hardware.
@@ -74,6 +77,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+
+    fn set_gateway_execution_fee() -> Weight {
+        Weight::from_parts(18_640_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn link_sibling_parachain() -> Weight {
+        Weight::from_parts(18_640_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn set_event_layout() -> Weight {
+        Weight::from_parts(21_310_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -105,4 +126,22 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn set_gateway_execution_fee() -> Weight {
+        Weight::from_parts(18_640_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn link_sibling_parachain() -> Weight {
+        Weight::from_parts(18_640_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_event_layout() -> Weight {
+        Weight::from_parts(21_310_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
@@ -10,10 +10,14 @@ use sp_std::prelude::*;
 use t3rn_types::sfx::TargetId;
 
 pub use t3rn_abi::{evm_ingress_logs::*, recode::recode_bytes_with_descriptor, Codec};
+
+pub mod bls;
+
 // Key types for attester crypto
 pub const ECDSA_ATTESTER_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"ecat");
 pub const ED25519_ATTESTER_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"edat");
 pub const SR25519_ATTESTER_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"srat");
+pub const BLS_ATTESTER_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"blat");
 
 // "\x19Ethereum Signed Message:\n32" encoded in hex!("19457468657265756d205369676e6564204d6573736167653a0a3332") -> [ 25,69,116,104,101,114,101,117,109,32,83,105,103,110,101,100,32,77,101,115,115,97,103,101,58,10,51,50 ]
 pub const ETH_SIGNED_MESSAGE_PREFIX: [u8; 28] = [
@@ -316,8 +320,81 @@ pub fn verify_secp256k1_ecdsa_signature(
 
 pub type Signature65b = [u8; 65];
 pub type PublicKeyEcdsa33b = [u8; 33];
+// Compressed BLS12-377 points: signatures on G1, public keys on G2
+pub type SignatureBls48b = [u8; 48];
+pub type PublicKeyBls96b = [u8; 96];
 pub const COMMITTEE_SIZE: usize = 32;
 
+/// Committee's BLS signatures over the batch message summed into one, with the signers marked
+///     by their position in the committee in the bitmap (lowest bit being the first member).
+///     Public keys of the signers are summed alongside at the time of signing, so that the aggregate
+///     stays verifiable regardless of later committee rotations or key changes.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct AggregatedBlsSignature {
+    pub signature: SignatureBls48b,
+    pub public_key: PublicKeyBls96b,
+    pub signers_bitmap: u32,
+}
+
+impl Default for AggregatedBlsSignature {
+    fn default() -> Self {
+        AggregatedBlsSignature {
+            signature: [0u8; 48],
+            public_key: [0u8; 96],
+            signers_bitmap: 0,
+        }
+    }
+}
+
+impl AggregatedBlsSignature {
+    pub fn has_signed(&self, committee_position: usize) -> bool {
+        committee_position < COMMITTEE_SIZE
+            && self.signers_bitmap & (1u32 << committee_position) != 0
+    }
+
+    pub fn signers_count(&self) -> usize {
+        self.signers_bitmap.count_ones() as usize
+    }
+
+    /// Adds the signature and the public key of committee member at the position to the aggregate.
+    pub fn aggregate(
+        &mut self,
+        committee_position: usize,
+        signature: &SignatureBls48b,
+        public_key: &PublicKeyBls96b,
+    ) -> Result<(), DispatchError> {
+        if committee_position >= COMMITTEE_SIZE || self.has_signed(committee_position) {
+            return Err("AggregatedBlsSignatureInvalidSigner".into())
+        }
+        let (signature, public_key) = if self.signers_bitmap == 0 {
+            (
+                bls::aggregate_signatures(&[*signature]),
+                bls::aggregate_public_keys(&[*public_key]),
+            )
+        } else {
+            (
+                bls::aggregate_signatures(&[self.signature, *signature]),
+                bls::aggregate_public_keys(&[self.public_key, *public_key]),
+            )
+        };
+        self.signature = signature.ok_or::<DispatchError>("InvalidSignature".into())?;
+        self.public_key = public_key.ok_or::<DispatchError>("InvalidPublicKey".into())?;
+        self.signers_bitmap |= 1u32 << committee_position;
+        Ok(())
+    }
+
+    /// Verifies the aggregate against the public keys of the signers snapshotted while signing.
+    pub fn verify(&self, message: &[u8]) -> bool {
+        self.signers_bitmap != 0
+            && bls::verify(
+                &self.public_key,
+                bls::BLS_ATTESTATION_DST,
+                message,
+                &self.signature,
+            )
+    }
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum LatencyStatus {
@@ -360,6 +437,7 @@ pub trait AttestersWriteApi<Account, Error> {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct BatchingFactor {
     pub latest_confirmed: u16,
     pub latest_signed: u16,
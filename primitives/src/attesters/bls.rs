@@ -0,0 +1,245 @@
+//! BLS12-377 attestations - signatures live on G1 and public keys on G2, so that the committee's signatures over
+//!     a batch message aggregate into a single G1 point, verified on target with a single pairing check
+//!     against the aggregated public key of the signers.
+use crate::attesters::{PublicKeyBls96b, SignatureBls48b};
+use ark_bls12_377::{Bls12_377, Fq, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use sp_std::prelude::*;
+use tiny_keccak::{Hasher, Keccak};
+
+// Domain separation tags of the messages hashed to G1
+pub const BLS_ATTESTATION_DST: &[u8] = b"T3RN_ATTESTERS_BLS12377G1_ATTESTATION_";
+pub const BLS_PROOF_OF_POSSESSION_DST: &[u8] = b"T3RN_ATTESTERS_BLS12377G1_POP_";
+
+fn keccak_256(chunks: &[&[u8]]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    for chunk in chunks {
+        keccak.update(chunk);
+    }
+    let mut output = [0u8; 32];
+    keccak.finalize(&mut output);
+    output
+}
+
+/// Hashes the message to G1 with try-and-increment over keccak256, simple enough to be replicated by targets:
+///     x = keccak256(dst ++ message ++ counter_be ++ 0x00) ++ keccak256(dst ++ message ++ counter_be ++ 0x01) mod q,
+///     taking the point with the smaller y for the first x on curve and clearing its cofactor.
+pub fn hash_to_g1(dst: &[u8], message: &[u8]) -> G1Affine {
+    let mut counter: u32 = 0;
+    loop {
+        let counter_be = counter.to_be_bytes();
+        let mut x_bytes = keccak_256(&[dst, message, &counter_be, &[0u8]]).to_vec();
+        x_bytes.extend_from_slice(&keccak_256(&[dst, message, &counter_be, &[1u8]]));
+        let x = Fq::from_be_bytes_mod_order(&x_bytes);
+
+        if let Some(point) = G1Affine::get_point_from_x_unchecked(x, false) {
+            let point = point.clear_cofactor();
+            if !point.is_zero() {
+                return point
+            }
+        }
+        counter = counter.wrapping_add(1);
+    }
+}
+
+/// Decodes compressed G1 signature, checking the point is on curve and in the prime order subgroup.
+pub fn decode_signature(signature: &SignatureBls48b) -> Option<G1Affine> {
+    G1Affine::deserialize_compressed(&signature[..]).ok()
+}
+
+/// Decodes compressed G2 public key, checking the point is on curve and in the prime order subgroup.
+pub fn decode_public_key(public_key: &PublicKeyBls96b) -> Option<G2Affine> {
+    G2Affine::deserialize_compressed(&public_key[..])
+        .ok()
+        .filter(|point| !point.is_zero())
+}
+
+pub fn encode_signature(signature: G1Affine) -> Option<SignatureBls48b> {
+    let mut encoded = [0u8; 48];
+    signature.serialize_compressed(&mut encoded[..]).ok()?;
+    Some(encoded)
+}
+
+pub fn encode_public_key(public_key: G2Affine) -> Option<PublicKeyBls96b> {
+    let mut encoded = [0u8; 96];
+    public_key.serialize_compressed(&mut encoded[..]).ok()?;
+    Some(encoded)
+}
+
+/// Derives the public key on G2 of the secret scalar, read from big endian bytes.
+pub fn public_key_from_secret(secret: &[u8; 32]) -> Option<PublicKeyBls96b> {
+    let secret = Fr::from_be_bytes_mod_order(secret);
+    encode_public_key((G2Affine::generator() * secret).into_affine())
+}
+
+/// Signs the message hashed to G1 with the secret scalar, read from big endian bytes.
+pub fn sign(secret: &[u8; 32], dst: &[u8], message: &[u8]) -> Option<SignatureBls48b> {
+    let secret = Fr::from_be_bytes_mod_order(secret);
+    encode_signature((hash_to_g1(dst, message) * secret).into_affine())
+}
+
+/// Checks e(signature, g2) == e(H(message), public_key) as a single multi-pairing.
+pub fn verify(
+    public_key: &PublicKeyBls96b,
+    dst: &[u8],
+    message: &[u8],
+    signature: &SignatureBls48b,
+) -> bool {
+    match (decode_public_key(public_key), decode_signature(signature)) {
+        (Some(public_key), Some(signature)) => verify_points(public_key, dst, message, signature),
+        _ => false,
+    }
+}
+
+fn verify_points(public_key: G2Affine, dst: &[u8], message: &[u8], signature: G1Affine) -> bool {
+    let hashed_message = hash_to_g1(dst, message);
+    Bls12_377::multi_pairing(
+        [signature, -hashed_message],
+        [G2Affine::generator(), public_key],
+    )
+    .is_zero()
+}
+
+/// Proof of possession is the signature of the public key itself, protecting aggregated signatures against rogue keys.
+pub fn verify_proof_of_possession(
+    public_key: &PublicKeyBls96b,
+    proof_of_possession: &SignatureBls48b,
+) -> bool {
+    verify(
+        public_key,
+        BLS_PROOF_OF_POSSESSION_DST,
+        public_key.as_slice(),
+        proof_of_possession,
+    )
+}
+
+/// Sums the signatures on G1, returning None if any of them is invalid.
+pub fn aggregate_signatures(signatures: &[SignatureBls48b]) -> Option<SignatureBls48b> {
+    let mut aggregated = G1Projective::zero();
+    for signature in signatures {
+        aggregated += decode_signature(signature)?;
+    }
+    encode_signature(aggregated.into_affine())
+}
+
+/// Sums the public keys on G2, returning None if any of them is invalid.
+pub fn aggregate_public_keys(public_keys: &[PublicKeyBls96b]) -> Option<PublicKeyBls96b> {
+    let mut aggregated = G2Projective::zero();
+    for public_key in public_keys {
+        aggregated += decode_public_key(public_key)?;
+    }
+    encode_public_key(aggregated.into_affine())
+}
+
+/// Verifies the signature aggregated over the same message against the public keys of all signers.
+pub fn verify_aggregated(
+    public_keys: &[PublicKeyBls96b],
+    dst: &[u8],
+    message: &[u8],
+    aggregated_signature: &SignatureBls48b,
+) -> bool {
+    if public_keys.is_empty() {
+        return false
+    }
+    match aggregate_public_keys(public_keys) {
+        Some(aggregated_public_key) =>
+            verify(&aggregated_public_key, dst, message, aggregated_signature),
+        None => false,
+    }
+}
+
+/// Encodes Fq as 64 bytes big endian, with top 16 bytes zeroed as expected by EIP-2539 precompiles.
+fn encode_fq_for_pairing_precompile(fq: Fq) -> [u8; 64] {
+    let mut encoded = [0u8; 64];
+    encoded[16..].copy_from_slice(&fq.into_bigint().to_bytes_be());
+    encoded
+}
+
+/// Encodes the signature as uncompressed (x, y) G1 point consumed by EIP-2539 BLS12-377 precompiles on targets.
+pub fn signature_for_pairing_precompile(signature: &SignatureBls48b) -> Option<[u8; 128]> {
+    let signature = decode_signature(signature)?;
+    let mut encoded = [0u8; 128];
+    if !signature.is_zero() {
+        encoded[0..64].copy_from_slice(&encode_fq_for_pairing_precompile(signature.x));
+        encoded[64..128].copy_from_slice(&encode_fq_for_pairing_precompile(signature.y));
+    }
+    Some(encoded)
+}
+
+/// Encodes the public key as uncompressed (x, y) G2 point consumed by EIP-2539 BLS12-377 precompiles on targets.
+pub fn public_key_for_pairing_precompile(public_key: &PublicKeyBls96b) -> Option<[u8; 256]> {
+    let public_key = decode_public_key(public_key)?;
+    let mut encoded = [0u8; 256];
+    encoded[0..64].copy_from_slice(&encode_fq_for_pairing_precompile(public_key.x.c0));
+    encoded[64..128].copy_from_slice(&encode_fq_for_pairing_precompile(public_key.x.c1));
+    encoded[128..192].copy_from_slice(&encode_fq_for_pairing_precompile(public_key.y.c0));
+    encoded[192..256].copy_from_slice(&encode_fq_for_pairing_precompile(public_key.y.c1));
+    Some(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregated_signature_verifies_against_aggregated_public_keys_of_signers() {
+        let message = b"batch_message_hash_to_sign_on_32";
+        let secrets = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        let public_keys: Vec<PublicKeyBls96b> = secrets
+            .iter()
+            .map(|secret| public_key_from_secret(secret).unwrap())
+            .collect();
+        let signatures: Vec<SignatureBls48b> = secrets
+            .iter()
+            .map(|secret| sign(secret, BLS_ATTESTATION_DST, message).unwrap())
+            .collect();
+
+        for (public_key, signature) in public_keys.iter().zip(signatures.iter()) {
+            assert!(verify(public_key, BLS_ATTESTATION_DST, message, signature));
+        }
+
+        let aggregated_signature = aggregate_signatures(&signatures).unwrap();
+        assert!(verify_aggregated(
+            &public_keys,
+            BLS_ATTESTATION_DST,
+            message,
+            &aggregated_signature
+        ));
+        // Missing signer or different message don't verify
+        assert!(!verify_aggregated(
+            &public_keys[..2],
+            BLS_ATTESTATION_DST,
+            message,
+            &aggregated_signature
+        ));
+        assert!(!verify_aggregated(
+            &public_keys,
+            BLS_ATTESTATION_DST,
+            b"different_message_hash_to_sign32",
+            &aggregated_signature
+        ));
+    }
+
+    #[test]
+    fn proof_of_possession_is_bound_to_its_domain() {
+        let secret = [7u8; 32];
+        let public_key = public_key_from_secret(&secret).unwrap();
+
+        let proof_of_possession =
+            sign(&secret, BLS_PROOF_OF_POSSESSION_DST, public_key.as_slice()).unwrap();
+        assert!(verify_proof_of_possession(
+            &public_key,
+            &proof_of_possession
+        ));
+
+        let attestation_of_public_key =
+            sign(&secret, BLS_ATTESTATION_DST, public_key.as_slice()).unwrap();
+        assert!(!verify_proof_of_possession(
+            &public_key,
+            &attestation_of_public_key
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    circuit::CircuitStatus, xtx::LocalState, ExecutionSource, GatewayVendor, SpeedMode, TargetId,
+    circuit::{CircuitStatus, OrderStep},
+    xtx::LocalState,
+    ExecutionSource, GatewayVendor, SpeedMode, TargetId,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -13,11 +15,13 @@ use frame_system::{
 use sp_core::H256;
 use sp_std::{fmt::Debug, vec::Vec};
 
-use crate::{circuit::AdaptiveTimeout, light_client::InclusionReceipt};
+use crate::{circuit::AdaptiveTimeout, light_client::InclusionReceipt, threevm::XtxCallback};
 use t3rn_sdk_primitives::signal::ExecutionSignal;
 use t3rn_types::{
     fsx::FullSideEffect,
-    sfx::{ConfirmedSideEffect, HardenedSideEffect, SecurityLvl, SideEffect, SideEffectId},
+    sfx::{
+        AuctionMode, ConfirmedSideEffect, HardenedSideEffect, SecurityLvl, SideEffect, SideEffectId,
+    },
 };
 
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
@@ -28,6 +32,8 @@ pub struct LocalTrigger<T: ConfigSystem> {
     pub submitted_side_effects: Vec<Vec<u8>>,
     pub speed_mode: SpeedMode,
     pub maybe_xtx_id: Option<T::Hash>,
+    /// Contract to be called back once the xtx resolves
+    pub maybe_callback: Option<XtxCallback<T::AccountId>>,
 }
 
 impl<T: ConfigSystem> LocalTrigger<T> {
@@ -42,8 +48,14 @@ impl<T: ConfigSystem> LocalTrigger<T> {
             submitted_side_effects,
             speed_mode,
             maybe_xtx_id,
+            maybe_callback: None,
         }
     }
+
+    pub fn with_callback(mut self, callback: XtxCallback<T::AccountId>) -> Self {
+        self.maybe_callback = Some(callback);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
@@ -92,6 +104,15 @@ pub trait CircuitSubmitAPI<T: ConfigSystem, Balance> {
         side_effects: Vec<SideEffect<T::AccountId, Balance>>,
         speed_mode: SpeedMode,
         preferred_security_level: SecurityLvl,
+        auction_mode: AuctionMode,
+    ) -> DispatchResultWithPostInfo;
+
+    fn on_multi_step_extrinsic_trigger(
+        origin: OriginFor<T>,
+        steps: Vec<OrderStep<SideEffect<T::AccountId, Balance>>>,
+        speed_mode: SpeedMode,
+        preferred_security_level: SecurityLvl,
+        auction_mode: AuctionMode,
     ) -> DispatchResultWithPostInfo;
 
     fn on_remote_origin_trigger(
@@ -139,6 +160,13 @@ pub trait OnLocalTrigger<T: ConfigSystem, Balance> {
     ) -> Result<LocalStateExecutionView<T, Balance>, sp_runtime::DispatchError>;
 
     fn on_signal(origin: &OriginFor<T>, signal: ExecutionSignal<T::Hash>) -> DispatchResult;
+
+    /// Weights of the calls above, charged upfront to the contracts invoking them.
+    fn on_local_trigger_weight() -> Weight;
+
+    fn load_local_state_weight() -> Weight;
+
+    fn on_signal_weight() -> Weight;
 }
 
 pub type XExecSignalId<T> = <T as ConfigSystem>::Hash;
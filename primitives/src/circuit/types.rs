@@ -1,21 +1,22 @@
 use crate::{
     circuit::{XExecSignalId, XExecStepSideEffectId},
+    xdns::Xdns,
     xtx::LocalState,
-    SpeedMode,
+    ExecutionVendor, GatewayVendor, SpeedMode,
 };
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchError;
 use frame_system::{pallet_prelude::BlockNumberFor, Config};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_core::{hexdisplay::AsBytesRef, Hasher};
+use sp_core::{hexdisplay::AsBytesRef, Hasher, H256};
 #[cfg(feature = "no_std")]
 use sp_runtime::RuntimeDebug as Debug;
 use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::{convert::TryInto, default::Default, fmt::Debug, prelude::*};
-use t3rn_types::sfx::TargetId;
-pub use t3rn_types::sfx::{FullSideEffect, SecurityLvl, SideEffect};
+pub use t3rn_types::sfx::{AuctionMode, FullSideEffect, SecurityLvl, SideEffect};
+use t3rn_types::sfx::{Sfx4bId, TargetId};
 
 type SystemHashing<T> = <T as Config>::Hashing;
 
@@ -31,6 +32,7 @@ type SystemHashing<T> = <T as Config>::Hashing;
 ///     Ready -> Committed: All of the side effects have been successfully confirmed
 ///     Ready -> Reverted: Some of the side effects failed and the Xtx was reverted
 #[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CircuitStatus {
     /// unvalidated xtx requested
     #[default]
@@ -58,6 +60,7 @@ pub enum CircuitStatus {
 
 /// Kill or Revert cause
 #[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Cause {
     /// timeout expired with incomplete expectations: either bids or SFX confirmations
     Timeout,
@@ -110,6 +113,8 @@ impl CircuitStatus {
                     (CircuitStatus::PendingExecution, CircuitStatus::FinishedAllSteps) => Ok(new),
                     (CircuitStatus::PendingExecution, CircuitStatus::Committed) => Ok(new),
                     // next steps transitions
+                    (CircuitStatus::Ready, CircuitStatus::PendingBidding) => Ok(new),
+                    (CircuitStatus::PendingExecution, CircuitStatus::PendingBidding) => Ok(new),
                     (CircuitStatus::Finished, CircuitStatus::PendingExecution) => Ok(new),
                     (CircuitStatus::Finished, CircuitStatus::Ready) => Ok(new),
                     (CircuitStatus::Finished, CircuitStatus::FinishedAllSteps) => Ok(new),
@@ -297,20 +302,15 @@ impl CircuitStatus {
     pub fn determine_xtx_status<T: Config, Balance: Clone>(
         steps: &[Vec<FullSideEffect<T::AccountId, BlockNumberFor<T>, Balance>>],
     ) -> CircuitStatus {
-        let mut lowest_determined_status = CircuitStatus::Requested;
-
         // If all of the steps are empty assume CircuitStatus::Reserved status
         if steps.iter().all(|step| step.is_empty()) {
             return CircuitStatus::Reserved
         }
         for step in steps.iter() {
             let current_step_status = Self::determine_step_status::<T, Balance>(step);
-            if current_step_status > lowest_determined_status {
-                lowest_determined_status = current_step_status.clone();
-            }
-            // Xtx status is reflected with the lowest status of unresolved Step -
+            // Xtx status is reflected with the status of the first unresolved Step -
             //  break the loop on the first unresolved step
-            if lowest_determined_status < CircuitStatus::Finished {
+            if current_step_status < CircuitStatus::Finished {
                 return current_step_status
             }
         }
@@ -445,6 +445,95 @@ impl<
     }
 }
 
+/// Snapshot of an order's Xtx status together with the status and executor of each of its SFX.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OrderStatusRead<Hash, BlockNumber, Account> {
+    pub xtx_id: Hash,
+    pub status: CircuitStatus,
+    pub all_included_sfx: Vec<(Hash, CircuitStatus, Option<Account>)>,
+    pub timeouts_at: AdaptiveTimeout<BlockNumber, TargetId>,
+}
+
+/// How a verified remote order has been picked up on the Circuit.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RemoteOrderPickup<Hash> {
+    /// Bridge order settled by minting wrapped assets directly on t3rn.
+    Minted,
+    /// Order submitted to Circuit as the SFX of the given Xtx.
+    Xtx { xtx_id: Hash, sfx_id: Hash },
+}
+
+/// Ledger entry of a remote order, consumed exactly once per (remote_target_id, remote_sfx_id, nonce).
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProcessedRemoteOrder<Hash, BlockNumber, Account> {
+    pub remote_target_id: TargetId,
+    pub nonce: u32,
+    /// SFX id of the order as indexed by the remote order contract
+    pub remote_sfx_id: H256,
+    pub processed_by: Account,
+    pub processed_at: BlockNumber,
+    pub pickup: RemoteOrderPickup<Hash>,
+}
+
+/// Reason of the Xtx landing in the Dead Letter Queue
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DLQCause {
+    /// Xtx timed out while some of its targets were inactive, therefore its revert couldn't be attested
+    InactiveTargets,
+    /// Attestation of the Xtx outcome couldn't be requested for some of its SFX::Escrow
+    AttestationRequestFailed,
+}
+
+/// Dead Letter Queue entry of the Xtx, awaiting either the retry, forced revert or timeout extension.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DLQEntry<BlockNumber, Account> {
+    pub added_at: BlockNumber,
+    pub requester: Account,
+    pub targets: Vec<TargetId>,
+    pub speed_mode: SpeedMode,
+    pub cause: DLQCause,
+    /// Status of the Xtx at the time of landing in DLQ
+    pub last_status: CircuitStatus,
+    /// Target and id of each SFX::Escrow whose attestation request failed, retried on their own
+    pub failed_sfx_ids: Vec<(TargetId, H256)>,
+}
+
+/// Kind of Xtx deadline indexed by block number in the Circuit's timeouts wheel
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum XtxTimeoutKind {
+    /// Bidding of the current step closes
+    Bids,
+    /// Xtx which finished all steps is committed
+    Commit,
+    /// Adaptive timeout here passes
+    Adaptive,
+    /// Emergency timeout here passes
+    Emergency,
+    /// Finalized proof of SFX provisionally confirmed under SpeedMode::Instant is due
+    InstantFinality,
+}
+
+/// SFX of Xtx with SpeedMode::Instant provisionally confirmed with the executor-signed commit log.
+///     Executor's bid bond stays locked until the finalized inclusion proof of the SFX arrives,
+///     or is slashed once the finality timeout passes.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct InstantConfirmation<AccountId, BlockNumber, Balance, Hash> {
+    pub xtx_id: Hash,
+    pub bid_id: Hash,
+    pub executor: AccountId,
+    pub side_effect: SideEffect<AccountId, Balance>,
+    pub submission_target_height: BlockNumber,
+    pub confirmed_at: BlockNumber,
+    pub finality_timeout_at: BlockNumber,
+}
+
 #[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AdaptiveTimeout<BlockNumber, TargetId> {
@@ -487,9 +576,40 @@ impl<BlockNumber: Zero + From<u32>, TargetId: Default> AdaptiveTimeout<BlockNumb
 pub enum SFXAction<Account, Asset, Balance, Destination, Input, MaxCost> {
     // All sorts of calls: composable, wasm, evm, etc. are vacuumed into a single Call SFX in the protocol level.
     Call(Destination, Account, Balance, MaxCost, Input),
-    // All of the DEX-related SFXs are vacuumed into a Transfer SFX in the protocol level: swap, add_liquidity, remove_liquidity, transfer asset, transfer native
+    // Transfer of asset or native currency to the beneficiary on the destination.
     Transfer(Destination, Asset, Account, Balance),
     DynamicDestinationDeal(Destination, Asset, Balance),
+    // Swap on the destination's DEX: beneficiary, asset_from, asset_to, amount_from, min_amount_to
+    Swap(Destination, Account, Asset, Asset, Balance, Balance),
+    // Provide liquidity: beneficiary, asset_left, asset_right, liquidity_token, amount_left, amount_right, min_amount_liquidity_token
+    AddLiquidity(
+        Destination,
+        Account,
+        Asset,
+        Asset,
+        Asset,
+        Balance,
+        Balance,
+        Balance,
+    ),
+    // Withdraw liquidity: beneficiary, asset_left, asset_right, liquidity_token, min_amount_left, min_amount_right, amount_liquidity_token
+    RemoveLiquidity(
+        Destination,
+        Account,
+        Asset,
+        Asset,
+        Asset,
+        Balance,
+        Balance,
+        Balance,
+    ),
+}
+
+/// Asset ids are passed to the DEX SFX ABIs as 32b words, left-aligned little endian.
+fn asset_id_as_32b_word(asset_id: u32) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    word[..4].copy_from_slice(&asset_id.to_le_bytes());
+    word.to_vec()
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -501,6 +621,40 @@ pub struct OrderSFX<AccountId, Asset, Balance, Destination, Input, MaxCost> {
     pub remote_origin_nonce: Option<u32>,
 }
 
+/// Argument of SFX in one of the later steps of multi-step order, resolved from the output of SFX
+///     confirmed at one of the previous steps - i.e. the received amount of swap feeding the transfer.
+///     The argument submitted in place of the placeholder must still be valid against the SFX ABI.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StepOutputPlaceholder {
+    /// Index of SFX within its step
+    pub sfx_index: u32,
+    /// Index of the argument in SFX::encoded_args replaced with the output
+    pub arg_index: u32,
+    /// Index of the previous step
+    pub source_step: u32,
+    /// Index of SFX within the previous step, which ConfirmedSideEffect::output is piped
+    pub source_sfx_index: u32,
+}
+
+/// Single step of multi-step order. Steps are executed in sequence - SFX of the next step
+///     only open for bidding after all SFX of the previous step are confirmed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OrderStep<SFX> {
+    pub side_effects: Vec<SFX>,
+    pub placeholders: Vec<StepOutputPlaceholder>,
+}
+
+impl<SFX> OrderStep<SFX> {
+    pub fn new(side_effects: Vec<SFX>) -> Self {
+        OrderStep {
+            side_effects,
+            placeholders: vec![],
+        }
+    }
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum OrderOrigin<AccountId> {
     Local(AccountId),
@@ -574,15 +728,106 @@ where
     type Error = DispatchError;
 
     fn try_into(self) -> Result<SideEffect<AccountId, Balance>, Self::Error> {
+        // Without access to XDNS, assume the call targets EVM
+        self.try_into_side_effect_with_call_action(*b"cevm")
+    }
+}
+
+/// Derives which call SFX (cevm / wasm / cgen) executes SFXAction::Call on the target.
+///     Targets reached over XCM (XBI) dispatch generic calls, otherwise the call goes
+///     to the contracts runtime of the target's execution vendor.
+pub fn derive_call_action(
+    verification_vendor: &GatewayVendor,
+    execution_vendor: &ExecutionVendor,
+) -> Sfx4bId {
+    match (verification_vendor, execution_vendor) {
+        (GatewayVendor::XBI, _) => *b"cgen",
+        (_, ExecutionVendor::EVM) => *b"cevm",
+        (_, ExecutionVendor::Substrate) => *b"wasm",
+    }
+}
+
+impl<AccountId, Asset: Clone, Balance, Destination, Input, MaxCost>
+    OrderSFX<AccountId, Asset, Balance, Destination, Input, MaxCost>
+where
+    u32: From<Asset>,
+    Balance: Encode,
+    MaxCost: Encode,
+    AccountId: Encode,
+    Input: AsBytesRef,
+    Destination: From<[u8; 4]>,
+    [u8; 4]: From<Destination>,
+{
+    /// Converts the order into SFX, routing SFXAction::Call to cevm, wasm or cgen
+    ///     by the target's verification and execution vendors registered in XDNS.
+    ///     Rejects the call if the target doesn't list the derived action as allowed.
+    pub fn try_into_side_effect_via_xdns<T: Config, X: Xdns<T, Balance>>(
+        self,
+    ) -> Result<SideEffect<AccountId, Balance>, DispatchError>
+    where
+        Destination: Clone,
+    {
+        let target: TargetId = match &self.sfx_action {
+            SFXAction::Call(target, ..) => target.clone().into(),
+            _ => return self.try_into_side_effect_with_call_action(*b"cevm"),
+        };
+
+        let call_action = derive_call_action(
+            &X::get_verification_vendor(&target)?,
+            &X::get_execution_vendor(&target)?,
+        );
+
+        if !X::allowed_side_effects(&target)
+            .iter()
+            .any(|(sfx_4b_id, _)| sfx_4b_id == &call_action)
+        {
+            return Err(DispatchError::Other(
+                "OrderSFX::call action derived for the target isn't allowed there",
+            ))
+        }
+
+        self.try_into_side_effect_with_call_action(call_action)
+    }
+
+    fn try_into_side_effect_with_call_action(
+        self,
+        call_action: Sfx4bId,
+    ) -> Result<SideEffect<AccountId, Balance>, DispatchError> {
         let (action, target, encoded_args) = match self.sfx_action {
             SFXAction::Call(target, destination, value, max_cost, input) => {
-                let mut encoded_args = vec![];
-                // todo: lookup destination target and derive the ActionType (call evm / wasm / composable)
-                encoded_args.push(destination.encode()); // target
-                encoded_args.push(value.encode()); // value
-                encoded_args.push(input.as_bytes_ref().to_vec()); // value
-                encoded_args.push(max_cost.encode()); // value
-                (*b"cevm", target.into(), encoded_args)
+                // Arguments follow the order of the standard ABI of each call SFX.
+                //  Optional arguments not carried by the order are left empty.
+                let encoded_args: Vec<Vec<u8>> = match &call_action {
+                    b"wasm" => vec![
+                        destination.encode(),          // contract
+                        value.encode(),                // value
+                        max_cost.encode(),             // gas_limit
+                        vec![],                        // storage_deposit_limit
+                        input.as_bytes_ref().to_vec(), // input
+                    ],
+                    b"cgen" => vec![
+                        destination.encode(),          // target
+                        value.encode(),                // value
+                        input.as_bytes_ref().to_vec(), // input
+                        max_cost.encode(),             // limit
+                        vec![],                        // additional_params
+                    ],
+                    b"cevm" => vec![
+                        destination.encode(),          // target
+                        value.encode(),                // value
+                        input.as_bytes_ref().to_vec(), // input
+                        max_cost.encode(),             // gas_limit
+                        vec![],                        // max_fee_per_gas
+                        vec![],                        // max_priority_fee_per_gas
+                        vec![],                        // nonce
+                        vec![],                        // access_list
+                    ],
+                    _ =>
+                        return Err(DispatchError::Other(
+                            "OrderSFX::unsupported call action - expected cevm, wasm or cgen",
+                        )),
+                };
+                (call_action, target.into(), encoded_args)
             },
             SFXAction::Transfer(target, asset, destination, amount) => {
                 let mut encoded_args: Vec<Vec<u8>> = vec![];
@@ -605,6 +850,65 @@ where
                 encoded_args.push(amount.encode());
                 (action_id, target.into(), encoded_args)
             },
+            SFXAction::Swap(
+                target,
+                beneficiary,
+                asset_from,
+                asset_to,
+                amount_from,
+                min_amount_to,
+            ) => {
+                let encoded_args: Vec<Vec<u8>> = vec![
+                    beneficiary.encode(),                                         // to
+                    amount_from.encode(),                                         // amount_from
+                    min_amount_to.encode(),                                       // amount_to
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_from)), // asset_from
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_to)),   // asset_to
+                ];
+                (*b"swap", target.into(), encoded_args)
+            },
+            SFXAction::AddLiquidity(
+                target,
+                beneficiary,
+                asset_left,
+                asset_right,
+                liquidity_token,
+                amount_left,
+                amount_right,
+                min_amount_liquidity_token,
+            ) => {
+                let encoded_args: Vec<Vec<u8>> = vec![
+                    beneficiary.encode(),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_left)),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_right)),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(liquidity_token)),
+                    amount_left.encode(),
+                    amount_right.encode(),
+                    min_amount_liquidity_token.encode(),
+                ];
+                (*b"aliq", target.into(), encoded_args)
+            },
+            SFXAction::RemoveLiquidity(
+                target,
+                beneficiary,
+                asset_left,
+                asset_right,
+                liquidity_token,
+                min_amount_left,
+                min_amount_right,
+                amount_liquidity_token,
+            ) => {
+                let encoded_args: Vec<Vec<u8>> = vec![
+                    beneficiary.encode(),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_left)),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(asset_right)),
+                    asset_id_as_32b_word(<Asset as Into<u32>>::into(liquidity_token)),
+                    min_amount_left.encode(),
+                    min_amount_right.encode(),
+                    amount_liquidity_token.encode(),
+                ];
+                (*b"rliq", target.into(), encoded_args)
+            },
         };
 
         let reward_asset_id = if <Asset as Into<u32>>::into(self.reward_asset.clone()) == 0 {
@@ -630,11 +934,12 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{OrderSFX, SFXAction};
+    use super::{derive_call_action, OrderSFX, SFXAction};
     use crate::{
         circuit::{AdaptiveTimeout, XExecSignal},
-        SpeedMode,
+        ExecutionVendor, GatewayVendor, SpeedMode,
     };
+    use codec::Encode;
     use frame_support::assert_ok;
     use hex_literal::hex;
     use mini_mock::MiniRuntime;
@@ -863,5 +1168,86 @@ mod tests {
         assert_eq!(side_effect.insurance, 50);
         assert_eq!(side_effect.action, *b"cevm");
         assert_eq!(side_effect.reward_asset_id, Some(1u32));
+        assert_eq!(side_effect.encoded_args.len(), 8);
+        assert_eq!(side_effect.encoded_args[2], vec![3u8; 4]);
+    }
+
+    #[test]
+    fn test_try_into_swap_orders_args_by_swap_abi_with_min_out() {
+        let order_sfx = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+            sfx_action: SFXAction::Swap(
+                [1u8; 4],
+                AccountId32::new([2u8; 32]),
+                1u32,
+                2u32,
+                100u128,
+                95u128,
+            ),
+            max_reward: 200u128,
+            insurance: 50u128,
+            reward_asset: 0u32,
+            remote_origin_nonce: None,
+        };
+
+        let side_effect: SideEffect<AccountId32, u128> = order_sfx.try_into().unwrap();
+
+        let mut asset_to_word = [0u8; 32];
+        asset_to_word[0] = 2u8;
+
+        assert_eq!(side_effect.action, *b"swap");
+        assert_eq!(side_effect.encoded_args.len(), 5);
+        assert_eq!(side_effect.encoded_args[0], [2u8; 32]);
+        assert_eq!(side_effect.encoded_args[1], 100u128.encode());
+        assert_eq!(side_effect.encoded_args[2], 95u128.encode());
+        assert_eq!(side_effect.encoded_args[4], asset_to_word.to_vec());
+    }
+
+    #[test]
+    fn test_derive_call_action_by_target_vendors() {
+        assert_eq!(
+            derive_call_action(&GatewayVendor::Ethereum, &ExecutionVendor::EVM),
+            *b"cevm"
+        );
+        assert_eq!(
+            derive_call_action(&GatewayVendor::Polkadot, &ExecutionVendor::Substrate),
+            *b"wasm"
+        );
+        assert_eq!(
+            derive_call_action(&GatewayVendor::XBI, &ExecutionVendor::Substrate),
+            *b"cgen"
+        );
+    }
+
+    #[test]
+    fn test_try_into_wasm_call_orders_args_by_wasm_abi() {
+        let order_sfx = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+            sfx_action: SFXAction::Call(
+                [1u8; 4],
+                AccountId32::new([2u8; 32]),
+                100u128,
+                200u128,
+                vec![3u8; 4],
+            ),
+            max_reward: 200u128,
+            insurance: 50u128,
+            reward_asset: 0u32,
+            remote_origin_nonce: None,
+        };
+
+        let side_effect = order_sfx
+            .try_into_side_effect_with_call_action(*b"wasm")
+            .unwrap();
+
+        assert_eq!(side_effect.action, *b"wasm");
+        assert_eq!(
+            side_effect.encoded_args,
+            vec![
+                [2u8; 32].to_vec(),
+                100u128.encode(),
+                200u128.encode(),
+                vec![],
+                vec![3u8; 4],
+            ]
+        );
     }
 }
@@ -1,11 +1,30 @@
-use crate::common::RoundInfo;
+use crate::common::{RoundInfo, BLOCKS_PER_DAY, BLOCKS_PER_HOUR};
+use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::Weight, sp_runtime::traits::Zero};
 use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{Percent, RuntimeDebug};
 use sp_std::marker::PhantomData;
 
+pub const BLOCKS_PER_WEEK: u32 = 7 * BLOCKS_PER_DAY;
+pub const BLOCKS_PER_2_WEEKS: u32 = 2 * BLOCKS_PER_WEEK;
+pub const SESSION_PERIOD: u32 = 6 * BLOCKS_PER_HOUR;
+
+pub const SIGNAL_QUEUE_JOB: &[u8] = b"signal_queue";
+pub const XTX_TICK_QUEUE_JOB: &[u8] = b"xtx_tick_queue";
+pub const REVERT_XTX_QUEUE_JOB: &[u8] = b"revert_xtx_queue";
+pub const AUTHOR_REWARDS_JOB: &[u8] = b"author_rewards";
+pub const HOURLY_JOB: &[u8] = b"hourly";
+pub const DAILY_JOB: &[u8] = b"daily";
+pub const WEEKLY_JOB: &[u8] = b"weekly";
+pub const BI_WEEKLY_JOB: &[u8] = b"bi_weekly";
+pub const SESSION_JOB: &[u8] = b"session";
+
 pub trait Clock<T: frame_system::Config> {
     fn current_round() -> RoundInfo<BlockNumberFor<T>>;
     fn round_duration() -> BlockNumberFor<T>;
+    /// Weight limit of the named job's share of the on initialize hook, zero for unknown jobs.
+    fn job_weight_limit(name: &[u8]) -> Weight;
 }
 
 pub struct ClockMock<T> {
@@ -20,6 +39,10 @@ impl<T: frame_system::Config> Clock<T> for ClockMock<T> {
     fn round_duration() -> BlockNumberFor<T> {
         Zero::zero()
     }
+
+    fn job_weight_limit(_name: &[u8]) -> Weight {
+        Zero::zero()
+    }
 }
 
 pub trait OnHookQueues<T: frame_system::Config> {
@@ -60,3 +83,134 @@ impl<T: frame_system::Config> OnHookQueues<T> for EmptyOnHookQueues<T> {
         Zero::zero()
     }
 }
+
+/// Schedule of a named periodic job run by the clock on initialize.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ClockJob<BlockNumber> {
+    /// The job is due on every block divisible by the period.
+    pub period: BlockNumber,
+    /// Max. share of the total on_init weight the job may consume per run.
+    pub weight_share: Percent,
+    /// Jobs with lower priority run first.
+    pub priority: u8,
+}
+
+/// Bookkeeping of a job's runs, reported by the clock runtime API.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ClockJobStatus<BlockNumber> {
+    pub last_run: BlockNumber,
+    /// Weight consumed by the last run.
+    pub consumed_weight: Weight,
+    /// Number of blocks the job's due work has been carried over for, zero once caught up.
+    pub backlog: u32,
+}
+
+pub trait ClockJobs<T: frame_system::Config> {
+    // Run the named job within the weight limit. Returns the consumed weight and whether the job completed;
+    // incomplete jobs are carried over to the next block.
+    fn run(name: &[u8], n: BlockNumberFor<T>, job_weight_limit: Weight) -> (Weight, bool);
+    // Whether the named job can be run, only known jobs can be scheduled.
+    fn is_known(name: &[u8]) -> bool;
+}
+
+pub struct EmptyClockJobs<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: frame_system::Config> ClockJobs<T> for EmptyClockJobs<T> {
+    fn run(_name: &[u8], _n: BlockNumberFor<T>, _job_weight_limit: Weight) -> (Weight, bool) {
+        (Zero::zero(), true)
+    }
+
+    fn is_known(_name: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Work of the pallets run by the standard clock jobs. Pallets implement the queues they take
+/// part in and runtimes configure the clock with a tuple of them, run in order.
+pub trait StandardJobQueues<T: frame_system::Config> {
+    // Process the signal queue within the weight limit, every block.
+    fn process_signal_queue(_n: BlockNumberFor<T>, _job_weight_limit: Weight) -> Weight {
+        Zero::zero()
+    }
+    // Process the xtx tick queue within the weight limit, every block.
+    fn process_xtx_tick_queue(_n: BlockNumberFor<T>, _job_weight_limit: Weight) -> Weight {
+        Zero::zero()
+    }
+    // Process the emergency revert xtx queue within the weight limit, every block.
+    fn process_revert_xtx_queue(_n: BlockNumberFor<T>, _job_weight_limit: Weight) -> Weight {
+        Zero::zero()
+    }
+    // Record the block author for rewards, every block.
+    fn process_author_rewards(_n: BlockNumberFor<T>) -> Weight {
+        Zero::zero()
+    }
+    // Process the queues once per hour.
+    fn process_hourly(_n: BlockNumberFor<T>) -> Weight {
+        Zero::zero()
+    }
+    // Process the queues once per day.
+    fn process_daily(_n: BlockNumberFor<T>) -> Weight {
+        Zero::zero()
+    }
+    // Process the queues once per week.
+    fn process_weekly(_n: BlockNumberFor<T>) -> Weight {
+        Zero::zero()
+    }
+    // Process the queues once per 2 weeks.
+    fn process_bi_weekly(_n: BlockNumberFor<T>) -> Weight {
+        Zero::zero()
+    }
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<T: frame_system::Config> StandardJobQueues<T> for Tuple {
+    fn process_signal_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_signal_queue(n, job_weight_limit)); )* );
+        weight
+    }
+
+    fn process_xtx_tick_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_xtx_tick_queue(n, job_weight_limit)); )* );
+        weight
+    }
+
+    fn process_revert_xtx_queue(n: BlockNumberFor<T>, job_weight_limit: Weight) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_revert_xtx_queue(n, job_weight_limit)); )* );
+        weight
+    }
+
+    fn process_author_rewards(n: BlockNumberFor<T>) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_author_rewards(n)); )* );
+        weight
+    }
+
+    fn process_hourly(n: BlockNumberFor<T>) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_hourly(n)); )* );
+        weight
+    }
+
+    fn process_daily(n: BlockNumberFor<T>) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_daily(n)); )* );
+        weight
+    }
+
+    fn process_weekly(n: BlockNumberFor<T>) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_weekly(n)); )* );
+        weight
+    }
+
+    fn process_bi_weekly(n: BlockNumberFor<T>) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::process_bi_weekly(n)); )* );
+        weight
+    }
+}
@@ -11,11 +11,10 @@ use sp_std::{
 };
 
 pub const EXECUTOR_LOCK_ID: LockIdentifier = *b"execstkl";
-pub const STAKER_LOCK_ID: LockIdentifier = *b"stkrstkl";
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct NominatedStake<Account, Balance> {
-    executor: Account,
+    pub executor: Account,
     pub staker: Account,
     pub nominated_stake: Balance,
 }
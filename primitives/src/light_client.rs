@@ -1,10 +1,10 @@
-use crate::{ExecutionSource, GatewayVendor, SpeedMode};
-use codec::{Decode, Encode};
+use crate::{ChainId, ExecutionSource, GatewayVendor, SpeedMode};
+use codec::{Compact, Decode, Encode};
 use frame_support::sp_runtime::traits::Zero;
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
-use sp_std::marker::PhantomData;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
 use t3rn_abi::types::Bytes;
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
@@ -24,6 +24,8 @@ pub struct InclusionReceipt<BlockNumber> {
     pub height: BlockNumber,
     pub including_header: Bytes,
     pub message: Bytes,
+    /// Position of the proven event within the including block's events; None for state and tx proofs
+    pub event_index: Option<u32>,
 }
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
@@ -49,12 +51,88 @@ impl<T: frame_system::Config> Default for LightClientHeartbeat<T> {
     }
 }
 
+/// SCALE layout of a field of the bridged chain's runtime event, enough to check where the encoded event ends
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
+pub enum ScaleLayout {
+    /// Fixed size in bytes, e.g. 32 for AccountId32 or 16 for u128
+    Fixed(u32),
+    /// Compact encoded integer
+    Compact,
+    /// Compact encoded length followed by the elements
+    Vec(Box<ScaleLayout>),
+    Option(Box<ScaleLayout>),
+    Tuple(Vec<ScaleLayout>),
+    /// Variant index followed by the fields of that variant
+    Enum(Vec<(u8, Vec<ScaleLayout>)>),
+}
+
+impl ScaleLayout {
+    /// Advance the input past the value encoded with this layout, or return None if it doesn't decode.
+    pub fn skip(&self, input: &mut &[u8]) -> Option<()> {
+        match self {
+            ScaleLayout::Fixed(size) => {
+                let size = *size as usize;
+                if input.len() < size {
+                    return None
+                }
+                *input = &input[size..];
+                Some(())
+            },
+            ScaleLayout::Compact => <Compact<u128>>::decode(input).ok().map(|_| ()),
+            ScaleLayout::Vec(element) => {
+                let len = <Compact<u32>>::decode(input).ok()?.0 as usize;
+                let element_min_size = element.min_size();
+                // Zero-sized elements take no input, others can't outnumber the remaining bytes
+                if element_min_size == 0 {
+                    return Some(())
+                }
+                if len.saturating_mul(element_min_size) > input.len() {
+                    return None
+                }
+                (0..len).try_for_each(|_| element.skip(input))
+            },
+            ScaleLayout::Option(inner) => match u8::decode(input).ok()? {
+                0 => Some(()),
+                1 => inner.skip(input),
+                _ => None,
+            },
+            ScaleLayout::Tuple(fields) => Self::skip_all(fields, input),
+            ScaleLayout::Enum(variants) => {
+                let index = u8::decode(input).ok()?;
+                let (_, fields) = variants.iter().find(|(variant, _)| *variant == index)?;
+                Self::skip_all(fields, input)
+            },
+        }
+    }
+
+    pub fn skip_all(layouts: &[ScaleLayout], input: &mut &[u8]) -> Option<()> {
+        layouts.iter().try_for_each(|layout| layout.skip(input))
+    }
+
+    fn min_size(&self) -> usize {
+        match self {
+            ScaleLayout::Fixed(size) => *size as usize,
+            ScaleLayout::Tuple(fields) => fields.iter().map(|field| field.min_size()).sum(),
+            ScaleLayout::Compact
+            | ScaleLayout::Vec(_)
+            | ScaleLayout::Option(_)
+            | ScaleLayout::Enum(_) => 1,
+        }
+    }
+}
+
 pub trait LightClientAsyncAPI<T: frame_system::Config> {
     fn on_new_epoch(
         verifier: GatewayVendor,
         new_epoch: BlockNumberFor<T>,
         current_hearbeat: LightClientHeartbeat<T>,
     );
+
+    /// Layouts of the fields of the runtime event at (pallet index, variant index) emitted by the bridged chain,
+    ///     which light clients check the proven event decodes by. None for events of unknown layout.
+    fn get_event_layout(_gateway_id: ChainId, _event_index: [u8; 2]) -> Option<Vec<ScaleLayout>> {
+        None
+    }
 }
 
 pub struct LightClientAsyncAPIEmptyMock<T> {
@@ -11,6 +11,7 @@ use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
 use sp_std::{convert::TryFrom, vec::Vec};
 use t3rn_abi::{recode::Codec, types::Bytes, Abi, FilledAbi};
+use t3rn_sdk_primitives::portal::{PortalRead, PortalVerify};
 use t3rn_types::sfx::Sfx4bId;
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
@@ -375,6 +376,51 @@ impl PrecompileArgs {
     }
 }
 
+/// Portal read of ink! contracts, submitted through the contracts chain extension
+impl From<PortalRead> for PrecompileArgs {
+    fn from(read: PortalRead) -> Self {
+        match read {
+            PortalRead::GetLatestFinalizedHeader(chain_id) =>
+                PrecompileArgs::GetLatestFinalizedHeader(chain_id),
+            PortalRead::GetFinalizedHeight(chain_id) =>
+                PrecompileArgs::GetFinalizedHeight(chain_id),
+            PortalRead::GetRationalHeight(chain_id) => PrecompileArgs::GetRationalHeight(chain_id),
+            PortalRead::GetFastHeight(chain_id) => PrecompileArgs::GetFastHeight(chain_id),
+        }
+    }
+}
+
+/// Portal verification of ink! contracts, submitted through the contracts chain extension
+impl TryFrom<PortalVerify> for PrecompileArgs {
+    type Error = DispatchError;
+
+    fn try_from(verify: PortalVerify) -> Result<Self, Self::Error> {
+        let speed_mode = |index: u8| {
+            SpeedMode::decode(&mut &[index][..])
+                .map_err(|_e| DispatchError::Other("PortalVerify::invalid speed mode index"))
+        };
+
+        Ok(match verify {
+            PortalVerify::Event {
+                chain_id,
+                speed_mode: index,
+                source,
+                proof,
+            } => PrecompileArgs::VerifyEventInclusion(chain_id, speed_mode(index)?, source, proof),
+            PortalVerify::State {
+                chain_id,
+                speed_mode: index,
+                proof,
+            } => PrecompileArgs::VerifyStateInclusion(chain_id, speed_mode(index)?, proof),
+            PortalVerify::Tx {
+                chain_id,
+                speed_mode: index,
+                proof,
+            } => PrecompileArgs::VerifyTxInclusion(chain_id, speed_mode(index)?, proof),
+        })
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -667,4 +713,40 @@ pub mod tests {
             PrecompileArgs::VerifyTxInclusion(chain_id, SpeedMode::Rational, event)
         );
     }
+
+    #[test]
+    fn portal_verify_of_ink_contracts_converts_to_precompile_args() {
+        let verify = PortalVerify::Event {
+            chain_id: [9, 9, 9, 9],
+            speed_mode: 2,
+            source: [1u8; 32],
+            proof: vec![1, 2, 3, 4],
+        };
+
+        assert_eq!(
+            PrecompileArgs::try_from(verify),
+            Ok(PrecompileArgs::VerifyEventInclusion(
+                [9, 9, 9, 9],
+                SpeedMode::Finalized,
+                [1u8; 32],
+                vec![1, 2, 3, 4]
+            ))
+        );
+    }
+
+    #[test]
+    fn portal_verify_of_ink_contracts_with_unknown_speed_mode_fails_to_convert() {
+        let verify = PortalVerify::Tx {
+            chain_id: [9, 9, 9, 9],
+            speed_mode: 9,
+            proof: vec![1, 2, 3, 4],
+        };
+
+        assert_eq!(
+            PrecompileArgs::try_from(verify),
+            Err(DispatchError::Other(
+                "PortalVerify::invalid speed mode index"
+            ))
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     account_manager::Outcome,
-    circuit::LocalStateExecutionView,
+    circuit::{CircuitStatus, LocalStateExecutionView},
     contract_metadata::ContractType,
     contracts_registry::{AuthorInfo, RegistryContract},
     portal::{PortalExecution, PrecompileArgs as PortalPrecompileArgs},
@@ -13,6 +13,7 @@ use scale_info::TypeInfo;
 use sp_core::{H160, H256, U256};
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::{fmt::Debug, result::Result, vec::Vec};
+pub use t3rn_sdk_primitives::ABI_VERSION;
 use t3rn_sdk_primitives::{
     signal::{ExecutionSignal, Signaller},
     state::SideEffects,
@@ -27,12 +28,21 @@ pub const SUBMIT: u8 = 56;
 pub const POST_SIGNAL: u8 = 57;
 pub const PORTAL: u8 = 70;
 
+// Selector of the message invoked on a contract once its xtx resolves, equal to
+// the ink! selector of `on_xtx_resolved`. The selector is followed by the SCALE encoded `XtxResolution`.
+pub const XTX_CALLBACK_SELECTOR: [u8; 4] = [153, 187, 110, 250];
+
+// Selector of the function invoked on an EVM contract once its xtx resolves - keccak256 of
+// `onXtxResolved(bytes32,uint8,bytes[])`. The selector is followed by the ABI encoded xtx id, status and outputs.
+pub const EVM_XTX_CALLBACK_SELECTOR: [u8; 4] = [195, 119, 27, 7];
+
 #[derive(Encode, Decode)]
 pub struct GetState<T: ConfigSystem> {
     pub xtx_id: Option<T::Hash>,
 }
 
-// FIXME: none of these work at the moment due to large updates to SFX ABI.
+/// Arguments of 3VM precompiles, recoded from either ink! chain extension or EVM precompile calls.
+/// Versioned by `ABI_VERSION`, side effects follow the layout of standard SFX ABI once submitted.
 #[derive(Encode, Decode)]
 pub enum PrecompileArgs<T, Balance>
 where
@@ -44,11 +54,156 @@ where
         T::RuntimeOrigin,
         SideEffects<T::AccountId, Balance, T::Hash>,
         SpeedMode,
+        Option<XtxCallback<T::AccountId>>,
     ),
     Signal(T::RuntimeOrigin, ExecutionSignal<T::Hash>),
     Portal(PortalPrecompileArgs),
 }
 
+/// The VM of a contract submitting side effects through 3VM
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum ContractVm {
+    Wasm,
+    Evm,
+}
+
+/// Contract to be called back once the xtx it submitted resolves
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct XtxCallback<AccountId> {
+    pub contract: AccountId,
+    pub vm: ContractVm,
+}
+
+/// Outcome of an xtx passed on to the callback of its originating contract
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct XtxResolution<Hash> {
+    pub xtx_id: Hash,
+    /// One of Committed, Reverted or Killed
+    pub status: CircuitStatus,
+    /// Outputs of the confirmed side effects, in the order of steps
+    pub outputs: Vec<Vec<u8>>,
+}
+
+impl<Hash: Encode> XtxResolution<Hash> {
+    /// Input of the callback call to a contract of the given VM: `XTX_CALLBACK_SELECTOR` followed by the
+    /// SCALE encoded resolution for ink!, `EVM_XTX_CALLBACK_SELECTOR` followed by the ABI encoded arguments for EVM.
+    pub fn to_callback_input(&self, vm: ContractVm) -> Vec<u8> {
+        match vm {
+            ContractVm::Wasm => {
+                let mut input = XTX_CALLBACK_SELECTOR.to_vec();
+                self.encode_to(&mut input);
+                input
+            },
+            ContractVm::Evm => {
+                let mut input = EVM_XTX_CALLBACK_SELECTOR.to_vec();
+                input.extend(self.to_abi_args());
+                input
+            },
+        }
+    }
+
+    /// Status as passed to EVM callbacks: 0 for Committed, 1 for Reverted and 2 for Killed.
+    pub fn evm_status(&self) -> u8 {
+        match self.status {
+            CircuitStatus::Committed => 0,
+            CircuitStatus::Reverted(_) => 1,
+            // Only Killed is left of the statuses resolving an xtx
+            _ => 2,
+        }
+    }
+
+    /// ABI encoding of `(bytes32 xtxId, uint8 status, bytes[] outputs)`
+    fn to_abi_args(&self) -> Vec<u8> {
+        fn word(value: usize) -> [u8; 32] {
+            let mut word = [0u8; 32];
+            word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+            word
+        }
+
+        let mut xtx_id = [0u8; 32];
+        let encoded_xtx_id = self.xtx_id.encode();
+        let len = encoded_xtx_id.len().min(32);
+        xtx_id[..len].copy_from_slice(&encoded_xtx_id[..len]);
+
+        let mut args = xtx_id.to_vec();
+        args.extend(word(self.evm_status() as usize));
+        // Offset of the outputs array, following the three head words
+        args.extend(word(3 * 32));
+
+        args.extend(word(self.outputs.len()));
+        // Offsets of the outputs are relative to the start of the array's elements
+        let mut offset = self.outputs.len() * 32;
+        for output in &self.outputs {
+            args.extend(word(offset));
+            offset += 32 + (output.len() + 31) / 32 * 32;
+        }
+        for output in &self.outputs {
+            args.extend(word(output.len()));
+            args.extend(output);
+            args.resize(args.len() + (32 - output.len() % 32) % 32, 0);
+        }
+        args
+    }
+}
+
+/// Failed callback call, along with the weight it consumed before failing
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XtxCallbackFailure {
+    pub consumed: Weight,
+    pub error: DispatchError,
+}
+
+impl From<DispatchError> for XtxCallbackFailure {
+    fn from(error: DispatchError) -> Self {
+        XtxCallbackFailure {
+            consumed: Weight::zero(),
+            error,
+        }
+    }
+}
+
+/// Calls a contract of a single VM with the callback input
+pub trait InvokeXtxCallback<AccountId> {
+    /// Returns the weight consumed by the call, never exceeding the gas limit, whether it succeeds or fails
+    fn invoke_xtx_callback(
+        caller: &AccountId,
+        contract: &AccountId,
+        input: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure>;
+}
+
+impl<AccountId> InvokeXtxCallback<AccountId> for () {
+    fn invoke_xtx_callback(
+        _caller: &AccountId,
+        _contract: &AccountId,
+        _input: Vec<u8>,
+        _gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure> {
+        Ok(Weight::zero())
+    }
+}
+
+/// Dispatches the callback of a resolved xtx to its originating contract and charges the contract for it.
+/// Effects of a failing callback are discarded, yet the contract is charged for the weight it consumed.
+pub trait DispatchXtxCallback<AccountId, Hash> {
+    fn dispatch_xtx_callback(
+        callback: &XtxCallback<AccountId>,
+        resolution: &XtxResolution<Hash>,
+        gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure>;
+}
+
+impl<AccountId, Hash> DispatchXtxCallback<AccountId, Hash> for () {
+    fn dispatch_xtx_callback(
+        _callback: &XtxCallback<AccountId>,
+        _resolution: &XtxResolution<Hash>,
+        _gas_limit: Weight,
+    ) -> Result<Weight, XtxCallbackFailure> {
+        Ok(Weight::zero())
+    }
+}
+
 /// The happy return type of an invocation
 pub enum PrecompileInvocation<T: ConfigSystem, Balance> {
     GetState(LocalStateExecutionView<T, Balance>),
@@ -113,6 +268,9 @@ where
     /// Invoke a precompile, providing raw bytes and a pointer
     fn invoke_raw(precompile: &u8, args: &[u8], output: &mut Vec<u8>);
 
+    /// Weight of the dispatch behind a raw precompile invocation, charged upfront to the caller
+    fn invoke_raw_weight(precompile: &u8) -> Weight;
+
     /// Invoke a precompile
     fn invoke(
         args: PrecompileArgs<T, Balance>,
@@ -279,6 +437,10 @@ where
 
     fn invoke_raw(_precompile: &u8, _args: &[u8], _output: &mut Vec<u8>) {}
 
+    fn invoke_raw_weight(_precompile: &u8) -> Weight {
+        Weight::zero()
+    }
+
     fn invoke(
         _args: PrecompileArgs<T, Balance>,
     ) -> Result<PrecompileInvocation<T, Balance>, DispatchError> {
@@ -379,14 +541,37 @@ impl<T: ConfigSystem, Balance> ModuleOperations<T, Balance> for ThreeVmInfo<T, B
     }
 }
 
+/// Leading bytes of the EVM address of every asset: `0xFFFFFFFF` ++ zeroes ++ AssetId (big-endian)
+pub const ERC20_ASSET_ADDRESS_PREFIX: [u8; 4] = [0xff; 4];
+
 /// The index from which the endoded AssetId bytes will be encoded into an EVM address
-pub const H160_POSITION_ASSET_ID_TYPE: usize = 15;
+pub const H160_POSITION_ASSET_ID_TYPE: usize = 16;
 
+/// AssetId to EvmAddress mapping of the ERC-20 precompiles.
+///     Runtimes share the default `ERC20_ASSET_ADDRESS_PREFIX` ++ zeroes ++ AssetId (big-endian) layout.
 pub trait Erc20Mapping {
     /// Encode the AssetId to EvmAddress.
-    fn encode_evm_address(v: AssetId) -> Option<EvmAddress>;
+    fn encode_evm_address(v: AssetId) -> Option<EvmAddress> {
+        let mut address = [0u8; 20];
+        address[..ERC20_ASSET_ADDRESS_PREFIX.len()].copy_from_slice(&ERC20_ASSET_ADDRESS_PREFIX);
+        address[H160_POSITION_ASSET_ID_TYPE..].copy_from_slice(&v.to_be_bytes());
+        Some(EvmAddress::from(address))
+    }
+
     /// Decode the AssetId from EvmAddress.
-    fn decode_evm_address(v: EvmAddress) -> Option<AssetId>;
+    fn decode_evm_address(v: EvmAddress) -> Option<AssetId> {
+        let (prefix, asset_id_bytes) = v.as_bytes().split_at(H160_POSITION_ASSET_ID_TYPE);
+        if prefix[..ERC20_ASSET_ADDRESS_PREFIX.len()] != ERC20_ASSET_ADDRESS_PREFIX
+            || prefix[ERC20_ASSET_ADDRESS_PREFIX.len()..]
+                .iter()
+                .any(|b| *b != 0)
+        {
+            return None
+        }
+        let mut asset_id_be_bytes = [0u8; 4];
+        asset_id_be_bytes.copy_from_slice(asset_id_bytes);
+        Some(AssetId::from_be_bytes(asset_id_be_bytes))
+    }
 }
 
 /// A mapping between `AccountId` and `EvmAddress`.
@@ -407,3 +592,37 @@ pub trait AddressMapping<AccountId> {
     /// and false if is not.
     fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::Cause;
+    use hex_literal::hex;
+    use sp_core::H256;
+
+    #[test]
+    fn evm_callback_input_abi_encodes_xtx_resolution() {
+        let resolution = XtxResolution {
+            xtx_id: H256::repeat_byte(1),
+            status: CircuitStatus::Reverted(Cause::Timeout),
+            outputs: vec![vec![0xaa], vec![]],
+        };
+
+        assert_eq!(
+            resolution.to_callback_input(ContractVm::Evm),
+            [
+                hex!("c3771b07").to_vec(),
+                hex!("0101010101010101010101010101010101010101010101010101010101010101").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000060").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000002").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000040").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000080").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+                hex!("aa00000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            ]
+            .concat()
+        );
+    }
+}
@@ -74,6 +74,20 @@ pub struct EpochEstimate<BlockNumber> {
     pub moving_average_remote: BlockNumber,
 }
 
+/// Price quote of a single SFX, denominated in native currency.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SfxCostEstimate<Balance> {
+    /// Gas / weight fees of executing the SFX on its target
+    pub execution_cost: Balance,
+    /// Attesters' finality fee, charged to the requester on top of max_reward for Escrow SFX
+    pub finality_fee: Balance,
+    /// Suggested max_reward, covering execution costs and recently accepted bids
+    pub max_reward: Balance,
+    /// Suggested insurance
+    pub insurance: Balance,
+}
+
 /// A preliminary representation of a xdns_record in the onchain registry.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -333,10 +347,14 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_verification_vendor(chain_id: &ChainId) -> Result<GatewayVendor, DispatchError>;
 
+    fn get_execution_vendor(chain_id: &ChainId) -> Result<ExecutionVendor, DispatchError>;
+
     fn get_target_codec(chain_id: &ChainId) -> Result<t3rn_abi::Codec, DispatchError>;
 
     fn get_escrow_account(chain_id: &ChainId) -> Result<Vec<u8>, DispatchError>;
 
+    fn get_sibling_para_id(chain_id: &ChainId) -> Option<u32>;
+
     fn fetch_full_gateway_records() -> Vec<FullGatewayRecord<T::AccountId>>;
 
     fn read_last_activity_overview() -> Vec<GatewayActivity<BlockNumberFor<T>>>;
@@ -349,5 +367,9 @@ pub trait Xdns<T: frame_system::Config, Balance> {
         security_lvl: &SecurityLvl,
     ) -> Result<LightClientHeartbeat<T>, DispatchError>;
 
-    fn estimate_costs(fsx: &Vec<FullSideEffect<T::AccountId, BlockNumberFor<T>, Balance>>);
+    fn estimate_costs(
+        fsx: &Vec<FullSideEffect<T::AccountId, BlockNumberFor<T>, Balance>>,
+    ) -> Vec<SfxCostEstimate<Balance>>;
+
+    fn note_accepted_bids(fsx: &Vec<FullSideEffect<T::AccountId, BlockNumberFor<T>, Balance>>);
 }
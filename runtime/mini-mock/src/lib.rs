@@ -8,7 +8,8 @@ use frame_support::{
 };
 use frame_system::EnsureSigned;
 pub use pallet_attesters::{
-    ActiveSet, AttestationTargets, Attesters as AttestersStore, AttestersAgreements, BatchMessage,
+    light_client::AttestersLightClient, ActiveSet, AttestationTargets, AttestedInflux,
+    AttestedInfluxExpiries, Attesters as AttestersStore, AttestersAgreements, BatchMessage,
     BatchStatus, Batches, CommitteeTransitionOn, Config as ConfigAttesters, CurrentCommittee,
     Error as AttestersError, Event as AttestersEvent, InfluxMessage, LatencyStatus, NextBatch,
     NextCommitteeOnTarget, Nominations, PaidFinalityFees, PendingUnnominations, PermanentSlashes,
@@ -26,9 +27,7 @@ pub use pallet_circuit::{
 };
 pub use pallet_circuit_vacuum::{Config as ConfigVacuum, Event as VacuumEvent, OrderStatusRead};
 use pallet_eth2_finality_verifier::types::Root;
-mod hooks;
 mod treasuries_config;
-pub use hooks::GlobalOnInitQueues;
 use sp_runtime::DispatchResult;
 
 use hex_literal::hex;
@@ -36,6 +35,11 @@ pub use pallet_account_manager::{
     Config as ConfigAccountManager, Error as AccountManagerError, Event as AccountManagerEvent,
     SettlementsPerRound,
 };
+pub use pallet_executors::{
+    ActiveSet as ExecutorsActiveSet, CandidateBonds, CollateralBonds, Error as ExecutorsError,
+    Event as ExecutorsEvent, ScheduledStakingRequests, Snapshots as ExecutorSnapshots,
+    StakerTotals, Stakes,
+};
 
 use sp_runtime::ConsensusEngineId;
 
@@ -62,7 +66,10 @@ use sp_runtime::{
     traits::{BlakeTwo256, ConstU32, ConvertInto, IdentityLookup},
     Perbill, Percent,
 };
-use t3rn_primitives::{EthereumToken, ExecutionVendor, GatewayVendor, SubstrateToken, TokenInfo};
+use t3rn_primitives::{
+    common::Range, executors::Fixtures, EthereumToken, ExecutionVendor, GatewayVendor,
+    SubstrateToken, TokenInfo,
+};
 pub type AccountId = sp_runtime::AccountId32;
 pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MiniRuntime>;
 pub type Block = sp_runtime::generic::Block<
@@ -101,6 +108,7 @@ frame_support::construct_runtime!(
         Clock: pallet_clock = 104,
         Circuit: pallet_circuit = 105,
         Vacuum: pallet_circuit_vacuum = 106,
+        Executors: pallet_executors = 107,
         // Portal
         Portal: pallet_portal = 128,
         RococoBridge: pallet_grandpa_finality_verifier = 129,
@@ -220,17 +228,54 @@ impl pallet_account_manager::Config for MiniRuntime {
     type Clock = Clock;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
     type RuntimeEvent = RuntimeEvent;
     type Time = Timestamp;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub ExecutorsFixtures: Fixtures<Balance> = Fixtures {
+        active_set_size: Range {
+            min: 1,
+            ideal: 8,
+            max: 16,
+        },
+        max_commission: Percent::from_percent(50),
+        max_risk: Percent::from_percent(50),
+        min_executor_bond: 1000,
+        min_candidate_bond: 500,
+        min_atomic_stake: 100,
+        min_total_stake: 100,
+        max_top_stakes_per_candidate: 4,
+        max_bottom_stakes_per_candidate: 4,
+        max_stakes_per_staker: 2,
+        configure_executor_delay: 1,
+        leave_candidates_delay: 2,
+        leave_stakers_delay: 1,
+        candidate_bond_less_delay: 1,
+        revoke_stake_delay: 1,
+    };
+}
+
+impl pallet_executors::Config for MiniRuntime {
+    type Clock = Clock;
+    type Currency = Balances;
+    type DefaultFixtures = ExecutorsFixtures;
+    type MaxCandidates = ConstU32<4>;
+    type RuntimeEvent = RuntimeEvent;
+    type SnapshotsRetention = ConstU32<2>;
+    type WeightInfo = ();
+}
+
 impl pallet_clock::Config for MiniRuntime {
+    type DefaultJobs = pallet_clock::StandardClockJobs<Self>;
+    type JobQueues = (Circuit, XDNS, Rewards);
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
-    type OnInitializeQueues = GlobalOnInitQueues;
+    type OnInitializeJobs = t3rn_primitives::clock::EmptyClockJobs<Self>;
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 use t3rn_primitives::monetary::TRN;
 
@@ -301,6 +346,7 @@ parameter_types! {
 
 impl pallet_attesters::Config for MiniRuntime {
     type ActiveSetSize = ConstU32<32>;
+    type AttestedInfluxRetention = ConstU32<100>;
     type BatchingWindow = ConstU32<6>;
     // type CommitmentRewardSource = CommitmentRewardSource;
     type CommitteeSize = ConstU32<32>;
@@ -319,6 +365,7 @@ impl pallet_attesters::Config for MiniRuntime {
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = ConstU32<400>;
     type TreasuryAccounts = MiniRuntime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<MiniRuntime>;
     type Xdns = XDNS;
 }
 
@@ -395,9 +442,19 @@ impl pallet_portal::SelectLightClient<MiniRuntime> for SelectLightClientRegistry
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 MiniRuntime,
             >(PhantomData))),
+            GatewayVendor::Attesters => Ok(Box::new(
+                pallet_attesters::light_client::AttestersLightClient::<MiniRuntime>::new(None),
+            )),
             _ => Err(PortalError::<MiniRuntime>::UnimplementedGatewayVendor),
         }
     }
+
+    fn select_for_gateway(
+        vendor: GatewayVendor,
+        gateway_id: t3rn_primitives::ChainId,
+    ) -> Result<Box<dyn LightClient<MiniRuntime>>, PortalError<MiniRuntime>> {
+        pallet_attesters::light_client::select_for_gateway(vendor, gateway_id, Self::select)
+    }
 }
 const SLOT_DURATION: u64 = 12000;
 
@@ -466,15 +523,22 @@ parameter_types! {
     pub const CircuitAccountId: AccountId = AccountId::new([51u8; 32]); // 0x333...3
     pub const SelfGatewayId: [u8; 4] = [3, 3, 3, 3];
     pub const SelfGatewayIdOptimistic: [u8; 4] = [0, 3, 3, 3];
+    pub MaxXtxCallbackWeight: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(2_000_000_000, 64 * 1024);
+    pub MaxXtxCallbacksWeightPerBlock: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(10_500_000_000, 336 * 1024);
 }
 
 impl pallet_circuit::Config for MiniRuntime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type Clock = Clock;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxXtxCallbackWeight = MaxXtxCallbackWeight;
+    type MaxXtxCallbacksWeightPerBlock = MaxXtxCallbacksWeightPerBlock;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
@@ -484,7 +548,12 @@ impl pallet_circuit::Config for MiniRuntime {
     type SignalQueueDepth = ConstU32<5u32>;
     type TreasuryAccounts = MiniRuntime;
     type WeightInfo = ();
+    // Not connected to sibling parachains over XCM
+    type XcmResponseOrigin =
+        frame_support::traits::NeverEnsureOrigin<pallet_circuit::xcm_route::MultiLocation>;
+    type XcmRouter = ();
     type Xdns = XDNS;
+    type XtxCallbackDispatcher = ();
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
@@ -12,7 +12,7 @@ use circuit_runtime_pallets::{
 use frame_support::{
     dispatch::DispatchResultWithPostInfo,
     parameter_types,
-    traits::{fungibles::Destroy, ConstU32},
+    traits::{fungibles::Destroy, ConstU32, Everything},
     Blake2_128Concat, StorageHasher,
 };
 use pallet_grandpa_finality_verifier::{
@@ -32,13 +32,14 @@ impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
     type Time = Timestamp;
 }
 
-pub use hooks::GlobalOnInitQueues;
-
 impl pallet_clock::Config for Runtime {
+    type DefaultJobs = pallet_clock::StandardClockJobs<Self>;
+    type JobQueues = (Circuit, XDNS, Rewards);
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
-    type OnInitializeQueues = GlobalOnInitQueues;
+    type OnInitializeJobs = t3rn_primitives::clock::EmptyClockJobs<Self>;
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 
 impl pallet_xdns::Config for Runtime {
@@ -67,6 +68,7 @@ parameter_types! {
 
 impl pallet_attesters::Config for Runtime {
     type ActiveSetSize = ConstU32<32>;
+    type AttestedInfluxRetention = ConstU32<100>;
     type BatchingWindow = ConstU32<6>;
     type CommitteeSize = ConstU32<16>;
     type Currency = Balances;
@@ -84,6 +86,7 @@ impl pallet_attesters::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = ConstU32<400>;
     type TreasuryAccounts = Runtime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
 
@@ -167,9 +170,19 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 Runtime,
             >(PhantomData))),
+            GatewayVendor::Attesters => Ok(Box::new(
+                pallet_attesters::light_client::AttestersLightClient::<Runtime>::new(None),
+            )),
             _ => Err(PortalError::<Runtime>::LightClientNotFoundByVendor),
         }
     }
+
+    fn select_for_gateway(
+        vendor: GatewayVendor,
+        gateway_id: t3rn_primitives::ChainId,
+    ) -> Result<Box<dyn LightClient<Runtime>>, PortalError<Runtime>> {
+        pallet_attesters::light_client::select_for_gateway(vendor, gateway_id, Self::select)
+    }
 }
 
 impl pallet_portal::Config for Runtime {
@@ -268,13 +281,22 @@ impl PalletAssetsOverlay<Runtime, Balance> for Runtime {
     }
 }
 
+parameter_types! {
+    pub MaxXtxCallbackWeight: Weight = Weight::from_parts(2_000_000_000, 64 * 1024);
+    // Room for five callbacks of the maximum weight per block
+    pub MaxXtxCallbacksWeightPerBlock: Weight = Weight::from_parts(10_500_000_000, 336 * 1024);
+}
+
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type Clock = Clock;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxXtxCallbackWeight = MaxXtxCallbackWeight;
+    type MaxXtxCallbacksWeightPerBlock = MaxXtxCallbacksWeightPerBlock;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
@@ -284,7 +306,10 @@ impl pallet_circuit::Config for Runtime {
     type SignalQueueDepth = ConstU32<5u32>;
     type TreasuryAccounts = Runtime;
     type WeightInfo = ();
+    type XcmResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+    type XcmRouter = crate::xbi_config::CircuitXcmRouter;
     type Xdns = XDNS;
+    type XtxCallbackDispatcher = ThreeVm;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
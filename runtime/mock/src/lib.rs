@@ -23,15 +23,15 @@ mod accounts_config;
 mod circuit_config;
 mod consensus_aura_config;
 mod contracts_config;
-mod hooks;
 mod system_no_version_config;
 pub mod test_utils;
 mod treasuries_config;
 mod xbi_config;
+pub mod xcm_mock_net;
 pub type RococoLightClient = ();
 pub type PolkadotLightClient = pallet_grandpa_finality_verifier::Instance1;
 pub type KusamaLightClient = pallet_grandpa_finality_verifier::Instance2;
-pub use crate::circuit_config::GlobalOnInitQueues;
+pub use crate::xbi_config::SiblingExecutionFee;
 use frame_support::traits::GenesisBuild;
 pub use pallet_3vm_account_mapping::{ethereum_signable_message, to_ascii_hex, EcdsaSignature};
 pub use pallet_3vm_evm::Config as ConfigEvm;
@@ -83,6 +83,7 @@ frame_support::construct_runtime!(
         Circuit: pallet_circuit = 108,
         Clock: pallet_clock = 110,
         Vacuum: pallet_vacuum = 111,
+        Executors: pallet_executors = 112,
 
         // XCM helpers.
         XcmpQueue: cumulus_pallet_xcmp_queue = 30,
@@ -1,12 +1,13 @@
 use crate::{
-    AccountId, AllPalletsWithSystem, AssetRegistry, Assets, Balance, Balances, DmpQueue,
-    ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+    AccountId, AllPalletsWithSystem, AssetRegistry, Assets, Balance, Balances, BlockNumber,
+    DmpQueue, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
     WeightToFee, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
 };
 use circuit_runtime_pallets::{cumulus_primitives_core::GetChannelInfo, *};
 use cumulus_primitives_core::ParaId;
 
 use frame_support::{
+    dispatch::GetDispatchInfo,
     match_types, parameter_types,
     traits::{ConstU32, Everything, Nothing},
     weights::Weight,
@@ -19,10 +20,10 @@ use xcm::latest::prelude::*;
 
 use parachains_common::AssetIdForTrustBackedAssets;
 use xcm_builder::{
-    AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom,
-    ConvertedConcreteAssetId, CurrencyAdapter, EnsureXcmOrigin, FixedWeightBounds,
-    FungiblesAdapter, IsConcrete, LocalMint, NativeAsset, ParentAsSuperuser, ParentIsPreset,
-    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+    AccountId32Aliases, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom,
+    AllowUnpaidExecutionFrom, ConvertedConcreteAssetId, CurrencyAdapter, EnsureXcmOrigin,
+    FixedWeightBounds, FungiblesAdapter, IsConcrete, LocalMint, NativeAsset, ParentAsSuperuser,
+    ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
     SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
     UsingComponents,
 };
@@ -99,7 +100,7 @@ parameter_types! {
 
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
     pub Ancestry: MultiLocation = Parachain(3333).into();
-    pub UniversalLocation: InteriorMultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+    pub UniversalLocation: InteriorMultiLocation = Parachain(SelfParaId::get().into()).into();
     pub CheckingAccount: AccountId = PolkadotXcm::check_account();
     pub AssetsPalletLocation: MultiLocation =
         PalletInstance(12u8).into();
@@ -159,6 +160,7 @@ match_types! {
 // FIXME: should be using asset_registry
 pub type Barrier = (
     TakeWeightCredit,
+    AllowKnownQueryResponses<PolkadotXcm>,
     AllowTopLevelPaidExecutionFrom<Everything>,
     AllowUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
     // ^^^ Parent and its exec plurality get free execution
@@ -294,12 +296,8 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
 }
 
-pub type XcmRouter = (
-    // Two routers - use UMP to communicate with the relay chain:
-    cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
-    // ..and XCMP to communicate with the sibling chains.
-    XcmpQueue,
-);
+// Route via xcm-simulator's message buses, so that XCM sent by Circuit can be followed over the mock network
+pub type XcmRouter = crate::xcm_mock_net::ParachainXcmRouter<SelfParaId>;
 
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
@@ -361,3 +359,55 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type WeightInfo = cumulus_pallet_xcmp_queue::weights::SubstrateWeight<Runtime>;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+parameter_types! {
+    // Native asset of the sibling parachain held by Circuit's sovereign account, buying execution of SFX::Escrow transfers
+    pub const SiblingExecutionFee: Balance = 1_000_000;
+}
+
+/// Routes SFX::Escrow to sibling parachains via pallet_xcm, notifying Circuit about their outcome.
+pub struct CircuitXcmRouter;
+impl pallet_circuit::xcm_route::XcmSideEffectsRouter<pallet_circuit::Call<Runtime>, BlockNumber>
+    for CircuitXcmRouter
+{
+    fn asset_location(asset_id: u32, para_id: u32) -> Option<MultiLocation> {
+        pallet_asset_registry::AssetIdMultiLocation::<Runtime>::get(asset_id)?
+            .reanchored(
+                &MultiLocation::new(1, X1(Parachain(para_id))),
+                UniversalLocation::get(),
+            )
+            .ok()
+    }
+
+    fn execution_fee(_para_id: u32) -> Option<MultiAsset> {
+        Some((MultiLocation::here(), SiblingExecutionFee::get()).into())
+    }
+
+    fn report_outcome_notify(
+        message: &mut Xcm<()>,
+        responder: MultiLocation,
+        notify: pallet_circuit::Call<Runtime>,
+        timeout: BlockNumber,
+        querier: MultiLocation,
+    ) -> Result<QueryId, XcmError> {
+        let destination = UniversalLocation::get()
+            .invert_target(&responder)
+            .map_err(|()| XcmError::LocationNotInvertible)?;
+        let notify: RuntimeCall = notify.into();
+        let max_weight = notify.get_dispatch_info().weight;
+        let query_id = PolkadotXcm::new_notify_query(responder, notify, timeout, querier);
+        message.0.insert(
+            0,
+            SetAppendix(Xcm(vec![ReportError(QueryResponseInfo {
+                destination,
+                query_id,
+                max_weight,
+            })])),
+        );
+        Ok(query_id)
+    }
+
+    fn send(dest: MultiLocation, message: Xcm<()>) -> Result<XcmHash, SendError> {
+        send_xcm::<XcmRouter>(dest, message).map(|(hash, _)| hash)
+    }
+}
@@ -0,0 +1,100 @@
+//! xcm-simulator network of Circuit's mock parachain and a sibling parachain, connected over a relay chain.
+//!     Allows testing SFX routed over XCM end to end - from the dispatch, through the execution on the sibling,
+//!     until the QueryResponse confirming or reverting the SFX arrives back to Circuit.
+pub mod relay_chain;
+pub mod sibling;
+
+use crate::{AccountId, ExtBuilder, ALICE};
+use circuit_runtime_pallets::{
+    pallet_balances, polkadot_parachain::primitives::Sibling as SiblingParaId,
+};
+use frame_support::traits::GenesisBuild;
+use sp_runtime::{traits::AccountIdConversion, BuildStorage};
+pub use xcm_simulator::TestExt;
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub const CIRCUIT_PARA_ID: u32 = 3333;
+pub const SIBLING_PARA_ID: u32 = 2000;
+pub const INITIAL_BALANCE: u128 = 1_000_000_000_000;
+
+decl_test_parachain! {
+    pub struct CircuitPara {
+        Runtime = crate::Runtime,
+        XcmpMessageHandler = crate::XcmpQueue,
+        DmpMessageHandler = crate::DmpQueue,
+        new_ext = circuit_ext(),
+    }
+}
+
+decl_test_parachain! {
+    pub struct SiblingPara {
+        Runtime = sibling::Runtime,
+        XcmpMessageHandler = sibling::XcmpQueue,
+        DmpMessageHandler = sibling::DmpQueue,
+        new_ext = sibling_ext(),
+    }
+}
+
+decl_test_relay_chain! {
+    pub struct Relay {
+        Runtime = relay_chain::Runtime,
+        RuntimeCall = relay_chain::RuntimeCall,
+        RuntimeEvent = relay_chain::RuntimeEvent,
+        XcmConfig = relay_chain::XcmConfig,
+        MessageQueue = relay_chain::MessageQueue,
+        System = relay_chain::System,
+        new_ext = relay_ext(),
+    }
+}
+
+decl_test_network! {
+    pub struct MockNet {
+        relay_chain = Relay,
+        parachains = vec![
+            (3333, CircuitPara),
+            (2000, SiblingPara),
+        ],
+    }
+}
+
+/// Sovereign account of the parachain on its siblings
+pub fn sibling_account_id(para_id: u32) -> AccountId {
+    SiblingParaId::from(para_id).into_account_truncating()
+}
+
+pub fn circuit_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+}
+
+pub fn sibling_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<sibling::Runtime>::default()
+        .build_storage()
+        .expect("Frame system builds valid default genesis config");
+
+    pallet_balances::GenesisConfig::<sibling::Runtime> {
+        balances: vec![
+            (ALICE, INITIAL_BALANCE),
+            (sibling_account_id(CIRCUIT_PARA_ID), INITIAL_BALANCE),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .expect("Pallet balances storage can be assimilated");
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| sibling::System::set_block_number(1));
+    ext
+}
+
+pub fn relay_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<relay_chain::Runtime>::default()
+        .build_storage()
+        .expect("Frame system builds valid default genesis config");
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| relay_chain::System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,244 @@
+//! A minimal sibling parachain executing SFX sent by Circuit over XCM
+use circuit_runtime_pallets::{
+    cumulus_pallet_dmp_queue, cumulus_pallet_xcm, cumulus_pallet_xcmp_queue,
+    cumulus_primitives_core::{ChannelStatus, GetChannelInfo, ParaId},
+    pallet_balances, pallet_xcm,
+    polkadot_parachain::primitives::Sibling,
+    xcm, xcm_builder, xcm_executor,
+};
+use frame_support::{
+    construct_runtime, match_types, parameter_types,
+    traits::{ConstU128, ConstU32, Everything, Nothing},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32,
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+    AccountId32Aliases, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom, CurrencyAdapter,
+    DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds,
+    HashedDescription, IsConcrete, ParentIsPreset, SiblingParachainAsNative,
+    SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+    SovereignSignedViaLocation, TakeWeightCredit, WithComputedOrigin,
+};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+pub type Block = sp_runtime::generic::Block<
+    sp_runtime::generic::Header<BlockNumber, BlakeTwo256>,
+    UncheckedExtrinsic,
+>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        XcmpQueue: cumulus_pallet_xcmp_queue,
+        DmpQueue: cumulus_pallet_dmp_queue,
+        CumulusXcm: cumulus_pallet_xcm,
+        PolkadotXcm: pallet_xcm,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockHashCount = ConstU32<250>;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type Nonce = u64;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type MaxHolds = ConstU32<0>;
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const SelfLocation: MultiLocation = MultiLocation::here();
+    pub const RelayNetwork: Option<NetworkId> = None;
+    pub UniversalLocation: InteriorMultiLocation = Parachain(super::SIBLING_PARA_ID).into();
+    pub UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+    pub const MaxInstructions: u32 = 100;
+    pub const MaxAssetsIntoHolding: u32 = 64;
+    // Free execution keeps the transferred amounts intact, simplifying the balance checks
+    pub NativePerSecond: (AssetId, u128, u128) = (Concrete(MultiLocation::here()), 0, 0);
+}
+
+pub type LocationToAccountId = (
+    ParentIsPreset<AccountId>,
+    SiblingParachainConvertsVia<Sibling, AccountId>,
+    AccountId32Aliases<RelayNetwork, AccountId>,
+    // Accounts of the sibling's users, e.g. requesters Transact-ing from Circuit
+    HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+);
+
+pub type LocalAssetTransactor =
+    CurrencyAdapter<Balances, IsConcrete<SelfLocation>, LocationToAccountId, AccountId, ()>;
+
+pub type XcmOriginToTransactDispatchOrigin = (
+    SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+    SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
+    SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
+);
+
+match_types! {
+    pub type Siblings: impl Contains<MultiLocation> = {
+        MultiLocation { parents: 1, interior: X1(Parachain(_)) }
+    };
+}
+
+pub type Barrier = (
+    TakeWeightCredit,
+    AllowKnownQueryResponses<PolkadotXcm>,
+    AllowTopLevelPaidExecutionFrom<Siblings>,
+    // Programs descending into the origin of the sibling's users
+    WithComputedOrigin<
+        (AllowTopLevelPaidExecutionFrom<Everything>,),
+        UniversalLocation,
+        ConstU32<8>,
+    >,
+);
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type Aliasers = Nothing;
+    type AssetClaims = PolkadotXcm;
+    type AssetExchanger = ();
+    type AssetLocker = ();
+    type AssetTransactor = LocalAssetTransactor;
+    type AssetTrap = PolkadotXcm;
+    type Barrier = Barrier;
+    type CallDispatcher = RuntimeCall;
+    type FeeManager = ();
+    type IsReserve = ();
+    type IsTeleporter = ();
+    type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+    type MessageExporter = ();
+    type OriginConverter = XcmOriginToTransactDispatchOrigin;
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type ResponseHandler = PolkadotXcm;
+    type RuntimeCall = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type SubscriptionService = PolkadotXcm;
+    type Trader = FixedRateOfFungible<NativePerSecond, ()>;
+    type UniversalAliases = Nothing;
+    type UniversalLocation = UniversalLocation;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type XcmSender = XcmRouter;
+}
+
+pub type XcmRouter = super::ParachainXcmRouter<SelfParaId>;
+
+parameter_types! {
+    pub SelfParaId: ParaId = ParaId::from(super::SIBLING_PARA_ID);
+}
+
+impl GetChannelInfo for Runtime {
+    fn get_channel_max(_id: ParaId) -> Option<usize> {
+        None
+    }
+
+    fn get_channel_status(_id: ParaId) -> ChannelStatus {
+        ChannelStatus::Ready(200, 200)
+    }
+}
+
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+    type ChannelInfo = Runtime;
+    type ControllerOrigin = EnsureRoot<AccountId>;
+    type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
+    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type PriceForSiblingDelivery = ();
+    type RuntimeEvent = RuntimeEvent;
+    type VersionWrapper = ();
+    type WeightInfo = ();
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_dmp_queue::Config for Runtime {
+    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_xcm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+    pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+}
+
+impl pallet_xcm::Config for Runtime {
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+    type Currency = Balances;
+    type CurrencyMatcher = ();
+    type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type MaxLockers = ConstU32<8>;
+    type MaxRemoteLockConsumers = ConstU32<0>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type ReachableDest = ReachableDest;
+    type RemoteLockConsumerIdentifier = ();
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type SovereignAccountOf = LocationToAccountId;
+    type TrustedLockers = ();
+    type UniversalLocation = UniversalLocation;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type WeightInfo = pallet_xcm::TestWeightInfo;
+    type XcmExecuteFilter = Everything;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+    type XcmReserveTransferFilter = Everything;
+    type XcmRouter = XcmRouter;
+    type XcmTeleportFilter = Nothing;
+
+    const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+}
@@ -37,8 +37,6 @@ impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
     type Time = Timestamp;
 }
 
-use crate::hooks::GlobalOnInitQueues;
-
 parameter_types! {
     // TODO: update me to be better
     pub const EscrowAccount: AccountId = AccountId::new([51_u8; 32]);
@@ -51,6 +49,8 @@ parameter_types! {
 
 impl pallet_attesters::Config for Runtime {
     type ActiveSetSize = ConstU32<32>;
+    // Attested influx stays verifiable for a week of 12s blocks
+    type AttestedInfluxRetention = ConstU32<50_400>;
     type BatchingWindow = ConstU32<6>;
     type CommitteeSize = ConstU32<16>;
     type Currency = Balances;
@@ -68,6 +68,7 @@ impl pallet_attesters::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = HourlyShufflingFrequency;
     type TreasuryAccounts = Runtime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
 
@@ -112,10 +113,13 @@ impl pallet_rewards::Config for Runtime {
 }
 
 impl pallet_clock::Config for Runtime {
+    type DefaultJobs = pallet_clock::StandardClockJobs<Self>;
+    type JobQueues = (Circuit, XDNS, Rewards);
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
-    type OnInitializeQueues = GlobalOnInitQueues;
+    type OnInitializeJobs = t3rn_primitives::clock::EmptyClockJobs<Self>;
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_clock::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_vacuum::Config for Runtime {
@@ -247,9 +251,19 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 Runtime,
             >(PhantomData))),
+            GatewayVendor::Attesters => Ok(Box::new(
+                pallet_attesters::light_client::AttestersLightClient::<Runtime>::new(None),
+            )),
             _ => Err(PortalError::<Runtime>::LightClientNotFoundByVendor),
         }
     }
+
+    fn select_for_gateway(
+        vendor: GatewayVendor,
+        gateway_id: t3rn_primitives::ChainId,
+    ) -> Result<Box<dyn LightClient<Runtime>>, PortalError<Runtime>> {
+        pallet_attesters::light_client::select_for_gateway(vendor, gateway_id, Self::select)
+    }
 }
 
 impl pallet_portal::Config for Runtime {
@@ -276,13 +290,22 @@ parameter_types! {
     pub const SelfGatewayIdOptimistic: [u8; 4] = [0, 3, 3, 3];
 }
 
+parameter_types! {
+    pub MaxXtxCallbackWeight: Weight = Weight::from_parts(2_000_000_000, 64 * 1024);
+    // Room for five callbacks of the maximum weight per block
+    pub MaxXtxCallbacksWeightPerBlock: Weight = Weight::from_parts(10_500_000_000, 336 * 1024);
+}
+
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type Clock = Clock;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxXtxCallbackWeight = MaxXtxCallbackWeight;
+    type MaxXtxCallbacksWeightPerBlock = MaxXtxCallbacksWeightPerBlock;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
@@ -294,7 +317,12 @@ impl pallet_circuit::Config for Runtime {
     type WeightInfo = pallet_circuit::weights::SubstrateWeight<Runtime>;
     // type XBIPortal = XBIPortalRuntimeEntry;
     // type XBIPromise = XBIPortal;
+    // Not connected to sibling parachains over XCM
+    type XcmResponseOrigin =
+        frame_support::traits::NeverEnsureOrigin<pallet_circuit::xcm_route::MultiLocation>;
+    type XcmRouter = ();
     type Xdns = XDNS;
+    type XtxCallbackDispatcher = ThreeVm;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
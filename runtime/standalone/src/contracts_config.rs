@@ -17,6 +17,7 @@ use pallet_3vm_evm_primitives::FeeCalculator;
 pub use pallet_3vm_evm_primitives::GenesisAccount as EvmGenesisAccount;
 use sp_core::{H160, U256};
 use sp_runtime::{traits::Keccak256, ConsensusEngineId, RuntimeAppPublic};
+use t3rn_primitives::threevm::Erc20Mapping;
 
 // Unit = the base number of indivisible units for balances
 const UNIT: Balance = 1_000_000_000_000;
@@ -45,14 +46,17 @@ parameter_types! {
 impl pallet_3vm::Config for Runtime {
     type AccountManager = AccountManager;
     type AssetId = AssetId;
+    type CallbackWeightToFee = frame_support::weights::IdentityFee<Balance>;
     type CircuitTargetId = CircuitTargetId;
     type ContractsRegistry = ContractsRegistry;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
+    type EvmCallbacks = Evm;
     type OnLocalTrigger = Circuit;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SignalBounceThreshold = ConstU32<2>;
+    type WasmCallbacks = Contracts;
 }
 
 impl pallet_3vm_contracts::Config for Runtime {
@@ -114,17 +118,18 @@ parameter_types! {
     pub BlockGasLimit: U256 = U256::from(BLOCK_GAS_LIMIT);
     pub const GasLimitPovSizeRatio: u64 = BLOCK_GAS_LIMIT.saturating_div(MAX_POV_SIZE);
     pub const ChainId: u64 = 42;
-    // pub PrecompilesValue: evm_precompile_util::Precompiles<Runtime> = evm_precompile_util::Precompiles::<Runtime>::new(sp_std::vec![
-    //     (0_u64, evm_precompile_util::KnownPrecompile::ECRecover),
-    //     (1_u64, evm_precompile_util::KnownPrecompile::Sha256),
-    //     (2_u64, evm_precompile_util::KnownPrecompile::Ripemd160),
-    //     (3_u64, evm_precompile_util::KnownPrecompile::Identity),
-    //     (4_u64, evm_precompile_util::KnownPrecompile::Modexp),
-    //     (5_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS256),
-    //     (6_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS512),
-    //     (7_u64, evm_precompile_util::KnownPrecompile::ECRecoverPublicKey),
-    //     (40_u64, evm_precompile_util::KnownPrecompile::Portal)
-    // ].into_iter().collect());
+    pub PrecompilesValue: evm_precompile_util::PrecompilesWithAssets<Runtime> = evm_precompile_util::PrecompilesWithAssets::<Runtime>::new(evm_precompile_util::Precompiles::<Runtime>::new(sp_std::vec![
+        (0_u64, evm_precompile_util::KnownPrecompile::ECRecover),
+        (1_u64, evm_precompile_util::KnownPrecompile::Sha256),
+        (2_u64, evm_precompile_util::KnownPrecompile::Ripemd160),
+        (3_u64, evm_precompile_util::KnownPrecompile::Identity),
+        (4_u64, evm_precompile_util::KnownPrecompile::Modexp),
+        (5_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS256),
+        (6_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS512),
+        (7_u64, evm_precompile_util::KnownPrecompile::ECRecoverPublicKey),
+        (40_u64, evm_precompile_util::KnownPrecompile::Portal),
+        (41_u64, evm_precompile_util::KnownPrecompile::ThreeVm)
+    ].into_iter().collect()));
     // pub MockPrecompiles: MockPrecompiles = MockPrecompileSet;
     pub WeightPerGas: Weight = Weight::from_parts(20_000, 0);
 }
@@ -148,10 +153,8 @@ impl pallet_3vm_evm::Config for Runtime {
     type GasWeightMapping = pallet_3vm_evm::FixedGasWeightMapping<Runtime>;
     type OnChargeTransaction = ();
     type OnCreate = ();
-    type PrecompilesType = ();
-    // type PrecompilesValue = PrecompilesValue;
-    // fixme: add and compile pre-compiles compile_error!("the wasm*-unknown-unknown targets are not supported by \
-    type PrecompilesValue = ();
+    type PrecompilesType = evm_precompile_util::PrecompilesWithAssets<Self>;
+    type PrecompilesValue = PrecompilesValue;
     type Runner = pallet_3vm_evm::runner::stack::Runner<Self>;
     type RuntimeEvent = RuntimeEvent;
     type ThreeVm = ThreeVm;
@@ -160,3 +163,5 @@ impl pallet_3vm_evm::Config for Runtime {
     type WeightPerGas = WeightPerGas;
     type WithdrawOrigin = EnsureAddressTruncated;
 }
+
+impl Erc20Mapping for Runtime {}
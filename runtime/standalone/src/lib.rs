@@ -46,7 +46,6 @@ pub mod accounts_config;
 pub mod circuit_config;
 pub mod consensus_aura_config;
 pub mod contracts_config;
-pub mod hooks;
 pub mod impl_versioned_runtime_with_api;
 pub mod signed_extrinsics_config;
 pub mod system_config;
@@ -122,6 +121,7 @@ construct_runtime!(
         ContractsRegistry: pallet_contracts_registry = 106,
         Circuit: pallet_circuit = 108,
         Vacuum: pallet_vacuum = 111,
+        Executors: pallet_executors = 112,
 
         // 3VM
         ThreeVm: pallet_3vm = 119,
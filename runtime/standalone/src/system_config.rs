@@ -192,7 +192,6 @@ impl Contains<RuntimeCall> for BaseCallFilter {
                 method,
                 pallet_circuit::Call::on_local_trigger { .. }
                     | pallet_circuit::Call::on_xcm_trigger { .. }
-                    | pallet_circuit::Call::on_remote_gateway_trigger { .. }
                     | pallet_circuit::Call::cancel_xtx { .. }
                     | pallet_circuit::Call::revert { .. }
                     | pallet_circuit::Call::on_extrinsic_trigger { .. }
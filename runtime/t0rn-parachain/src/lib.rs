@@ -239,6 +239,7 @@ construct_runtime!(
         Circuit: pallet_circuit = 108,
         Clock: pallet_clock = 110,
         Vacuum: pallet_vacuum = 111,
+        Executors: pallet_executors = 112,
 
         // 3VM
         ThreeVm: pallet_3vm = 119,
@@ -282,6 +283,9 @@ mod benches {
         // [pallet_account_manager, AccountManager]
         [pallet_eth2_finality_verifier, EthereumBridge]
         [pallet_vacuum, Vacuum]
+        [pallet_circuit, Circuit]
+        [pallet_executors, Executors]
+        [pallet_clock, Clock]
     );
 }
 
@@ -482,7 +486,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId> for Runtime {
+    impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId, Balance> for Runtime {
         fn fetch_records() -> Vec<GatewayRecord<AccountId>> {
              <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::fetch_gateways()
         }
@@ -499,6 +503,10 @@ impl_runtime_apis! {
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId {
             Runtime::get_treasury_account(treasury_account)
         }
+
+        fn estimate_costs(side_effects: Vec<SideEffect<AccountId, Balance>>) -> Vec<pallet_xdns_rpc_runtime_api::SfxCostEstimate<Balance>> {
+            XDNS::estimate_side_effects_costs(side_effects)
+        }
     }
 
      impl pallet_portal_rpc_runtime_api::PortalRuntimeApi<Block, AccountId, Balance, Hash> for Runtime {
@@ -520,6 +528,122 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_circuit_rpc_runtime_api::CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+        fn fetch_order_status(xtx_id: Hash) -> Option<pallet_circuit_rpc_runtime_api::OrderStatusRead<Hash, BlockNumber, AccountId>> {
+            Vacuum::fetch_order_status(xtx_id).ok()
+        }
+
+        fn fetch_all_pending_orders_status() -> Vec<pallet_circuit_rpc_runtime_api::OrderStatusRead<Hash, BlockNumber, AccountId>> {
+            Vacuum::fetch_all_pending_orders_status()
+        }
+
+        fn fetch_full_side_effects(xtx_id: Hash) -> Option<Vec<Vec<pallet_circuit_rpc_runtime_api::FullSideEffect<AccountId, BlockNumber, Balance>>>> {
+            Circuit::get_full_side_effects(xtx_id)
+        }
+
+        fn fetch_dlq_entries(requester: AccountId) -> Vec<(Hash, pallet_circuit_rpc_runtime_api::DLQEntry<BlockNumber, AccountId>)> {
+            Circuit::get_dlq_entries_of_requester(&requester)
+        }
+
+        fn fetch_processed_remote_order(remote_target_id: pallet_circuit_rpc_runtime_api::TargetId, remote_sfx_id: pallet_circuit_rpc_runtime_api::H256, nonce: u32) -> Option<pallet_circuit_rpc_runtime_api::ProcessedRemoteOrder<Hash, BlockNumber, AccountId>> {
+            Vacuum::get_processed_remote_order((remote_target_id, remote_sfx_id, nonce))
+        }
+    }
+
+    impl pallet_attesters_rpc_runtime_api::AttestersRuntimeApi<Block, Balance> for Runtime {
+        fn fetch_pending_batches() -> Vec<(pallet_attesters_rpc_runtime_api::TargetId, Vec<(u32, H256)>)> {
+            Attesters::pending_batches_overview()
+        }
+
+        fn fetch_latest_batching_factor_overview() -> Vec<(pallet_attesters_rpc_runtime_api::TargetId, Option<pallet_attesters_rpc_runtime_api::BatchingFactor>)> {
+            Attesters::batching_factor_overview()
+        }
+
+        fn estimate_user_finality_fee(target: pallet_attesters_rpc_runtime_api::TargetId) -> Option<Balance> {
+            Attesters::user_finality_fee_estimate(&target)
+        }
+    }
+
+    impl pallet_clock_rpc_runtime_api::ClockRuntimeApi<Block, BlockNumber> for Runtime {
+        fn jobs() -> Vec<(Vec<u8>, pallet_clock_rpc_runtime_api::ClockJob<BlockNumber>, pallet_clock_rpc_runtime_api::ClockJobStatus<BlockNumber>)> {
+            Clock::jobs_overview()
+        }
+    }
+
+    impl pallet_evm_rpc_runtime_api::EvmRuntimeApi<Block> for Runtime {
+        fn chain_id() -> u64 {
+            <<Runtime as pallet_3vm_evm::Config>::ChainId as frame_support::traits::Get<u64>>::get()
+        }
+
+        fn gas_price() -> sp_core::U256 {
+            let (gas_price, _) = <<Runtime as pallet_3vm_evm::Config>::FeeCalculator as pallet_3vm_evm::FeeCalculator>::min_gas_price();
+            gas_price
+        }
+
+        fn account_basic(address: sp_core::H160) -> pallet_evm_rpc_runtime_api::Account {
+            Evm::account_basic(&address).0
+        }
+
+        fn account_code_at(address: sp_core::H160) -> Vec<u8> {
+            pallet_3vm_evm::AccountCodes::<Runtime>::get(address)
+        }
+
+        fn storage_at(address: sp_core::H160, index: sp_core::U256) -> sp_core::H256 {
+            Evm::storage_at(address, index)
+        }
+
+        fn call(
+            from: sp_core::H160,
+            to: sp_core::H160,
+            data: Vec<u8>,
+            value: sp_core::U256,
+            gas_limit: sp_core::U256,
+            max_fee_per_gas: Option<sp_core::U256>,
+            nonce: Option<sp_core::U256>,
+            estimate: bool,
+        ) -> Result<pallet_evm_rpc_runtime_api::CallInfo, sp_runtime::DispatchError> {
+            Evm::dry_run_call(from, to, data, value, gas_limit, max_fee_per_gas, nonce, estimate)
+        }
+
+        fn create(
+            from: sp_core::H160,
+            data: Vec<u8>,
+            value: sp_core::U256,
+            gas_limit: sp_core::U256,
+            max_fee_per_gas: Option<sp_core::U256>,
+            nonce: Option<sp_core::U256>,
+            estimate: bool,
+        ) -> Result<pallet_evm_rpc_runtime_api::CreateInfo, sp_runtime::DispatchError> {
+            Evm::dry_run_create(from, data, value, gas_limit, max_fee_per_gas, nonce, estimate)
+        }
+
+        fn block_logs() -> Vec<(u32, pallet_evm_rpc_runtime_api::Log)> {
+            Evm::block_logs()
+        }
+
+        fn block_transactions() -> Vec<(pallet_evm_rpc_runtime_api::Transaction, pallet_evm_rpc_runtime_api::TransactionStatus)> {
+            pallet_3vm_evm::BlockTransactions::<Runtime>::get()
+        }
+
+        fn block_author() -> sp_core::H160 {
+            Evm::find_author()
+        }
+
+        fn block_timestamp() -> u64 {
+            Timestamp::now()
+        }
+
+        fn block_gas_limit() -> sp_core::U256 {
+            <<Runtime as pallet_3vm_evm::Config>::BlockGasLimit as frame_support::traits::Get<sp_core::U256>>::get()
+        }
+
+        fn convert_transaction(
+            transaction: pallet_evm_rpc_runtime_api::Transaction,
+        ) -> <Block as sp_runtime::traits::Block>::Extrinsic {
+            UncheckedExtrinsic::new_unsigned(pallet_3vm_evm::Call::<Runtime>::transact { transaction }.into())
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             let collation_info = ParachainSystem::collect_collation_info(header);
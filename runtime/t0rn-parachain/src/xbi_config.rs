@@ -3,11 +3,12 @@ use crate::{
     ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
     WeightToFee, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
 };
-use circuit_runtime_types::default_fee_per_second;
+use circuit_runtime_types::{default_fee_per_second, MILLIUNIT};
 use cumulus_primitives_core::ParaId;
 
 use cumulus_primitives_core::GetChannelInfo;
 use frame_support::{
+    dispatch::GetDispatchInfo,
     match_types, parameter_types,
     traits::{ConstU32, Contains, ContainsPair, Currency, Everything, Get, Nothing, OnUnbalanced},
     weights::Weight,
@@ -476,3 +477,58 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type WeightInfo = cumulus_pallet_xcmp_queue::weights::SubstrateWeight<Runtime>;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+parameter_types! {
+    // Native asset of the sibling parachain held by Circuit's sovereign account, buying execution of SFX::Escrow transfers
+    pub const SiblingExecutionFee: Balance = MILLIUNIT;
+}
+
+/// Routes SFX::Escrow to sibling parachains via pallet_xcm, notifying Circuit about their outcome.
+pub struct CircuitXcmRouter;
+impl
+    pallet_circuit::xcm_route::XcmSideEffectsRouter<
+        pallet_circuit::Call<Runtime>,
+        crate::BlockNumber,
+    > for CircuitXcmRouter
+{
+    fn asset_location(asset_id: u32, para_id: u32) -> Option<MultiLocation> {
+        pallet_asset_registry::AssetIdMultiLocation::<Runtime>::get(asset_id)?
+            .reanchored(
+                &MultiLocation::new(1, X1(Parachain(para_id))),
+                UniversalLocation::get(),
+            )
+            .ok()
+    }
+
+    fn execution_fee(_para_id: u32) -> Option<MultiAsset> {
+        Some((MultiLocation::here(), SiblingExecutionFee::get()).into())
+    }
+
+    fn report_outcome_notify(
+        message: &mut Xcm<()>,
+        responder: MultiLocation,
+        notify: pallet_circuit::Call<Runtime>,
+        timeout: crate::BlockNumber,
+        querier: MultiLocation,
+    ) -> Result<QueryId, XcmError> {
+        let destination = UniversalLocation::get()
+            .invert_target(&responder)
+            .map_err(|()| XcmError::LocationNotInvertible)?;
+        let notify: RuntimeCall = notify.into();
+        let max_weight = notify.get_dispatch_info().weight;
+        let query_id = PolkadotXcm::new_notify_query(responder, notify, timeout, querier);
+        message.0.insert(
+            0,
+            SetAppendix(Xcm(vec![ReportError(QueryResponseInfo {
+                destination,
+                query_id,
+                max_weight,
+            })])),
+        );
+        Ok(query_id)
+    }
+
+    fn send(dest: MultiLocation, message: Xcm<()>) -> Result<XcmHash, SendError> {
+        send_xcm::<XcmRouter>(dest, message).map(|(hash, _)| hash)
+    }
+}
@@ -1,21 +1,28 @@
 use crate::{
-    hooks::GlobalOnInitQueues, treasuries_config::EscrowTreasuryId, AssetId, Assets, Balance,
-    Balances, Clock, Imbalance, OnUnbalanced, Runtime, RuntimeCall, RuntimeEvent, ThreeVm,
-    Timestamp,
+    treasuries_config::EscrowTreasuryId, AssetId, Assets, Balance, Balances, Circuit, Clock,
+    Executors, Imbalance, OnUnbalanced, Rewards, Runtime, RuntimeCall, RuntimeEvent, ThreeVm,
+    Timestamp, UNIT, XDNS,
 };
 use frame_support::parameter_types;
 use sp_core::{crypto::AccountId32, ConstU32};
-use sp_runtime::traits::{AccountIdConversion, ConvertInto};
+use sp_runtime::{
+    traits::{AccountIdConversion, ConvertInto},
+    Percent,
+};
+use t3rn_primitives::{common::Range, executors::Fixtures};
 
 parameter_types! {
     pub EscrowAccount: AccountId32 = EscrowTreasuryId::get().into_account_truncating();
 }
 
 impl pallet_clock::Config for Runtime {
+    type DefaultJobs = crate::hooks::ParachainClockJobs;
+    type JobQueues = (Circuit, XDNS, Rewards);
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
-    type OnInitializeQueues = GlobalOnInitQueues;
+    type OnInitializeJobs = crate::hooks::ParachainClockJobs;
     type RoundDuration = ConstU32<300u32>;
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_clock::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_account_manager::Config for Runtime {
@@ -25,7 +32,7 @@ impl pallet_account_manager::Config for Runtime {
     type Clock = Clock;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
     type RuntimeEvent = RuntimeEvent;
     type Time = Timestamp;
     type WeightInfo = ();
@@ -33,6 +40,40 @@ impl pallet_account_manager::Config for Runtime {
 
 pallet_account_manager::setup_currency_adapter!();
 
+parameter_types! {
+    pub ExecutorsFixtures: Fixtures<Balance> = Fixtures {
+        active_set_size: Range {
+            min: 1,
+            ideal: 32,
+            max: 64,
+        },
+        max_commission: Percent::from_percent(50),
+        max_risk: Percent::from_percent(50),
+        min_executor_bond: 1_000 * UNIT,
+        min_candidate_bond: 500 * UNIT,
+        min_atomic_stake: 10 * UNIT,
+        min_total_stake: 10 * UNIT,
+        max_top_stakes_per_candidate: 300,
+        max_bottom_stakes_per_candidate: 50,
+        max_stakes_per_staker: 100,
+        configure_executor_delay: 1,
+        leave_candidates_delay: 2,
+        leave_stakers_delay: 2,
+        candidate_bond_less_delay: 2,
+        revoke_stake_delay: 2,
+    };
+}
+
+impl pallet_executors::Config for Runtime {
+    type Clock = Clock;
+    type Currency = Balances;
+    type DefaultFixtures = ExecutorsFixtures;
+    type MaxCandidates = ConstU32<256>;
+    type RuntimeEvent = RuntimeEvent;
+    type SnapshotsRetention = ConstU32<168>;
+    type WeightInfo = pallet_executors::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const AssetDeposit: Balance = 0; // 1 UNIT deposit to create asset
     pub const ApprovalDeposit: Balance = 0;
@@ -48,6 +48,8 @@ parameter_types! {
 
 impl pallet_attesters::Config for Runtime {
     type ActiveSetSize = ConstU32<32>;
+    // Attested influx stays verifiable for a week of 12s blocks
+    type AttestedInfluxRetention = ConstU32<50_400>;
     type BatchingWindow = ConstU32<6>;
     type CommitteeSize = ConstU32<16>;
     type Currency = Balances;
@@ -65,6 +67,7 @@ impl pallet_attesters::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = HourlyShufflingFrequency;
     type TreasuryAccounts = Runtime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
 
@@ -237,9 +240,19 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 Runtime,
             >(PhantomData))),
+            GatewayVendor::Attesters => Ok(Box::new(
+                pallet_attesters::light_client::AttestersLightClient::<Runtime>::new(None),
+            )),
             _ => Err(PortalError::<Runtime>::LightClientNotFoundByVendor),
         }
     }
+
+    fn select_for_gateway(
+        vendor: GatewayVendor,
+        gateway_id: t3rn_primitives::ChainId,
+    ) -> Result<Box<dyn LightClient<Runtime>>, PortalError<Runtime>> {
+        pallet_attesters::light_client::select_for_gateway(vendor, gateway_id, Self::select)
+    }
 }
 
 impl pallet_portal::Config for Runtime {
@@ -265,13 +278,22 @@ parameter_types! {
     pub const SelfGatewayIdOptimistic: [u8; 4] = [0, 3, 3, 3];
 }
 
+parameter_types! {
+    pub MaxXtxCallbackWeight: Weight = Weight::from_parts(2_000_000_000, 64 * 1024);
+    // Room for five callbacks of the maximum weight per block
+    pub MaxXtxCallbacksWeightPerBlock: Weight = Weight::from_parts(10_500_000_000, 336 * 1024);
+}
+
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type Clock = Clock;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxXtxCallbackWeight = MaxXtxCallbackWeight;
+    type MaxXtxCallbacksWeightPerBlock = MaxXtxCallbacksWeightPerBlock;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
@@ -281,7 +303,10 @@ impl pallet_circuit::Config for Runtime {
     type SignalQueueDepth = ConstU32<5u32>;
     type TreasuryAccounts = Runtime;
     type WeightInfo = pallet_circuit::weights::SubstrateWeight<Runtime>;
+    type XcmResponseOrigin = pallet_xcm::EnsureResponse<frame_support::traits::Everything>;
+    type XcmRouter = crate::xbi_config::CircuitXcmRouter;
     type Xdns = XDNS;
+    type XtxCallbackDispatcher = ThreeVm;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
@@ -10,8 +10,7 @@ use frame_support::{
     PalletId,
 };
 
-// use evm_precompile_util::KnownPrecompile;
-use circuit_runtime_types::{AssetId, EvmAddress};
+use circuit_runtime_types::AssetId;
 pub use pallet_3vm_account_mapping::EvmAddressMapping;
 use pallet_3vm_contracts::NoopMigration;
 use pallet_3vm_evm::{EnsureAddressTruncated, HashedAddressMapping, SubstrateBlockHashMapping};
@@ -23,7 +22,7 @@ use sp_runtime::{
     traits::{AccountIdConversion, Keccak256},
     ConsensusEngineId, RuntimeAppPublic,
 };
-use t3rn_primitives::threevm::{Erc20Mapping, H160_POSITION_ASSET_ID_TYPE};
+use t3rn_primitives::threevm::Erc20Mapping;
 
 // Unit = the base number of indivisible units for balances
 const UNIT: Balance = 1_000_000_000_000;
@@ -52,14 +51,17 @@ parameter_types! {
 impl pallet_3vm::Config for Runtime {
     type AccountManager = AccountManager;
     type AssetId = AssetId;
+    type CallbackWeightToFee = frame_support::weights::IdentityFee<Balance>;
     type CircuitTargetId = CircuitTargetId;
     type ContractsRegistry = ContractsRegistry;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
+    type EvmCallbacks = Evm;
     type OnLocalTrigger = Circuit;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SignalBounceThreshold = ConstU32<2>;
+    type WasmCallbacks = Contracts;
 }
 
 impl pallet_3vm_contracts::Config for Runtime {
@@ -121,17 +123,18 @@ parameter_types! {
     pub BlockGasLimit: U256 = U256::from(BLOCK_GAS_LIMIT);
     pub const GasLimitPovSizeRatio: u64 = BLOCK_GAS_LIMIT.saturating_div(MAX_POV_SIZE);
     pub const ChainId: u64 = 42;
-    // pub PrecompilesValue: evm_precompile_util::Precompiles<Runtime> = evm_precompile_util::Precompiles::<Runtime>::new(sp_std::vec![
-    //     (0_u64, evm_precompile_util::KnownPrecompile::ECRecover),
-    //     (1_u64, evm_precompile_util::KnownPrecompile::Sha256),
-    //     (2_u64, evm_precompile_util::KnownPrecompile::Ripemd160),
-    //     (3_u64, evm_precompile_util::KnownPrecompile::Identity),
-    //     (4_u64, evm_precompile_util::KnownPrecompile::Modexp),
-    //     (5_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS256),
-    //     (6_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS512),
-    //     (7_u64, evm_precompile_util::KnownPrecompile::ECRecoverPublicKey),
-    //     (40_u64, evm_precompile_util::KnownPrecompile::Portal)
-    // ].into_iter().collect());
+    pub PrecompilesValue: evm_precompile_util::PrecompilesWithAssets<Runtime> = evm_precompile_util::PrecompilesWithAssets::<Runtime>::new(evm_precompile_util::Precompiles::<Runtime>::new(sp_std::vec![
+        (0_u64, evm_precompile_util::KnownPrecompile::ECRecover),
+        (1_u64, evm_precompile_util::KnownPrecompile::Sha256),
+        (2_u64, evm_precompile_util::KnownPrecompile::Ripemd160),
+        (3_u64, evm_precompile_util::KnownPrecompile::Identity),
+        (4_u64, evm_precompile_util::KnownPrecompile::Modexp),
+        (5_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS256),
+        (6_u64, evm_precompile_util::KnownPrecompile::Sha3FIPS512),
+        (7_u64, evm_precompile_util::KnownPrecompile::ECRecoverPublicKey),
+        (40_u64, evm_precompile_util::KnownPrecompile::Portal),
+        (41_u64, evm_precompile_util::KnownPrecompile::ThreeVm)
+    ].into_iter().collect()));
     // pub MockPrecompiles: MockPrecompiles = MockPrecompileSet;
     pub WeightPerGas: Weight = Weight::from_parts(20_000, 0);
 }
@@ -155,10 +158,8 @@ impl pallet_3vm_evm::Config for Runtime {
     type GasWeightMapping = pallet_3vm_evm::FixedGasWeightMapping<Runtime>;
     type OnChargeTransaction = ();
     type OnCreate = ();
-    type PrecompilesType = ();
-    // type PrecompilesValue = PrecompilesValue;
-    // fixme: add and compile pre-compiles compile_error!("the wasm*-unknown-unknown targets are not supported by \
-    type PrecompilesValue = ();
+    type PrecompilesType = evm_precompile_util::PrecompilesWithAssets<Self>;
+    type PrecompilesValue = PrecompilesValue;
     type Runner = pallet_3vm_evm::runner::stack::Runner<Self>;
     type RuntimeEvent = RuntimeEvent;
     type ThreeVm = ThreeVm;
@@ -183,27 +184,4 @@ impl pallet_3vm_account_mapping::Config for Runtime {
     type StorageDepositFee = StorageDepositFee;
 }
 
-// AssetId to EvmAddress mapping
-impl Erc20Mapping for Runtime {
-    fn encode_evm_address(v: AssetId) -> Option<EvmAddress> {
-        let mut address = [0u8; 20];
-        let mut asset_id_bytes: Vec<u8> = v.to_be_bytes().to_vec();
-
-        for byte_index in 0..asset_id_bytes.len() {
-            address[byte_index + H160_POSITION_ASSET_ID_TYPE] =
-                asset_id_bytes.as_slice()[byte_index];
-        }
-
-        Some(EvmAddress::from_slice(&asset_id_bytes.as_slice()))
-    }
-
-    fn decode_evm_address(v: EvmAddress) -> Option<AssetId> {
-        let address = v.as_bytes();
-        let mut asset_id_bytes = [0u8; 4];
-        for byte_index in H160_POSITION_ASSET_ID_TYPE..20 {
-            asset_id_bytes[byte_index - H160_POSITION_ASSET_ID_TYPE] = address[byte_index];
-        }
-        let asset_id = u32::from_be_bytes(asset_id_bytes);
-        Some(asset_id)
-    }
-}
+impl Erc20Mapping for Runtime {}
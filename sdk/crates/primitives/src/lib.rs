@@ -5,6 +5,7 @@ use codec::Error as CodecError;
 pub use scale_info::prelude::{boxed::Box, collections::BTreeMap, fmt::Debug, vec::Vec};
 
 pub mod error;
+pub mod portal;
 pub mod signal;
 pub mod state;
 pub mod storage;
@@ -16,6 +17,16 @@ pub const GET_STATE_FUNCTION_CODE: u32 = 2_8008_8008;
 pub const SUBMIT_FUNCTION_CODE: u32 = 3_8008_8008;
 /// A function pointer for posting execution signals
 pub const POST_SIGNAL_FUNCTION_CODE: u32 = 4_8008_8008;
+/// A function pointer for reading the version of 3VM interface
+pub const ABI_VERSION_FUNCTION_CODE: u32 = 5_8008_8008;
+/// A function pointer for reading heights and headers of light clients through the portal
+pub const PORTAL_READ_FUNCTION_CODE: u32 = 6_8008_8008;
+/// A function pointer for verifying inclusion proofs against light clients through the portal
+pub const PORTAL_VERIFY_FUNCTION_CODE: u32 = 7_8008_8008;
+
+/// The version of 3VM interface, bumped whenever the layout of submitted side effects,
+/// state or signals changes. Contracts can compare it with the version they were built against.
+pub const ABI_VERSION: u32 = 1;
 
 /// The maximum amount of parameters we allow users to pass to a function
 pub const MAX_PARAMETERS_IN_FUNCTION: usize = 16;
@@ -0,0 +1,75 @@
+use crate::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Identifier of a gateway registered in the portal, e.g. `*b"eth2"`
+pub type ChainId = [u8; 4];
+
+/// Index of the speed mode to verify against: 0 - Fast, 1 - Rational, 2 - Finalized, 3 - Instant
+pub type SpeedModeIndex = u8;
+
+/// A read of light client heights and headers, passed to the portal read chain extension
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum PortalRead {
+    GetLatestFinalizedHeader(ChainId),
+    GetFinalizedHeight(ChainId),
+    GetRationalHeight(ChainId),
+    GetFastHeight(ChainId),
+}
+
+/// An inclusion proof verified by the light client of a gateway, passed to the portal verify chain extension
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum PortalVerify {
+    /// Event emitted by the source within the proven block; zeroed source skips the check
+    Event {
+        chain_id: ChainId,
+        speed_mode: SpeedModeIndex,
+        source: [u8; 32],
+        proof: Vec<u8>,
+    },
+    State {
+        chain_id: ChainId,
+        speed_mode: SpeedModeIndex,
+        proof: Vec<u8>,
+    },
+    Tx {
+        chain_id: ChainId,
+        speed_mode: SpeedModeIndex,
+        proof: Vec<u8>,
+    },
+}
+
+impl PortalVerify {
+    pub fn proof(&self) -> &[u8] {
+        match self {
+            PortalVerify::Event { proof, .. }
+            | PortalVerify::State { proof, .. }
+            | PortalVerify::Tx { proof, .. } => proof,
+        }
+    }
+}
+
+// HELLO: These have to have field parity with t3rn_primitives::light_client
+/// Height of a light client returned by the portal read chain extension
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum HeightResult<BlockNumber> {
+    Height(BlockNumber),
+    NotActive,
+}
+
+/// Header of a light client returned by the portal read chain extension
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum HeaderResult {
+    Header(Vec<u8>),
+    NotActive,
+}
+
+/// Receipt of a verified inclusion returned by the portal verify chain extension
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct InclusionReceipt<BlockNumber> {
+    pub height: BlockNumber,
+    pub including_header: Vec<u8>,
+    pub message: Vec<u8>,
+    /// Position of the proven event within the including block's events; None for state and tx proofs
+    pub event_index: Option<u32>,
+}
@@ -5,9 +5,12 @@ use crate::{
 use codec::{Decode, Encode};
 use ink_env::{self, chain_extension::ChainExtensionMethod};
 use t3rn_sdk_primitives::{
+    portal::{HeaderResult, HeightResult, InclusionReceipt, PortalRead, PortalVerify},
     signal::{ExecutionSignal, Signaller},
     state::{ExecutionState, SideEffects},
-    Debug, GET_STATE_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE, SUBMIT_FUNCTION_CODE,
+    Debug, ABI_VERSION, ABI_VERSION_FUNCTION_CODE, GET_STATE_FUNCTION_CODE,
+    PORTAL_READ_FUNCTION_CODE, PORTAL_VERIFY_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE,
+    SUBMIT_FUNCTION_CODE,
 };
 
 /// This provider utilizes chain extensions in the case of ink to call through to 3vm.
@@ -69,6 +72,64 @@ impl From<codec::Error> for StateHandlerError {
     }
 }
 
+impl InkProvider {
+    /// Reads the version of 3VM interface exposed by the chain
+    pub fn abi_version() -> Result<u32, ExecutorError> {
+        ChainExtensionMethod::build(ABI_VERSION_FUNCTION_CODE)
+            .input::<()>()
+            .output_result::<u32, StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&())
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+
+    /// Checks whether the chain exposes the version of 3VM interface this SDK was built against
+    pub fn is_abi_compatible() -> Result<bool, ExecutorError> {
+        Self::abi_version().map(|version| version == ABI_VERSION)
+    }
+
+    /// Reads the latest finalized header of a light client
+    pub fn latest_finalized_header(chain_id: [u8; 4]) -> Result<HeaderResult, ExecutorError> {
+        Self::portal_read(PortalRead::GetLatestFinalizedHeader(chain_id))
+    }
+
+    /// Reads a height of a light client, depending on the variant of the read
+    pub fn height<BlockNumber: Decode>(
+        read: PortalRead,
+    ) -> Result<HeightResult<BlockNumber>, ExecutorError> {
+        Self::portal_read(read)
+    }
+
+    /// Verifies an inclusion proof against the light client of a gateway
+    pub fn verify_inclusion<BlockNumber: Decode>(
+        verify: PortalVerify,
+    ) -> Result<InclusionReceipt<BlockNumber>, ExecutorError> {
+        log_msg!(
+            "[INKSDK] verifying inclusion of {:?} bytes",
+            verify.proof().len()
+        );
+
+        ChainExtensionMethod::build(PORTAL_VERIFY_FUNCTION_CODE)
+            .input::<PortalVerify>()
+            .output_result::<InclusionReceipt<BlockNumber>, StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&verify)
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+
+    fn portal_read<Output: Decode>(read: PortalRead) -> Result<Output, ExecutorError> {
+        ChainExtensionMethod::build(PORTAL_READ_FUNCTION_CODE)
+            .input::<PortalRead>()
+            .output_result::<Output, StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&read)
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+}
+
 impl<Hash, AccountId, BlockNumber, Balance>
     StateHandler<Hash, ExecutionState<Hash, AccountId, BlockNumber, Balance>> for InkProvider
 where
@@ -73,8 +73,12 @@ impl RemoteEVMInstantOrderCommitLog {
     pub fn trim_signature(event_bytes: Vec<u8>) -> Result<Bytes, DispatchError> {
         // Cut off and trim the bytes of SFX ID as well
         let mut event_bytes = event_bytes;
-        // assert event_bytes length is correct and matches the length of the event 32 * 5 = 160
-        assert!(event_bytes.len() > 192);
+        // ensure event_bytes length is correct and matches the length of the event 32 * 5 = 160
+        if event_bytes.len() <= 192 {
+            return Err(DispatchError::Other(
+                "OrderCommitted log is too short to trim the signature off",
+            ))
+        }
         // repeat the bytes of Amount on the last 32 bytes overriding last 32 bytes of signature
         let amount_bytes = event_bytes[event_bytes.len() - 192..event_bytes.len() - 160].to_vec();
         event_bytes.truncate(event_bytes.len() - 32);
@@ -104,8 +108,10 @@ impl Decode for RemoteEVMInstantOrderCommitLog {
         signature.extend_from_slice(&signature_part_2[..]);
         signature.extend_from_slice(&signature_part_3[..1]);
 
-        // assert signature length is correct and matches the length of the signature 65
-        assert_eq!(signature_length, 65);
+        // ensure signature length is correct and matches the length of the signature 65
+        if signature_length != 65 {
+            return Err("OrderCommitted log signature must be 65 bytes long".into())
+        }
 
         Ok(RemoteEVMInstantOrderCommitLog {
             sfx_id,
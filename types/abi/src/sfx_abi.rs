@@ -100,6 +100,25 @@ impl SFXAbi {
         received_payload: Data,
         ordered_args_codec: &Codec,
         payload_codec: &Codec,
+    ) -> Result<(), DispatchError> {
+        self.validate_arguments_against_received_with_min_out(
+            ordered_args,
+            received_payload,
+            ordered_args_codec,
+            payload_codec,
+            &[],
+        )
+    }
+
+    /// Validates the received payload against ordered arguments, where arguments named in min_out_args
+    ///     are slippage bounds - the received amount must be greater or equal than the ordered one.
+    pub fn validate_arguments_against_received_with_min_out(
+        &self,
+        ordered_args: &Vec<Data>,
+        received_payload: Data,
+        ordered_args_codec: &Codec,
+        payload_codec: &Codec,
+        min_out_args: &[Name],
     ) -> Result<(), DispatchError> {
         self.ensure_arguments_order(ordered_args)?;
         let abi: Abi = self
@@ -152,7 +171,13 @@ impl SFXAbi {
             let recoded_payload: Data =
                 filled_abi_matched_by_name.recode_as(payload_codec, ordered_args_codec, true)?;
 
-            match recoded_payload == *ordered_arg {
+            let is_valid = if min_out_args.contains(current_arg_name) {
+                received_meets_min_out(&recoded_payload, ordered_arg)
+            } else {
+                recoded_payload == *ordered_arg
+            };
+
+            match is_valid {
                 true => continue,
                 false => {
                     log::error!(
@@ -168,3 +193,14 @@ impl SFXAbi {
         Ok(())
     }
 }
+
+/// Compares amounts recoded as Value128 - received must meet the ordered minimum.
+fn received_meets_min_out(received: &Data, min_out: &Data) -> bool {
+    match (
+        u128::decode(&mut &received[..]),
+        u128::decode(&mut &min_out[..]),
+    ) {
+        (Ok(received), Ok(min_out)) => received >= min_out,
+        _ => false,
+    }
+}
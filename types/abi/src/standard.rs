@@ -1,6 +1,6 @@
 use crate::{
     sfx_abi::{PerCodecAbiDescriptors, SFXAbi},
-    types::Sfx4bId,
+    types::{Name, Sfx4bId},
 };
 use sp_std::prelude::*;
 
@@ -37,6 +37,17 @@ pub fn standard_sfx_abi_ids() -> Vec<Sfx4bId> {
     ]
 }
 
+/// Arguments of the standard SFX which are slippage bounds of the DEX actions,
+///     confirmed as the minimum amount received rather than the exact one.
+pub fn standard_sfx_min_out_args(sfx_4b_id: Sfx4bId) -> Vec<Name> {
+    match &sfx_4b_id {
+        b"swap" => vec![b"amount_to".to_vec()],
+        b"aliq" => vec![b"amount_liquidity_token".to_vec()],
+        b"rliq" => vec![b"amount_left".to_vec(), b"amount_right".to_vec()],
+        _ => vec![],
+    }
+}
+
 pub fn get_dynamic_destination_deal_abi() -> SFXAbi {
     SFXAbi {
         args_names: vec![(b"asset_id".to_vec(), true), (b"amount".to_vec(), true)],
@@ -308,6 +319,71 @@ mod test_abi_standards {
         assert!(res.is_ok());
     }
 
+    fn scale_encoded_swap_event(amount_from: u128, amount_to: u128) -> Vec<u8> {
+        // Event index byte followed by an extra pallet event index byte
+        let mut swap_event = vec![2u8, 1u8];
+        swap_event.extend(AccountId32::new([4; 32]).encode()); // from
+        swap_event.extend(AccountId32::new([1; 32]).encode()); // to
+        swap_event.extend(amount_from.encode());
+        swap_event.extend(amount_to.encode());
+        swap_event.extend([5u8; 32]); // asset_from
+        swap_event.extend([6u8; 32]); // asset_to
+        swap_event
+    }
+
+    #[test]
+    fn test_swap_validate_arguments_against_received_accepts_amount_to_above_min_out() {
+        let swap_interface = get_swap_abi();
+        let ordered_args = vec![
+            AccountId32::new([1; 32]).encode(), // to
+            100u128.encode(),                   // amount_from
+            90u128.encode(),                    // amount_to - min out
+            [5u8; 32].to_vec(),                 // asset_from
+            [6u8; 32].to_vec(),                 // asset_to
+        ];
+
+        for received_amount_to in [90u128, 120u128] {
+            let res = swap_interface.validate_arguments_against_received_with_min_out(
+                &ordered_args,
+                scale_encoded_swap_event(100u128, received_amount_to),
+                &Codec::Scale,
+                &Codec::Scale,
+                &standard_sfx_min_out_args(*b"swap"),
+            );
+            assert_eq!(res, Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_swap_validate_arguments_against_received_rejects_amount_to_below_min_out() {
+        let swap_interface = get_swap_abi();
+        let ordered_args = vec![
+            AccountId32::new([1; 32]).encode(), // to
+            100u128.encode(),                   // amount_from
+            90u128.encode(),                    // amount_to - min out
+            [5u8; 32].to_vec(),                 // asset_from
+            [6u8; 32].to_vec(),                 // asset_to
+        ];
+
+        let res = swap_interface.validate_arguments_against_received_with_min_out(
+            &ordered_args,
+            scale_encoded_swap_event(100u128, 89u128),
+            &Codec::Scale,
+            &Codec::Scale,
+            &standard_sfx_min_out_args(*b"swap"),
+        );
+        assert!(res.is_err());
+
+        // Without the min out bounds, amount_to must match exactly
+        let res = swap_interface.validate_arguments_against_received(
+            &ordered_args,
+            scale_encoded_swap_event(100u128, 120u128),
+            &Codec::Scale,
+            &Codec::Scale,
+        );
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_dynamic_destination_deal_to_validate_arguments_against_received_substrate_balances_event(
     ) {
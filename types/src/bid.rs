@@ -1,5 +1,7 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 
 /// All Executors from the active set can bid for SFX executions in order to claim the rewards (max_fee) set by users,
@@ -8,6 +10,7 @@ use sp_runtime::RuntimeDebug;
 ///     other Optimistic Executors co-executing given Xtx with their bonded collateral (reserved_bond)
 /// Their balance
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SFXBid<AccountId, BalanceOf, AssetId> {
     /// Bid amount - always below SFX::max_fee requested by a user
     pub amount: BalanceOf,
@@ -65,3 +68,17 @@ impl<AccountId: Encode, BalanceOf, AssetId> SFXBid<AccountId, BalanceOf, AssetId
         Hasher::hash(sfx_id_and_index.as_ref())
     }
 }
+
+/// Executor's commitment to a bid for SFX auctioned in AuctionMode::SealedBid.
+///     The bid carries the collateral deposited at commit time, sized as for a bid at SFX::max_reward,
+///     and gets its amount replaced by the revealed one.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SealedSFXBid<Hash, AccountId, BalanceOf, AssetId> {
+    /// Hash of SFX id, executor, bid amount and salt
+    pub commitment: Hash,
+    /// Bid backing the commitment
+    pub bid: SFXBid<AccountId, BalanceOf, AssetId>,
+    /// Whether the executor revealed the bid matching the commitment
+    pub revealed: bool,
+}
@@ -3,12 +3,9 @@ pub use crate::{
     bid::SFXBid,
     fsx::{FullSideEffect, SideEffectId},
 };
-use bytes::Buf;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, Encode, Input, MaxEncodedLen};
 #[cfg(feature = "runtime")]
 use num::Zero;
-#[cfg(feature = "runtime")]
-use scale_info::prelude::collections::VecDeque;
 use scale_info::{
     prelude::{fmt::Debug, vec, vec::Vec},
     TypeInfo,
@@ -136,17 +133,19 @@ where
         egress_codec: &Codec,
         ingress_codec: &Codec,
     ) -> Result<(), DispatchError> {
-        sfx_abi.validate_arguments_against_received(
+        sfx_abi.validate_arguments_against_received_with_min_out(
             &self.encoded_args,
             ingress_payload,
             egress_codec,
             ingress_codec,
+            &t3rn_abi::standard::standard_sfx_min_out_args(self.action),
         )
     }
 }
 
 #[cfg(feature = "runtime")]
-/// Decode the side effect from encoded Chain.
+/// Decode the side effect from encoded Chain of 3VM SDK, laying out the arguments
+///     in the order of the standard SFX ABI of the action.
 impl<AccountId, BalanceOf> TryFrom<Vec<u8>> for SideEffect<AccountId, BalanceOf>
 where
     AccountId: Encode + MaxEncodedLen,
@@ -155,26 +154,27 @@ where
     type Error = &'static str;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-        let mut bytes: VecDeque<u8> = bytes.into();
-        let mut take_next = || bytes.pop_front().ok_or("no more bytes");
+        let input = &mut &bytes[..];
+        let mut take_next = || input.read_byte().map_err(|_| "no more bytes");
 
         let target: TargetId = TargetByte(take_next()?).try_into()?;
         let action = Action::try_from(take_next()?)?;
 
-        let bytes: Vec<u8> = bytes.into();
-        let args = extract_args::<AccountId, BalanceOf, [u8; 32], Insurance<BalanceOf>>(
-            &action,
-            &mut bytes::Bytes::from(bytes),
-        )?;
+        let (action, args, maybe_insurance) =
+            extract_args::<AccountId, BalanceOf, [u8; 32]>(action, input)?;
         let action_bytes: [u8; 4] = action.into();
+        let (insurance, max_reward) = match maybe_insurance {
+            Some(Insurance { insurance, reward }) => (insurance, reward),
+            None => (Zero::zero(), Zero::zero()),
+        };
 
         Ok(SideEffect::<AccountId, BalanceOf> {
             target,
-            max_reward: Zero::zero(),
+            max_reward,
             action: action_bytes,
             encoded_args: args,
             signature: vec![],
-            insurance: Zero::zero(),
+            insurance,
             enforce_executor: None,
             reward_asset_id: None,
         })
@@ -218,7 +218,7 @@ impl TryFrom<u8> for Action {
             1 => Ok(Action::TransferMulti),
             2 => Ok(Action::AddLiquidity),
             3 => Ok(Action::Swap),
-            4 => Ok(Action::Call), // This needs to be structured nicer
+            4 => Ok(Action::Call), // resolved to CallEvm or CallWasm by the VM of operation
             5 => Ok(Action::Data),
             _ => Err("Invalid action id"),
         }
@@ -236,154 +236,154 @@ impl From<Action> for [u8; 4] {
             Action::CallWasm => *WASM_CALL_SIDE_EFFECT_ID,
             Action::CallComposable => *COMPOSABLE_CALL_SIDE_EFFECT_ID,
             Action::Data => *DATA_SIDE_EFFECT_ID,
-            Action::TransferMulti => *MULTI_TRANSFER_SIDE_EFFECT_ID,
+            Action::TransferMulti => *ASSETS_TRANSFER_SIDE_EFFECT_ID,
         }
     }
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
 pub struct Insurance<Balance> {
-    insurance: Balance,
-    reward: Balance,
+    pub insurance: Balance,
+    pub reward: Balance,
 }
 
+type ExtractedArgs<Balance> = (Action, Vec<Bytes>, Option<Insurance<Balance>>);
+
+/// Lays out the arguments of SDK operation in the order of the standard SFX ABI.
+///     Caller of the operation is dropped, since the requester of Xtx is the contract submitting it.
+///     Call resolves to either cevm or wasm action, depending on the VM of the operation.
 fn extract_args<
     AccountId: MaxEncodedLen,
-    BalanceOf: MaxEncodedLen,
+    BalanceOf: Decode + MaxEncodedLen,
     Hash: MaxEncodedLen,
-    Insurance: MaxEncodedLen,
 >(
-    action: &Action,
-    bytes: &mut bytes::Bytes,
-) -> Result<Vec<Bytes>, &'static str> {
-    let mut args = Vec::new();
-
-    match *action {
+    action: Action,
+    input: &mut &[u8],
+) -> Result<ExtractedArgs<BalanceOf>, &'static str> {
+    let account_len = AccountId::max_encoded_len();
+    let balance_len = BalanceOf::max_encoded_len();
+    let hash_len = Hash::max_encoded_len();
+
+    match action {
         Action::Transfer => {
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // from
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // to
-
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt
-
-            take_insurance::<BalanceOf>(bytes, &mut args);
+            take(input, account_len)?; // caller
+            let args = vec![
+                take(input, account_len)?, // to
+                take(input, balance_len)?, // amount
+            ];
 
-            Ok(args)
+            Ok((action, args, take_insurance::<BalanceOf>(input)?))
+        },
+        Action::TransferMulti => {
+            // Assets are identified by the first 4 bytes of the asset hash
+            let asset = take(input, hash_len)?;
+            let asset_id = asset.get(..4).ok_or("asset shorter than 4 bytes")?.to_vec();
+            take(input, account_len)?; // caller
+            let args = vec![
+                asset_id,                  // asset_id
+                take(input, account_len)?, // to
+                take(input, balance_len)?, // amount
+            ];
+
+            Ok((action, args, take_insurance::<BalanceOf>(input)?))
         },
         Action::AddLiquidity => {
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // from
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // to
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // asset_left
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // asset_right
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // liquidity_token
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt_left
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt_right
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt_liquidity_token
-            take_insurance::<BalanceOf>(bytes, &mut args);
-
-            Ok(args)
+            take(input, account_len)?; // caller
+            let args = vec![
+                take(input, account_len)?, // to
+                take(input, hash_len)?,    // asset_left
+                take(input, hash_len)?,    // asset_right
+                take(input, hash_len)?,    // liquidity_token
+                take(input, balance_len)?, // amount_left
+                take(input, balance_len)?, // amount_right
+                take(input, balance_len)?, // amount_liquidity_token
+            ];
+
+            Ok((action, args, take_insurance::<BalanceOf>(input)?))
         },
         Action::Swap => {
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // from
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // to
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt_left
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt_right
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // asset_left
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // asset_right
-            take_insurance::<BalanceOf>(bytes, &mut args);
-
-            Ok(args)
+            take(input, account_len)?; // caller
+            let args = vec![
+                take(input, account_len)?, // to
+                take(input, balance_len)?, // amount_from
+                take(input, balance_len)?, // amount_to
+                take(input, hash_len)?,    // asset_from
+                take(input, hash_len)?,    // asset_to
+            ];
+
+            Ok((action, args, take_insurance::<BalanceOf>(input)?))
         },
         Action::Call => {
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // caller
-
-            // now we check the VM
-            match bytes.first() {
-                Some(byte) if byte == &0_u8 => {
-                    // its an evm op, get rid of that byte
-                    bytes.advance(1);
-                    args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // dest
-                    args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec());
-                    // value
-                },
-                Some(byte) if byte == &1_u8 => {
-                    // its a wasm op, get rid of that byte
-                    bytes.advance(1);
-                    args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // dest
-                    args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // value
-                    args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // gas_limit
-
-                    match bytes.first() {
-                        Some(byte) if byte == &0_u8 => args.push(vec![*byte]),
-                        Some(byte) if byte == &1_u8 => {
-                            match bytes.first() {
-                                Some(byte) if byte == &0_u8 => args.push(vec![*byte]),
-                                Some(byte) if byte == &1_u8 => {
-                                    bytes.advance(1);
-                                    args.push(
-                                        bytes.split_to(BalanceOf::max_encoded_len()).to_vec(),
-                                    ); // storage_limit
-                                },
-                                _ => {},
-                            }
-                        },
-                        _ => {},
-                    }
+            take(input, account_len)?; // caller
+
+            match input.read_byte().map_err(|_| "no more bytes")? {
+                0 => {
+                    // EVM addresses are carried as accounts padded with 12 bytes
+                    let mut target = take(input, account_len)?;
+                    target.truncate(20);
+                    let value = take(input, balance_len)?;
+                    let args = vec![
+                        target,            // target
+                        value,             // value
+                        take_data(input)?, // input
+                        vec![],            // gas_limit
+                        vec![],            // max_fee_per_gas
+                        vec![],            // max_priority_fee_per_gas
+                        vec![],            // nonce
+                        vec![],            // access_list
+                    ];
+
+                    Ok((Action::CallEvm, args, None))
                 },
-                _ => {
-                    // should die
+                1 => {
+                    let contract = take(input, account_len)?;
+                    let value = take(input, balance_len)?;
+                    let gas_limit = take(input, balance_len)?;
+                    let storage_deposit_limit = match input.read_byte() {
+                        Ok(0) => vec![],
+                        Ok(1) => take(input, balance_len)?,
+                        _ => return Err("Invalid storage limit"),
+                    };
+                    let args = vec![
+                        contract,              // contract
+                        value,                 // value
+                        gas_limit,             // gas_limit
+                        storage_deposit_limit, // storage_deposit_limit
+                        take_data(input)?,     // input
+                    ];
+
+                    Ok((Action::CallWasm, args, None))
                 },
+                _ => Err("Invalid VM id"),
             }
-
-            // remove the length of the input
-            bytes.advance(1);
-
-            args.push(bytes.to_vec()); // data
-
-            Ok(args)
-        },
-        Action::CallEvm => Ok(args),
-        Action::CallWasm => Ok(args),
-        Action::CallComposable => {
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // dest
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // value
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // gas
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // storage
-            args.push(bytes.to_vec()); // data
-
-            Ok(args)
-        },
-        Action::TransferMulti => {
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // asset id
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // from
-            args.push(bytes.split_to(AccountId::max_encoded_len()).to_vec()); // to
-            args.push(bytes.split_to(BalanceOf::max_encoded_len()).to_vec()); // amt
-            take_insurance::<BalanceOf>(bytes, &mut args);
-
-            Ok(args)
-        },
-        Action::Data => {
-            args.push(bytes.split_to(Hash::max_encoded_len()).to_vec()); // key
-
-            Ok(args)
         },
+        Action::CallEvm | Action::CallWasm | Action::CallComposable =>
+            Err("Call VM must be resolved from the operation"),
+        Action::Data => Ok((action, vec![take(input, hash_len)?], None)), // key
     }
 }
 
-fn take_insurance<Balance: MaxEncodedLen>(bytes: &mut bytes::Bytes, args: &mut Vec<Vec<u8>>) {
-    match bytes.first() {
-        Some(byte) if byte == &0_u8 => args.push(vec![]),
-        Some(byte) if byte == &1_u8 => {
-            args.push(
-                bytes
-                    .split_to(Option::<Insurance<Balance>>::max_encoded_len())
-                    .to_vec(),
-            ); // opt insurance
-        },
-        _ => {},
+fn take(input: &mut &[u8], len: usize) -> Result<Bytes, &'static str> {
+    if input.len() < len {
+        return Err("no more bytes")
     }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken.to_vec())
+}
+
+fn take_data(input: &mut &[u8]) -> Result<Bytes, &'static str> {
+    Vec::<u8>::decode(input).map_err(|_| "Invalid call data")
+}
+
+fn take_insurance<Balance: Decode>(
+    input: &mut &[u8],
+) -> Result<Option<Insurance<Balance>>, &'static str> {
+    Option::<Insurance<Balance>>::decode(input).map_err(|_| "Invalid insurance")
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ConfirmationOutcome {
     #[default]
     Success,
@@ -396,6 +396,7 @@ pub enum ConfirmationOutcome {
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ConfirmedSideEffect<AccountId, BlockNumber, BalanceOf> {
     pub err: Option<ConfirmationOutcome>,
     pub output: Option<Bytes>,
@@ -413,6 +414,18 @@ pub enum SecurityLvl {
     Escrow,
 }
 
+/// Auction format executors compete in for the SFX of an Xtx, chosen by the requester at order time.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AuctionMode {
+    /// Descending auction - every bid is public and replaces the current best bid right away.
+    #[default]
+    Open,
+    /// Executors commit to a hash of their bid and a salt while bidding is open
+    ///     and reveal it once bidding closes. The lowest valid reveal wins.
+    SealedBid,
+}
+
 // Side effects conversion error.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
@@ -510,258 +523,206 @@ mod tests {
         );
     }
 
-    // fixme: Revisit t3rn_sdk_primitives and update TryFrom SideEffect new interface changed
-    //  after Executors Bidding (t3rn/t3rn#477)
-    // use t3rn_sdk_primitives::{
-    //     storage::BoundedVec,
-    //     xc::{Call, Chain, Operation, VM},
-    // };
-    // #[test]
-    // fn encoded_evm_call_to_side_effect() {
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::Call(box Call {
-    //                 caller: ALICE,
-    //                 call: VM::<AccountId, BalanceOf>::Evm {
-    //                     dest: BOB,
-    //                     value: 50,
-    //                 },
-    //                 data: t3rn_sdk_primitives::storage::BoundedVec::<u8, 1024>::from_iter(vec![
-    //                     0_u8, 1_u8, 2_u8,
-    //                 ]),
-    //             }),
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *CALL_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             50_u128.encode(),
-    //             vec![0, 1, 2]
-    //         ]
-    //     );
-    // }
-    //
-    // #[test]
-    // fn encoded_wasm_call_to_side_effect() {
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::Call(box Call {
-    //                 caller: ALICE,
-    //                 call: VM::<AccountId, BalanceOf>::Wasm {
-    //                     dest: BOB,
-    //                     value: 50,
-    //                     gas_limit: 60,
-    //                     storage_limit: Some(70),
-    //                 },
-    //                 data: BoundedVec::<u8, 1024>::from_iter(vec![0_u8, 1_u8, 2_u8]),
-    //             }),
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *CALL_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             50_u128.encode(),
-    //             60_u128.encode(),
-    //             70_u128.encode(),
-    //             vec![0, 1, 2]
-    //         ]
-    //     );
-    // }
-    //
-    // #[test]
-    // fn encoded_transfer_to_side_effect() {
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::Transfer {
-    //                 caller: ALICE,
-    //                 to: BOB,
-    //                 amount: 50,
-    //                 insurance: None,
-    //             },
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *TRANSFER_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             50_u128.encode(),
-    //             vec![]
-    //         ]
-    //     );
-    // }
-    //
-    // #[test]
-    // fn encoded_multi_transfer_to_side_effect() {
-    //     let asset = [5_u8; 32];
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::TransferMulti {
-    //                 asset,
-    //                 caller: ALICE,
-    //                 to: BOB,
-    //                 amount: 50,
-    //                 insurance: None,
-    //             },
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *MULTI_TRANSFER_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             asset.to_vec(),
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             50_u128.encode(),
-    //             vec![]
-    //         ]
-    //     );
-    // }
-    //
-    // #[test]
-    // fn encoded_aliq_to_side_effect() {
-    //     let amount_left = 1_u128;
-    //     let amount_right = 2_u128;
-    //     let amount_liquidity_token = 3_u128;
-    //     let liquidity_token = [4_u8; 32];
-    //     let asset_right = [3_u8; 32];
-    //     let asset_left = [2_u8; 32];
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::AddLiquidity {
-    //                 caller: ALICE,
-    //                 to: BOB,
-    //                 asset_left,
-    //                 asset_right,
-    //                 liquidity_token,
-    //                 amount_left,
-    //                 amount_right,
-    //                 amount_liquidity_token,
-    //                 insurance: None,
-    //             },
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *ADD_LIQUIDITY_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             asset_left.to_vec(),
-    //             asset_right.to_vec(),
-    //             liquidity_token.to_vec(),
-    //             amount_left.encode(),
-    //             amount_right.encode(),
-    //             amount_liquidity_token.encode(),
-    //             vec![]
-    //         ]
-    //     );
-    // }
-    //
-    // #[test]
-    // fn encoded_swap_to_side_effect() {
-    //     let amount_from = 1_u128;
-    //     let amount_to = 2_u128;
-    //     let asset_from = [3_u8; 32];
-    //     let asset_to = [2_u8; 32];
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::Swap {
-    //                 caller: ALICE,
-    //                 to: BOB,
-    //                 amount_from,
-    //                 amount_to,
-    //                 asset_from,
-    //                 asset_to,
-    //                 insurance: None,
-    //             },
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *SWAP_SIDE_EFFECT_ID);
-    //     assert_eq!(
-    //         s.encoded_args,
-    //         vec![
-    //             [1_u8; 32].to_vec(),
-    //             [2_u8; 32].to_vec(),
-    //             amount_from.encode(),
-    //             amount_to.encode(),
-    //             asset_from.to_vec(),
-    //             asset_to.to_vec(),
-    //             vec![]
-    //         ]
-    //     );
-    // }
-    // #[test]
-    // fn encoded_data_to_side_effect() {
-    //     let index = [3_u8; 32];
-    //     let se =
-    //         Chain::<AccountId, BalanceOf, Hash>::Polkadot(
-    //             Operation::<AccountId, BalanceOf, Hash>::Data { index },
-    //         );
-    //     let bytes = se.encode();
-    //     let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
-    //
-    //     assert_eq!(s.target, *b"pdot");
-    //     assert_eq!(s.encoded_action, *DATA_SIDE_EFFECT_ID);
-    //     assert_eq!(s.encoded_args, vec![index.to_vec(),]);
-    // }
-    //
-    // #[test]
-    // fn from_encoded_chain_to_side_effect() {
-    //     let v: Vec<u8> = vec![
-    //         1, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    //         5, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    //         6, 6, 6, 6, 6, 6, 6, 6, 100, 0, 0, 0,
-    //     ];
-    //     let s = SideEffect::<[u8; 32], u32, u32>::try_from(v).unwrap();
-    //
-    //     assert_eq!(
-    //         s,
-    //         SideEffect {
-    //             target: [112, 100, 111, 116],
-    //             max_reward: 0,
-    //             encoded_action: vec![116, 114, 97, 110],
-    //             encoded_args: vec![
-    //                 vec![
-    //                     5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    //                     5, 5, 5, 5, 5, 5, 5
-    //                 ],
-    //                 vec![
-    //                     6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    //                     6, 6, 6, 6, 6, 6, 6
-    //                 ],
-    //                 vec![100, 0, 0, 0],
-    //             ],
-    //             signature: vec![],
-    //             insurance: 0,
-    //             nonce: 0,
-    //             enforce_executor: None
-    //         }
-    //     );
-    // }
+    #[cfg(feature = "runtime")]
+    mod from_sdk_chain {
+        use super::*;
+        use t3rn_sdk_primitives::{
+            storage::BoundedVec,
+            xc::{Call, Chain, Operation, VM},
+        };
+
+        type Hash = [u8; 32];
+
+        const ALICE: AccountId = AccountId32::new([1u8; 32]);
+        const BOB: AccountId = AccountId32::new([2u8; 32]);
+
+        #[test]
+        fn encoded_evm_call_to_side_effect() {
+            let se =
+                Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::Call(Box::new(Call {
+                    caller: ALICE,
+                    call: VM::Evm {
+                        dest: BOB,
+                        value: 50,
+                    },
+                    data: BoundedVec::<u8, 1024>::from_iter(vec![0_u8, 1_u8, 2_u8]),
+                })));
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.target, *b"pdot");
+            assert_eq!(s.action, *EVM_CALL_SIDE_EFFECT_ID);
+            assert_eq!(
+                s.encoded_args,
+                vec![
+                    [2_u8; 20].to_vec(),
+                    50_u128.encode(),
+                    vec![0, 1, 2],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                ]
+            );
+        }
+
+        #[test]
+        fn encoded_wasm_call_to_side_effect() {
+            let se =
+                Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::Call(Box::new(Call {
+                    caller: ALICE,
+                    call: VM::Wasm {
+                        dest: BOB,
+                        value: 50,
+                        gas_limit: 60,
+                        storage_limit: Some(70),
+                    },
+                    data: BoundedVec::<u8, 1024>::from_iter(vec![0_u8, 1_u8, 2_u8]),
+                })));
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.target, *b"pdot");
+            assert_eq!(s.action, *WASM_CALL_SIDE_EFFECT_ID);
+            assert_eq!(
+                s.encoded_args,
+                vec![
+                    [2_u8; 32].to_vec(),
+                    50_u128.encode(),
+                    60_u128.encode(),
+                    70_u128.encode(),
+                    vec![0, 1, 2]
+                ]
+            );
+        }
+
+        #[test]
+        fn encoded_transfer_with_insurance_to_side_effect() {
+            let se = Chain::<AccountId, BalanceOf, Hash>::Kusama(Operation::Transfer {
+                caller: ALICE,
+                to: BOB,
+                amount: 50,
+                insurance: None,
+            });
+            // SDK links its own instance of this crate, so swap the encoded insurance in place
+            let mut bytes = se.encode();
+            bytes.pop();
+            bytes.extend(
+                Some(Insurance::<BalanceOf> {
+                    insurance: 2,
+                    reward: 3,
+                })
+                .encode(),
+            );
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(bytes).unwrap();
+
+            assert_eq!(s.target, *b"ksma");
+            assert_eq!(s.action, *TRANSFER_SIDE_EFFECT_ID);
+            assert_eq!(s.encoded_args, vec![[2_u8; 32].to_vec(), 50_u128.encode()]);
+            assert_eq!(s.insurance, 2);
+            assert_eq!(s.max_reward, 3);
+        }
+
+        #[test]
+        fn encoded_multi_transfer_to_side_effect() {
+            let mut asset = [0_u8; 32];
+            asset[..4].copy_from_slice(&5_u32.to_le_bytes());
+            let se = Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::TransferMulti {
+                asset,
+                caller: ALICE,
+                to: BOB,
+                amount: 50,
+                insurance: None,
+            });
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.action, *ASSETS_TRANSFER_SIDE_EFFECT_ID);
+            assert_eq!(
+                s.encoded_args,
+                vec![5_u32.encode(), [2_u8; 32].to_vec(), 50_u128.encode()]
+            );
+        }
+
+        #[test]
+        fn encoded_aliq_to_side_effect() {
+            let se = Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::AddLiquidity {
+                caller: ALICE,
+                to: BOB,
+                asset_left: [2_u8; 32],
+                asset_right: [3_u8; 32],
+                liquidity_token: [4_u8; 32],
+                amount_left: 1,
+                amount_right: 2,
+                amount_liquidity_token: 3,
+                insurance: None,
+            });
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.action, *ADD_LIQUIDITY_SIDE_EFFECT_ID);
+            assert_eq!(
+                s.encoded_args,
+                vec![
+                    [2_u8; 32].to_vec(),
+                    [2_u8; 32].to_vec(),
+                    [3_u8; 32].to_vec(),
+                    [4_u8; 32].to_vec(),
+                    1_u128.encode(),
+                    2_u128.encode(),
+                    3_u128.encode(),
+                ]
+            );
+        }
+
+        #[test]
+        fn encoded_swap_to_side_effect() {
+            let se = Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::Swap {
+                caller: ALICE,
+                to: BOB,
+                amount_from: 1,
+                amount_to: 2,
+                asset_from: [3_u8; 32],
+                asset_to: [4_u8; 32],
+                insurance: None,
+            });
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.action, *SWAP_SIDE_EFFECT_ID);
+            assert_eq!(
+                s.encoded_args,
+                vec![
+                    [2_u8; 32].to_vec(),
+                    1_u128.encode(),
+                    2_u128.encode(),
+                    [3_u8; 32].to_vec(),
+                    [4_u8; 32].to_vec(),
+                ]
+            );
+        }
+
+        #[test]
+        fn encoded_data_to_side_effect() {
+            let index = [3_u8; 32];
+            let se = Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::Data { index });
+            let s = SideEffect::<AccountId, BalanceOf>::try_from(se.encode()).unwrap();
+
+            assert_eq!(s.action, *DATA_SIDE_EFFECT_ID);
+            assert_eq!(s.encoded_args, vec![index.to_vec()]);
+        }
+
+        #[test]
+        fn truncated_chain_fails_to_convert() {
+            let se = Chain::<AccountId, BalanceOf, Hash>::Polkadot(Operation::Transfer {
+                caller: ALICE,
+                to: BOB,
+                amount: 50,
+                insurance: None,
+            });
+            let mut bytes = se.encode();
+            bytes.truncate(40);
+
+            assert_eq!(
+                SideEffect::<AccountId, BalanceOf>::try_from(bytes),
+                Err("no more bytes")
+            );
+        }
+    }
 }